@@ -0,0 +1,58 @@
+// Compares drawing many rectangles one call at a time against the batched API
+//
+// Run with `cargo bench --bench batch`
+
+use std::time::{Duration, Instant};
+
+use farba::{Canvas, RGBAColor};
+
+const CANVAS_WIDTH: usize = 800;
+const CANVAS_HEIGHT: usize = 600;
+const RECT_COUNT: usize = 100_000;
+const ITERATIONS: u32 = 20;
+
+fn main() {
+    // Simple xorshift so the benchmark doesn't need any dependencies
+    let mut seed = 0x2545F491u32;
+    let mut random = move |max: i32| {
+        seed ^= seed << 13;
+        seed ^= seed >> 17;
+        seed ^= seed << 5;
+        (seed % max as u32) as i32
+    };
+
+    let rects: Vec<(i32, i32, i32, i32)> = (0..RECT_COUNT)
+        .map(|_| {
+            (
+                random(CANVAS_WIDTH as i32 + 20) - 10,
+                random(CANVAS_HEIGHT as i32 + 20) - 10,
+                random(16) + 1,
+                random(16) + 1,
+            )
+        })
+        .collect();
+
+    let mut canvas = Canvas::new(CANVAS_WIDTH, CANVAS_HEIGHT);
+
+    let singular = time(|| {
+        for &(x, y, width, height) in &rects {
+            canvas.rect(x, y, width, height, RGBAColor::MAGENTA);
+        }
+    });
+
+    let batched = time(|| canvas.rects(&rects, RGBAColor::MAGENTA));
+
+    println!("{RECT_COUNT} rects, average of {ITERATIONS} iterations");
+    println!("  rect (loop): {singular:?}");
+    println!("  rects:       {batched:?}");
+}
+
+fn time<F: FnMut()>(mut f: F) -> Duration {
+    let start = Instant::now();
+
+    for _ in 0..ITERATIONS {
+        f();
+    }
+
+    start.elapsed() / ITERATIONS
+}
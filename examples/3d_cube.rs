@@ -6,7 +6,7 @@
 
 #![allow(unused)]
 
-use farba::{Canvas, Mat3, RGBAColor, Vec3};
+use farba::{Aabb3, Canvas, DepthBuffer, Frustum, Mat3, Mat4, Mesh, Plane, RGBAColor, Vec3, Vec4};
 
 const CANVAS_WIDTH: usize = 400;
 const CANVAS_HEIGHT: usize = 400;
@@ -19,16 +19,26 @@ fn main() {
     #[cfg(feature = "image")]
     render_frame_sequence(canvas, model, camera);
 
+    #[cfg(feature = "gif")]
+    render_gif(canvas, model, camera);
+
     #[cfg(feature = "window")]
     render_window(canvas, model, camera);
 
+    #[cfg(not(any(feature = "image", feature = "gif", feature = "window")))]
+    eprintln!(
+        "For this example, enable either the \"image\" feature to render a png sequence, \
+         the \"gif\" feature to render an animated gif, or the \"window\" feature to \
+         render an animated window"
+    );
+
     #[cfg(any(
-        not(any(feature = "image", feature = "window")),
-        all(feature = "image", feature = "window")
+        all(feature = "image", feature = "gif"),
+        all(feature = "image", feature = "window"),
+        all(feature = "gif", feature = "window")
     ))]
     compile_error!(
-        "For this example, enable either the \"image\" feature to render a png sequence \
-         or the \"window\" feature to render an animated window (not both)"
+        "For this example, enable only one of the \"image\", \"gif\", or \"window\" features"
     );
 }
 
@@ -36,16 +46,36 @@ fn main() {
 fn render_frame_sequence(mut canvas: Canvas, mut model: Model, camera: Camera) {
     std::fs::create_dir_all("./examples/3d_cube").expect("Could not create directory");
 
+    let mut buffers = FrameBuffers::new();
+
     for t in 0..180 {
-        render_frame(t as f32, &mut canvas, &mut model, &camera);
+        render_frame(t as f32, &mut canvas, &mut model, &camera, &mut buffers);
 
         canvas.save_to_file(&format!("./examples/3d_cube/{t}.png"));
     }
 }
 
+#[cfg(feature = "gif")]
+fn render_gif(mut canvas: Canvas, mut model: Model, camera: Camera) {
+    use farba::GifRecorder;
+
+    let mut recorder = GifRecorder::new("./examples/3d_cube.gif", CANVAS_WIDTH, CANVAS_HEIGHT, 30);
+    let mut buffers = FrameBuffers::new();
+
+    for t in 0..180 {
+        render_frame(t as f32, &mut canvas, &mut model, &camera, &mut buffers);
+
+        recorder
+            .push_frame(&canvas)
+            .expect("Could not record frame");
+    }
+
+    recorder.finish().expect("Could not save gif");
+}
+
 #[cfg(feature = "window")]
 fn render_window(mut canvas: Canvas, mut model: Model, camera: Camera) {
-    use farba::Color;
+    use farba::ChannelOrder;
     use minifb::{Key, Window, WindowOptions};
 
     let mut window = Window::new(
@@ -62,21 +92,14 @@ fn render_window(mut canvas: Canvas, mut model: Model, camera: Camera) {
     window.limit_update_rate(Some(std::time::Duration::from_micros(16600)));
 
     let mut t = 0;
+    let mut pixels = Vec::new();
+    let mut buffers = FrameBuffers::new();
 
     while window.is_open() && !window.is_key_down(Key::Escape) {
-        render_frame(t as f32, &mut canvas, &mut model, &camera);
+        render_frame(t as f32, &mut canvas, &mut model, &camera, &mut buffers);
 
-        // minifb uses a weird ARGB ordering instead of the standard ABGR ordering
-        let pixels: Vec<u32> = canvas
-            .get_pixels()
-            .iter()
-            .map(|pixel| {
-                ((pixel.blue() as u32 & 0xFF) << (8 * 0))
-                    | ((pixel.green() as u32 & 0xFF) << (8 * 1))
-                    | ((pixel.red() as u32 & 0xFF) << (8 * 2))
-                    | ((pixel.alpha() as u32 & 0xFF) << (8 * 3))
-            })
-            .collect();
+        // minifb wants 0xAARRGGBB pixels instead of the canvas's own ordering
+        canvas.copy_pixels_in_order(ChannelOrder::Bgra, &mut pixels);
 
         // We unwrap here as we want this code to exit if it fails. Real applications may want to handle this in a different way
         window
@@ -96,7 +119,7 @@ struct Triangle3d {
 
 #[derive(Debug, Clone)]
 struct Model {
-    triangles: Vec<Triangle3d>,
+    mesh: Mesh,
     origin: Vec3,
     position: Vec3,
     scale: Vec3,
@@ -104,125 +127,30 @@ struct Model {
 }
 
 impl Model {
-    /// Creates a cube mesh by manually defining every single individual vertex
+    /// Creates a cube with a different color on each face
     fn create_cube() -> Model {
+        // In the order of the faces of the cube: -z, +x, +z, -x, +y and -y
+        let face_colors = [
+            RGBAColor::CYAN,
+            RGBAColor::RED,
+            RGBAColor::BLUE,
+            RGBAColor::MAGENTA,
+            RGBAColor::GREEN,
+            RGBAColor::YELLOW,
+        ];
+
+        let mut mesh = Mesh::cube();
+
+        // Each face has four vertices of its own
+        mesh.colors = Some(
+            face_colors
+                .into_iter()
+                .flat_map(|color| std::iter::repeat_n(color, 4))
+                .collect(),
+        );
+
         Model {
-            triangles: vec![
-                // Face 1
-                Triangle3d {
-                    vertices: [
-                        Vec3::new(-1.0, 1.0, -1.0),
-                        Vec3::new(1.0, -1.0, -1.0),
-                        Vec3::new(-1.0, -1.0, -1.0),
-                    ],
-                    normal: Vec3::new(0.0, 0.0, -1.0),
-                    color: RGBAColor::CYAN,
-                },
-                Triangle3d {
-                    vertices: [
-                        Vec3::new(-1.0, 1.0, -1.0),
-                        Vec3::new(1.0, 1.0, -1.0),
-                        Vec3::new(1.0, -1.0, -1.0),
-                    ],
-                    normal: Vec3::new(0.0, 0.0, -1.0),
-                    color: RGBAColor::CYAN,
-                },
-                // Face 2
-                Triangle3d {
-                    vertices: [
-                        Vec3::new(1.0, 1.0, -1.0),
-                        Vec3::new(1.0, -1.0, 1.0),
-                        Vec3::new(1.0, -1.0, -1.0),
-                    ],
-                    normal: Vec3::new(1.0, 0.0, 0.0),
-                    color: RGBAColor::RED,
-                },
-                Triangle3d {
-                    vertices: [
-                        Vec3::new(1.0, 1.0, -1.0),
-                        Vec3::new(1.0, 1.0, 1.0),
-                        Vec3::new(1.0, -1.0, 1.0),
-                    ],
-                    normal: Vec3::new(1.0, 0.0, 0.0),
-                    color: RGBAColor::RED,
-                },
-                // Face 3
-                Triangle3d {
-                    vertices: [
-                        Vec3::new(1.0, 1.0, 1.0),
-                        Vec3::new(-1.0, -1.0, 1.0),
-                        Vec3::new(1.0, -1.0, 1.0),
-                    ],
-                    normal: Vec3::new(0.0, 0.0, 1.0),
-                    color: RGBAColor::BLUE,
-                },
-                Triangle3d {
-                    vertices: [
-                        Vec3::new(1.0, 1.0, 1.0),
-                        Vec3::new(-1.0, 1.0, 1.0),
-                        Vec3::new(-1.0, -1.0, 1.0),
-                    ],
-                    normal: Vec3::new(0.0, 0.0, 1.0),
-                    color: RGBAColor::BLUE,
-                },
-                // Face 4
-                Triangle3d {
-                    vertices: [
-                        Vec3::new(-1.0, 1.0, 1.0),
-                        Vec3::new(-1.0, -1.0, -1.0),
-                        Vec3::new(-1.0, -1.0, 1.0),
-                    ],
-                    normal: Vec3::new(-1.0, 0.0, 0.0),
-                    color: RGBAColor::MAGENTA,
-                },
-                Triangle3d {
-                    vertices: [
-                        Vec3::new(-1.0, 1.0, 1.0),
-                        Vec3::new(-1.0, 1.0, -1.0),
-                        Vec3::new(-1.0, -1.0, -1.0),
-                    ],
-                    normal: Vec3::new(-1.0, 0.0, 0.0),
-                    color: RGBAColor::MAGENTA,
-                },
-                // Face 5
-                Triangle3d {
-                    vertices: [
-                        Vec3::new(1.0, 1.0, 1.0),
-                        Vec3::new(-1.0, 1.0, -1.0),
-                        Vec3::new(-1.0, 1.0, 1.0),
-                    ],
-                    normal: Vec3::new(0.0, 1.0, 0.0),
-                    color: RGBAColor::GREEN,
-                },
-                Triangle3d {
-                    vertices: [
-                        Vec3::new(1.0, 1.0, 1.0),
-                        Vec3::new(1.0, 1.0, -1.0),
-                        Vec3::new(-1.0, 1.0, -1.0),
-                    ],
-                    normal: Vec3::new(0.0, 1.0, 0.0),
-                    color: RGBAColor::GREEN,
-                },
-                // Face 6
-                Triangle3d {
-                    vertices: [
-                        Vec3::new(1.0, -1.0, 1.0),
-                        Vec3::new(-1.0, -1.0, 1.0),
-                        Vec3::new(-1.0, -1.0, -1.0),
-                    ],
-                    normal: Vec3::new(0.0, -1.0, 0.0),
-                    color: RGBAColor::YELLOW,
-                },
-                Triangle3d {
-                    vertices: [
-                        Vec3::new(1.0, -1.0, 1.0),
-                        Vec3::new(-1.0, -1.0, -1.0),
-                        Vec3::new(1.0, -1.0, -1.0),
-                    ],
-                    normal: Vec3::new(0.0, -1.0, 0.0),
-                    color: RGBAColor::YELLOW,
-                },
-            ],
+            mesh,
             origin: Vec3::ZERO,
             position: Vec3::ZERO,
             scale: Vec3::new(1.0, 1.0, 1.0),
@@ -233,48 +161,92 @@ impl Model {
 
 struct Camera {
     position: Vec3,
-    rotation: Vec3,
     look_at: Vec3,
+    up: Vec3,
 }
 
 impl Camera {
     pub fn new() -> Camera {
         Camera {
-            position: Vec3::new(0.0, 1.0, -2.0),
-            rotation: Vec3::new(15f32.to_radians(), 0.0, 0.0),
-            look_at: Vec3::new(0.0, 0.0, 1.0),
+            position: Vec3::new(0.0, 2.5, -5.0),
+            look_at: Vec3::ZERO,
+            up: Vec3::new(0.0, 1.0, 0.0),
         }
     }
 }
 
-fn render_frame(t: f32, canvas: &mut Canvas, model: &mut Model, camera: &Camera) {
+/// Buffers kept from one frame to the next, so that rendering a frame doesn't
+/// need to allocate
+struct FrameBuffers {
+    depth_buffer: DepthBuffer,
+    triangles: Vec<Triangle3d>,
+    clipped: Vec<Triangle3d>,
+}
+
+impl FrameBuffers {
+    fn new() -> FrameBuffers {
+        FrameBuffers {
+            depth_buffer: DepthBuffer::new(CANVAS_WIDTH, CANVAS_HEIGHT),
+            triangles: Vec::new(),
+            clipped: Vec::new(),
+        }
+    }
+}
+
+fn render_frame(
+    t: f32,
+    canvas: &mut Canvas,
+    model: &mut Model,
+    camera: &Camera,
+    buffers: &mut FrameBuffers,
+) {
     canvas.fill(RGBAColor::from_rgb(200, 200, 200));
+    buffers.depth_buffer.clear();
 
     model.rotation.y = (t * 4.0).to_radians();
     model.rotation.x = (t * 2.0).to_radians();
     model.rotation.z = (t * 1.0).to_radians();
 
-    let projected_triangles = transform_and_project(model, camera);
-
-    let mut depth_buffer: Vec<f32> = vec![f32::INFINITY; CANVAS_WIDTH * CANVAS_HEIGHT];
+    transform_and_project(model, camera, &mut buffers.triangles, &mut buffers.clipped);
 
-    for triangle in projected_triangles {
-        canvas.triangle_with_depth_buffer(
+    for triangle in &buffers.triangles {
+        canvas.triangle_aa(
             triangle.vertices[0],
             triangle.vertices[1],
             triangle.vertices[2],
-            triangle.color,
-            &mut depth_buffer,
+            triangle.color.clone(),
+            &mut buffers.depth_buffer,
         )
     }
 }
 
-fn transform_and_project(model: &Model, camera: &Camera) -> Vec<Triangle3d> {
-    let mut triangles = model.triangles.clone();
+/// Fills `triangles` with the triangles of the model in pixel coordinates,
+/// using `clipped` as scratch space
+fn transform_and_project(
+    model: &Model,
+    camera: &Camera,
+    triangles: &mut Vec<Triangle3d>,
+    clipped: &mut Vec<Triangle3d>,
+) {
+    let mesh = &model.mesh;
+    let colors = mesh.colors.as_deref().unwrap_or_default();
+
+    triangles.clear();
+    triangles.extend(
+        mesh.indices
+            .iter()
+            .enumerate()
+            .map(|(index, &[first, ..])| Triangle3d {
+                vertices: mesh.triangle(index),
+                normal: mesh.face_normal(index),
+                color: colors
+                    .get(first as usize)
+                    .cloned()
+                    .unwrap_or(RGBAColor::WHITE),
+            }),
+    );
 
-    let rotation_matrix = Mat3::rotate_z(model.rotation.z)
-        * Mat3::rotate_y(model.rotation.y)
-        * Mat3::rotate_x(model.rotation.x);
+    let rotation_matrix = Mat3::from_euler(model.rotation);
 
     // Convert triangles to world space
     triangles.iter_mut().for_each(|triangle| {
@@ -297,50 +269,54 @@ fn transform_and_project(model: &Model, camera: &Camera) -> Vec<Triangle3d> {
         });
     });
 
-    let camera_rotation_matrix = Mat3::rotate_z(-camera.rotation.z)
-        * Mat3::rotate_y(-camera.rotation.y)
-        * Mat3::rotate_x(-camera.rotation.x);
+    // Moves everything in the world opposite to the camera, i.e. if the camera
+    // moves to the left, everything else moves to the right. Likewise, the world
+    // is rotated so that the camera ends up looking down the z axis.
+    let view_matrix = Mat4::look_at(camera.position, camera.look_at, camera.up);
 
     // Convert world space to camera space
     triangles.iter_mut().for_each(|triangle| {
-        // Apply transformations to the vertices
         triangle.vertices.iter_mut().for_each(|vertex| {
-            // Move everything in the world opposite to the camera, i.e. if the
-            // camera moves to the left, everything else moves to the right.
-            *vertex -= camera.position;
-
-            // Likewise, you can perform rotations as well. If the camera rotates
-            // to the left with angle alpha, everything else rotates away from the
-            // camera to the right with angle -alpha.
-            *vertex = camera_rotation_matrix * *vertex
-
-            // TODO: Implement camera look_at
+            *vertex = (view_matrix * Vec4::from_vec3(*vertex, 1.0)).xyz();
         });
     });
 
     // TODO: Cull triangles who's normals are facing in the same direction as the camera using dot product
-    // TODO: Cull triangles completely outside the viewing frustum
-    // TODO: Clip triangles that are partially outside the viewing frustum by cutting them into 2 triangles
+    const NEAR: f32 = 0.1;
+    const FAR: f32 = 100.0;
+
+    let aspect = CANVAS_WIDTH as f32 / CANVAS_HEIGHT as f32;
+    let projection = Mat4::perspective(60f32.to_radians(), aspect, NEAR, FAR);
+
+    // Cull triangles completely outside the viewing frustum
+    let frustum = Frustum::from_matrix(&projection);
+    triangles.retain(|triangle| frustum.intersects_aabb(&Aabb3::from_points(triangle.vertices)));
+
+    // Cut away the parts of triangles closer to the camera than the near plane,
+    // since they can't be projected. Triangles with a corner cut off become two triangles.
+    let near_plane = Plane::from_point_normal(Vec3::new(0.0, 0.0, NEAR), Vec3::new(0.0, 0.0, 1.0));
+
+    clipped.clear();
+    clipped.extend(triangles.drain(..).flat_map(|triangle| {
+        let [a, b, c] = triangle.vertices;
+
+        near_plane
+            .clip_triangle(a, b, c)
+            .into_iter()
+            .map(move |vertices| Triangle3d {
+                vertices,
+                ..triangle.clone()
+            })
+    }));
+    std::mem::swap(triangles, clipped);
 
-    // Project triangles to 2 pixel coordinates
+    // Project triangles to pixel coordinates, keeping the depth of every vertex
+    // for the depth buffer
     triangles.iter_mut().for_each(|triangle| {
         triangle.vertices.iter_mut().for_each(|vertex| {
-            // 2d Projection
-            vertex.x /= (vertex.z + 10.0) * 0.1;
-            vertex.y /= (vertex.z + 10.0) * 0.1;
-
-            // Mirror across x axis so that we are not upside down
-            vertex.y *= -1.0;
-
-            // Scale up to pixel space
-            vertex.x *= CANVAS_WIDTH as f32 / 8.0;
-            vertex.y *= CANVAS_HEIGHT as f32 / 8.0;
-
-            // Translate (0, 0) to be in the center of the screen
-            vertex.x += CANVAS_WIDTH as f32 / 2.0;
-            vertex.y += CANVAS_HEIGHT as f32 / 2.0;
+            *vertex = projection
+                .project_point(*vertex, CANVAS_WIDTH, CANVAS_HEIGHT)
+                .expect("Clipped vertices are in front of the camera");
         })
     });
-
-    triangles
 }
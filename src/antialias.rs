@@ -0,0 +1,218 @@
+use crate::{Canvas, Mat3, Paint, Vec2};
+
+/// How the edges of a shape are smoothed when it is rasterized
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum AntiAliasing {
+    /// Hard edges where every pixel is either fully covered or not at all
+    None,
+    /// Coverage is estimated analytically from the distance between the pixel
+    /// and the edges of the shape, which is cheap and smooth but can overestimate
+    /// coverage near sharp corners
+    #[default]
+    EdgeCoverage,
+    /// Coverage is the fraction of this many sample points inside of the shape,
+    /// only evaluated for pixels near an edge. The samples are laid out in a
+    /// square grid, so the count is rounded up to the next square number
+    Multisample(u32),
+}
+
+/// Offsets from the sampling point of a pixel for `samples` evenly spaced
+/// sample points, all within half a pixel
+pub(crate) fn sample_offsets(samples: u32) -> Vec<(f32, f32)> {
+    let grid = (samples.max(1) as f32).sqrt().ceil() as u32;
+
+    let offset = |i: u32| (i as f32 + 0.5) / grid as f32 - 0.5;
+
+    (0..grid * grid)
+        .map(|i| (offset(i % grid), offset(i / grid)))
+        .collect()
+}
+
+/// Estimates how much of the pixel sampled at `(x, y)` is covered by a shape,
+/// given the signed `distance` (in pixels) from a point to the edge of the
+/// shape, which is positive on the inside
+///
+/// `samples` must be the `sample_offsets` for the sample count when
+/// multisampling, and is ignored otherwise
+pub(crate) fn coverage<F: Fn(f32, f32) -> f32>(
+    antialiasing: AntiAliasing,
+    samples: &[(f32, f32)],
+    x: f32,
+    y: f32,
+    distance: F,
+) -> f32 {
+    use std::f32::consts::FRAC_1_SQRT_2;
+
+    let center = distance(x, y);
+
+    match antialiasing {
+        AntiAliasing::None => (center >= 0.0) as u32 as f32,
+        // A pixel centered exactly on the edge is half covered
+        AntiAliasing::EdgeCoverage => (center + 0.5).clamp(0.0, 1.0),
+        // Pixels further than half a diagonal from the edge are entirely on one
+        // side of it, so only the ones in between need sampling
+        AntiAliasing::Multisample(_) if center >= FRAC_1_SQRT_2 => 1.0,
+        AntiAliasing::Multisample(_) if center <= -FRAC_1_SQRT_2 => 0.0,
+        AntiAliasing::Multisample(_) => {
+            let inside = samples
+                .iter()
+                .filter(|(dx, dy)| distance(x + dx, y + dy) >= 0.0)
+                .count();
+
+            inside as f32 / samples.len() as f32
+        }
+    }
+}
+
+impl Canvas {
+    /// Draws a circle at the provided center with the given radius, smoothing its
+    /// edge according to `antialiasing`
+    ///
+    /// Unlike [`Canvas::circle`], the center and radius may be fractional. Pixels
+    /// are sampled at their centers, `(x + 0.5, y + 0.5)`.
+    pub fn circle_antialiased<P: Paint>(
+        &mut self,
+        center: Vec2,
+        radius: f32,
+        paint: P,
+        antialiasing: AntiAliasing,
+    ) {
+        if radius <= 0.0 {
+            return;
+        }
+
+        let bounds = (
+            center.x - radius,
+            center.y - radius,
+            center.x + radius,
+            center.y + radius,
+        );
+
+        self.fill_coverage(bounds, antialiasing, &paint, |x, y| {
+            let (dx, dy) = (x - center.x, y - center.y);
+
+            radius - (dx * dx + dy * dy).sqrt()
+        });
+    }
+
+    /// Draws a rectangle with its top left corner at `(x, y)`, smoothing its edges
+    /// according to `antialiasing`
+    ///
+    /// Unlike [`Canvas::rect`], the position and size may be fractional, and the
+    /// size must be positive. Pixels are sampled at their centers,
+    /// `(x + 0.5, y + 0.5)`.
+    ///
+    /// ```
+    /// use farba::{AntiAliasing, Canvas, Color, RGBAColor};
+    ///
+    /// let mut canvas = Canvas::new(10, 10);
+    /// canvas.rect_antialiased(2.0, 2.0, 4.5, 4.0, RGBAColor::WHITE, AntiAliasing::Multisample(4));
+    ///
+    /// // The right edge cuts through the middle of the pixels in column 6
+    /// assert_eq!(canvas.get_pixel(5, 3).red(), 255);
+    /// assert_eq!(canvas.get_pixel(6, 3).red(), 128);
+    /// assert_eq!(canvas.get_pixel(7, 3).red(), 0);
+    /// ```
+    pub fn rect_antialiased<P: Paint>(
+        &mut self,
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+        paint: P,
+        antialiasing: AntiAliasing,
+    ) {
+        if width <= 0.0 || height <= 0.0 {
+            return;
+        }
+
+        let bounds = (x, y, x + width, y + height);
+
+        self.fill_coverage(bounds, antialiasing, &paint, |px, py| {
+            (px - bounds.0)
+                .min(bounds.2 - px)
+                .min(py - bounds.1)
+                .min(bounds.3 - py)
+        });
+    }
+
+    /// Blends `paint` onto every pixel within `(left, top, right, bottom)`
+    /// according to its coverage by the shape described by `distance`
+    ///
+    /// The bounds and distances are in the coordinates of draw calls, and are
+    /// mapped onto the canvas through the current transform
+    fn fill_coverage<P: Paint, F: Fn(f32, f32) -> f32>(
+        &mut self,
+        bounds: (f32, f32, f32, f32),
+        antialiasing: AntiAliasing,
+        paint: &P,
+        distance: F,
+    ) {
+        let samples = match antialiasing {
+            AntiAliasing::Multisample(count) => sample_offsets(count),
+            _ => Vec::new(),
+        };
+
+        let transformed = self.has_transform();
+        let inverse = self.get_inverse_transform();
+
+        // Distances grow and shrink along with the shape. This is only exact for
+        // transforms which scale both axes evenly
+        let Mat3 { a, b, d, e, .. } = self.get_transform();
+        let stretch = (a * e - b * d).abs().sqrt();
+
+        let to_local = |x: f32, y: f32| match transformed {
+            true => inverse.transform_point(Vec2::new(x, y)),
+            false => Vec2::new(x, y),
+        };
+
+        let canvas_distance = |x: f32, y: f32| match transformed {
+            true => {
+                let local = to_local(x, y);
+                distance(local.x, local.y) * stretch
+            }
+            false => distance(x, y),
+        };
+
+        let (mut left, mut top) = (f32::INFINITY, f32::INFINITY);
+        let (mut right, mut bottom) = (f32::NEG_INFINITY, f32::NEG_INFINITY);
+
+        for (x, y) in [
+            (bounds.0, bounds.1),
+            (bounds.2, bounds.1),
+            (bounds.0, bounds.3),
+            (bounds.2, bounds.3),
+        ] {
+            let corner = self.transform_point(Vec2::new(x, y));
+
+            left = left.min(corner.x);
+            top = top.min(corner.y);
+            right = right.max(corner.x);
+            bottom = bottom.max(corner.y);
+        }
+
+        // Include an extra pixel on every side for the partially covered fringe
+        let left_x = (left.floor() as i32 - 1).max(0);
+        let right_x = (right.ceil() as i32 + 1).min(self.get_width() as i32 - 1);
+        let top_y = (top.floor() as i32 - 1).max(0);
+        let bottom_y = (bottom.ceil() as i32 + 1).min(self.get_height() as i32 - 1);
+
+        for y in top_y..=bottom_y {
+            for x in left_x..=right_x {
+                let (sample_x, sample_y) = (x as f32 + 0.5, y as f32 + 0.5);
+
+                let coverage =
+                    coverage(antialiasing, &samples, sample_x, sample_y, canvas_distance);
+
+                if coverage <= 0.0 {
+                    continue;
+                }
+
+                let local = to_local(sample_x, sample_y);
+                let pixel_color = paint.color_at(local.x, local.y);
+
+                self.cover_pixel(x, y, pixel_color, coverage);
+            }
+        }
+    }
+}
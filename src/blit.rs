@@ -0,0 +1,789 @@
+use crate::{
+    normalize_rect, rgba, transform::affine_inverse, Canvas, Color, CompositeOp, Mat3, Vec2,
+};
+
+/// How an image is sampled when it is drawn at a different size
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Filter {
+    /// Takes the color of the nearest source pixel, which keeps pixel art crisp
+    #[default]
+    Nearest,
+    /// Mixes the four nearest source pixels, which smooths out photos and
+    /// interface elements
+    Bilinear,
+}
+
+/// Which ways an image is mirrored when it is drawn
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Flip {
+    #[default]
+    None,
+    /// Mirrored left to right
+    Horizontal,
+    /// Mirrored top to bottom
+    Vertical,
+    /// Mirrored both ways, which is the same as a half turn
+    Both,
+}
+
+impl Flip {
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Flip::Horizontal | Flip::Both)
+    }
+
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Flip::Vertical | Flip::Both)
+    }
+}
+
+impl Canvas {
+    /// Copies the area of `src` given by `src_rect` as `(x, y, width, height)`
+    /// onto the canvas, with the top left corner of the area landing on
+    /// `(dst_x, dst_y)`
+    ///
+    /// Only the part of the area inside of `src` is copied, and whatever lands
+    /// outside of the canvas (or the clip rectangle) is clipped. Negative sizes
+    /// are handled the same way as in [`Canvas::rect`]. Pixels are written with
+    /// the composite operator of the canvas like everything else, which replaces
+    /// the existing pixels by default. Like [`Canvas::fill`], blits work on whole
+    /// pixels and ignore the transform.
+    ///
+    /// ```
+    /// use farba::{Canvas, RGBAColor};
+    ///
+    /// let mut sprite = Canvas::new(4, 4);
+    /// sprite.fill(RGBAColor::BLUE);
+    /// sprite.set_pixel(3, 3, RGBAColor::RED);
+    ///
+    /// let mut canvas = Canvas::new(10, 10);
+    ///
+    /// // Copy the bottom right quarter of the sprite, partly off of the canvas
+    /// canvas.blit(&sprite, (2, 2, 2, 2), 8, 8);
+    ///
+    /// assert_eq!(*canvas.get_pixel(8, 8), RGBAColor::BLUE.into());
+    /// assert_eq!(*canvas.get_pixel(9, 9), RGBAColor::RED.into());
+    /// assert_eq!(*canvas.get_pixel(7, 7), 0);
+    /// ```
+    pub fn blit(&mut self, src: &Canvas, src_rect: (i32, i32, i32, i32), dst_x: i32, dst_y: i32) {
+        self.blit_pixels(
+            src,
+            src_rect,
+            dst_x,
+            dst_y,
+            Flip::None,
+            |canvas, x, y, color| {
+                canvas.write_pixel(x, y, color);
+            },
+        );
+    }
+
+    /// Copies an area of `src` onto the canvas like [`Canvas::blit`], mirrored
+    /// according to `flip`
+    ///
+    /// The mirrored area still has its top left corner on `(dst_x, dst_y)`.
+    ///
+    /// ```
+    /// use farba::{Canvas, Flip, RGBAColor};
+    ///
+    /// let mut sprite = Canvas::new(2, 1);
+    /// sprite.set_pixel(0, 0, RGBAColor::RED);
+    /// sprite.set_pixel(1, 0, RGBAColor::BLUE);
+    ///
+    /// let mut canvas = Canvas::new(10, 10);
+    /// canvas.blit_flipped(&sprite, (0, 0, 2, 1), 0, 0, Flip::Horizontal);
+    ///
+    /// assert_eq!(*canvas.get_pixel(0, 0), RGBAColor::BLUE.into());
+    /// assert_eq!(*canvas.get_pixel(1, 0), RGBAColor::RED.into());
+    /// ```
+    pub fn blit_flipped(
+        &mut self,
+        src: &Canvas,
+        src_rect: (i32, i32, i32, i32),
+        dst_x: i32,
+        dst_y: i32,
+        flip: Flip,
+    ) {
+        self.blit_pixels(src, src_rect, dst_x, dst_y, flip, |canvas, x, y, color| {
+            canvas.write_pixel(x, y, color);
+        });
+    }
+
+    /// Copies an area of `src` onto the canvas like [`Canvas::blit`], but blends
+    /// it over the existing pixels using the alpha of the source, so sprites
+    /// with transparent backgrounds composite onto the scene
+    ///
+    /// Source pixels are blended like with [`CompositeOp::SourceOver`] when the
+    /// canvas would otherwise copy them, or with the composite operator of the
+    /// canvas if it has been set to something else.
+    ///
+    /// ```
+    /// use farba::{Canvas, RGBAColor};
+    ///
+    /// // A red dot on a transparent background
+    /// let mut sprite = Canvas::new(3, 3);
+    /// sprite.set_pixel(1, 1, RGBAColor::RED);
+    ///
+    /// let mut canvas = Canvas::new(10, 10);
+    /// canvas.fill(RGBAColor::BLUE);
+    /// canvas.blit_blend(&sprite, (0, 0, 3, 3), 0, 0);
+    ///
+    /// assert_eq!(*canvas.get_pixel(1, 1), RGBAColor::RED.into());
+    /// assert_eq!(*canvas.get_pixel(0, 0), RGBAColor::BLUE.into());
+    /// ```
+    pub fn blit_blend(
+        &mut self,
+        src: &Canvas,
+        src_rect: (i32, i32, i32, i32),
+        dst_x: i32,
+        dst_y: i32,
+    ) {
+        self.with_composite_op(self.image_composite_op(), |canvas| {
+            canvas.blit(src, src_rect, dst_x, dst_y);
+        });
+    }
+
+    /// Copies an area of `src` onto the canvas like [`Canvas::blit`], but skips
+    /// every source pixel which has the same color as `key`, treating it as
+    /// fully transparent
+    ///
+    /// Only the red, green and blue channels are compared, which suits sprites
+    /// that mark their background with a color nothing else uses (such as
+    /// magenta) instead of an alpha channel.
+    ///
+    /// ```
+    /// use farba::{Canvas, RGBAColor};
+    ///
+    /// let mut sprite = Canvas::new(3, 3);
+    /// sprite.fill(RGBAColor::MAGENTA);
+    /// sprite.set_pixel(1, 1, RGBAColor::RED);
+    ///
+    /// let mut canvas = Canvas::new(10, 10);
+    /// canvas.fill(RGBAColor::BLUE);
+    /// canvas.blit_keyed(&sprite, (0, 0, 3, 3), 0, 0, RGBAColor::MAGENTA);
+    ///
+    /// assert_eq!(*canvas.get_pixel(1, 1), RGBAColor::RED.into());
+    /// assert_eq!(*canvas.get_pixel(0, 0), RGBAColor::BLUE.into());
+    /// ```
+    pub fn blit_keyed<C: Color>(
+        &mut self,
+        src: &Canvas,
+        src_rect: (i32, i32, i32, i32),
+        dst_x: i32,
+        dst_y: i32,
+        key: C,
+    ) {
+        let key = key.pack() & 0x00FFFFFF;
+
+        self.blit_pixels(
+            src,
+            src_rect,
+            dst_x,
+            dst_y,
+            Flip::None,
+            |canvas, x, y, color| {
+                if color & 0x00FFFFFF != key {
+                    canvas.write_pixel(x, y, color);
+                }
+            },
+        );
+    }
+
+    /// The composite operator images are blended with, which is source-over
+    /// unless the canvas has been set to something other than copying
+    pub(crate) fn image_composite_op(&self) -> CompositeOp {
+        match self.get_composite_op() {
+            CompositeOp::Copy => CompositeOp::SourceOver,
+            op => op,
+        }
+    }
+
+    /// Draws the area of `src` given by `src_rect` stretched to cover `dst_rect`
+    /// on the canvas, both given as `(x, y, width, height)`, sampling the
+    /// source with `filter`
+    ///
+    /// Each destination pixel samples the source at the point matching its
+    /// center, and only ever from inside of `src_rect`. Clipping and compositing
+    /// work the same way as in [`Canvas::blit`].
+    ///
+    /// ```
+    /// use farba::{Canvas, Filter, RGBAColor};
+    ///
+    /// let mut sprite = Canvas::new(2, 1);
+    /// sprite.set_pixel(0, 0, RGBAColor::RED);
+    /// sprite.set_pixel(1, 0, RGBAColor::BLUE);
+    ///
+    /// // Every source pixel becomes a 10x10 block
+    /// let mut canvas = Canvas::new(20, 10);
+    /// canvas.blit_scaled(&sprite, (0, 0, 2, 1), (0, 0, 20, 10), Filter::Nearest);
+    ///
+    /// assert_eq!(*canvas.get_pixel(9, 9), RGBAColor::RED.into());
+    /// assert_eq!(*canvas.get_pixel(10, 0), RGBAColor::BLUE.into());
+    /// ```
+    pub fn blit_scaled(
+        &mut self,
+        src: &Canvas,
+        src_rect: (i32, i32, i32, i32),
+        dst_rect: (i32, i32, i32, i32),
+        filter: Filter,
+    ) {
+        let (src_width, src_height) = (src.get_width() as i32, src.get_height() as i32);
+        let (canvas_width, canvas_height) = (self.get_width() as i32, self.get_height() as i32);
+
+        let (x, y, width, height) = src_rect;
+        let Some(source) = normalize_rect(x, y, width, height, src_width, src_height) else {
+            return;
+        };
+
+        let (x, y, width, height) = dst_rect;
+        let Some(dest) = normalize_rect(x, y, width, height, canvas_width, canvas_height) else {
+            return;
+        };
+
+        // Size of a destination pixel in source pixels
+        let scale_x =
+            (source.orig_x2 - source.orig_x1 + 1) as f32 / (dest.orig_x2 - dest.orig_x1 + 1) as f32;
+        let scale_y =
+            (source.orig_y2 - source.orig_y1 + 1) as f32 / (dest.orig_y2 - dest.orig_y1 + 1) as f32;
+
+        let bounds = (source.x1, source.y1, source.x2, source.y2);
+        let (left, top, right, bottom) = self.clip_bounds();
+
+        for y in dest.y1.max(top)..=dest.y2.min(bottom) {
+            // Map the center of the destination pixel into the source
+            let v = source.orig_y1 as f32 + ((y - dest.orig_y1) as f32 + 0.5) * scale_y;
+
+            for x in dest.x1.max(left)..=dest.x2.min(right) {
+                let u = source.orig_x1 as f32 + ((x - dest.orig_x1) as f32 + 0.5) * scale_x;
+
+                if let Some(color) = src.sample_filtered(u, v, bounds, filter) {
+                    self.write_pixel(x, y, color);
+                }
+            }
+        }
+    }
+
+    /// Draws the area of `src` given by `src_rect` as `(x, y, width, height)`
+    /// rotated by `angle` (in radians, clockwise on screen) around `pivot`, such
+    /// that the pivot lands on `dst`
+    ///
+    /// The pivot is relative to the top left corner of `src_rect`, so a pivot in
+    /// the middle of the area spins it in place. Every destination pixel is
+    /// mapped back into the source and sampled with `filter`, and pixels which
+    /// map outside of `src_rect` are left untouched. Clipping and compositing
+    /// work the same way as in [`Canvas::blit`].
+    ///
+    /// ```
+    /// use farba::{Canvas, Filter, RGBAColor, Vec2};
+    ///
+    /// let mut needle = Canvas::new(3, 1);
+    /// needle.set_pixel(0, 0, RGBAColor::RED);
+    /// needle.set_pixel(1, 0, RGBAColor::GREEN);
+    /// needle.set_pixel(2, 0, RGBAColor::BLUE);
+    ///
+    /// // Spin the needle a quarter turn around its middle pixel
+    /// let mut canvas = Canvas::new(20, 20);
+    /// canvas.blit_rotated(
+    ///     &needle,
+    ///     (0, 0, 3, 1),
+    ///     Vec2::new(1.5, 0.5),
+    ///     Vec2::new(10.5, 10.5),
+    ///     std::f32::consts::FRAC_PI_2,
+    ///     Filter::Nearest,
+    /// );
+    ///
+    /// assert_eq!(*canvas.get_pixel(10, 9), RGBAColor::RED.into());
+    /// assert_eq!(*canvas.get_pixel(10, 10), RGBAColor::GREEN.into());
+    /// assert_eq!(*canvas.get_pixel(10, 11), RGBAColor::BLUE.into());
+    /// ```
+    pub fn blit_rotated(
+        &mut self,
+        src: &Canvas,
+        src_rect: (i32, i32, i32, i32),
+        pivot: Vec2,
+        dst: Vec2,
+        angle: f32,
+        filter: Filter,
+    ) {
+        let (src_width, src_height) = (src.get_width() as i32, src.get_height() as i32);
+
+        let (x, y, width, height) = src_rect;
+        let Some(source) = normalize_rect(x, y, width, height, src_width, src_height) else {
+            return;
+        };
+
+        // Where the pivot is on the source canvas
+        let origin = Vec2::new(source.orig_x1 as f32, source.orig_y1 as f32) + pivot;
+
+        let (sin, cos) = angle.sin_cos();
+
+        // Find the bounding box of the rotated area on the canvas
+        let (mut min_x, mut min_y) = (f32::INFINITY, f32::INFINITY);
+        let (mut max_x, mut max_y) = (f32::NEG_INFINITY, f32::NEG_INFINITY);
+
+        for (x, y) in [
+            (source.orig_x1, source.orig_y1),
+            (source.orig_x2 + 1, source.orig_y1),
+            (source.orig_x1, source.orig_y2 + 1),
+            (source.orig_x2 + 1, source.orig_y2 + 1),
+        ] {
+            let offset = Vec2::new(x as f32, y as f32) - origin;
+            let corner_x = dst.x + offset.x * cos - offset.y * sin;
+            let corner_y = dst.y + offset.x * sin + offset.y * cos;
+
+            min_x = min_x.min(corner_x);
+            min_y = min_y.min(corner_y);
+            max_x = max_x.max(corner_x);
+            max_y = max_y.max(corner_y);
+        }
+
+        let (left, top, right, bottom) = self.clip_bounds();
+        let bounds = (source.x1, source.y1, source.x2, source.y2);
+
+        for y in (min_y.floor() as i32).max(top)..=(max_y.ceil() as i32).min(bottom) {
+            for x in (min_x.floor() as i32).max(left)..=(max_x.ceil() as i32).min(right) {
+                // Rotate the center of the destination pixel back into the source
+                let offset = Vec2::new(x as f32 + 0.5, y as f32 + 0.5) - dst;
+                let u = origin.x + offset.x * cos + offset.y * sin;
+                let v = origin.y - offset.x * sin + offset.y * cos;
+
+                if let Some(color) = src.sample_filtered(u, v, bounds, filter) {
+                    self.write_pixel(x, y, color);
+                }
+            }
+        }
+    }
+
+    /// Draws the whole of `src` stretched to cover `dst_rect` as
+    /// `(x, y, width, height)`, keeping its corners at their original size
+    ///
+    /// `margins` gives the size of the `(left, top, right, bottom)` borders of
+    /// the source. The corners are copied as they are, the edges are only
+    /// stretched along their length and the middle is stretched to fill the
+    /// rest, which lets a small texture become a button or panel of any size.
+    /// Destinations too small to fit the borders shrink them in proportion.
+    /// Clipping and compositing work the same way as in [`Canvas::blit`].
+    ///
+    /// ```
+    /// use farba::{Canvas, RGBAColor};
+    ///
+    /// // A 3x3 texture with a one pixel red border around a blue middle
+    /// let mut panel = Canvas::new(3, 3);
+    /// panel.fill(RGBAColor::RED);
+    /// panel.set_pixel(1, 1, RGBAColor::BLUE);
+    ///
+    /// let mut canvas = Canvas::new(50, 50);
+    /// canvas.blit_nine_patch(&panel, (1, 1, 1, 1), (10, 10, 30, 20));
+    ///
+    /// // The border stays one pixel wide no matter the size
+    /// assert_eq!(*canvas.get_pixel(10, 10), RGBAColor::RED.into());
+    /// assert_eq!(*canvas.get_pixel(11, 11), RGBAColor::BLUE.into());
+    /// assert_eq!(*canvas.get_pixel(38, 28), RGBAColor::BLUE.into());
+    /// assert_eq!(*canvas.get_pixel(39, 29), RGBAColor::RED.into());
+    /// ```
+    pub fn blit_nine_patch(
+        &mut self,
+        src: &Canvas,
+        margins: (i32, i32, i32, i32),
+        dst_rect: (i32, i32, i32, i32),
+    ) {
+        let (src_width, src_height) = (src.get_width() as i32, src.get_height() as i32);
+
+        let (x, y, width, height) = dst_rect;
+
+        // Negative sizes flip the rectangle around its corner, but `i32::MIN`
+        // has no positive size to flip to
+        let (Ok(dst_width), Ok(dst_height)) = (
+            i32::try_from(width.unsigned_abs()),
+            i32::try_from(height.unsigned_abs()),
+        ) else {
+            return;
+        };
+
+        let Some(dest) = normalize_rect(x, y, width, height, i32::MAX, i32::MAX) else {
+            return;
+        };
+
+        // Shrinks a pair of opposite borders in proportion until they fit
+        let fit = |start: i32, end: i32, available: i32| {
+            let (start, end) = (start.max(0), end.max(0));
+            let total = start as i64 + end as i64;
+
+            if total > available as i64 {
+                let start = (start as i64 * available as i64 / total) as i32;
+                (start, available - start)
+            } else {
+                (start, end)
+            }
+        };
+
+        let (left, top, right, bottom) = margins;
+        let (src_left, src_right) = fit(left, right, src_width);
+        let (src_top, src_bottom) = fit(top, bottom, src_height);
+        let (dst_left, dst_right) = fit(src_left, src_right, dst_width);
+        let (dst_top, dst_bottom) = fit(src_top, src_bottom, dst_height);
+
+        // Start and size of the three columns (or rows) on each side
+        let columns = [
+            (0, src_left, dest.orig_x1, dst_left),
+            (
+                src_left,
+                src_width - src_left - src_right,
+                dest.orig_x1 + dst_left,
+                dst_width - dst_left - dst_right,
+            ),
+            (
+                src_width - src_right,
+                src_right,
+                dest.orig_x1 + dst_width - dst_right,
+                dst_right,
+            ),
+        ];
+        let rows = [
+            (0, src_top, dest.orig_y1, dst_top),
+            (
+                src_top,
+                src_height - src_top - src_bottom,
+                dest.orig_y1 + dst_top,
+                dst_height - dst_top - dst_bottom,
+            ),
+            (
+                src_height - src_bottom,
+                src_bottom,
+                dest.orig_y1 + dst_height - dst_bottom,
+                dst_bottom,
+            ),
+        ];
+
+        for &(src_y, src_h, dst_y, dst_h) in &rows {
+            for &(src_x, src_w, dst_x, dst_w) in &columns {
+                if src_w <= 0 || src_h <= 0 || dst_w <= 0 || dst_h <= 0 {
+                    continue;
+                }
+
+                self.blit_scaled(
+                    src,
+                    (src_x, src_y, src_w, src_h),
+                    (dst_x, dst_y, dst_w, dst_h),
+                    Filter::Nearest,
+                );
+            }
+        }
+    }
+
+    /// Covers `dst_rect` as `(x, y, width, height)` with copies of `src` repeated
+    /// in every direction, starting from the top left corner of the rectangle
+    /// shifted by `offset`
+    ///
+    /// Changing the offset over time scrolls the tiles, which wrap around at the
+    /// edges of the rectangle. Clipping and compositing work the same way as in
+    /// [`Canvas::blit`]. To fill shapes with a repeating texture, or to tile
+    /// through the transform, use a [`Pattern`](crate::Pattern) instead.
+    ///
+    /// ```
+    /// use farba::{Canvas, RGBAColor};
+    ///
+    /// let mut tile = Canvas::new(2, 1);
+    /// tile.set_pixel(0, 0, RGBAColor::RED);
+    /// tile.set_pixel(1, 0, RGBAColor::BLUE);
+    ///
+    /// let mut canvas = Canvas::new(10, 10);
+    /// canvas.blit_tiled(&tile, (0, 0, 10, 10), (1, 0));
+    ///
+    /// // Shifting by one pixel puts the end of a tile in the corner
+    /// assert_eq!(*canvas.get_pixel(0, 5), RGBAColor::BLUE.into());
+    /// assert_eq!(*canvas.get_pixel(1, 5), RGBAColor::RED.into());
+    /// assert_eq!(*canvas.get_pixel(9, 9), RGBAColor::RED.into());
+    /// ```
+    pub fn blit_tiled(&mut self, src: &Canvas, dst_rect: (i32, i32, i32, i32), offset: (i32, i32)) {
+        let (src_width, src_height) = (src.get_width() as i64, src.get_height() as i64);
+
+        if src_width == 0 || src_height == 0 {
+            return;
+        }
+
+        let (canvas_width, canvas_height) = (self.get_width() as i32, self.get_height() as i32);
+
+        let (x, y, width, height) = dst_rect;
+        let Some(dest) = normalize_rect(x, y, width, height, canvas_width, canvas_height) else {
+            return;
+        };
+
+        let (left, top, right, bottom) = self.clip_bounds();
+
+        for y in dest.y1.max(top)..=dest.y2.min(bottom) {
+            let src_y = (y as i64 - dest.orig_y1 as i64 - offset.1 as i64).rem_euclid(src_height);
+
+            for x in dest.x1.max(left)..=dest.x2.min(right) {
+                let src_x =
+                    (x as i64 - dest.orig_x1 as i64 - offset.0 as i64).rem_euclid(src_width);
+
+                let color = src.straight_pixel(src_x as i32, src_y as i32);
+                self.write_pixel(x, y, color);
+            }
+        }
+    }
+
+    /// Samples the canvas at the continuous coordinates `(u, v)` with `filter`,
+    /// only from the pixels in the inclusive `(left, top, right, bottom)`
+    /// bounds, giving `None` outside of them
+    fn sample_filtered(
+        &self,
+        u: f32,
+        v: f32,
+        bounds: (i32, i32, i32, i32),
+        filter: Filter,
+    ) -> Option<u32> {
+        match filter {
+            Filter::Nearest => {
+                let (x, y) = (u.floor() as i32, v.floor() as i32);
+                let (left, top, right, bottom) = bounds;
+
+                if x < left || x > right || y < top || y > bottom {
+                    return None;
+                }
+
+                Some(self.straight_pixel(x, y))
+            }
+            Filter::Bilinear => self.sample_bilinear_within(u, v, bounds),
+        }
+    }
+
+    /// Calls `write` with every destination pixel of a blit that isn't clipped,
+    /// along with the color of its source pixel (with straight alpha)
+    fn blit_pixels<F: FnMut(&mut Canvas, i32, i32, u32)>(
+        &mut self,
+        src: &Canvas,
+        (x, y, width, height): (i32, i32, i32, i32),
+        dst_x: i32,
+        dst_y: i32,
+        flip: Flip,
+        mut write: F,
+    ) {
+        let Some(nr) = normalize_rect(
+            x,
+            y,
+            width,
+            height,
+            src.get_width() as i32,
+            src.get_height() as i32,
+        ) else {
+            return;
+        };
+
+        // Maps destination coordinates along one axis to source coordinates as
+        // `base + direction * x`, giving the range of destination coordinates
+        // covered by the visible part of the source
+        let axis = |dst: i32, (orig_1, orig_2): (i32, i32), (visible_1, visible_2), flipped| {
+            if flipped {
+                // The far end of the source lands on `dst`
+                let base = dst + orig_2;
+                (base, -1, base - visible_2, base - visible_1)
+            } else {
+                let base = orig_1 - dst;
+                (base, 1, visible_1 - base, visible_2 - base)
+            }
+        };
+
+        let (base_x, direction_x, start_x, end_x) = axis(
+            dst_x,
+            (nr.orig_x1, nr.orig_x2),
+            (nr.x1, nr.x2),
+            flip.is_horizontal(),
+        );
+        let (base_y, direction_y, start_y, end_y) = axis(
+            dst_y,
+            (nr.orig_y1, nr.orig_y2),
+            (nr.y1, nr.y2),
+            flip.is_vertical(),
+        );
+
+        let (left, top, right, bottom) = self.clip_bounds();
+
+        for y in start_y.max(top)..=end_y.min(bottom) {
+            for x in start_x.max(left)..=end_x.min(right) {
+                let color = src.straight_pixel(base_x + direction_x * x, base_y + direction_y * y);
+
+                write(self, x, y, color);
+            }
+        }
+    }
+
+    /// Samples the canvas at the continuous coordinates `(u, v)` using bilinear
+    /// filtering, where pixel `(x, y)` covers the area from `(x, y)` to
+    /// `(x + 1, y + 1)`
+    ///
+    /// Returns `None` if the coordinates fall outside of the canvas. Neighbors
+    /// past the edges are clamped, and channels are weighted by alpha so that
+    /// transparent pixels don't bleed their color into the result
+    pub(crate) fn sample_bilinear(&self, u: f32, v: f32) -> Option<u32> {
+        let max_x = self.get_width() as i32 - 1;
+        let max_y = self.get_height() as i32 - 1;
+
+        self.sample_bilinear_within(u, v, (0, 0, max_x, max_y))
+    }
+
+    /// Samples the canvas like `sample_bilinear`, but only from the pixels in
+    /// the inclusive `(left, top, right, bottom)` bounds, which must be within
+    /// the canvas. This keeps neighboring sprites in a sheet from bleeding in
+    fn sample_bilinear_within(
+        &self,
+        u: f32,
+        v: f32,
+        (left, top, right, bottom): (i32, i32, i32, i32),
+    ) -> Option<u32> {
+        let outside_x = u < left as f32 || u >= (right + 1) as f32;
+        let outside_y = v < top as f32 || v >= (bottom + 1) as f32;
+
+        if outside_x || outside_y || left > right || top > bottom {
+            return None;
+        }
+
+        // Shift into pixel-center space so whole coordinates land between pixels
+        let fx = u - 0.5;
+        let fy = v - 0.5;
+
+        let x0 = fx.floor() as i32;
+        let y0 = fy.floor() as i32;
+        let tx = fx - x0 as f32;
+        let ty = fy - y0 as f32;
+
+        let (mut red, mut green, mut blue, mut alpha) = (0.0, 0.0, 0.0, 0.0);
+
+        for (x, y, weight) in [
+            (x0, y0, (1.0 - tx) * (1.0 - ty)),
+            (x0 + 1, y0, tx * (1.0 - ty)),
+            (x0, y0 + 1, (1.0 - tx) * ty),
+            (x0 + 1, y0 + 1, tx * ty),
+        ] {
+            let pixel = self.straight_pixel(x.clamp(left, right), y.clamp(top, bottom));
+            let weight = weight * pixel.alpha() as f32;
+
+            red += pixel.red() as f32 * weight;
+            green += pixel.green() as f32 * weight;
+            blue += pixel.blue() as f32 * weight;
+            alpha += weight;
+        }
+
+        if alpha <= 0.0 {
+            return Some(0);
+        }
+
+        Some(rgba!(
+            (red / alpha).round(),
+            (green / alpha).round(),
+            (blue / alpha).round(),
+            alpha.round()
+        ))
+    }
+
+    /// Draws the `src` canvas rotated by `angle` (in radians, clockwise on screen)
+    /// and scaled by `scale`, such that the center of `src` lands on `center`
+    ///
+    /// Every destination pixel is mapped back into the source image and sampled
+    /// with bilinear filtering before being alpha blended onto the canvas. Pixels
+    /// which map outside of the source are left untouched. With an `angle` of `0`
+    /// and a `scale` of `1` the source pixels are copied over unfiltered.
+    ///
+    /// ```
+    /// use farba::{Canvas, Color, RGBAColor};
+    ///
+    /// // A 3x2 image with only its top right pixel colored red
+    /// let mut sprite = Canvas::new(3, 2);
+    /// sprite.fill(RGBAColor::BLUE);
+    /// sprite.set_pixel(2, 0, RGBAColor::RED);
+    ///
+    /// let mut canvas = Canvas::new(20, 20);
+    /// canvas.draw_image_rotated(&sprite, (10, 10), std::f32::consts::FRAC_PI_2, 1.0);
+    ///
+    /// // A quarter turn moves the top right corner to the bottom right
+    /// assert_eq!(*canvas.get_pixel(10, 11), RGBAColor::RED.pack());
+    /// assert_eq!(*canvas.get_pixel(9, 11), RGBAColor::BLUE.pack());
+    /// ```
+    ///
+    /// Without rotation or scaling it draws exactly like [`Canvas::blit_blend`].
+    ///
+    /// ```
+    /// use farba::{Canvas, RGBAColor};
+    ///
+    /// let mut sprite = Canvas::new(5, 4);
+    /// for y in 0..4 {
+    ///     for x in 0..5 {
+    ///         let alpha = (x * 4 + y) as u8 * 12;
+    ///         sprite.set_pixel(x, y, RGBAColor::from_rgba(200, x as u8 * 50, 90, alpha));
+    ///     }
+    /// }
+    ///
+    /// let background = RGBAColor::from_rgba(10, 120, 240, 200);
+    ///
+    /// let mut rotated = Canvas::new(12, 12);
+    /// rotated.fill(background.clone());
+    ///
+    /// let mut blitted = Canvas::new(12, 12);
+    /// blitted.fill(background);
+    ///
+    /// // The center of a 5x4 image is 2 pixels in from its top left corner
+    /// rotated.draw_image_rotated(&sprite, (6, 6), 0.0, 1.0);
+    /// blitted.blit_blend(&sprite, (0, 0, 5, 4), 4, 4);
+    ///
+    /// assert_eq!(rotated.get_data(), blitted.get_data());
+    /// ```
+    pub fn draw_image_rotated(&mut self, src: &Canvas, center: (i32, i32), angle: f32, scale: f32) {
+        if scale <= 0.0 || src.get_width() == 0 || src.get_height() == 0 {
+            return;
+        }
+
+        // Integer division keeps the source center on a pixel boundary so that
+        // unrotated, unscaled draws sample exact pixels
+        let src_center_x = (src.get_width() / 2) as f32;
+        let src_center_y = (src.get_height() / 2) as f32;
+
+        // Maps the source onto the canvas by centering it on the origin, scaling
+        // and rotating it, moving it into place and finally applying the current
+        // transform
+        let forward = self.get_transform()
+            * Mat3::translate_2d(center.0 as f32, center.1 as f32)
+            * Mat3::rotate_2d(angle)
+            * Mat3::scale_2d(scale, scale)
+            * Mat3::translate_2d(-src_center_x, -src_center_y);
+        let inverse = affine_inverse(&forward);
+
+        // Find the bounding box of the transformed source on the canvas
+        let (mut min_x, mut min_y) = (f32::INFINITY, f32::INFINITY);
+        let (mut max_x, mut max_y) = (f32::NEG_INFINITY, f32::NEG_INFINITY);
+
+        for (x, y) in [
+            (0.0, 0.0),
+            (src.get_width() as f32, 0.0),
+            (0.0, src.get_height() as f32),
+            (src.get_width() as f32, src.get_height() as f32),
+        ] {
+            let corner = forward.transform_point(Vec2::new(x, y));
+
+            min_x = min_x.min(corner.x);
+            min_y = min_y.min(corner.y);
+            max_x = max_x.max(corner.x);
+            max_y = max_y.max(corner.y);
+        }
+
+        let left_x = (min_x.floor() as i32).max(0);
+        let right_x = (max_x.ceil() as i32).min(self.get_width() as i32 - 1);
+        let top_y = (min_y.floor() as i32).max(0);
+        let bottom_y = (max_y.ceil() as i32).min(self.get_height() as i32 - 1);
+
+        // Images are always blended, even when the canvas copies everything else
+        self.with_composite_op(self.image_composite_op(), |canvas| {
+            for y in top_y..=bottom_y {
+                for x in left_x..=right_x {
+                    // Map the center of the destination pixel back into the source
+                    let source = inverse.transform_point(Vec2::new(x as f32 + 0.5, y as f32 + 0.5));
+
+                    let Some(color) = src.sample_bilinear(source.x, source.y) else {
+                        continue;
+                    };
+
+                    canvas.write_pixel(x, y, color);
+                }
+            }
+        });
+    }
+}
@@ -1,12 +1,35 @@
 use core::panic;
 
-use crate::{normalize_rect, normalize_triangle, Color, Vec3};
+use crate::{
+    barycentric, blend_packed, clipped_line_pixels, composite_linear, composite_packed,
+    composite_premultiplied, coverage, normalize_rect, normalize_triangle, premultiply_packed,
+    sample_offsets, transform::TransformStack, triangle_spans, unpremultiply_packed, AntiAliasing,
+    BlendMode, ChannelOrder, Color, ColorSpace, CompositeOp, DepthBuffer, FillRule, MaskCanvas,
+    Paint, RGBAColor, Vec2, Vec3,
+};
 
 #[derive(Debug, PartialEq)]
 pub struct Canvas {
     pixels: Vec<u32>,
     width: usize,
     height: usize,
+    supersampling: usize,
+    /// Inclusive `(x1, y1, x2, y2)` bounds which drawing is restricted to
+    clip: Option<(i32, i32, i32, i32)>,
+    /// Alpha of the clip mask for every pixel, which scales how much of each
+    /// draw call gets through
+    clip_mask: Option<Vec<u8>>,
+    pub(crate) transforms: TransformStack,
+    /// How drawn colors combine with the pixels already on the canvas
+    pub(crate) composite_op: CompositeOp,
+    /// How drawn colors are changed by the pixels already on the canvas
+    pub(crate) blend_mode: BlendMode,
+    /// Scales the alpha of everything drawn
+    pub(crate) global_alpha: f32,
+    /// Whether the color channels of every pixel are stored scaled by its alpha
+    pub(crate) premultiplied: bool,
+    /// The space drawn colors are blended with the pixels already on the canvas in
+    pub(crate) blend_space: ColorSpace,
 }
 
 impl Canvas {
@@ -16,6 +39,29 @@ impl Canvas {
             pixels: vec![0u32; width * height],
             width,
             height,
+            supersampling: 1,
+            clip: None,
+            clip_mask: None,
+            transforms: TransformStack::default(),
+            composite_op: CompositeOp::Copy,
+            blend_mode: BlendMode::Normal,
+            global_alpha: 1.0,
+            premultiplied: false,
+            blend_space: ColorSpace::Srgb,
+        }
+    }
+
+    /// Creates a new Canvas which renders at `factor` times the specified width
+    /// and height, to be downsampled with [`Canvas::resolve`] once drawing is done
+    ///
+    /// Everything drawn onto the canvas is in the supersampled coordinate space,
+    /// so `get_width` and `get_height` report the enlarged size
+    pub fn new_supersampled(width: usize, height: usize, factor: usize) -> Self {
+        let factor = factor.max(1);
+
+        Self {
+            supersampling: factor,
+            ..Self::new(width * factor, height * factor)
         }
     }
 
@@ -27,6 +73,11 @@ impl Canvas {
         self.height
     }
 
+    /// Gets how many pixels along each axis are combined into one by `resolve`
+    pub fn get_supersampling(&self) -> usize {
+        self.supersampling
+    }
+
     /// Allows you to take ownership of the underlying pixel buffer
     pub fn take(self) -> Vec<u32> {
         self.pixels
@@ -42,6 +93,31 @@ impl Canvas {
         self.pixels.as_mut_slice()
     }
 
+    /// Copies every pixel into `buffer` packed in the channel `order`, resizing
+    /// it to fit
+    ///
+    /// Reusing the same buffer every frame avoids reallocating it when handing
+    /// pixels over to a window or framebuffer which expects a different order.
+    /// Pixels are copied the way they are stored, so a premultiplied canvas
+    /// gives premultiplied pixels.
+    ///
+    /// ```
+    /// use farba::{Canvas, ChannelOrder, RGBAColor};
+    ///
+    /// let mut canvas = Canvas::new(4, 4);
+    /// canvas.fill(RGBAColor::RED);
+    ///
+    /// let mut buffer = Vec::new();
+    /// canvas.copy_pixels_in_order(ChannelOrder::Bgra, &mut buffer);
+    ///
+    /// assert_eq!(buffer.len(), 16);
+    /// assert_eq!(buffer[0], 0xFFFF0000);
+    /// ```
+    pub fn copy_pixels_in_order(&self, order: ChannelOrder, buffer: &mut Vec<u32>) {
+        buffer.clear();
+        buffer.extend(self.pixels.iter().map(|&pixel| order.pack(pixel)));
+    }
+
     /// Gets a slice over the raw pixel buffer owned by the canvas but as bytes
     pub fn get_data(&self) -> &[u8] {
         use std::mem::size_of;
@@ -66,8 +142,155 @@ impl Canvas {
         }
     }
 
+    /// Checks whether `(x, y)` is the coordinate of a pixel in the canvas
+    ///
+    /// ```
+    /// use farba::Canvas;
+    ///
+    /// let canvas = Canvas::new(10, 5);
+    ///
+    /// assert!(canvas.in_bounds(0, 0));
+    /// assert!(canvas.in_bounds(9, 4));
+    /// assert!(!canvas.in_bounds(10, 4));
+    /// assert!(!canvas.in_bounds(0, -1));
+    /// ```
     pub fn in_bounds(&self, x: i32, y: i32) -> bool {
-        x < 0 || x >= self.width as i32 || y < 0 || y >= self.height as i32
+        x >= 0 && x < self.width as i32 && y >= 0 && y < self.height as i32
+    }
+
+    /// Restricts every following draw call to the rectangle with its corner at
+    /// `(x, y)`, leaving all pixels outside of it untouched
+    ///
+    /// Negative sizes are handled the same way as in [`Canvas::rect`]. A clip
+    /// rectangle which doesn't overlap the canvas stops anything from being
+    /// drawn until the clip is cleared.
+    ///
+    /// ```
+    /// use farba::{Canvas, RGBAColor};
+    ///
+    /// let mut canvas = Canvas::new(100, 100);
+    ///
+    /// canvas.set_clip_rect(0, 0, 50, 100);
+    /// canvas.fill(RGBAColor::RED);
+    ///
+    /// assert_eq!(*canvas.get_pixel(49, 50), RGBAColor::RED.into());
+    /// assert_eq!(*canvas.get_pixel(50, 50), 0);
+    ///
+    /// canvas.clear_clip();
+    /// canvas.fill(RGBAColor::RED);
+    ///
+    /// assert_eq!(*canvas.get_pixel(50, 50), RGBAColor::RED.into());
+    /// ```
+    pub fn set_clip_rect(&mut self, x: i32, y: i32, width: i32, height: i32) {
+        let clip = normalize_rect(x, y, width, height, self.width as i32, self.height as i32)
+            .map_or((0, 0, -1, -1), |nr| (nr.x1, nr.y1, nr.x2, nr.y2));
+
+        self.clip = Some(clip);
+    }
+
+    /// Removes the clip rectangle, so that drawing can reach the whole canvas again
+    pub fn clear_clip(&mut self) {
+        self.clip = None;
+    }
+
+    /// Gets the area drawing is restricted to as `(x, y, width, height)`, already
+    /// limited to the canvas, or `None` if there is no clip rectangle
+    pub fn get_clip_rect(&self) -> Option<(i32, i32, i32, i32)> {
+        self.clip
+            .map(|(x1, y1, x2, y2)| (x1, y1, x2 - x1 + 1, y2 - y1 + 1))
+    }
+
+    /// Restricts every following draw call to the pixels where `mask` is opaque,
+    /// on top of any clip rectangle
+    ///
+    /// The mask can be a [`MaskCanvas`] or a canvas, of which only the alpha
+    /// channel is used. Pixels with no intensity are never drawn to, while
+    /// partial intensities only let that fraction of the drawn color through,
+    /// which allows for smooth edges. The mask is copied, so it can be reused or
+    /// dropped afterwards.
+    ///
+    /// Panics if `mask` isn't the same size as the canvas.
+    ///
+    /// ```
+    /// use farba::{Canvas, RGBAColor};
+    ///
+    /// let mut mask = Canvas::new(100, 100);
+    /// mask.circle(50, 50, 40, RGBAColor::WHITE);
+    ///
+    /// let mut canvas = Canvas::new(100, 100);
+    /// canvas.set_clip_mask(&mask);
+    /// canvas.rect(0, 0, 100, 100, RGBAColor::RED);
+    ///
+    /// // Only the inside of the circle was filled
+    /// assert_eq!(*canvas.get_pixel(50, 50), RGBAColor::RED.into());
+    /// assert_eq!(*canvas.get_pixel(5, 5), 0);
+    /// ```
+    pub fn set_clip_mask<M: Into<MaskCanvas>>(&mut self, mask: M) {
+        let mask = mask.into();
+
+        if mask.get_width() != self.width || mask.get_height() != self.height {
+            panic!("Clip mask was not correct size to match canvas")
+        }
+
+        self.clip_mask = Some(mask.get_pixels().to_vec());
+    }
+
+    /// Removes the clip mask, leaving only the clip rectangle (if any) in place
+    pub fn clear_clip_mask(&mut self) {
+        self.clip_mask = None;
+    }
+
+    /// Checks whether drawing at `(x, y)` is allowed by the clip rectangle
+    #[inline]
+    pub(crate) fn in_clip(&self, x: i32, y: i32) -> bool {
+        match self.clip {
+            None => true,
+            Some((x1, y1, x2, y2)) => x >= x1 && x <= x2 && y >= y1 && y <= y2,
+        }
+    }
+
+    /// The inclusive `(left, top, right, bottom)` bounds of the pixels which can
+    /// be drawn to, taking both the canvas and the clip rectangle into account
+    pub(crate) fn clip_bounds(&self) -> (i32, i32, i32, i32) {
+        // The clip rectangle is always limited to the canvas when it is set
+        self.clip
+            .unwrap_or((0, 0, self.width as i32 - 1, self.height as i32 - 1))
+    }
+
+    /// Makes every following draw call composite its colors over the existing
+    /// pixels using source-over alpha blending, rather than replacing them
+    ///
+    /// Blending is off by default, in which case drawing a color copies it to
+    /// the canvas as is, alpha included. Turning it on or off is the same as
+    /// setting the composite operator to [`CompositeOp::SourceOver`] or
+    /// [`CompositeOp::Copy`]. This applies to everything drawn
+    /// through the canvas, from [`Canvas::set_pixel`] and [`Canvas::fill`] to
+    /// every shape.
+    ///
+    /// ```
+    /// use farba::{Canvas, RGBAColor};
+    ///
+    /// let mut canvas = Canvas::new(100, 100);
+    /// canvas.fill(RGBAColor::WHITE);
+    ///
+    /// canvas.set_alpha_blending(true);
+    /// canvas.rect(0, 0, 50, 50, RGBAColor::from_rgba(255, 0, 0, 128));
+    ///
+    /// // Half of the white shows through the red
+    /// assert_eq!(*canvas.get_pixel(25, 25), RGBAColor::from_rgb(255, 127, 127).into());
+    /// ```
+    pub fn set_alpha_blending(&mut self, enabled: bool) {
+        self.composite_op = if enabled {
+            CompositeOp::SourceOver
+        } else {
+            CompositeOp::Copy
+        };
+    }
+
+    /// Checks whether drawn colors are combined with the existing pixels at all,
+    /// rather than copied over them
+    pub fn get_alpha_blending(&self) -> bool {
+        self.composite_op != CompositeOp::Copy
     }
 
     /// Performs a bounds check on the coordinates to ensure they are within
@@ -77,7 +300,7 @@ impl Canvas {
         let pixel_color = color.pack();
 
         if self.in_bounds(x, y) {
-            *self.get_pixel_mut(x, y) = pixel_color;
+            self.write_pixel(x, y, pixel_color);
         }
     }
 
@@ -90,7 +313,7 @@ impl Canvas {
     pub fn set_pixel_unchecked<C: Color>(&mut self, x: i32, y: i32, color: C) {
         let pixel_color = color.pack();
 
-        *self.get_pixel_mut(x, y) = pixel_color;
+        self.write_pixel(x, y, pixel_color);
     }
 
     #[inline]
@@ -110,15 +333,125 @@ impl Canvas {
         &mut self.pixels[index]
     }
 
+    /// Sets the pixel at `(x, y)` to `pixel_color` unless it is clipped. This is
+    /// the path every draw call writes its pixels through
+    ///
+    /// `(x, y)` must be a valid coordinate within the canvas
+    #[inline]
+    pub(crate) fn write_pixel(&mut self, x: i32, y: i32, pixel_color: u32) {
+        self.cover_pixel(x, y, pixel_color, 1.0);
+    }
+
+    /// Draws `pixel_color` over the fraction `coverage` of the pixel at
+    /// `(x, y)`, such as along the edge of an anti-aliased shape, combining it
+    /// with the existing pixel using the global alpha, blend mode and composite
+    /// operator
+    ///
+    /// The coverage is further scaled by the clip mask.
+    ///
+    /// `(x, y)` must be a valid coordinate within the canvas
+    #[inline]
+    pub(crate) fn cover_pixel(&mut self, x: i32, y: i32, pixel_color: u32, coverage: f32) {
+        if !self.in_clip(x, y) {
+            return;
+        }
+
+        let index = self.get_index(x, y);
+
+        let coverage = match self.clip_mask.as_ref().map(|mask| mask[index]) {
+            None | Some(255) => coverage,
+            Some(0) => return,
+            Some(alpha) => coverage * alpha as f32 / 255.0,
+        };
+
+        let pixel_color = if self.global_alpha < 1.0 {
+            let alpha = (pixel_color.alpha() as f32 * self.global_alpha).round() as u32;
+
+            (pixel_color & 0x00FFFFFF) | (alpha << 24)
+        } else {
+            pixel_color
+        };
+
+        let destination = self.pixels[index];
+
+        self.pixels[index] = if self.blend_space != ColorSpace::Srgb {
+            let straight = match self.premultiplied {
+                true => unpremultiply_packed(destination),
+                false => destination,
+            };
+
+            let composited = composite_linear(
+                self.composite_op,
+                self.blend_mode,
+                straight,
+                pixel_color,
+                coverage,
+            );
+
+            match self.premultiplied {
+                true => premultiply_packed(composited),
+                false => composited,
+            }
+        } else if self.premultiplied {
+            let pixel_color = match self.blend_mode {
+                BlendMode::Normal => pixel_color,
+                mode => blend_packed(mode, unpremultiply_packed(destination), pixel_color),
+            };
+
+            composite_premultiplied(
+                self.composite_op,
+                destination,
+                premultiply_packed(pixel_color),
+                coverage,
+            )
+        } else {
+            let pixel_color = blend_packed(self.blend_mode, destination, pixel_color);
+
+            composite_packed(self.composite_op, destination, pixel_color, coverage)
+        };
+    }
+
+    /// Fills the pixel at `(x, y)` with the color of `paint` at the center of the
+    /// pixel. This is the path every filled shape draws through
+    ///
+    /// `(x, y)` must be a valid coordinate within the canvas
+    #[inline]
+    pub(crate) fn paint_pixel<P: Paint>(&mut self, x: i32, y: i32, paint: &P) {
+        let mut point = Vec2::new(x as f32 + 0.5, y as f32 + 0.5);
+
+        // Paints are positioned in the same coordinates as the shapes they fill
+        if self.has_transform() {
+            point = self.untransform_point(point);
+        }
+
+        let color = paint.color_at(point.x, point.y);
+
+        self.write_pixel(x, y, color);
+    }
+
     #[cfg(feature = "image")]
     pub fn save_to_file(&self, file_path: &str) {
         use image::{save_buffer, ColorType};
 
         // TODO: Return Result instead of expecting
 
+        // Image files are saved with straight alpha
+        let straight: Vec<u8>;
+        let data = if self.premultiplied {
+            straight = self
+                .pixels
+                .iter()
+                .flat_map(|pixel| unpremultiply_packed(*pixel).to_le_bytes())
+                .collect();
+
+            &straight
+        } else {
+            self.get_data()
+        };
+
         save_buffer(
             file_path,
-            self.get_data(),
+            data,
             self.get_width() as u32,
             self.get_height() as u32,
             ColorType::Rgba8,
@@ -132,19 +465,27 @@ impl Canvas {
 
         for y in 0..self.height {
             for x in 0..self.width {
-                *self.get_pixel_mut(x as i32, y as i32) = pixel_color;
+                self.write_pixel(x as i32, y as i32, pixel_color);
             }
         }
     }
 
     /// Draws a circle at the provided center with the given radius
-    pub fn circle<C: Color>(&mut self, center_x: i32, center_y: i32, radius: i32, color: C) {
-        // TODO: Anti-Aliasing
-
-        let pixel_color = color.pack();
+    pub fn circle<P: Paint>(&mut self, center_x: i32, center_y: i32, radius: i32, paint: P) {
+        if self.has_transform() {
+            self.sector_pixels(center_x, center_y, radius, 0, None, &paint);
+            return;
+        }
 
         // Clip the rectangle to the canvas
-        let Some(nr) = normalize_rect(center_x - radius, center_y - radius, radius * 2, radius * 2, self.width as i32, self.height as i32) else {
+        let Some(nr) = normalize_rect(
+            center_x - radius,
+            center_y - radius,
+            radius * 2,
+            radius * 2,
+            self.width as i32,
+            self.height as i32,
+        ) else {
             // Nothing to render
             return;
         };
@@ -159,8 +500,352 @@ impl Canvas {
                 // If the point satisfies the equation for a circle then fill in that
                 // pixel with the provided color
                 if dx * dx + dy * dy < radius * radius {
-                    *self.get_pixel_mut(x, y) = pixel_color;
+                    self.paint_pixel(x, y, &paint);
+                }
+            }
+        }
+    }
+
+    /// Draws a circular arc at the provided center, `thickness` pixels wide on the
+    /// inside of `radius`
+    ///
+    /// Angles are in radians and measured clockwise (on screen) from the positive
+    /// x axis. The arc sweeps from `start_angle` to `end_angle`, so it goes
+    /// counter-clockwise when `end_angle` is less than `start_angle`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn arc<P: Paint>(
+        &mut self,
+        center_x: i32,
+        center_y: i32,
+        radius: i32,
+        thickness: i32,
+        start_angle: f32,
+        end_angle: f32,
+        paint: P,
+    ) {
+        if thickness <= 0 {
+            return;
+        }
+
+        self.sector_pixels(
+            center_x,
+            center_y,
+            radius,
+            radius - thickness,
+            Some((start_angle, end_angle)),
+            &paint,
+        );
+    }
+
+    /// Fills the wedge of the circle at the provided center between `start_angle`
+    /// and `end_angle`
+    ///
+    /// Angles follow the same convention as [`Canvas::arc`]
+    pub fn pie<P: Paint>(
+        &mut self,
+        center_x: i32,
+        center_y: i32,
+        radius: i32,
+        start_angle: f32,
+        end_angle: f32,
+        paint: P,
+    ) {
+        self.sector_pixels(
+            center_x,
+            center_y,
+            radius,
+            0,
+            Some((start_angle, end_angle)),
+            &paint,
+        );
+    }
+
+    /// Draws a ring (annulus) at the provided center which extends `thickness`
+    /// pixels inwards from `outer_radius`, leaving the inside untouched
+    pub fn ring<P: Paint>(
+        &mut self,
+        center_x: i32,
+        center_y: i32,
+        outer_radius: i32,
+        thickness: i32,
+        paint: P,
+    ) {
+        if thickness <= 0 {
+            return;
+        }
+
+        self.sector_pixels(
+            center_x,
+            center_y,
+            outer_radius,
+            outer_radius - thickness,
+            None,
+            &paint,
+        );
+    }
+
+    /// Fills the pixels inside of the circle of `outer_radius` but not inside of
+    /// the circle of `inner_radius`, optionally limited to the wedge between the
+    /// `(start, end)` angles
+    fn sector_pixels<P: Paint>(
+        &mut self,
+        center_x: i32,
+        center_y: i32,
+        outer_radius: i32,
+        inner_radius: i32,
+        angles: Option<(f32, f32)>,
+        paint: &P,
+    ) {
+        use std::f32::consts::TAU;
+
+        if outer_radius <= 0 || inner_radius >= outer_radius {
+            return;
+        }
+
+        let inner_radius = inner_radius.max(0);
+
+        // Normalize the angles into a clockwise sweep starting at `start`. A
+        // sweep of a full turn or more covers the whole ring
+        let wedge = angles.and_then(|(start, end)| {
+            let (start, sweep) = if end >= start {
+                (start, end - start)
+            } else {
+                (end, start - end)
+            };
+
+            (sweep < TAU).then(|| (start.rem_euclid(TAU), sweep))
+        });
+
+        if self.has_transform() {
+            // Pixel (x, y) covers the area from (x, y) to (x + 1, y + 1), so the
+            // integer center is in the middle of a pixel
+            let center = Vec2::new(center_x as f32 + 0.5, center_y as f32 + 0.5);
+            let (outer, inner) = (outer_radius as f32, inner_radius as f32);
+
+            let contours = match wedge {
+                None => {
+                    let mut contours =
+                        vec![self.ellipse_outline_points(center, outer, outer, 0.0, (0.0, TAU))];
+
+                    // Wound the other way to cut a hole
+                    if inner_radius > 0 {
+                        contours.push(self.ellipse_outline_points(
+                            center,
+                            inner,
+                            inner,
+                            0.0,
+                            (TAU, 0.0),
+                        ));
+                    }
+
+                    contours
                 }
+                Some((start, sweep)) => {
+                    let end = start + sweep;
+                    let mut outline =
+                        self.ellipse_outline_points(center, outer, outer, 0.0, (start, end));
+
+                    if inner_radius > 0 {
+                        outline.extend(self.ellipse_outline_points(
+                            center,
+                            inner,
+                            inner,
+                            0.0,
+                            (end, start),
+                        ));
+                    } else {
+                        outline.push(center);
+                    }
+
+                    vec![outline]
+                }
+            };
+
+            self.fill_contours(&contours, FillRule::NonZero, paint);
+            return;
+        }
+
+        // Clip the bounding box of the outer circle to the canvas
+        let Some(nr) = normalize_rect(
+            center_x - outer_radius,
+            center_y - outer_radius,
+            outer_radius * 2,
+            outer_radius * 2,
+            self.width as i32,
+            self.height as i32,
+        ) else {
+            // Nothing to render
+            return;
+        };
+
+        for x in nr.x1..=nr.x2 {
+            for y in nr.y1..=nr.y2 {
+                let dx = x - center_x;
+                let dy = y - center_y;
+                let distance_squared = dx * dx + dy * dy;
+
+                // Same boundary as `circle` so that sectors line up with circles
+                if distance_squared >= outer_radius * outer_radius
+                    || distance_squared < inner_radius * inner_radius
+                {
+                    continue;
+                }
+
+                if let Some((start, sweep)) = wedge {
+                    let angle = (dy as f32).atan2(dx as f32);
+
+                    if (angle - start).rem_euclid(TAU) > sweep {
+                        continue;
+                    }
+                }
+
+                self.paint_pixel(x, y, paint);
+            }
+        }
+    }
+
+    /// Draws an ellipse at the provided center with the horizontal radius `radius_x`
+    /// and the vertical radius `radius_y`
+    pub fn ellipse<P: Paint>(
+        &mut self,
+        center_x: i32,
+        center_y: i32,
+        radius_x: i32,
+        radius_y: i32,
+        paint: P,
+    ) {
+        self.rotated_ellipse(center_x, center_y, radius_x, radius_y, 0.0, paint);
+    }
+
+    /// Draws the one pixel wide outline of the ellipse that [`Canvas::ellipse`]
+    /// would fill
+    pub fn ellipse_outline<C: Color>(
+        &mut self,
+        center_x: i32,
+        center_y: i32,
+        radius_x: i32,
+        radius_y: i32,
+        color: C,
+    ) {
+        self.rotated_ellipse_outline(center_x, center_y, radius_x, radius_y, 0.0, color);
+    }
+
+    /// Draws an ellipse like [`Canvas::ellipse`] which is rotated clockwise by
+    /// `angle` (in radians) around its center
+    pub fn rotated_ellipse<P: Paint>(
+        &mut self,
+        center_x: i32,
+        center_y: i32,
+        radius_x: i32,
+        radius_y: i32,
+        angle: f32,
+        paint: P,
+    ) {
+        self.ellipse_pixels(center_x, center_y, radius_x, radius_y, angle, false, &paint);
+    }
+
+    /// Draws the one pixel wide outline of the ellipse that
+    /// [`Canvas::rotated_ellipse`] would fill
+    pub fn rotated_ellipse_outline<C: Color>(
+        &mut self,
+        center_x: i32,
+        center_y: i32,
+        radius_x: i32,
+        radius_y: i32,
+        angle: f32,
+        color: C,
+    ) {
+        self.ellipse_pixels(
+            center_x,
+            center_y,
+            radius_x,
+            radius_y,
+            angle,
+            true,
+            &color.pack(),
+        );
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn ellipse_pixels<P: Paint>(
+        &mut self,
+        center_x: i32,
+        center_y: i32,
+        radius_x: i32,
+        radius_y: i32,
+        angle: f32,
+        outline: bool,
+        paint: &P,
+    ) {
+        if radius_x <= 0 || radius_y <= 0 {
+            return;
+        }
+
+        let (sin, cos) = angle.sin_cos();
+        let (rx, ry) = (radius_x as f32, radius_y as f32);
+
+        if self.has_transform() {
+            use std::f32::consts::TAU;
+
+            let center = Vec2::new(center_x as f32 + 0.5, center_y as f32 + 0.5);
+            let points = self.ellipse_outline_points(center, rx, ry, angle, (0.0, TAU));
+
+            if outline {
+                self.connected_segments(&points, true, paint);
+            } else {
+                self.fill_contours(&[points], FillRule::NonZero, paint);
+            }
+
+            return;
+        }
+
+        // Checks if the point satisfies the equation for the ellipse after undoing
+        // the rotation
+        let inside = |x: i32, y: i32| {
+            let dx = (x - center_x) as f32;
+            let dy = (y - center_y) as f32;
+
+            let u = dx * cos + dy * sin;
+            let v = -dx * sin + dy * cos;
+
+            (u * u) / (rx * rx) + (v * v) / (ry * ry) < 1.0
+        };
+
+        // Half extents of the bounding box of the rotated ellipse
+        let extent_x = (rx * rx * cos * cos + ry * ry * sin * sin).sqrt().ceil() as i32;
+        let extent_y = (rx * rx * sin * sin + ry * ry * cos * cos).sqrt().ceil() as i32;
+
+        // Clip the bounding box to the canvas
+        let Some(nr) = normalize_rect(
+            center_x - extent_x,
+            center_y - extent_y,
+            extent_x * 2 + 1,
+            extent_y * 2 + 1,
+            self.width as i32,
+            self.height as i32,
+        ) else {
+            // Nothing to render
+            return;
+        };
+
+        for x in nr.x1..=nr.x2 {
+            for y in nr.y1..=nr.y2 {
+                if !inside(x, y) {
+                    continue;
+                }
+
+                // The outline is made up of the filled pixels that border on at
+                // least one unfilled pixel
+                if outline
+                    && inside(x - 1, y)
+                    && inside(x + 1, y)
+                    && inside(x, y - 1)
+                    && inside(x, y + 1)
+                {
+                    continue;
+                }
+
+                self.paint_pixel(x, y, paint);
             }
         }
     }
@@ -173,10 +858,56 @@ impl Canvas {
     /// The same logic follows for height where when height is positive, y will be the
     /// top bound of the rectangle, and when height is negative, y will be the bottom
     /// bound of the rect
-    pub fn rect<C: Color>(&mut self, x: i32, y: i32, width: i32, height: i32, color: C) {
+    pub fn rect<P: Paint>(&mut self, x: i32, y: i32, width: i32, height: i32, paint: P) {
+        self.rect_painted(x, y, width, height, &paint);
+    }
+
+    /// Draws many rectangles of the same color, given as `(x, y, width, height)`
+    ///
+    /// The output is identical to calling [`Canvas::rect`] for each of them in
+    /// order, but the color is only packed once.
+    ///
+    /// ```
+    /// use farba::{Canvas, RGBAColor};
+    ///
+    /// let rects = [(10, 10, 20, 5), (-5, 30, 15, 15), (40, 45, -10, -20)];
+    ///
+    /// let mut batched = Canvas::new(50, 50);
+    /// batched.rects(&rects, RGBAColor::GREEN);
+    ///
+    /// let mut looped = Canvas::new(50, 50);
+    /// for &(x, y, width, height) in &rects {
+    ///     looped.rect(x, y, width, height, RGBAColor::GREEN);
+    /// }
+    ///
+    /// assert_eq!(batched, looped);
+    /// ```
+    pub fn rects<C: Color>(&mut self, rects: &[(i32, i32, i32, i32)], color: C) {
         let pixel_color = color.pack();
 
-        let Some(nr) = normalize_rect(x, y, width, height, self.width as i32, self.height as i32) else {
+        for &(x, y, width, height) in rects {
+            self.rect_painted(x, y, width, height, &pixel_color);
+        }
+    }
+
+    fn rect_painted<P: Paint>(&mut self, x: i32, y: i32, width: i32, height: i32, paint: &P) {
+        if self.has_transform() {
+            if let Some((x1, y1, x2, y2)) = rect_bounds(x, y, width, height) {
+                let corners = [
+                    Vec2::new(x1, y1),
+                    Vec2::new(x2, y1),
+                    Vec2::new(x2, y2),
+                    Vec2::new(x1, y2),
+                ];
+
+                self.fill_contours(&[corners], FillRule::NonZero, paint);
+            }
+
+            return;
+        }
+
+        let Some(nr) = normalize_rect(x, y, width, height, self.width as i32, self.height as i32)
+        else {
             // Nothing to render
             return;
         };
@@ -184,15 +915,211 @@ impl Canvas {
         // Iterate through the clipped bounding box of the rect and fill in all the pixels
         for x in nr.x1..=nr.x2 {
             for y in nr.y1..=nr.y2 {
-                *self.get_pixel_mut(x, y) = pixel_color;
+                self.paint_pixel(x, y, paint);
             }
         }
     }
 
+    /// Draws a rectangle like [`Canvas::rect`] with its corners rounded off by
+    /// quarter circles of `corner_radius`
+    ///
+    /// The radius is limited to half of the shorter side of the rectangle
+    pub fn rounded_rect<P: Paint>(
+        &mut self,
+        x: i32,
+        y: i32,
+        width: i32,
+        height: i32,
+        corner_radius: i32,
+        paint: P,
+    ) {
+        self.rounded_rect_pixels(x, y, width, height, corner_radius, false, &paint);
+    }
+
+    /// Draws the one pixel wide outline of the rectangle that
+    /// [`Canvas::rounded_rect`] would fill
+    pub fn rounded_rect_outline<C: Color>(
+        &mut self,
+        x: i32,
+        y: i32,
+        width: i32,
+        height: i32,
+        corner_radius: i32,
+        color: C,
+    ) {
+        self.rounded_rect_pixels(x, y, width, height, corner_radius, true, &color.pack());
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn rounded_rect_pixels<P: Paint>(
+        &mut self,
+        x: i32,
+        y: i32,
+        width: i32,
+        height: i32,
+        corner_radius: i32,
+        outline: bool,
+        paint: &P,
+    ) {
+        if self.has_transform() {
+            let Some((x1, y1, x2, y2)) = rect_bounds(x, y, width, height) else {
+                return;
+            };
+
+            use std::f32::consts::PI;
+
+            // Same radius as below, extended to the edge of the pixels
+            let radius = corner_radius
+                .min(((x2 - x1) as i32 - 1) / 2)
+                .min(((y2 - y1) as i32 - 1) / 2)
+                .max(0) as f32
+                + 0.5;
+
+            let mut points = Vec::new();
+
+            // Quarter circles around each corner, clockwise from the top left
+            for (corner_x, corner_y, start) in [
+                (x1 + radius, y1 + radius, PI),
+                (x2 - radius, y1 + radius, PI * 1.5),
+                (x2 - radius, y2 - radius, 0.0),
+                (x1 + radius, y2 - radius, PI * 0.5),
+            ] {
+                let center = Vec2::new(corner_x, corner_y);
+                let range = (start, start + PI / 2.0);
+
+                points.extend(self.ellipse_outline_points(center, radius, radius, 0.0, range));
+            }
+
+            if outline {
+                self.connected_segments(&points, true, paint);
+            } else {
+                self.fill_contours(&[points], FillRule::NonZero, paint);
+            }
+
+            return;
+        }
+
+        let Some(nr) = normalize_rect(x, y, width, height, self.width as i32, self.height as i32)
+        else {
+            // Nothing to render
+            return;
+        };
+
+        let radius = corner_radius
+            .min((nr.orig_x2 - nr.orig_x1) / 2)
+            .min((nr.orig_y2 - nr.orig_y1) / 2)
+            .max(0);
+
+        // Each corner is a quarter circle centered on the matching corner of this
+        // inner rectangle
+        let (inner_x1, inner_x2) = (nr.orig_x1 + radius, nr.orig_x2 - radius);
+        let (inner_y1, inner_y2) = (nr.orig_y1 + radius, nr.orig_y2 - radius);
+
+        let inside = |x: i32, y: i32| {
+            if x < nr.orig_x1 || x > nr.orig_x2 || y < nr.orig_y1 || y > nr.orig_y2 {
+                return false;
+            }
+
+            let dx = x - x.clamp(inner_x1, inner_x2);
+            let dy = y - y.clamp(inner_y1, inner_y2);
+
+            dx * dx + dy * dy <= radius * radius
+        };
+
+        for x in nr.x1..=nr.x2 {
+            for y in nr.y1..=nr.y2 {
+                if !inside(x, y) {
+                    continue;
+                }
+
+                // The outline is made up of the filled pixels that border on at
+                // least one unfilled pixel
+                if outline
+                    && inside(x - 1, y)
+                    && inside(x + 1, y)
+                    && inside(x, y - 1)
+                    && inside(x, y + 1)
+                {
+                    continue;
+                }
+
+                self.paint_pixel(x, y, paint);
+            }
+        }
+    }
+
+    /// Draws a one pixel wide line between the two provided points (inclusive)
+    /// using Bresenham's algorithm
+    ///
+    /// The line is clipped to the canvas (and the clip rectangle) before any
+    /// pixels are walked, so a line reaching far off of the canvas costs no more
+    /// than the part of it which is visible
+    ///
+    /// ```
+    /// use farba::{Canvas, RGBAColor};
+    ///
+    /// let mut canvas = Canvas::new(100, 100);
+    /// canvas.line(-1_000_000_000, 50, 1_000_000_000, 50, RGBAColor::RED);
+    ///
+    /// assert_eq!(*canvas.get_pixel(0, 50), RGBAColor::RED.into());
+    /// assert_eq!(*canvas.get_pixel(99, 50), RGBAColor::RED.into());
+    /// ```
+    pub fn line<C: Color>(&mut self, x1: i32, y1: i32, x2: i32, y2: i32, color: C) {
+        let pixel_color = color.pack();
+
+        let ((x1, y1), (x2, y2)) = if self.has_transform() {
+            // Transform the centers of the end pixels and find where they land
+            let end = |x: i32, y: i32| {
+                let point = self.transform_point(Vec2::new(x as f32 + 0.5, y as f32 + 0.5));
+                (point.x.floor() as i32, point.y.floor() as i32)
+            };
+
+            (end(x1, y1), end(x2, y2))
+        } else {
+            ((x1, y1), (x2, y2))
+        };
+
+        clipped_line_pixels(x1, y1, x2, y2, self.clip_bounds(), |x, y| {
+            self.write_pixel(x, y, pixel_color);
+        });
+    }
+
+    /// Fills the polygon described by `points`, which may be concave or even
+    /// intersect itself, using `rule` to decide what counts as its inside
+    ///
+    /// The polygon is implicitly closed. A pixel is filled when its center lies
+    /// inside of the polygon.
+    ///
+    /// ```
+    /// use farba::{Canvas, FillRule, RGBAColor, Vec2};
+    ///
+    /// // A five pointed star drawn in a single stroke overlaps itself in the middle
+    /// let star: Vec<Vec2> = (0..5)
+    ///     .map(|i| {
+    ///         let angle = (-90.0 + 144.0 * i as f32).to_radians();
+    ///         Vec2::new(25.0 + 20.0 * angle.cos(), 25.0 + 20.0 * angle.sin())
+    ///     })
+    ///     .collect();
+    ///
+    /// let mut canvas = Canvas::new(50, 50);
+    ///
+    /// canvas.fill_polygon(&star, FillRule::NonZero, RGBAColor::RED);
+    /// assert_ne!(*canvas.get_pixel(25, 25), 0);
+    ///
+    /// canvas.fill(0u32);
+    ///
+    /// canvas.fill_polygon(&star, FillRule::EvenOdd, RGBAColor::RED);
+    /// assert_eq!(*canvas.get_pixel(25, 25), 0);
+    /// ```
+    pub fn fill_polygon<P: Paint>(&mut self, points: &[Vec2], rule: FillRule, paint: P) {
+        self.fill_contours(&[points], rule, &paint);
+    }
+
     /// Draws a triangle with the provided coordinates as vertices
     ///
     /// Vertices may be supplied in any order as they are normalized before drawing
-    pub fn triangle<C: Color>(
+    #[allow(clippy::too_many_arguments)]
+    pub fn triangle<P: Paint>(
         &mut self,
         x1: i32,
         y1: i32,
@@ -200,36 +1127,159 @@ impl Canvas {
         y2: i32,
         x3: i32,
         y3: i32,
-        color: C,
+        paint: P,
     ) {
-        // TODO: Anti-Aliasing
+        if self.has_transform() {
+            let vertex = |x: i32, y: i32| Vec2::new(x as f32 + 0.5, y as f32 + 0.5);
+            let vertices = [vertex(x1, y1), vertex(x2, y2), vertex(x3, y3)];
 
-        let pixel_color = color.pack();
+            self.fill_contours(&[vertices], FillRule::NonZero, &paint);
+            return;
+        }
 
-        let Some(nt) = normalize_triangle(self.width, self.height, x1, y1, x2, y2, x3, y3) else {
+        let (width, height) = (self.width, self.height);
+
+        triangle_spans(width, height, (x1, y1), (x2, y2), (x3, y3), |y, x1, x2| {
+            for x in x1..=x2 {
+                self.paint_pixel(x, y, &paint);
+            }
+        });
+    }
+
+    /// Draws a triangle whose color is smoothly interpolated between the colors
+    /// given for each of its vertices
+    ///
+    /// Every channel (including alpha) is blended using the barycentric
+    /// coordinates of the center of each pixel, `(x + 0.5, y + 0.5)`. Vertices may
+    /// be supplied in any order.
+    ///
+    /// ```
+    /// use farba::{Canvas, Color, RGBAColor, Vec2};
+    ///
+    /// let mut canvas = Canvas::new(100, 100);
+    /// canvas.triangle_gradient(
+    ///     Vec2::new(0.0, 0.0),
+    ///     RGBAColor::RED,
+    ///     Vec2::new(100.0, 0.0),
+    ///     RGBAColor::GREEN,
+    ///     Vec2::new(0.0, 100.0),
+    ///     RGBAColor::BLUE,
+    /// );
+    ///
+    /// // Each corner takes on the color of its vertex
+    /// assert_eq!(canvas.get_pixel(0, 0).red(), 252);
+    /// assert_eq!(canvas.get_pixel(98, 0).green(), 251);
+    /// assert_eq!(canvas.get_pixel(0, 98).blue(), 251);
+    ///
+    /// // While the middle of the hypotenuse is an even mix of its two ends
+    /// let middle = canvas.get_pixel(49, 49);
+    /// assert_eq!(middle.green(), middle.blue());
+    /// ```
+    pub fn triangle_gradient<C: Color>(
+        &mut self,
+        v1: Vec2,
+        c1: C,
+        v2: Vec2,
+        c2: C,
+        v3: Vec2,
+        c3: C,
+    ) {
+        let [v1, v2, v3] = [v1, v2, v3].map(|vertex| self.transform_point(vertex));
+
+        // Twice the signed area of the triangle (a, b, p)
+        let edge =
+            |a: Vec2, b: Vec2, p: Vec2| (b.x - a.x) * (p.y - a.y) - (b.y - a.y) * (p.x - a.x);
+
+        let area = edge(v1, v2, v3);
+
+        // Degenerate triangles don't cover anything
+        if area.abs() <= f32::EPSILON {
             return;
+        }
+
+        let channels = |color: &C| {
+            [color.red(), color.green(), color.blue(), color.alpha()].map(|channel| channel as f32)
         };
+        let (c1, c2, c3) = (channels(&c1), channels(&c2), channels(&c3));
 
-        let point_in_bounds = |x: i32, y: i32| {
-            // Check (v1, v2)
-            let z1 = (x2 - x1) * (y - y1) - (y2 - y1) * (x - x1);
-            // Check (v2, v3)
-            let z2 = (x3 - x2) * (y - y2) - (y3 - y2) * (x - x2);
-            // Check (v3, v1)
-            let z3 = (x1 - x3) * (y - y3) - (y1 - y3) * (x - x3);
+        let left_x = (v1.x.min(v2.x).min(v3.x).floor() as i32).max(0);
+        let right_x = (v1.x.max(v2.x).max(v3.x).ceil() as i32).min(self.width as i32 - 1);
+        let top_y = (v1.y.min(v2.y).min(v3.y).floor() as i32).max(0);
+        let bottom_y = (v1.y.max(v2.y).max(v3.y).ceil() as i32).min(self.height as i32 - 1);
 
-            z1.signum() >= 0 && z2.signum() >= 0 && z3.signum() >= 0
-        };
+        // How much each barycentric coordinate changes from one pixel to the next
+        let steps = [(v2, v3), (v3, v1), (v1, v2)].map(|(a, b)| -(b.y - a.y) / area);
+
+        for y in top_y..=bottom_y {
+            let start = Vec2::new(left_x as f32 + 0.5, y as f32 + 0.5);
+
+            // Barycentric coordinates, which are all positive inside of the
+            // triangle no matter its winding
+            let start_weights = barycentric(start, v1, v2, v3);
+            let mut weights = [start_weights.x, start_weights.y, start_weights.z];
+
+            for x in left_x..=right_x {
+                let [w1, w2, w3] = weights;
+
+                if w1 >= 0.0 && w2 >= 0.0 && w3 >= 0.0 {
+                    let [red, green, blue, alpha] =
+                        [0, 1, 2, 3].map(|i| (c1[i] * w1 + c2[i] * w2 + c3[i] * w3).round() as u8);
+
+                    self.write_pixel(x, y, crate::rgba!(red, green, blue, alpha));
+                }
 
-        for x in nt.left_x..=nt.right_x {
-            for y in nt.top_y..=nt.bottom_y {
-                if point_in_bounds(x, y) {
-                    *self.get_pixel_mut(x, y) = pixel_color;
+                for (weight, step) in weights.iter_mut().zip(steps) {
+                    *weight += step;
                 }
             }
         }
     }
 
+    /// Draws the one pixel wide outline of a triangle with the provided coordinates
+    /// as vertices
+    ///
+    /// Each vertex is only drawn once, where two edges meet
+    #[allow(clippy::too_many_arguments)]
+    pub fn triangle_outline<C: Color>(
+        &mut self,
+        x1: i32,
+        y1: i32,
+        x2: i32,
+        y2: i32,
+        x3: i32,
+        y3: i32,
+        color: C,
+    ) {
+        let pixel_color = color.pack();
+
+        if self.has_transform() {
+            let vertex = |x: i32, y: i32| Vec2::new(x as f32 + 0.5, y as f32 + 0.5);
+            let vertices = [vertex(x1, y1), vertex(x2, y2), vertex(x3, y3)];
+
+            self.connected_segments(&vertices, true, &pixel_color);
+            return;
+        }
+
+        let Some(nt) = normalize_triangle(self.width, self.height, x1, y1, x2, y2, x3, y3) else {
+            return;
+        };
+
+        for ((start_x, start_y), (end_x, end_y)) in [
+            ((x1, y1), (x2, y2)),
+            ((x2, y2), (x3, y3)),
+            ((x3, y3), (x1, y1)),
+        ] {
+            let bounds = (nt.left_x, nt.top_y, nt.right_x, nt.bottom_y);
+
+            clipped_line_pixels(start_x, start_y, end_x, end_y, bounds, |x, y| {
+                // The end of each edge is the start of the next one
+                if x != end_x || y != end_y {
+                    self.write_pixel(x, y, pixel_color);
+                }
+            });
+        }
+    }
+
     /// Draws a triangle with the provided coordinates as vertices
     ///
     /// Vertices may be supplied in any order as they are normalized before drawing
@@ -239,11 +1289,63 @@ impl Canvas {
         v2: Vec3,
         v3: Vec3,
         color: C,
-        depth_buffer: &mut Vec<f32>,
+        depth_buffer: &mut [f32],
     ) {
-        // TODO: Anti-Aliasing
+        if depth_buffer.len() != self.width * self.height {
+            panic!("Depth buffer was not correct size to match canvas")
+        }
 
-        let pixel_color = color.pack();
+        self.triangle_with_depth_buffer_packed(v1, v2, v3, color.pack(), depth_buffer);
+    }
+
+    /// Draws many depth tested triangles, where `colors[i]` is the color of `tris[i]`
+    ///
+    /// The output is identical to calling [`Canvas::triangle_with_depth_buffer`]
+    /// for each of them in order.
+    ///
+    /// ```
+    /// use farba::{Canvas, DepthBuffer, RGBAColor, Vec3};
+    ///
+    /// let tris = [
+    ///     [Vec3::new(0.0, 0.0, 1.0), Vec3::new(40.0, 0.0, 1.0), Vec3::new(0.0, 40.0, 1.0)],
+    ///     [Vec3::new(10.0, 10.0, 0.0), Vec3::new(50.0, 10.0, 0.0), Vec3::new(10.0, 50.0, 2.0)],
+    /// ];
+    /// let colors = [RGBAColor::RED, RGBAColor::BLUE];
+    ///
+    /// let mut batched = Canvas::new(50, 50);
+    /// batched.triangles(&tris, &colors, &mut DepthBuffer::new(50, 50));
+    ///
+    /// let mut looped = Canvas::new(50, 50);
+    /// let mut depth_buffer = DepthBuffer::new(50, 50);
+    /// for ([v1, v2, v3], color) in tris.iter().zip(&colors) {
+    ///     looped.triangle_with_depth_buffer(*v1, *v2, *v3, color.clone(), &mut depth_buffer);
+    /// }
+    ///
+    /// assert_eq!(batched, looped);
+    /// ```
+    pub fn triangles(&mut self, tris: &[[Vec3; 3]], colors: &[RGBAColor], depth: &mut DepthBuffer) {
+        if tris.len() != colors.len() {
+            panic!("Every triangle must have exactly one color")
+        }
+
+        if depth.len() != self.width * self.height {
+            panic!("Depth buffer was not correct size to match canvas")
+        }
+
+        for (&[v1, v2, v3], color) in tris.iter().zip(colors) {
+            self.triangle_with_depth_buffer_packed(v1, v2, v3, color.pack(), depth);
+        }
+    }
+
+    fn triangle_with_depth_buffer_packed(
+        &mut self,
+        v1: Vec3,
+        v2: Vec3,
+        v3: Vec3,
+        pixel_color: u32,
+        depth_buffer: &mut [f32],
+    ) {
+        let [v1, v2, v3] = [v1, v2, v3].map(|vertex| self.transform_vertex(vertex));
 
         let x1 = v1.x as i32;
         let y1 = v1.y as i32;
@@ -252,25 +1354,6 @@ impl Canvas {
         let x3 = v3.x as i32;
         let y3 = v3.y as i32;
 
-        let Some(nt) = normalize_triangle(self.width, self.height, x1, y1, x2, y2, x3, y3) else {
-            return;
-        };
-
-        let point_in_bounds = |x: i32, y: i32| {
-            // Check (v1, v2)
-            let z1 = (x2 - x1) * (y - y1) - (y2 - y1) * (x - x1);
-            // Check (v2, v3)
-            let z2 = (x3 - x2) * (y - y2) - (y3 - y2) * (x - x2);
-            // Check (v3, v1)
-            let z3 = (x1 - x3) * (y - y3) - (y1 - y3) * (x - x3);
-
-            z1.signum() >= 0 && z2.signum() >= 0 && z3.signum() >= 0
-        };
-
-        if depth_buffer.len() != self.width * self.height {
-            panic!("Depth buffer was not correct size to match canvas")
-        }
-
         // Here we calculate the z value of the pixel on the plane defined by the 3 points
         // Shamelessly stolen from https://math.stackexchange.com/questions/28043/finding-the-z-value-on-a-plane-with-x-y-values
 
@@ -287,31 +1370,269 @@ impl Canvas {
         // Pull out variables
         let Vec3 { x: r, y: s, z: t } = plane_normal;
 
-        // Closure that computes the z value for each pixel and tells us if we
-        // should draw there based on the depth buffer
+        // The plane is linear in x, so z only needs to be solved for at the
+        // start of each span and then changes by the same amount every pixel
+        let z_step = -r / t;
 
-        let width = self.width; // Required for borrow checker :/
+        let (width, height) = (self.width, self.height);
 
-        let mut pixel_is_nearer = |x: i32, y: i32| {
-            let z = (1.0 / t) * (k - r * x as f32 - s * y as f32);
+        triangle_spans(width, height, (x1, y1), (x2, y2), (x3, y3), |y, x1, x2| {
+            let mut z = (1.0 / t) * (k - r * x1 as f32 - s * y as f32);
 
-            let index = width * y as usize + x as usize;
+            for x in x1..=x2 {
+                let index = width * y as usize + x as usize;
 
-            let should_draw = z < depth_buffer[index];
+                // Clipped pixels don't write to the depth buffer either
+                if self.in_clip(x, y) && z < depth_buffer[index] {
+                    depth_buffer[index] = z;
+                    self.write_pixel(x, y, pixel_color);
+                }
 
-            if should_draw {
-                depth_buffer[index] = z;
+                z += z_step;
             }
+        });
+    }
 
-            should_draw
+    /// Draws an anti-aliased triangle with the provided coordinates as vertices
+    ///
+    /// Pixels near an edge are blended with the existing canvas contents based on
+    /// how much of them is covered by the triangle, which is estimated from their
+    /// signed distance to each edge at the pixel center. Partially covered pixels
+    /// don't write to the depth buffer, so the fringe never hides geometry drawn
+    /// later.
+    ///
+    /// The depth buffer also keeps how much of each fringe pixel has been
+    /// covered so far. A later triangle is blended into only the part of the
+    /// pixel left uncovered, assuming it doesn't overlap the earlier fringes,
+    /// and the pixel is written solid once it is fully covered. Triangles
+    /// sharing an edge get complementary coverage along it, so no background
+    /// seam is left between them.
+    ///
+    /// ```
+    /// use farba::{Canvas, Color, DepthBuffer, RGBAColor, Vec3};
+    ///
+    /// let mut canvas = Canvas::new(100, 100);
+    /// let mut depth_buffer = DepthBuffer::new(100, 100);
+    ///
+    /// canvas.triangle_aa(
+    ///     Vec3::new(10.0, 10.0, 0.0),
+    ///     Vec3::new(90.0, 10.0, 0.0),
+    ///     Vec3::new(10.0, 90.0, 0.0),
+    ///     RGBAColor::RED,
+    ///     &mut depth_buffer,
+    /// );
+    ///
+    /// // The centroid is solid
+    /// assert_eq!(*canvas.get_pixel(36, 36), RGBAColor::RED.pack());
+    ///
+    /// // A pixel centered on the hypotenuse is only partially covered
+    /// let edge = canvas.get_pixel(49, 50);
+    /// assert!(edge.red() > 0 && edge.red() < 255);
+    /// ```
+    ///
+    /// Two triangles splitting a square along its diagonal cover it completely:
+    ///
+    /// ```
+    /// use farba::{Canvas, Color, DepthBuffer, RGBAColor, Vec3};
+    ///
+    /// let mut canvas = Canvas::new(40, 40);
+    /// let mut depth_buffer = DepthBuffer::new(40, 40);
+    /// canvas.fill(RGBAColor::WHITE);
+    ///
+    /// let [top_left, top_right, bottom_left, bottom_right] = [
+    ///     Vec3::new(0.0, 0.0, 0.0),
+    ///     Vec3::new(40.0, 0.0, 0.0),
+    ///     Vec3::new(0.0, 40.0, 0.0),
+    ///     Vec3::new(40.0, 40.0, 0.0),
+    /// ];
+    ///
+    /// canvas.triangle_aa(top_left, top_right, bottom_left, RGBAColor::RED, &mut depth_buffer);
+    /// canvas.triangle_aa(top_right, bottom_right, bottom_left, RGBAColor::RED, &mut depth_buffer);
+    ///
+    /// // Including the pixels along the diagonal and the edges of the canvas
+    /// for x in 0..40 {
+    ///     assert_eq!(*canvas.get_pixel(x, 39 - x), RGBAColor::RED.pack());
+    ///     assert_eq!(*canvas.get_pixel(x, 0), RGBAColor::RED.pack());
+    /// }
+    ///
+    /// assert!(canvas.get_pixels().iter().all(|&pixel| pixel == RGBAColor::RED.pack()));
+    /// ```
+    pub fn triangle_aa<C: Color>(
+        &mut self,
+        v1: Vec3,
+        v2: Vec3,
+        v3: Vec3,
+        color: C,
+        depth_buffer: &mut DepthBuffer,
+    ) {
+        self.triangle_antialiased(v1, v2, v3, color, depth_buffer, AntiAliasing::EdgeCoverage);
+    }
+
+    /// Draws a triangle like [`Canvas::triangle_aa`], using `antialiasing` to
+    /// decide how the coverage of the pixels along its edges is estimated
+    ///
+    /// ```
+    /// use farba::{AntiAliasing, Canvas, Color, DepthBuffer, RGBAColor, Vec3};
+    ///
+    /// let mut canvas = Canvas::new(100, 100);
+    /// let mut depth_buffer = DepthBuffer::new(100, 100);
+    ///
+    /// canvas.triangle_antialiased(
+    ///     Vec3::new(10.5, 10.5, 0.0),
+    ///     Vec3::new(90.5, 10.5, 0.0),
+    ///     Vec3::new(10.5, 90.5, 0.0),
+    ///     RGBAColor::RED,
+    ///     &mut depth_buffer,
+    ///     AntiAliasing::Multisample(4),
+    /// );
+    ///
+    /// // Two of the four samples of a pixel centered on the left edge are covered
+    /// assert_eq!(canvas.get_pixel(10, 50).red(), 128);
+    /// ```
+    pub fn triangle_antialiased<C: Color>(
+        &mut self,
+        v1: Vec3,
+        v2: Vec3,
+        v3: Vec3,
+        color: C,
+        depth_buffer: &mut DepthBuffer,
+        antialiasing: AntiAliasing,
+    ) {
+        let pixel_color = color.pack();
+
+        if depth_buffer.len() != self.width * self.height {
+            panic!("Depth buffer was not correct size to match canvas")
+        }
+
+        let [v1, v2, v3] = [v1, v2, v3].map(|vertex| self.transform_vertex(vertex));
+
+        // Twice the signed area of the triangle, which also tells us the winding
+        // order so that the edge functions can be made positive on the inside
+        let area = (v2.x - v1.x) * (v3.y - v1.y) - (v2.y - v1.y) * (v3.x - v1.x);
+
+        // Degenerate triangles don't cover anything
+        if area.abs() <= f32::EPSILON {
+            return;
+        }
+
+        let orientation = area.signum();
+
+        // A triangle can never cover more of a pixel than its own area, which
+        // keeps tiny and very thin triangles from blooming into solid pixels
+        let max_coverage = (area.abs() / 2.0).min(1.0);
+
+        // The signed distance (in pixels) from a point to the edge (a, b) is linear
+        // in x and y, so each edge is described by `(step_x, step_y, offset)` such
+        // that the distance is `step_x * x + step_y * y + offset`
+        let edge_equation = |a: Vec3, b: Vec3| {
+            let length = ((b.x - a.x) * (b.x - a.x) + (b.y - a.y) * (b.y - a.y)).sqrt();
+            let scale = orientation / length;
+
+            (
+                -(b.y - a.y) * scale,
+                (b.x - a.x) * scale,
+                ((b.y - a.y) * a.x - (b.x - a.x) * a.y) * scale,
+            )
+        };
+
+        let edges = [
+            edge_equation(v1, v2),
+            edge_equation(v2, v3),
+            edge_equation(v3, v1),
+        ];
+
+        let edge_distances =
+            |x: f32, y: f32| edges.map(|(step_x, step_y, offset)| step_x * x + step_y * y + offset);
+
+        let samples = match antialiasing {
+            AntiAliasing::Multisample(count) => sample_offsets(count),
+            _ => Vec::new(),
+        };
+
+        let coverage_at = |x: f32, y: f32, distances: [f32; 3]| match antialiasing {
+            // Each edge covers the half of the pixel on its inner side, so a pixel
+            // centered exactly on an edge is half covered
+            AntiAliasing::EdgeCoverage => distances
+                .iter()
+                .map(|distance| (distance + 0.5).clamp(0.0, 1.0))
+                .product::<f32>()
+                .min(max_coverage),
+            _ => coverage(antialiasing, &samples, x, y, |x, y| {
+                let [d1, d2, d3] = edge_distances(x, y);
+                d1.min(d2).min(d3)
+            }),
         };
 
-        for x in nt.left_x..=nt.right_x {
-            for y in nt.top_y..=nt.bottom_y {
-                if point_in_bounds(x, y) && pixel_is_nearer(x, y) {
-                    *self.get_pixel_mut(x, y) = pixel_color;
+        // Expand the bounding box by a pixel so that the fringe on the outside of
+        // each edge is visited as well
+        let left_x = (v1.x.min(v2.x).min(v3.x).floor() as i32 - 1).max(0);
+        let right_x = (v1.x.max(v2.x).max(v3.x).ceil() as i32 + 1).min(self.width as i32 - 1);
+        let top_y = (v1.y.min(v2.y).min(v3.y).floor() as i32 - 1).max(0);
+        let bottom_y = (v1.y.max(v2.y).max(v3.y).ceil() as i32 + 1).min(self.height as i32 - 1);
+
+        // Same plane equation as `triangle_with_depth_buffer`
+        let plane_normal = Vec3::cross(&(v1 - v2), &(v1 - v3));
+        let k = Vec3::dot(&v1, &plane_normal);
+        let Vec3 { x: r, y: s, z: t } = plane_normal;
+
+        // Both the edge distances and the depth only change by a constant amount
+        // from one pixel to the next, so they are stepped along each row instead
+        // of being evaluated from scratch
+        let z_step = -r / t;
+
+        // Pixels are sampled at their centers, like the other shapes
+        for y in top_y..=bottom_y {
+            let py = y as f32 + 0.5;
+            let left = left_x as f32 + 0.5;
+
+            let mut distances = edge_distances(left, py);
+            let mut z = (1.0 / t) * (k - r * left - s * py);
+
+            for x in left_x..=right_x {
+                let coverage = coverage_at(x as f32 + 0.5, py, distances);
+                let index = self.get_index(x, y);
+
+                if coverage > 0.0 && self.in_clip(x, y) && z < depth_buffer[index] {
+                    // Only the part of the pixel left uncovered by earlier
+                    // fringes is blended into, so triangles meeting along an
+                    // edge add up to a solid pixel without the background
+                    // showing through
+                    let covered = depth_buffer.coverage[index];
+                    let blend = (coverage / (1.0 - covered)).min(1.0);
+
+                    if covered + coverage >= 1.0 {
+                        depth_buffer[index] = z;
+                        depth_buffer.coverage[index] = 0.0;
+                    } else {
+                        depth_buffer.coverage[index] = covered + coverage;
+                    }
+
+                    self.cover_pixel(x, y, pixel_color, blend);
                 }
+
+                for (distance, (step_x, _, _)) in distances.iter_mut().zip(&edges) {
+                    *distance += step_x;
+                }
+                z += z_step;
             }
         }
     }
 }
+
+/// Converts a rectangle given like in [`Canvas::rect`] into the `(x1, y1, x2, y2)`
+/// bounds of the area covered by its pixels, without clipping it to a canvas
+fn rect_bounds(x: i32, y: i32, width: i32, height: i32) -> Option<(f32, f32, f32, f32)> {
+    if width == 0 || height == 0 {
+        return None;
+    }
+
+    let (x1, x2) = (x, x + width - width.signum());
+    let (y1, y2) = (y, y + height - height.signum());
+
+    Some((
+        x1.min(x2) as f32,
+        y1.min(y2) as f32,
+        x1.max(x2) as f32 + 1.0,
+        y1.max(y2) as f32 + 1.0,
+    ))
+}
@@ -1,13 +1,15 @@
-use crate::Vec3;
+use std::sync::OnceLock;
+
+use crate::{mix_oklab, mix_oklch, Vec3};
 
 /// Macro to pack RGBA values into a u32
 #[macro_export]
 macro_rules! rgba {
     ($r:expr, $g:expr, $b:expr, $a:expr) => {
-        (($r as u32 & 0xFF) << (8 * 0))
-            | (($g as u32 & 0xFF) << (8 * 1))
-            | (($b as u32 & 0xFF) << (8 * 2))
-            | (($a as u32 & 0xFF) << (8 * 3))
+        ($r as u32 & 0xFF)
+            | (($g as u32 & 0xFF) << 8)
+            | (($b as u32 & 0xFF) << 16)
+            | (($a as u32 & 0xFF) << 24)
     };
 }
 
@@ -15,10 +17,7 @@ macro_rules! rgba {
 #[macro_export]
 macro_rules! rgb {
     ($r:expr, $g:expr, $b:expr) => {
-        (($r as u32 & 0xFF) << (8 * 0))
-            | (($g as u32 & 0xFF) << (8 * 1))
-            | (($b as u32 & 0xFF) << (8 * 2))
-            | ((0xFF as u32 & 0xFF) << (8 * 3))
+        ($r as u32 & 0xFF) | (($g as u32 & 0xFF) << 8) | (($b as u32 & 0xFF) << 16) | (0xFF << 24)
     };
 }
 
@@ -60,22 +59,22 @@ impl Color for RGBAColor {
 impl Color for u32 {
     #[inline]
     fn red(&self) -> u8 {
-        ((self & 0x000000FF) >> (8 * 0)) as u8
+        (self & 0x000000FF) as u8
     }
 
     #[inline]
     fn green(&self) -> u8 {
-        ((self & 0x0000FF00) >> (8 * 1)) as u8
+        ((self & 0x0000FF00) >> 8) as u8
     }
 
     #[inline]
     fn blue(&self) -> u8 {
-        ((self & 0x00FF0000) >> (8 * 2)) as u8
+        ((self & 0x00FF0000) >> 16) as u8
     }
 
     #[inline]
     fn alpha(&self) -> u8 {
-        ((self & 0xFF000000) >> (8 * 3)) as u8
+        ((self & 0xFF000000) >> 24) as u8
     }
 
     #[inline]
@@ -84,6 +83,130 @@ impl Color for u32 {
     }
 }
 
+/// Opaque colors as `(red, green, blue)`
+///
+/// ```
+/// use farba::{Canvas, RGBAColor};
+///
+/// let mut canvas = Canvas::new(10, 10);
+/// canvas.rect(0, 0, 5, 5, (255, 0, 0));
+/// canvas.rect(5, 5, 5, 5, [0, 0, 255, 255]);
+///
+/// assert_eq!(*canvas.get_pixel(2, 2), RGBAColor::RED.into());
+/// assert_eq!(*canvas.get_pixel(7, 7), RGBAColor::BLUE.into());
+/// ```
+impl Color for (u8, u8, u8) {
+    #[inline]
+    fn red(&self) -> u8 {
+        self.0
+    }
+
+    #[inline]
+    fn green(&self) -> u8 {
+        self.1
+    }
+
+    #[inline]
+    fn blue(&self) -> u8 {
+        self.2
+    }
+
+    #[inline]
+    fn alpha(&self) -> u8 {
+        255
+    }
+
+    #[inline]
+    fn pack(&self) -> u32 {
+        rgb!(self.0, self.1, self.2)
+    }
+}
+
+/// Colors as `(red, green, blue, alpha)`
+impl Color for (u8, u8, u8, u8) {
+    #[inline]
+    fn red(&self) -> u8 {
+        self.0
+    }
+
+    #[inline]
+    fn green(&self) -> u8 {
+        self.1
+    }
+
+    #[inline]
+    fn blue(&self) -> u8 {
+        self.2
+    }
+
+    #[inline]
+    fn alpha(&self) -> u8 {
+        self.3
+    }
+
+    #[inline]
+    fn pack(&self) -> u32 {
+        rgba!(self.0, self.1, self.2, self.3)
+    }
+}
+
+/// Opaque colors as `[red, green, blue]`
+impl Color for [u8; 3] {
+    #[inline]
+    fn red(&self) -> u8 {
+        self[0]
+    }
+
+    #[inline]
+    fn green(&self) -> u8 {
+        self[1]
+    }
+
+    #[inline]
+    fn blue(&self) -> u8 {
+        self[2]
+    }
+
+    #[inline]
+    fn alpha(&self) -> u8 {
+        255
+    }
+
+    #[inline]
+    fn pack(&self) -> u32 {
+        rgb!(self[0], self[1], self[2])
+    }
+}
+
+/// Colors as `[red, green, blue, alpha]`, the order of the bytes of a pixel in
+/// memory
+impl Color for [u8; 4] {
+    #[inline]
+    fn red(&self) -> u8 {
+        self[0]
+    }
+
+    #[inline]
+    fn green(&self) -> u8 {
+        self[1]
+    }
+
+    #[inline]
+    fn blue(&self) -> u8 {
+        self[2]
+    }
+
+    #[inline]
+    fn alpha(&self) -> u8 {
+        self[3]
+    }
+
+    #[inline]
+    fn pack(&self) -> u32 {
+        u32::from_le_bytes(*self)
+    }
+}
+
 #[derive(Default, Debug, Clone)]
 pub struct RGBAColor {
     pub red: u8,
@@ -121,6 +244,132 @@ impl RGBAColor {
             alpha: a,
         }
     }
+
+    /// Linearly mixes `a` and `b` channel by channel, where a `t` of `0.0`
+    /// yields `a` and a `t` of `1.0` yields `b`
+    ///
+    /// This is a mix in [`ColorSpace::Srgb`]. `t` is clamped to `[0, 1]`.
+    pub fn lerp(a: &RGBAColor, b: &RGBAColor, t: f32) -> RGBAColor {
+        mix_packed(a.pack(), b.pack(), t.clamp(0.0, 1.0)).into()
+    }
+
+    /// Mixes the color with `other` in the color `space`, where a `t` of `0.0`
+    /// yields this color and a `t` of `1.0` yields `other`
+    ///
+    /// Alpha is always mixed linearly. `t` is clamped to `[0, 1]`.
+    ///
+    /// ```
+    /// use farba::{ColorSpace, RGBAColor};
+    ///
+    /// let srgb = RGBAColor::RED.mix(&RGBAColor::GREEN, 0.5, ColorSpace::Srgb);
+    /// let linear = RGBAColor::RED.mix(&RGBAColor::GREEN, 0.5, ColorSpace::Linear);
+    ///
+    /// // Half of the light of each color is brighter than half of their values
+    /// assert_eq!((srgb.red, srgb.green, srgb.blue), (128, 128, 0));
+    /// assert_eq!((linear.red, linear.green, linear.blue), (188, 188, 0));
+    /// ```
+    pub fn mix(&self, other: &RGBAColor, t: f32, space: ColorSpace) -> RGBAColor {
+        mix_packed_in(space, self.pack(), other.pack(), t.clamp(0.0, 1.0)).into()
+    }
+
+    /// Converts the color to premultiplied alpha, where the color channels have
+    /// already been scaled by the alpha
+    ///
+    /// ```
+    /// use farba::RGBAColor;
+    ///
+    /// let color = RGBAColor::from_rgba(255, 100, 0, 128).premultiplied();
+    /// assert_eq!((color.red, color.green, color.blue, color.alpha), (128, 50, 0, 128));
+    /// ```
+    pub fn premultiplied(&self) -> RGBAColor {
+        premultiply_packed(self.pack()).into()
+    }
+
+    /// Converts the color from premultiplied alpha back to straight alpha
+    ///
+    /// Fully transparent colors have no color left to recover, so they become
+    /// transparent black
+    pub fn unpremultiplied(&self) -> RGBAColor {
+        unpremultiply_packed(self.pack()).into()
+    }
+}
+
+/// The space colors are mixed in, by [`RGBAColor::mix`], gradients and
+/// [`Canvas::set_blend_space`]
+///
+/// [`Canvas::set_blend_space`]: crate::Canvas::set_blend_space
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ColorSpace {
+    /// Mixes the stored sRGB values directly, which is cheap but makes the
+    /// middle of a mix between two bright colors darker than it should be
+    #[default]
+    Srgb,
+    /// Converts to linear light before mixing and back to sRGB afterwards, so
+    /// colors mix the same way light does
+    Linear,
+    /// Mixes in [`Oklab`], which keeps the lightness of a mix even to the eye
+    /// and avoids the grayish middle of mixes between opposite hues in sRGB
+    Oklab,
+    /// Mixes in [`Oklch`], turning the hue the short way around the color wheel
+    /// so that mixes stay as colorful as the colors they come from
+    Oklch,
+}
+
+/// The order the channels of a color are packed into a `u32`, from the lowest
+/// byte to the highest, which is also the order of the bytes in memory on little
+/// endian machines
+///
+/// Canvases pack their pixels as [`ChannelOrder::Rgba`], while many windowing
+/// libraries and framebuffers expect one of the other orders.
+///
+/// ```
+/// use farba::{rgba, ChannelOrder};
+///
+/// let color = rgba!(0x11, 0x22, 0x33, 0x44);
+///
+/// assert_eq!(ChannelOrder::Rgba.pack(color), 0x44332211);
+/// // `0xAARRGGBB`, which minifb and Windows bitmaps call ARGB
+/// assert_eq!(ChannelOrder::Bgra.pack(color), 0x44112233);
+/// assert_eq!(ChannelOrder::Argb.pack(color), 0x33221144);
+/// assert_eq!(ChannelOrder::Abgr.pack(color), 0x11223344);
+///
+/// assert_eq!(ChannelOrder::Argb.unpack(0x33221144), 0x44332211);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ChannelOrder {
+    #[default]
+    Rgba,
+    Bgra,
+    Argb,
+    Abgr,
+}
+
+impl ChannelOrder {
+    /// Packs `color` into a `u32` in this order
+    #[inline]
+    pub fn pack<C: Color>(&self, color: C) -> u32 {
+        let packed = color.pack();
+
+        match self {
+            ChannelOrder::Rgba => packed,
+            ChannelOrder::Bgra => {
+                (packed & 0xFF00FF00) | ((packed & 0xFF) << 16) | ((packed >> 16) & 0xFF)
+            }
+            ChannelOrder::Argb => packed.rotate_left(8),
+            ChannelOrder::Abgr => packed.swap_bytes(),
+        }
+    }
+
+    /// Converts a `u32` packed in this order back into the order used by
+    /// canvases
+    #[inline]
+    pub fn unpack(&self, packed: u32) -> u32 {
+        match self {
+            ChannelOrder::Argb => packed.rotate_right(8),
+            // Every other conversion is its own inverse
+            _ => self.pack(packed),
+        }
+    }
 }
 
 impl From<Vec3> for RGBAColor {
@@ -145,10 +394,10 @@ impl From<RGBAColor> for u32 {
 impl From<&RGBAColor> for u32 {
     #[inline]
     fn from(color: &RGBAColor) -> Self {
-        ((color.red as u32 & 0xFF) << (8 * 0))
-            | ((color.green as u32 & 0xFF) << (8 * 1))
-            | ((color.blue as u32 & 0xFF) << (8 * 2))
-            | ((color.alpha as u32 & 0xFF) << (8 * 3))
+        (color.red as u32 & 0xFF)
+            | ((color.green as u32 & 0xFF) << 8)
+            | ((color.blue as u32 & 0xFF) << 16)
+            | ((color.alpha as u32 & 0xFF) << 24)
     }
 }
 
@@ -163,3 +412,157 @@ impl From<u32> for RGBAColor {
         }
     }
 }
+
+/// Linearly mixes two packed colors channel by channel, where a `t` of `0.0`
+/// yields `a` and a `t` of `1.0` yields `b`
+#[inline]
+pub(crate) fn mix_packed(a: u32, b: u32, t: f32) -> u32 {
+    use crate::interpolation::lerp;
+
+    let mix = |a: u8, b: u8| lerp(t, a as f32, b as f32).round() as u8;
+
+    rgba!(
+        mix(a.red(), b.red()),
+        mix(a.green(), b.green()),
+        mix(a.blue(), b.blue()),
+        mix(a.alpha(), b.alpha())
+    )
+}
+
+/// Mixes two packed colors like `mix_packed`, but in the color `space`
+#[inline]
+pub(crate) fn mix_packed_in(space: ColorSpace, a: u32, b: u32, t: f32) -> u32 {
+    match space {
+        ColorSpace::Srgb => return mix_packed(a, b, t),
+        ColorSpace::Oklab => return mix_oklab(a, b, t),
+        ColorSpace::Oklch => return mix_oklch(a, b, t),
+        ColorSpace::Linear => {}
+    }
+
+    use crate::interpolation::lerp;
+
+    let mix = |a: u8, b: u8| linear_to_srgb(lerp(t, srgb_to_linear(a), srgb_to_linear(b)));
+
+    rgba!(
+        mix(a.red(), b.red()),
+        mix(a.green(), b.green()),
+        mix(a.blue(), b.blue()),
+        lerp(t, a.alpha() as f32, b.alpha() as f32).round() as u8
+    )
+}
+
+/// Converts an sRGB encoded channel to linear light in `[0, 1]`
+#[inline]
+pub(crate) fn srgb_to_linear(channel: u8) -> f32 {
+    static TABLE: OnceLock<[f32; 256]> = OnceLock::new();
+
+    let table = TABLE.get_or_init(|| {
+        std::array::from_fn(|channel| {
+            let value = channel as f32 / 255.0;
+
+            if value <= 0.04045 {
+                value / 12.92
+            } else {
+                ((value + 0.055) / 1.055).powf(2.4)
+            }
+        })
+    });
+
+    table[channel as usize]
+}
+
+/// Number of steps linear light is rounded to when converting it back to sRGB,
+/// which is fine enough that every channel survives a round trip
+const LINEAR_STEPS: usize = 4096;
+
+/// Converts linear light in `[0, 1]` to an sRGB encoded channel
+#[inline]
+pub(crate) fn linear_to_srgb(value: f32) -> u8 {
+    static TABLE: OnceLock<[u8; LINEAR_STEPS]> = OnceLock::new();
+
+    let table = TABLE.get_or_init(|| {
+        std::array::from_fn(|step| {
+            let value = step as f32 / (LINEAR_STEPS - 1) as f32;
+
+            let encoded = if value <= 0.0031308 {
+                value * 12.92
+            } else {
+                1.055 * value.powf(1.0 / 2.4) - 0.055
+            };
+
+            (encoded * 255.0).round() as u8
+        })
+    });
+
+    let step = (value.clamp(0.0, 1.0) * (LINEAR_STEPS - 1) as f32).round();
+    table[step as usize]
+}
+
+/// Composites the packed `src` color over the packed `dst` color using straight
+/// (non-premultiplied) source-over alpha blending
+#[inline]
+pub(crate) fn blend_over(dst: u32, src: u32) -> u32 {
+    match src.alpha() {
+        255 => return src,
+        0 => return dst,
+        _ => {}
+    }
+
+    let src_a = src.alpha() as f32 / 255.0;
+    let dst_a = dst.alpha() as f32 / 255.0;
+
+    let out_a = src_a + dst_a * (1.0 - src_a);
+
+    let channel = |s: u8, d: u8| {
+        ((s as f32 * src_a + d as f32 * dst_a * (1.0 - src_a)) / out_a).round() as u8
+    };
+
+    rgba!(
+        channel(src.red(), dst.red()),
+        channel(src.green(), dst.green()),
+        channel(src.blue(), dst.blue()),
+        (out_a * 255.0).round() as u8
+    )
+}
+
+/// Scales the color channels of a packed straight alpha color by its alpha
+#[inline]
+pub(crate) fn premultiply_packed(color: u32) -> u32 {
+    let alpha = color.alpha() as u32;
+
+    match alpha {
+        255 => color,
+        0 => 0,
+        _ => {
+            let scale = |channel: u8| (channel as u32 * alpha + 127) / 255;
+
+            rgba!(
+                scale(color.red()),
+                scale(color.green()),
+                scale(color.blue()),
+                alpha
+            )
+        }
+    }
+}
+
+/// Undoes `premultiply_packed`, as closely as the lost precision allows
+#[inline]
+pub(crate) fn unpremultiply_packed(color: u32) -> u32 {
+    let alpha = color.alpha() as u32;
+
+    match alpha {
+        255 => color,
+        0 => 0,
+        _ => {
+            let unscale = |channel: u8| ((channel as u32 * 255 + alpha / 2) / alpha).min(255);
+
+            rgba!(
+                unscale(color.red()),
+                unscale(color.green()),
+                unscale(color.blue()),
+                alpha
+            )
+        }
+    }
+}
@@ -0,0 +1,367 @@
+use crate::{rgba, Canvas, Color, LumaWeights, Paint};
+
+/// A 4x5 matrix which maps every color onto a new one, covering effects such as
+/// desaturation, hue rotation, sepia toning and swapping channels
+///
+/// Each row produces one channel of the result, in the order red, green, blue
+/// and alpha, from the channels of the input scaled to `[0, 1]`:
+///
+/// ```text
+/// | r' |   | m[0][0] m[0][1] m[0][2] m[0][3] m[0][4] |   | r |
+/// | g' |   | m[1][0] m[1][1] m[1][2] m[1][3] m[1][4] |   | g |
+/// | b' | = | m[2][0] m[2][1] m[2][2] m[2][3] m[2][4] | * | b |
+/// | a' |   | m[3][0] m[3][1] m[3][2] m[3][3] m[3][4] |   | a |
+///                                                       | 1 |
+/// ```
+///
+/// The last column is an offset added to each channel. Results are clamped back
+/// into range, and colors are always taken with straight alpha.
+///
+/// ```
+/// use farba::{Canvas, Color, ColorMatrix, RGBAColor};
+///
+/// let mut canvas = Canvas::new(2, 1);
+/// canvas.set_pixel(0, 0, RGBAColor::RED);
+/// canvas.set_pixel(1, 0, RGBAColor::from_rgb(200, 150, 100));
+///
+/// canvas.apply_color_matrix(&ColorMatrix::saturation(0.0));
+///
+/// let pixel = canvas.get_pixel(1, 0);
+/// assert_eq!((pixel.red(), pixel.green(), pixel.blue()), (157, 157, 157));
+///
+/// // Effects can be chained into a single matrix
+/// let effect = ColorMatrix::sepia().then(&ColorMatrix::brightness(0.5));
+/// let color = effect.apply(RGBAColor::WHITE);
+/// assert_eq!((color.red(), color.green(), color.blue()), (172, 153, 119));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColorMatrix {
+    pub m: [[f32; 5]; 4],
+}
+
+impl ColorMatrix {
+    /// The matrix which leaves every color as it is
+    #[rustfmt::skip]
+    pub const IDENTITY: ColorMatrix = ColorMatrix {
+        m: [
+            [1.0, 0.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0, 0.0],
+        ],
+    };
+
+    pub const fn new(m: [[f32; 5]; 4]) -> Self {
+        Self { m }
+    }
+
+    /// Scales how colorful every color is, where `0.0` turns everything gray
+    /// and values above `1.0` oversaturate
+    pub fn saturation(amount: f32) -> Self {
+        let [r, g, b] = LumaWeights::Rec709.weights();
+        let s = amount;
+
+        #[rustfmt::skip]
+        let m = [
+            [r + (1.0 - r) * s, g - g * s,         b - b * s,         0.0, 0.0],
+            [r - r * s,         g + (1.0 - g) * s, b - b * s,         0.0, 0.0],
+            [r - r * s,         g - g * s,         b + (1.0 - b) * s, 0.0, 0.0],
+            [0.0,               0.0,               0.0,               1.0, 0.0],
+        ];
+
+        Self::new(m)
+    }
+
+    /// Turns every color into the gray of the same brightness, like
+    /// `saturation(0.0)`
+    pub fn grayscale() -> Self {
+        Self::saturation(0.0)
+    }
+
+    /// Rotates the hue of every color by `angle` radians while keeping its
+    /// brightness
+    pub fn hue_rotate(angle: f32) -> Self {
+        let (sin, cos) = angle.sin_cos();
+
+        #[rustfmt::skip]
+        let m = [
+            [
+                0.213 + cos * 0.787 - sin * 0.213,
+                0.715 - cos * 0.715 - sin * 0.715,
+                0.072 - cos * 0.072 + sin * 0.928,
+                0.0, 0.0,
+            ],
+            [
+                0.213 - cos * 0.213 + sin * 0.143,
+                0.715 + cos * 0.285 + sin * 0.140,
+                0.072 - cos * 0.072 - sin * 0.283,
+                0.0, 0.0,
+            ],
+            [
+                0.213 - cos * 0.213 - sin * 0.787,
+                0.715 - cos * 0.715 + sin * 0.715,
+                0.072 + cos * 0.928 + sin * 0.072,
+                0.0, 0.0,
+            ],
+            [0.0, 0.0, 0.0, 1.0, 0.0],
+        ];
+
+        Self::new(m)
+    }
+
+    /// Gives every color the warm brown tone of an old photograph
+    #[rustfmt::skip]
+    pub fn sepia() -> Self {
+        Self::new([
+            [0.393, 0.769, 0.189, 0.0, 0.0],
+            [0.349, 0.686, 0.168, 0.0, 0.0],
+            [0.272, 0.534, 0.131, 0.0, 0.0],
+            [0.0,   0.0,   0.0,   1.0, 0.0],
+        ])
+    }
+
+    /// Multiplies the color channels of every color by `factor`
+    pub fn brightness(factor: f32) -> Self {
+        let mut matrix = Self::IDENTITY;
+
+        for channel in 0..3 {
+            matrix.m[channel][channel] = factor;
+        }
+
+        matrix
+    }
+
+    /// Shifts the white balance as if the scene were lit by light `kelvin_shift`
+    /// kelvin away from the 6500K of daylight, so that negative shifts warm the
+    /// colors towards candle light and positive ones cool them towards a blue sky
+    ///
+    /// Each channel is scaled by the color a blackbody glows at that temperature
+    /// relative to daylight, with the strongest channel left unchanged so that
+    /// nothing gets brighter. Temperatures are kept between 1000K and 40000K.
+    pub fn temperature(kelvin_shift: f32) -> Self {
+        let daylight = blackbody(DAYLIGHT);
+        let light = blackbody((DAYLIGHT + kelvin_shift).clamp(1000.0, 40000.0));
+
+        let factors = [0, 1, 2].map(|c| light[c] / daylight[c]);
+        let strongest = factors.iter().copied().fold(f32::EPSILON, f32::max);
+
+        let mut matrix = Self::IDENTITY;
+
+        for (channel, factor) in factors.iter().enumerate() {
+            matrix.m[channel][channel] = factor / strongest;
+        }
+
+        matrix
+    }
+
+    /// Shifts colors along the green to magenta axis, which
+    /// [`ColorMatrix::temperature`] leaves alone
+    ///
+    /// `amount` runs from `-1` to `1`. Positive amounts tint towards magenta by
+    /// taking away green, and negative ones tint towards green by taking away
+    /// red and blue, with `±1` removing them entirely.
+    pub fn tint(amount: f32) -> Self {
+        let amount = amount.clamp(-1.0, 1.0);
+        let mut matrix = Self::IDENTITY;
+
+        if amount > 0.0 {
+            matrix.m[1][1] = 1.0 - amount;
+        } else {
+            matrix.m[0][0] = 1.0 + amount;
+            matrix.m[2][2] = 1.0 + amount;
+        }
+
+        matrix
+    }
+
+    /// Replaces every color with its negative, leaving alpha alone
+    pub fn invert() -> Self {
+        let mut matrix = Self::IDENTITY;
+
+        for channel in 0..3 {
+            matrix.m[channel][channel] = -1.0;
+            matrix.m[channel][4] = 1.0;
+        }
+
+        matrix
+    }
+
+    /// Rearranges the channels of every color, where each entry of `channels`
+    /// is the index (`0` red, `1` green, `2` blue, `3` alpha) of the input
+    /// channel that the red, green, blue and alpha of the result are taken from
+    ///
+    /// `swizzle([2, 1, 0, 3])` swaps red and blue, for example.
+    ///
+    /// Panics if any index is above `3`
+    pub fn swizzle(channels: [usize; 4]) -> Self {
+        let mut matrix = Self::new([[0.0; 5]; 4]);
+
+        for (row, &channel) in channels.iter().enumerate() {
+            if channel > 3 {
+                panic!("Channel index {channel} is out of range")
+            }
+
+            matrix.m[row][channel] = 1.0;
+        }
+
+        matrix
+    }
+
+    /// Combines two matrices into one which applies this one first and then
+    /// `next`
+    ///
+    /// Unlike applying the two in turn, the color isn't clamped in between.
+    pub fn then(&self, next: &ColorMatrix) -> ColorMatrix {
+        let mut result = [[0.0; 5]; 4];
+
+        for (row, output) in result.iter_mut().enumerate() {
+            for (column, value) in output.iter_mut().enumerate() {
+                *value = (0..4).map(|k| next.m[row][k] * self.m[k][column]).sum();
+            }
+
+            output[4] += next.m[row][4];
+        }
+
+        ColorMatrix::new(result)
+    }
+
+    /// Maps a single color through the matrix, giving the packed result
+    pub fn apply<C: Color>(&self, color: C) -> u32 {
+        let input = [
+            color.red() as f32 / 255.0,
+            color.green() as f32 / 255.0,
+            color.blue() as f32 / 255.0,
+            color.alpha() as f32 / 255.0,
+        ];
+
+        let channel = |row: &[f32; 5]| {
+            let value = row[4] + (0..4).map(|k| row[k] * input[k]).sum::<f32>();
+
+            (value.clamp(0.0, 1.0) * 255.0).round()
+        };
+
+        rgba!(
+            channel(&self.m[0]),
+            channel(&self.m[1]),
+            channel(&self.m[2]),
+            channel(&self.m[3])
+        )
+    }
+}
+
+/// Color temperature of daylight in kelvin, which is taken as neutral white
+const DAYLIGHT: f32 = 6500.0;
+
+/// Approximates the color of a blackbody glowing at `kelvin`, as red, green and
+/// blue from `0` to `1`
+///
+/// This is Tanner Helland's curve fit of the blackbody colors, which holds from
+/// 1000K to 40000K.
+#[allow(clippy::excessive_precision)]
+fn blackbody(kelvin: f32) -> [f32; 3] {
+    let temperature = kelvin / 100.0;
+
+    let red = if temperature <= 66.0 {
+        255.0
+    } else {
+        329.698727446 * (temperature - 60.0).powf(-0.1332047592)
+    };
+
+    let green = if temperature <= 66.0 {
+        99.4708025861 * temperature.ln() - 161.1195681661
+    } else {
+        288.1221695283 * (temperature - 60.0).powf(-0.0755148492)
+    };
+
+    let blue = if temperature >= 66.0 {
+        255.0
+    } else if temperature <= 19.0 {
+        0.0
+    } else {
+        138.5177312231 * (temperature - 10.0).ln() - 305.0447927307
+    };
+
+    [red, green, blue].map(|channel| channel.clamp(0.0, 255.0) / 255.0)
+}
+
+impl Default for ColorMatrix {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+/// A paint which passes the colors of another paint through a [`ColorMatrix`]
+///
+/// ```
+/// use farba::{Canvas, ColorMatrix, ColorMatrixPaint, RGBAColor};
+///
+/// let mut canvas = Canvas::new(10, 10);
+/// canvas.rect(0, 0, 10, 10, ColorMatrixPaint::new(RGBAColor::RED, ColorMatrix::invert()));
+///
+/// assert_eq!(*canvas.get_pixel(5, 5), RGBAColor::CYAN.into());
+/// ```
+#[derive(Debug, Clone)]
+pub struct ColorMatrixPaint<P: Paint> {
+    pub paint: P,
+    pub matrix: ColorMatrix,
+}
+
+impl<P: Paint> ColorMatrixPaint<P> {
+    pub fn new(paint: P, matrix: ColorMatrix) -> Self {
+        Self { paint, matrix }
+    }
+}
+
+impl<P: Paint> Paint for ColorMatrixPaint<P> {
+    fn color_at(&self, x: f32, y: f32) -> u32 {
+        self.matrix.apply(self.paint.color_at(x, y))
+    }
+}
+
+impl<P: Paint> Paint for &ColorMatrixPaint<P> {
+    #[inline]
+    fn color_at(&self, x: f32, y: f32) -> u32 {
+        (**self).color_at(x, y)
+    }
+}
+
+impl Canvas {
+    /// Maps every pixel on the canvas through `matrix`
+    ///
+    /// This works on whole pixels, so the clip, transform, composite operator
+    /// and blend mode are all ignored.
+    pub fn apply_color_matrix(&mut self, matrix: &ColorMatrix) {
+        for y in 0..self.get_height() as i32 {
+            for x in 0..self.get_width() as i32 {
+                let color = matrix.apply(self.straight_pixel(x, y));
+
+                *self.get_pixel_mut(x, y) = self.stored_color(color);
+            }
+        }
+    }
+
+    /// Shifts the white balance of every pixel, as described in
+    /// [`ColorMatrix::temperature`]
+    ///
+    /// ```
+    /// use farba::{Canvas, Color, RGBAColor};
+    ///
+    /// let mut canvas = Canvas::new(10, 10);
+    /// canvas.fill(RGBAColor::WHITE);
+    ///
+    /// // Evening light around 3500K
+    /// canvas.adjust_temperature(-3000.0);
+    ///
+    /// let pixel = canvas.get_pixel(5, 5);
+    /// assert_eq!((pixel.red(), pixel.green(), pixel.blue()), (255, 193, 144));
+    /// ```
+    pub fn adjust_temperature(&mut self, kelvin_shift: f32) {
+        self.apply_color_matrix(&ColorMatrix::temperature(kelvin_shift));
+    }
+
+    /// Shifts every pixel along the green to magenta axis, as described in
+    /// [`ColorMatrix::tint`]
+    pub fn tint(&mut self, amount: f32) {
+        self.apply_color_matrix(&ColorMatrix::tint(amount));
+    }
+}
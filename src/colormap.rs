@@ -0,0 +1,282 @@
+use crate::{normalize_rect, rgb, Canvas, RGBAColor};
+
+/// A scientific colormap turning a value from `0` to `1` into a color, for
+/// plotting heatmaps and heightfields
+///
+/// Viridis, plasma and inferno change evenly in lightness along the whole
+/// range, so they stay readable in grayscale and for colorblind viewers. The
+/// colors come from smooth polynomial fits of the original tables rather than
+/// the tables themselves.
+///
+/// ```
+/// use farba::Colormap;
+///
+/// let low = Colormap::Viridis.sample(0.0);
+/// let high = Colormap::Viridis.sample(1.0);
+///
+/// // Dark purple to yellow
+/// assert!(low.blue > low.green && low.red < 80);
+/// assert!(high.red > 240 && high.green > 220 && high.blue < 50);
+///
+/// let middle = Colormap::Grayscale.sample(0.5);
+/// assert_eq!((middle.red, middle.green, middle.blue), (128, 128, 128));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Colormap {
+    /// Dark purple through blue and green to yellow
+    #[default]
+    Viridis,
+    /// Dark blue through magenta and orange to yellow
+    Plasma,
+    /// Black through purple and red to pale yellow
+    Inferno,
+    /// A rainbow from dark blue through green to dark red, which shows fine
+    /// detail well but doesn't change evenly in lightness
+    Turbo,
+    /// Black to white
+    Grayscale,
+}
+
+// Coefficients of the polynomial for each channel, from the constant term up
+
+#[allow(clippy::excessive_precision)]
+const VIRIDIS: [[f32; 7]; 3] = [
+    [
+        0.2777273272234177,
+        0.1050930431085774,
+        -0.3308618287255563,
+        -4.634230498983486,
+        6.228269936347081,
+        4.776384997670288,
+        -5.435455855934631,
+    ],
+    [
+        0.005407344544966578,
+        1.404613529898575,
+        0.214847559468213,
+        -5.799100973351585,
+        14.17993336680509,
+        -13.74514537774601,
+        4.645852612178535,
+    ],
+    [
+        0.3340998053353061,
+        1.384590162594685,
+        0.09509516302823659,
+        -19.33244095627987,
+        56.69055260068105,
+        -65.35303263337234,
+        26.3124352495832,
+    ],
+];
+
+#[allow(clippy::excessive_precision)]
+const PLASMA: [[f32; 7]; 3] = [
+    [
+        0.05873234392399702,
+        2.176514634195958,
+        -2.689460476458034,
+        6.130348345893603,
+        -11.10743619062271,
+        10.02306557647065,
+        -3.658713842777788,
+    ],
+    [
+        0.02333670892565664,
+        0.2383834171260182,
+        -7.455851135738909,
+        42.3461881477227,
+        -82.66631109428045,
+        71.41361770095349,
+        -22.93153465461149,
+    ],
+    [
+        0.5433401826748754,
+        0.7539604599784036,
+        3.110799939717086,
+        -28.51885465332158,
+        60.13984767418263,
+        -54.07218655560067,
+        18.19190778539828,
+    ],
+];
+
+#[allow(clippy::excessive_precision)]
+const INFERNO: [[f32; 7]; 3] = [
+    [
+        0.0002189403691192265,
+        0.1065134194856116,
+        11.60249308247187,
+        -41.70399613139459,
+        77.162935699427,
+        -71.31942824499214,
+        25.13112622477341,
+    ],
+    [
+        0.001651004631001012,
+        0.5639564367884091,
+        -3.972853965665698,
+        17.43639888205313,
+        -33.40235894210092,
+        32.62606426397723,
+        -12.24266895238567,
+    ],
+    [
+        -0.01948089843709184,
+        3.932712388889277,
+        -15.9423941062914,
+        44.35414519872813,
+        -81.80730925738993,
+        73.20951985803202,
+        -23.07032500287172,
+    ],
+];
+
+#[allow(clippy::excessive_precision)]
+const TURBO: [[f32; 7]; 3] = [
+    [
+        0.13572138,
+        4.6153926,
+        -42.66032258,
+        132.13108234,
+        -152.94239396,
+        59.28637943,
+        0.0,
+    ],
+    [
+        0.09140261,
+        2.19418839,
+        4.84296658,
+        -14.18503333,
+        4.27729857,
+        2.82956604,
+        0.0,
+    ],
+    [
+        0.1066733,
+        12.64194608,
+        -60.58204836,
+        110.36276771,
+        -89.90310912,
+        27.34824973,
+        0.0,
+    ],
+];
+
+/// Evaluates a polynomial at `t`, giving a channel from `0` to `255`
+#[inline]
+fn channel(coefficients: &[f32; 7], t: f32) -> u8 {
+    let value = coefficients
+        .iter()
+        .rev()
+        .fold(0.0, |value, &coefficient| value * t + coefficient);
+
+    (value.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+impl Colormap {
+    /// Gets the color for `t`, which is clamped between `0` and `1`
+    pub fn sample(&self, t: f32) -> RGBAColor {
+        self.sample_packed(t).into()
+    }
+
+    /// Gets the packed color for `t`, which is clamped between `0` and `1`
+    pub(crate) fn sample_packed(&self, t: f32) -> u32 {
+        let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+
+        let coefficients = match self {
+            Colormap::Viridis => &VIRIDIS,
+            Colormap::Plasma => &PLASMA,
+            Colormap::Inferno => &INFERNO,
+            Colormap::Turbo => &TURBO,
+            Colormap::Grayscale => {
+                let value = (t * 255.0).round() as u8;
+                return rgb!(value, value, value);
+            }
+        };
+
+        rgb!(
+            channel(&coefficients[0], t),
+            channel(&coefficients[1], t),
+            channel(&coefficients[2], t)
+        )
+    }
+}
+
+impl Canvas {
+    /// Draws a grid of `values` as a heatmap stretched over `dst_rect` as
+    /// `(x, y, width, height)`, coloring each value with `colormap`
+    ///
+    /// `values` are stored row by row with `columns` values in each row, and
+    /// `range` gives the values which map onto the start and end of the
+    /// colormap. Each destination pixel shows the value its center falls on,
+    /// and NaN values are left undrawn so that gaps in the data show through.
+    /// Like blits, the rectangle is placed in the coordinates of the canvas
+    /// without going through the current transform.
+    ///
+    /// ```
+    /// use farba::{Canvas, Colormap};
+    ///
+    /// // A 2x2 heightfield scaled up to fill the whole canvas
+    /// let heights = [0.0, 5.0, 5.0, 10.0];
+    ///
+    /// let mut canvas = Canvas::new(20, 20);
+    /// canvas.heatmap(&heights, 2, (0.0, 10.0), Colormap::Inferno, (0, 0, 20, 20));
+    ///
+    /// assert_eq!(*canvas.get_pixel(0, 0), Colormap::Inferno.sample(0.0).into());
+    /// assert_eq!(*canvas.get_pixel(15, 5), Colormap::Inferno.sample(0.5).into());
+    /// assert_eq!(*canvas.get_pixel(19, 19), Colormap::Inferno.sample(1.0).into());
+    /// ```
+    pub fn heatmap(
+        &mut self,
+        values: &[f32],
+        columns: usize,
+        range: (f32, f32),
+        colormap: Colormap,
+        dst_rect: (i32, i32, i32, i32),
+    ) {
+        let rows = values.len().checked_div(columns).unwrap_or(0);
+
+        if rows == 0 {
+            return;
+        }
+
+        let (canvas_width, canvas_height) = (self.get_width() as i32, self.get_height() as i32);
+
+        let (x, y, width, height) = dst_rect;
+        let Some(dest) = normalize_rect(x, y, width, height, canvas_width, canvas_height) else {
+            return;
+        };
+
+        // Size of a destination pixel in cells
+        let scale_x = columns as f32 / (dest.orig_x2 - dest.orig_x1 + 1) as f32;
+        let scale_y = rows as f32 / (dest.orig_y2 - dest.orig_y1 + 1) as f32;
+
+        let (min, max) = range;
+        let span = max - min;
+
+        let (left, top, right, bottom) = self.clip_bounds();
+
+        for y in dest.y1.max(top)..=dest.y2.min(bottom) {
+            let row = (((y - dest.orig_y1) as f32 + 0.5) * scale_y) as usize;
+            let row = row.min(rows - 1) * columns;
+
+            for x in dest.x1.max(left)..=dest.x2.min(right) {
+                let column = (((x - dest.orig_x1) as f32 + 0.5) * scale_x) as usize;
+                let value = values[row + column.min(columns - 1)];
+
+                if value.is_nan() {
+                    continue;
+                }
+
+                let t = if span == 0.0 {
+                    0.0
+                } else {
+                    (value - min) / span
+                };
+
+                self.write_pixel(x, y, colormap.sample_packed(t));
+            }
+        }
+    }
+}
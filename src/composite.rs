@@ -0,0 +1,476 @@
+use crate::{
+    blend_over, linear_to_srgb, mix_packed, premultiply_packed, rgba, srgb_to_linear,
+    unpremultiply_packed, Canvas, Color, ColorSpace,
+};
+
+/// The Porter–Duff operator deciding how drawn colors (the source) combine with
+/// the pixels already on the canvas (the destination)
+///
+/// Operators other than [`CompositeOp::Copy`] take the alpha of both colors
+/// into account, so for example [`CompositeOp::SourceIn`] only draws where the
+/// canvas is already opaque, and [`CompositeOp::DestinationOut`] erases the
+/// canvas wherever something is drawn.
+///
+/// ```
+/// use farba::{Canvas, CompositeOp, RGBAColor};
+///
+/// let mut canvas = Canvas::new(100, 100);
+/// canvas.fill(RGBAColor::RED);
+///
+/// // Punch a hole in the middle of the canvas
+/// canvas.set_composite_op(CompositeOp::DestinationOut);
+/// canvas.circle(50, 50, 20, RGBAColor::BLACK);
+///
+/// assert_eq!(*canvas.get_pixel(50, 50), 0);
+/// assert_eq!(*canvas.get_pixel(5, 5), RGBAColor::RED.into());
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum CompositeOp {
+    /// Replaces the destination with the source, alpha included
+    #[default]
+    Copy,
+    /// Clears the destination wherever something is drawn
+    Clear,
+    /// Leaves the destination untouched
+    Destination,
+    /// Draws the source on top of the destination
+    SourceOver,
+    /// Draws the source behind the destination
+    DestinationOver,
+    /// Keeps the source only where the destination is opaque
+    SourceIn,
+    /// Keeps the destination only where the source is opaque
+    DestinationIn,
+    /// Keeps the source only where the destination is transparent
+    SourceOut,
+    /// Keeps the destination only where the source is transparent
+    DestinationOut,
+    /// Draws the source on top of the destination, but only where the
+    /// destination is opaque
+    SourceAtop,
+    /// Draws the destination on top of the source, but only where the source
+    /// is opaque
+    DestinationAtop,
+    /// Keeps the source and destination only where the other one is transparent
+    Xor,
+}
+
+impl CompositeOp {
+    /// How much of the source and the destination make it into the result,
+    /// given the alpha of each
+    fn factors(self, src_alpha: f32, dst_alpha: f32) -> (f32, f32) {
+        match self {
+            CompositeOp::Copy => (1.0, 0.0),
+            CompositeOp::Clear => (0.0, 0.0),
+            CompositeOp::Destination => (0.0, 1.0),
+            CompositeOp::SourceOver => (1.0, 1.0 - src_alpha),
+            CompositeOp::DestinationOver => (1.0 - dst_alpha, 1.0),
+            CompositeOp::SourceIn => (dst_alpha, 0.0),
+            CompositeOp::DestinationIn => (0.0, src_alpha),
+            CompositeOp::SourceOut => (1.0 - dst_alpha, 0.0),
+            CompositeOp::DestinationOut => (0.0, 1.0 - src_alpha),
+            CompositeOp::SourceAtop => (dst_alpha, 1.0 - src_alpha),
+            CompositeOp::DestinationAtop => (1.0 - dst_alpha, src_alpha),
+            CompositeOp::Xor => (1.0 - dst_alpha, 1.0 - src_alpha),
+        }
+    }
+}
+
+/// Changes the color of everything drawn based on the color already underneath
+/// it, like the blend modes of image editors
+///
+/// The blended color takes the place of the drawn color wherever the canvas is
+/// opaque, before it is combined with the canvas by the [`CompositeOp`]. Since
+/// the default operator copies the drawn color, opaque colors fully take on the
+/// blended color, while [`CompositeOp::SourceOver`] also lets translucent colors
+/// blend in part of the way.
+///
+/// ```
+/// use farba::{BlendMode, Canvas, RGBAColor};
+///
+/// let mut canvas = Canvas::new(100, 100);
+/// canvas.fill(RGBAColor::from_rgb(100, 50, 200));
+///
+/// // Additive blending brightens everything it touches
+/// canvas.set_blend_mode(BlendMode::Additive);
+/// canvas.rect(0, 0, 50, 50, RGBAColor::from_rgb(100, 100, 100));
+///
+/// assert_eq!(*canvas.get_pixel(25, 25), RGBAColor::from_rgb(200, 150, 255).into());
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum BlendMode {
+    /// Uses the drawn color as is
+    #[default]
+    Normal,
+    /// Multiplies the channels together, which can only darken
+    Multiply,
+    /// Multiplies the inverse of the channels together, which can only lighten
+    Screen,
+    /// Multiplies dark areas of the canvas and screens light ones, increasing
+    /// contrast
+    Overlay,
+    /// Keeps the darker of the two channels
+    Darken,
+    /// Keeps the lighter of the two channels
+    Lighten,
+    /// Adds the channels together, for glowing effects like lights and particles
+    Additive,
+    /// Subtracts the drawn channels from the ones on the canvas
+    Subtract,
+}
+
+impl BlendMode {
+    /// Blends a single channel from the canvas (`dst`) with a drawn channel
+    /// (`src`), both between `0` and `1`
+    fn blend_channel(self, dst: f32, src: f32) -> f32 {
+        let multiply = |a: f32, b: f32| a * b;
+        let screen = |a: f32, b: f32| a + b - a * b;
+
+        match self {
+            BlendMode::Normal => src,
+            BlendMode::Multiply => multiply(dst, src),
+            BlendMode::Screen => screen(dst, src),
+            BlendMode::Overlay if dst <= 0.5 => multiply(src, 2.0 * dst),
+            BlendMode::Overlay => screen(src, 2.0 * dst - 1.0),
+            BlendMode::Darken => dst.min(src),
+            BlendMode::Lighten => dst.max(src),
+            BlendMode::Additive => (dst + src).min(1.0),
+            BlendMode::Subtract => (dst - src).max(0.0),
+        }
+    }
+}
+
+/// Replaces the color channels of the packed `src` color with the ones blended
+/// from it and the packed `dst` color by `mode`, to the extent that `dst` is
+/// opaque. The alpha of `src` is kept as is
+pub(crate) fn blend_packed(mode: BlendMode, dst: u32, src: u32) -> u32 {
+    if mode == BlendMode::Normal || dst.alpha() == 0 {
+        return src;
+    }
+
+    let dst_alpha = dst.alpha() as f32 / 255.0;
+
+    let channel = |s: u8, d: u8| {
+        let (s, d) = (s as f32 / 255.0, d as f32 / 255.0);
+        let blended = mode.blend_channel(d, s);
+
+        ((s + (blended - s) * dst_alpha) * 255.0).round() as u8
+    };
+
+    rgba!(
+        channel(src.red(), dst.red()),
+        channel(src.green(), dst.green()),
+        channel(src.blue(), dst.blue()),
+        src.alpha()
+    )
+}
+
+/// Composites the packed `src` color with the packed `dst` color using `op`,
+/// over the fraction `coverage` of the pixel
+///
+/// Pixels which are only partially covered end up part of the way between the
+/// destination and the fully composited color.
+pub(crate) fn composite_packed(op: CompositeOp, dst: u32, src: u32, coverage: f32) -> u32 {
+    match (op, coverage >= 1.0) {
+        (CompositeOp::Copy, true) => return src,
+        (CompositeOp::Copy, false) => return mix_packed(dst, src, coverage),
+        (CompositeOp::SourceOver, true) => return blend_over(dst, src),
+        (CompositeOp::SourceOver, false) => {
+            // Blending a fraction of the source is the same as scaling its alpha
+            let alpha = (src.alpha() as f32 * coverage).round() as u32;
+
+            return blend_over(dst, (src & 0x00FFFFFF) | (alpha << 24));
+        }
+        _ => {}
+    }
+
+    let src_alpha = src.alpha() as f32 / 255.0;
+    let dst_alpha = dst.alpha() as f32 / 255.0;
+
+    let (src_factor, dst_factor) = op.factors(src_alpha, dst_alpha);
+
+    // Work with premultiplied channels, where mixing by coverage is linear
+    let src_weight = src_alpha * src_factor;
+    let dst_weight = dst_alpha * dst_factor;
+
+    let out_alpha = src_weight + dst_weight;
+    let alpha = dst_alpha + (out_alpha - dst_alpha) * coverage;
+
+    if alpha <= 0.0 {
+        return 0;
+    }
+
+    let channel = |s: u8, d: u8| {
+        let composited = s as f32 * src_weight + d as f32 * dst_weight;
+        let mixed = d as f32 * dst_alpha + (composited - d as f32 * dst_alpha) * coverage;
+
+        (mixed / alpha).round() as u8
+    };
+
+    rgba!(
+        channel(src.red(), dst.red()),
+        channel(src.green(), dst.green()),
+        channel(src.blue(), dst.blue()),
+        (alpha * 255.0).round() as u8
+    )
+}
+
+/// Blends and composites the packed `src` color with the packed `dst` color like
+/// `blend_packed` followed by `composite_packed`, but with the color channels
+/// converted to linear light while they are combined
+pub(crate) fn composite_linear(
+    op: CompositeOp,
+    mode: BlendMode,
+    dst: u32,
+    src: u32,
+    coverage: f32,
+) -> u32 {
+    let opaque_source = op == CompositeOp::Copy || src.alpha() == 255;
+
+    if mode == BlendMode::Normal && coverage >= 1.0 && opaque_source {
+        match op {
+            CompositeOp::Copy | CompositeOp::SourceOver => return src,
+            _ => {}
+        }
+    }
+
+    let src_alpha = src.alpha() as f32 / 255.0;
+    let dst_alpha = dst.alpha() as f32 / 255.0;
+
+    let (src_factor, dst_factor) = op.factors(src_alpha, dst_alpha);
+
+    let src_weight = src_alpha * src_factor;
+    let dst_weight = dst_alpha * dst_factor;
+
+    let out_alpha = src_weight + dst_weight;
+    let alpha = dst_alpha + (out_alpha - dst_alpha) * coverage;
+
+    if alpha <= 0.0 {
+        return 0;
+    }
+
+    let channel = |s: u8, d: u8| {
+        let (mut s, d) = (srgb_to_linear(s), srgb_to_linear(d));
+
+        if mode != BlendMode::Normal {
+            s += (mode.blend_channel(d, s) - s) * dst_alpha;
+        }
+
+        let composited = s * src_weight + d * dst_weight;
+        let mixed = d * dst_alpha + (composited - d * dst_alpha) * coverage;
+
+        linear_to_srgb(mixed / alpha)
+    };
+
+    rgba!(
+        channel(src.red(), dst.red()),
+        channel(src.green(), dst.green()),
+        channel(src.blue(), dst.blue()),
+        (alpha * 255.0).round() as u8
+    )
+}
+
+/// Composites the premultiplied packed `src` color with the premultiplied
+/// packed `dst` color using `op`, over the fraction `coverage` of the pixel
+///
+/// Every channel, alpha included, is combined the same way, so no division is
+/// needed like with straight alpha.
+pub(crate) fn composite_premultiplied(op: CompositeOp, dst: u32, src: u32, coverage: f32) -> u32 {
+    if op == CompositeOp::Copy && coverage >= 1.0 {
+        return src;
+    }
+
+    let src_alpha = src.alpha() as f32 / 255.0;
+    let dst_alpha = dst.alpha() as f32 / 255.0;
+
+    let (src_factor, dst_factor) = op.factors(src_alpha, dst_alpha);
+
+    let channel = |s: u8, d: u8| {
+        let composited = s as f32 * src_factor + d as f32 * dst_factor;
+
+        (d as f32 + (composited - d as f32) * coverage).round() as u8
+    };
+
+    rgba!(
+        channel(src.red(), dst.red()),
+        channel(src.green(), dst.green()),
+        channel(src.blue(), dst.blue()),
+        channel(src.alpha(), dst.alpha())
+    )
+}
+
+impl Canvas {
+    /// Sets the operator every following draw call uses to combine its colors
+    /// with the pixels already on the canvas
+    pub fn set_composite_op(&mut self, op: CompositeOp) {
+        self.composite_op = op;
+    }
+
+    pub fn get_composite_op(&self) -> CompositeOp {
+        self.composite_op
+    }
+
+    /// Sets the mode every following draw call uses to blend its colors with
+    /// the colors already on the canvas
+    pub fn set_blend_mode(&mut self, mode: BlendMode) {
+        self.blend_mode = mode;
+    }
+
+    pub fn get_blend_mode(&self) -> BlendMode {
+        self.blend_mode
+    }
+
+    /// Scales the alpha of everything drawn after this by `alpha`, from `0.0`
+    /// (invisible) to `1.0` (unchanged), which makes it easy to fade whole
+    /// layers in and out
+    ///
+    /// This only changes the colors being drawn, so it is usually combined with
+    /// alpha blending. Set it back to `1.0` to stop fading.
+    ///
+    /// ```
+    /// use farba::{Canvas, RGBAColor};
+    ///
+    /// let mut canvas = Canvas::new(100, 100);
+    /// canvas.fill(RGBAColor::WHITE);
+    ///
+    /// canvas.set_alpha_blending(true);
+    /// canvas.set_global_alpha(0.5);
+    /// canvas.rect(0, 0, 50, 50, RGBAColor::RED);
+    /// canvas.set_global_alpha(1.0);
+    ///
+    /// assert_eq!(*canvas.get_pixel(25, 25), RGBAColor::from_rgb(255, 127, 127).into());
+    /// ```
+    pub fn set_global_alpha(&mut self, alpha: f32) {
+        self.global_alpha = alpha.clamp(0.0, 1.0);
+    }
+
+    pub fn get_global_alpha(&self) -> f32 {
+        self.global_alpha
+    }
+
+    /// Switches between storing pixels with straight alpha (the default) and
+    /// premultiplied alpha, converting the pixels already on the canvas so that
+    /// they keep looking the same
+    ///
+    /// With premultiplied alpha the color channels of every pixel are stored
+    /// already scaled by its alpha, which is what some window systems expect,
+    /// and which avoids the fringing that builds up when many translucent layers
+    /// are blended with straight alpha. Colors are still given to draw calls
+    /// with straight alpha and converted as they are drawn, while
+    /// [`Canvas::get_pixel`] and the other ways of reading the pixel buffer
+    /// return the premultiplied values.
+    ///
+    /// ```
+    /// use farba::{Canvas, RGBAColor};
+    ///
+    /// let mut canvas = Canvas::new(100, 100);
+    /// canvas.set_premultiplied(true);
+    ///
+    /// canvas.fill(RGBAColor::from_rgba(255, 0, 0, 128));
+    /// assert_eq!(*canvas.get_pixel(0, 0), RGBAColor::from_rgba(128, 0, 0, 128).into());
+    ///
+    /// canvas.set_premultiplied(false);
+    /// assert_eq!(*canvas.get_pixel(0, 0), RGBAColor::from_rgba(255, 0, 0, 128).into());
+    /// ```
+    pub fn set_premultiplied(&mut self, premultiplied: bool) {
+        if premultiplied == self.premultiplied {
+            return;
+        }
+
+        let convert = if premultiplied {
+            premultiply_packed
+        } else {
+            unpremultiply_packed
+        };
+
+        for pixel in self.get_pixels_mut() {
+            *pixel = convert(*pixel);
+        }
+
+        self.premultiplied = premultiplied;
+    }
+
+    pub fn is_premultiplied(&self) -> bool {
+        self.premultiplied
+    }
+
+    /// Sets the color space every following draw call blends and composites
+    /// its colors with the pixels already on the canvas in
+    ///
+    /// Mixing the stored sRGB values directly is cheap, but makes anti-aliased
+    /// edges and translucent colors look darker than they should.
+    /// [`ColorSpace::Linear`] converts both colors to linear light first and
+    /// back to sRGB afterwards, so they mix the same way light does. The OKLab
+    /// spaces are meant for mixing colors in gradients, so canvases set to
+    /// either of them blend in linear light as well.
+    ///
+    /// ```
+    /// use farba::{Canvas, Color, ColorSpace, RGBAColor};
+    ///
+    /// let mut canvas = Canvas::new(10, 10);
+    /// canvas.fill(RGBAColor::BLACK);
+    /// canvas.set_alpha_blending(true);
+    ///
+    /// canvas.set_blend_space(ColorSpace::Linear);
+    /// canvas.rect(0, 0, 10, 10, RGBAColor::from_rgba(255, 255, 255, 128));
+    ///
+    /// // Half of the light of white, rather than half of its value
+    /// assert_eq!(canvas.get_pixel(0, 0).red(), 188);
+    /// ```
+    pub fn set_blend_space(&mut self, space: ColorSpace) {
+        self.blend_space = space;
+    }
+
+    pub fn get_blend_space(&self) -> ColorSpace {
+        self.blend_space
+    }
+
+    /// Gets the pixel at `(x, y)` with straight alpha, however it is stored
+    ///
+    /// `(x, y)` must be a valid coordinate within the canvas
+    pub(crate) fn straight_pixel(&self, x: i32, y: i32) -> u32 {
+        let pixel = *self.get_pixel(x, y);
+
+        if self.premultiplied {
+            unpremultiply_packed(pixel)
+        } else {
+            pixel
+        }
+    }
+
+    /// Converts a straight alpha color to the way pixels are stored on the canvas
+    pub(crate) fn stored_color(&self, color: u32) -> u32 {
+        if self.premultiplied {
+            premultiply_packed(color)
+        } else {
+            color
+        }
+    }
+
+    /// Calls `draw` with the composite operator temporarily set to `op`, for
+    /// drawing with a different operator without changing the one the canvas
+    /// otherwise uses
+    ///
+    /// ```
+    /// use farba::{Canvas, CompositeOp, RGBAColor};
+    ///
+    /// let mut canvas = Canvas::new(100, 100);
+    /// canvas.rect(0, 0, 50, 100, RGBAColor::RED);
+    ///
+    /// // Only the half of the circle over the red is drawn
+    /// canvas.with_composite_op(CompositeOp::SourceAtop, |canvas| {
+    ///     canvas.circle(50, 50, 20, RGBAColor::BLUE);
+    /// });
+    ///
+    /// assert_eq!(*canvas.get_pixel(40, 50), RGBAColor::BLUE.into());
+    /// assert_eq!(*canvas.get_pixel(60, 50), 0);
+    /// assert_eq!(canvas.get_composite_op(), CompositeOp::Copy);
+    /// ```
+    pub fn with_composite_op<F: FnOnce(&mut Canvas)>(&mut self, op: CompositeOp, draw: F) {
+        let previous = self.composite_op;
+
+        self.composite_op = op;
+        draw(self);
+        self.composite_op = previous;
+    }
+}
@@ -0,0 +1,146 @@
+use crate::{Canvas, Color, Vec2};
+
+/// Maximum distance (in pixels) a flattened curve may stray from the true curve
+pub(crate) const FLATNESS_TOLERANCE: f32 = 0.25;
+
+/// Limits how many times a curve can be split, in case of non-finite inputs
+const MAX_SUBDIVISIONS: u32 = 16;
+
+/// Approximates the quadratic Bézier curve from `p0` to `p1` with line segments,
+/// pushing every point after `p0` onto `points`
+///
+/// The curve is split in half until each piece is within `FLATNESS_TOLERANCE`
+/// of a straight line, so tight bends get more segments than gentle ones
+pub(crate) fn flatten_quad(p0: Vec2, ctrl: Vec2, p1: Vec2, points: &mut Vec<Vec2>) {
+    flatten_quad_recursive(p0, ctrl, p1, points, 0);
+}
+
+fn flatten_quad_recursive(p0: Vec2, ctrl: Vec2, p1: Vec2, points: &mut Vec<Vec2>, depth: u32) {
+    // The curve never strays further than a quarter of this from its chord
+    let deviation = p0 - ctrl * 2.0 + p1;
+
+    if depth >= MAX_SUBDIVISIONS || deviation.magnitude() / 4.0 <= FLATNESS_TOLERANCE {
+        points.push(p1);
+        return;
+    }
+
+    // De Casteljau split at t = 0.5
+    let left = (p0 + ctrl) * 0.5;
+    let right = (ctrl + p1) * 0.5;
+    let middle = (left + right) * 0.5;
+
+    flatten_quad_recursive(p0, left, middle, points, depth + 1);
+    flatten_quad_recursive(middle, right, p1, points, depth + 1);
+}
+
+/// Approximates the cubic Bézier curve from `p0` to `p1` with line segments,
+/// pushing every point after `p0` onto `points`
+///
+/// Uses the same adaptive subdivision as `flatten_quad`
+pub(crate) fn flatten_cubic(p0: Vec2, c0: Vec2, c1: Vec2, p1: Vec2, points: &mut Vec<Vec2>) {
+    flatten_cubic_recursive(p0, c0, c1, p1, points, 0);
+}
+
+fn flatten_cubic_recursive(
+    p0: Vec2,
+    c0: Vec2,
+    c1: Vec2,
+    p1: Vec2,
+    points: &mut Vec<Vec2>,
+    depth: u32,
+) {
+    // The curve never strays further than 3/4 of the larger of these from its chord
+    let d0 = p0 - c0 * 2.0 + c1;
+    let d1 = c0 - c1 * 2.0 + p1;
+    let deviation = d0.dot(d0).max(d1.dot(d1)).sqrt() * 0.75;
+
+    if depth >= MAX_SUBDIVISIONS || deviation <= FLATNESS_TOLERANCE {
+        points.push(p1);
+        return;
+    }
+
+    // De Casteljau split at t = 0.5
+    let p0_c0 = (p0 + c0) * 0.5;
+    let c0_c1 = (c0 + c1) * 0.5;
+    let c1_p1 = (c1 + p1) * 0.5;
+    let left = (p0_c0 + c0_c1) * 0.5;
+    let right = (c0_c1 + c1_p1) * 0.5;
+    let middle = (left + right) * 0.5;
+
+    flatten_cubic_recursive(p0, p0_c0, left, middle, points, depth + 1);
+    flatten_cubic_recursive(middle, right, c1_p1, p1, points, depth + 1);
+}
+
+impl Canvas {
+    /// Draws a one pixel wide quadratic Bézier curve from `p0` to `p1`, bending
+    /// towards `ctrl`
+    pub fn quad_bezier<C: Color>(&mut self, p0: Vec2, ctrl: Vec2, p1: Vec2, color: C) {
+        let mut points = vec![p0];
+        flatten_quad(p0, ctrl, p1, &mut points);
+
+        self.polyline(&points, color);
+    }
+
+    /// Draws a cubic Bézier curve from `p0` to `p1`, leaving `p0` towards `c0`
+    /// and arriving at `p1` from `c1`
+    ///
+    /// Curves with a `width` of one pixel or less are drawn like
+    /// [`Canvas::quad_bezier`], while wider curves are filled as a single shape
+    /// with rounded ends.
+    pub fn cubic_bezier<C: Color>(
+        &mut self,
+        p0: Vec2,
+        c0: Vec2,
+        c1: Vec2,
+        p1: Vec2,
+        width: f32,
+        color: C,
+    ) {
+        let mut points = vec![p0];
+        flatten_cubic(p0, c0, c1, p1, &mut points);
+
+        if width <= 1.0 {
+            self.polyline(&points, color);
+        } else {
+            self.thick_polyline(&points, width, &color.pack());
+        }
+    }
+
+    /// Draws a smooth one pixel wide curve passing through every point in order
+    ///
+    /// The curve is a cardinal spline, where a `tension` of `0` gives a classic
+    /// Catmull-Rom spline and a `tension` of `1` pulls it tight into straight
+    /// lines between the points. The curve leaves the first point and arrives at
+    /// the last one heading straight towards their neighbors.
+    pub fn spline<C: Color>(&mut self, points: &[Vec2], tension: f32, color: C) {
+        if points.len() < 3 {
+            self.polyline(points, color);
+            return;
+        }
+
+        let last = points.len() - 1;
+
+        // Tangent at each point, based on the direction between its neighbors
+        let tangent = |i: usize| {
+            let previous = points[i.saturating_sub(1)];
+            let next = points[(i + 1).min(last)];
+
+            (next - previous) * ((1.0 - tension) / 2.0)
+        };
+
+        let mut flattened = vec![points[0]];
+
+        // Each span of a cardinal spline is a cubic Bézier with its control points
+        // a third of the tangent away from each end
+        for i in 0..last {
+            let (p0, p1) = (points[i], points[i + 1]);
+
+            let c0 = p0 + tangent(i) * (1.0 / 3.0);
+            let c1 = p1 - tangent(i + 1) * (1.0 / 3.0);
+
+            flatten_cubic(p0, c0, c1, p1, &mut flattened);
+        }
+
+        self.polyline(&flattened, color);
+    }
+}
@@ -0,0 +1,59 @@
+/// A buffer holding the depth of the nearest surface drawn at every pixel of a
+/// canvas, used to decide which triangles are visible
+///
+/// Dereferences to a slice of depths so it can be passed anywhere a raw depth
+/// buffer is accepted.
+///
+/// Alongside the depths, it keeps how much of each pixel is covered by the
+/// anti-aliased edges of triangles drawn so far, so that triangles sharing an
+/// edge can fill the pixels along it between them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DepthBuffer {
+    depths: Vec<f32>,
+    /// Fraction of each pixel covered by partially covered pixels of
+    /// anti-aliased triangles since the pixel was last fully covered
+    pub(crate) coverage: Vec<f32>,
+    width: usize,
+    height: usize,
+}
+
+impl DepthBuffer {
+    /// Creates a new depth buffer where every pixel is infinitely far away
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            depths: vec![f32::INFINITY; width * height],
+            coverage: vec![0.0; width * height],
+            width,
+            height,
+        }
+    }
+
+    pub fn get_width(&self) -> usize {
+        self.width
+    }
+
+    pub fn get_height(&self) -> usize {
+        self.height
+    }
+
+    /// Resets every pixel to be infinitely far away so the buffer can be reused
+    /// for the next frame without reallocating
+    pub fn clear(&mut self) {
+        self.depths.fill(f32::INFINITY);
+        self.coverage.fill(0.0);
+    }
+}
+
+impl std::ops::Deref for DepthBuffer {
+    type Target = [f32];
+
+    fn deref(&self) -> &Self::Target {
+        self.depths.as_slice()
+    }
+}
+
+impl std::ops::DerefMut for DepthBuffer {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.depths.as_mut_slice()
+    }
+}
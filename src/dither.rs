@@ -0,0 +1,217 @@
+use crate::{rgba, Canvas, Color};
+
+/// How the error is spread around when an image is reduced to fewer colors
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Dither {
+    /// Every pixel snaps to its nearest color, which leaves visible bands in
+    /// smooth gradients
+    #[default]
+    None,
+    /// The error of each pixel is diffused onto its unvisited neighbors, which
+    /// preserves the average color of an area at the cost of some noise
+    FloydSteinberg,
+    /// Each pixel is nudged by a threshold from a repeating 2x2 Bayer matrix
+    /// before it is reduced
+    ///
+    /// Ordered dithering doesn't depend on neighboring pixels, so the pattern
+    /// stays put between the frames of an animation instead of crawling.
+    Bayer2,
+    /// Ordered dithering like [`Dither::Bayer2`] with a 4x4 matrix, which gives
+    /// more in-between shades
+    Bayer4,
+    /// Ordered dithering like [`Dither::Bayer2`] with an 8x8 matrix
+    Bayer8,
+}
+
+impl Dither {
+    /// Width of the Bayer matrix used by ordered dithering
+    fn bayer_size(&self) -> Option<usize> {
+        match self {
+            Dither::Bayer2 => Some(2),
+            Dither::Bayer4 => Some(4),
+            Dither::Bayer8 => Some(8),
+            _ => None,
+        }
+    }
+}
+
+/// Gets the entry of the `size` by `size` Bayer matrix for `(x, y)`, which
+/// ranges from `0` to `size * size - 1`
+fn bayer_value(x: usize, y: usize, size: usize) -> usize {
+    if size < 2 {
+        return 0;
+    }
+
+    let half = size / 2;
+    let quadrant = [[0, 2], [3, 1]][(y % size) / half][(x % size) / half];
+
+    4 * bayer_value(x % half, y % half, half) + quadrant
+}
+
+/// Typical distance within each channel between neighboring colors of a
+/// palette with `len` colors spread evenly over the color cube, which is how
+/// far ordered dithering nudges pixels
+pub(crate) fn palette_spread(len: usize) -> [f32; 3] {
+    let steps = ((len as f32).cbrt() - 1.0).max(1.0);
+
+    [255.0 / steps; 3]
+}
+
+/// Reduces the straight alpha `pixels` of an image `width` pixels wide to fewer
+/// colors, visiting them in reading order
+///
+/// `choose` is given the index of each pixel along with the color it should be,
+/// including any error diffused onto it, and returns the color it becomes. The
+/// error in the red, green and blue channels is spread according to `dither`, while
+/// alpha is passed through untouched. Ordered dithering nudges each channel by up
+/// to half of `spread`, the distance between the colors it can become.
+pub(crate) fn dither_pixels<F: FnMut(usize, u32) -> u32>(
+    pixels: &[u32],
+    width: usize,
+    dither: Dither,
+    spread: [f32; 3],
+    mut choose: F,
+) {
+    let height = pixels.len().checked_div(width).unwrap_or(0);
+    let diffuse = dither == Dither::FloydSteinberg;
+    let bayer_size = dither.bayer_size();
+
+    // Accumulated quantization error (r, g, b) for every pixel
+    let mut errors = vec![[0.0f32; 3]; if diffuse { pixels.len() } else { 0 }];
+
+    for y in 0..height {
+        for x in 0..width {
+            let index = y * width + x;
+            let pixel = pixels[index];
+
+            let error = if diffuse {
+                errors[index]
+            } else if let Some(size) = bayer_size {
+                // Threshold centered on zero, from just above -0.5 to just below 0.5
+                let threshold = (bayer_value(x, y, size) as f32 + 0.5) / (size * size) as f32 - 0.5;
+
+                spread.map(|spread| threshold * spread)
+            } else {
+                [0.0; 3]
+            };
+
+            let red = (pixel.red() as f32 + error[0]).clamp(0.0, 255.0);
+            let green = (pixel.green() as f32 + error[1]).clamp(0.0, 255.0);
+            let blue = (pixel.blue() as f32 + error[2]).clamp(0.0, 255.0);
+
+            let target = rgba!(red.round(), green.round(), blue.round(), pixel.alpha());
+            let chosen = choose(index, target);
+
+            if !diffuse {
+                continue;
+            }
+
+            let error = [
+                red - chosen.red() as f32,
+                green - chosen.green() as f32,
+                blue - chosen.blue() as f32,
+            ];
+
+            let mut spread = |dx: isize, dy: usize, weight: f32| {
+                let nx = x as isize + dx;
+                let ny = y + dy;
+
+                if nx < 0 || nx >= width as isize || ny >= height {
+                    return;
+                }
+
+                let neighbor = &mut errors[ny * width + nx as usize];
+
+                for c in 0..3 {
+                    neighbor[c] += error[c] * weight;
+                }
+            };
+
+            spread(1, 0, 7.0 / 16.0);
+            spread(-1, 1, 3.0 / 16.0);
+            spread(0, 1, 5.0 / 16.0);
+            spread(1, 1, 1.0 / 16.0);
+        }
+    }
+}
+
+/// Rounds an 8 bit channel to the nearest of the levels that `bits` bits can
+/// store, expanded back out to 8 bits
+#[inline]
+pub(crate) fn reduce_channel(channel: u8, bits: u8) -> u8 {
+    let levels = (1u32 << bits.clamp(1, 8)) - 1;
+    let level = (channel as u32 * levels + 127) / 255;
+
+    ((level * 255 + levels / 2) / levels) as u8
+}
+
+impl Canvas {
+    /// Gets every pixel on the canvas with straight alpha, in reading order
+    pub(crate) fn straight_pixels(&self) -> Vec<u32> {
+        let width = self.get_width() as i32;
+
+        (0..self.get_width() * self.get_height())
+            .map(|i| self.straight_pixel(i as i32 % width, i as i32 / width))
+            .collect()
+    }
+
+    /// Reduces the red, green and blue channels of every pixel to the given
+    /// number of `bits` (from 1 to 8), such as `[5, 6, 5]` for an RGB565 display
+    /// or `[1, 1, 1]` for eight colors
+    ///
+    /// The channels are still stored in 8 bits afterwards, spread over the
+    /// full range. Turning the canvas to grayscale first and reducing it to one
+    /// bit gives a black and white image.
+    ///
+    /// ```
+    /// use farba::{Canvas, Color, Dither, LumaWeights, RGBAColor};
+    ///
+    /// let mut gradient = Canvas::new(64, 64);
+    /// for x in 0..64 {
+    ///     gradient.rect(x, 0, 1, 64, RGBAColor::from_rgb(x as u8 * 4, 100, 50));
+    /// }
+    ///
+    /// let mut banded = gradient.to_grayscale(LumaWeights::Rec709);
+    /// banded.posterize([1, 1, 1], Dither::None);
+    ///
+    /// let mut dithered = gradient.to_grayscale(LumaWeights::Rec709);
+    /// dithered.posterize([1, 1, 1], Dither::FloydSteinberg);
+    ///
+    /// // Both are black and white, but only dithering keeps the brightness
+    /// let average = |canvas: &Canvas| {
+    ///     let pixels = canvas.get_pixels();
+    ///     pixels.iter().map(|p| p.red() as f32).sum::<f32>() / pixels.len() as f32
+    /// };
+    ///
+    /// assert!(banded.get_pixels().iter().all(|p| p.red() == 0 || p.red() == 255));
+    /// assert!(dithered.get_pixels().iter().all(|p| p.red() == 0 || p.red() == 255));
+    ///
+    /// let original = average(&gradient.to_grayscale(LumaWeights::Rec709));
+    /// assert!((average(&dithered) - original).abs() < 2.0);
+    /// assert!((average(&banded) - original).abs() > 10.0);
+    /// ```
+    pub fn posterize(&mut self, bits: [u8; 3], dither: Dither) {
+        let pixels = self.straight_pixels();
+        let spread = bits.map(|bits| 255.0 / ((1u32 << bits.clamp(1, 8)) - 1) as f32);
+
+        dither_pixels(
+            &pixels,
+            self.get_width(),
+            dither,
+            spread,
+            |index, target| {
+                let chosen = rgba!(
+                    reduce_channel(target.red(), bits[0]),
+                    reduce_channel(target.green(), bits[1]),
+                    reduce_channel(target.blue(), bits[2]),
+                    target.alpha()
+                );
+
+                let stored = self.stored_color(chosen);
+                self.get_pixels_mut()[index] = stored;
+
+                chosen
+            },
+        );
+    }
+}
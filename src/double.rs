@@ -0,0 +1,1426 @@
+//! Double precision versions of the math types, for keeping track of positions
+//! and transforms in large scenes or over long running animations, where the
+//! rounding errors of `f32` build up into visible drift
+//!
+//! Drawing still happens in `f32`, so values are converted with `to_f32` once
+//! they are ready to be drawn, ideally after moving them close to the origin
+//! (such as into camera space).
+
+use crate::{Mat3, Mat4, Quat, Vec2, Vec3, Vec4};
+
+/* ===== DVec3 ===== */
+
+/// A [`Vec3`] in double precision
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct DVec3 {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+impl DVec3 {
+    pub const ZERO: Self = Self::new(0.0, 0.0, 0.0);
+
+    pub const fn new(x: f64, y: f64, z: f64) -> Self {
+        Self { x, y, z }
+    }
+
+    pub fn dot(&self, other: &Self) -> f64 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    pub fn cross(&self, other: &Self) -> DVec3 {
+        DVec3::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+
+    pub fn magnitude_squared(&self) -> f64 {
+        self.x * self.x + self.y * self.y + self.z * self.z
+    }
+
+    pub fn magnitude(&self) -> f64 {
+        self.magnitude_squared().sqrt()
+    }
+
+    pub fn normalize(&self) -> DVec3 {
+        let mag = self.magnitude();
+
+        DVec3::new(self.x / mag, self.y / mag, self.z / mag)
+    }
+
+    /// Reflects the vector off a surface facing `normal`, like
+    /// [`Vec3::reflect`]
+    pub fn reflect(&self, normal: &Self) -> DVec3 {
+        *self - 2.0 * self.dot(normal) * *normal
+    }
+
+    /// Gets the part of the vector which points along `other`, like
+    /// [`Vec3::project_onto`]
+    pub fn project_onto(&self, other: &Self) -> DVec3 {
+        let length_squared = other.magnitude_squared();
+
+        if length_squared == 0.0 {
+            return DVec3::ZERO;
+        }
+
+        (self.dot(other) / length_squared) * *other
+    }
+
+    /// Gets the angle between the two vectors in radians, like
+    /// [`Vec3::angle_between`]
+    pub fn angle_between(&self, other: &Self) -> f64 {
+        let lengths = (self.magnitude_squared() * other.magnitude_squared()).sqrt();
+
+        if lengths == 0.0 {
+            return 0.0;
+        }
+
+        (self.dot(other) / lengths).clamp(-1.0, 1.0).acos()
+    }
+
+    /// Gets the distance between the two vectors treated as points
+    pub fn distance(&self, other: &Self) -> f64 {
+        (*self - *other).magnitude()
+    }
+
+    /// Takes the smaller of each component of the two vectors
+    pub fn min(&self, other: &Self) -> DVec3 {
+        DVec3::new(
+            self.x.min(other.x),
+            self.y.min(other.y),
+            self.z.min(other.z),
+        )
+    }
+
+    /// Takes the larger of each component of the two vectors
+    pub fn max(&self, other: &Self) -> DVec3 {
+        DVec3::new(
+            self.x.max(other.x),
+            self.y.max(other.y),
+            self.z.max(other.z),
+        )
+    }
+
+    /// Takes the absolute value of each component
+    pub fn abs(&self) -> DVec3 {
+        DVec3::new(self.x.abs(), self.y.abs(), self.z.abs())
+    }
+
+    /// Clamps each component between the matching components of `min` and
+    /// `max`
+    pub fn clamp(&self, min: &Self, max: &Self) -> DVec3 {
+        self.max(min).min(max)
+    }
+
+    /// Checks whether every component is within `epsilon` of the matching
+    /// component of `other`
+    pub fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+        (self.x - other.x).abs() <= epsilon
+            && (self.y - other.y).abs() <= epsilon
+            && (self.z - other.z).abs() <= epsilon
+    }
+
+    pub fn to_f32(&self) -> Vec3 {
+        Vec3::new(self.x as f32, self.y as f32, self.z as f32)
+    }
+}
+
+impl From<Vec3> for DVec3 {
+    fn from(vector: Vec3) -> Self {
+        DVec3::new(vector.x as f64, vector.y as f64, vector.z as f64)
+    }
+}
+
+impl std::ops::Add<DVec3> for DVec3 {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl std::ops::AddAssign<DVec3> for DVec3 {
+    fn add_assign(&mut self, rhs: DVec3) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+        self.z += rhs.z;
+    }
+}
+
+impl std::ops::Sub<DVec3> for DVec3 {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl std::ops::SubAssign<DVec3> for DVec3 {
+    fn sub_assign(&mut self, rhs: DVec3) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+        self.z -= rhs.z;
+    }
+}
+
+impl std::ops::Mul<DVec3> for f64 {
+    type Output = DVec3;
+
+    fn mul(self, rhs: DVec3) -> Self::Output {
+        DVec3::new(rhs.x * self, rhs.y * self, rhs.z * self)
+    }
+}
+
+impl std::ops::Mul<f64> for DVec3 {
+    type Output = DVec3;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl std::ops::MulAssign<f64> for DVec3 {
+    fn mul_assign(&mut self, rhs: f64) {
+        self.x *= rhs;
+        self.y *= rhs;
+        self.z *= rhs;
+    }
+}
+
+impl std::ops::MulAssign<DVec3> for DVec3 {
+    fn mul_assign(&mut self, rhs: DVec3) {
+        self.x *= rhs.x;
+        self.y *= rhs.y;
+        self.z *= rhs.z;
+    }
+}
+
+impl std::ops::Mul<DVec3> for DVec3 {
+    type Output = DVec3;
+
+    fn mul(self, rhs: DVec3) -> Self::Output {
+        DVec3::new(self.x * rhs.x, self.y * rhs.y, self.z * rhs.z)
+    }
+}
+
+impl std::ops::Div<f64> for DVec3 {
+    type Output = DVec3;
+
+    fn div(self, rhs: f64) -> Self::Output {
+        DVec3::new(self.x / rhs, self.y / rhs, self.z / rhs)
+    }
+}
+
+impl std::ops::DivAssign<f64> for DVec3 {
+    fn div_assign(&mut self, rhs: f64) {
+        self.x /= rhs;
+        self.y /= rhs;
+        self.z /= rhs;
+    }
+}
+
+impl std::ops::Div<DVec3> for DVec3 {
+    type Output = DVec3;
+
+    fn div(self, rhs: DVec3) -> Self::Output {
+        DVec3::new(self.x / rhs.x, self.y / rhs.y, self.z / rhs.z)
+    }
+}
+
+impl std::ops::DivAssign<DVec3> for DVec3 {
+    fn div_assign(&mut self, rhs: DVec3) {
+        self.x /= rhs.x;
+        self.y /= rhs.y;
+        self.z /= rhs.z;
+    }
+}
+
+impl std::ops::Neg for DVec3 {
+    type Output = DVec3;
+
+    fn neg(self) -> Self::Output {
+        DVec3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl std::ops::Index<usize> for DVec3 {
+    type Output = f64;
+
+    /// Gets `x`, `y` or `z` for indices `0`, `1` and `2`
+    ///
+    /// Panics if the index is above `2`
+    fn index(&self, index: usize) -> &f64 {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            _ => panic!("Index {index} is out of range for DVec3"),
+        }
+    }
+}
+
+impl std::ops::IndexMut<usize> for DVec3 {
+    fn index_mut(&mut self, index: usize) -> &mut f64 {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            _ => panic!("Index {index} is out of range for DVec3"),
+        }
+    }
+}
+
+impl std::fmt::Display for DVec3 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+/* ===== DVec2 ===== */
+
+/// A [`Vec2`] in double precision
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct DVec2 {
+    pub x: f64,
+    pub y: f64,
+}
+
+impl DVec2 {
+    pub const ZERO: Self = Self::new(0.0, 0.0);
+
+    pub const fn new(x: f64, y: f64) -> Self {
+        Self { x, y }
+    }
+
+    pub fn dot(&self, other: Self) -> f64 {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// Gets the `z` of the cross product of the two vectors extended into 3D,
+    /// like [`Vec2::cross`]
+    pub fn cross(&self, other: Self) -> f64 {
+        self.x * other.y - self.y * other.x
+    }
+
+    pub fn magnitude_squared(&self) -> f64 {
+        self.x * self.x + self.y * self.y
+    }
+
+    pub fn magnitude(&self) -> f64 {
+        self.magnitude_squared().sqrt()
+    }
+
+    pub fn normalize(&self) -> DVec2 {
+        let mag = self.magnitude();
+
+        DVec2::new(self.x / mag, self.y / mag)
+    }
+
+    /// Gets the vector turned a quarter turn, like [`Vec2::perp`]
+    pub fn perp(&self) -> DVec2 {
+        DVec2::new(self.y, -self.x)
+    }
+
+    /// Rotates the vector by `angle` radians around the origin, like
+    /// [`Vec2::rotate`]
+    pub fn rotate(&self, angle: f64) -> DVec2 {
+        let (sin, cos) = angle.sin_cos();
+
+        DVec2::new(self.x * cos - self.y * sin, self.x * sin + self.y * cos)
+    }
+
+    /// Gets the distance between the two vectors treated as points
+    pub fn distance(&self, other: Self) -> f64 {
+        (*self - other).magnitude()
+    }
+
+    /// Gets the angle between the two vectors in radians, like
+    /// [`Vec2::angle_between`]
+    pub fn angle_between(&self, other: Self) -> f64 {
+        let lengths = (self.magnitude_squared() * other.magnitude_squared()).sqrt();
+
+        if lengths == 0.0 {
+            return 0.0;
+        }
+
+        (self.dot(other) / lengths).clamp(-1.0, 1.0).acos()
+    }
+
+    /// Takes the smaller of each component of the two vectors
+    pub fn min(&self, other: Self) -> DVec2 {
+        DVec2::new(self.x.min(other.x), self.y.min(other.y))
+    }
+
+    /// Takes the larger of each component of the two vectors
+    pub fn max(&self, other: Self) -> DVec2 {
+        DVec2::new(self.x.max(other.x), self.y.max(other.y))
+    }
+
+    /// Takes the absolute value of each component
+    pub fn abs(&self) -> DVec2 {
+        DVec2::new(self.x.abs(), self.y.abs())
+    }
+
+    /// Clamps each component between the matching components of `min` and
+    /// `max`
+    pub fn clamp(&self, min: Self, max: Self) -> DVec2 {
+        self.max(min).min(max)
+    }
+
+    /// Checks whether every component is within `epsilon` of the matching
+    /// component of `other`
+    pub fn approx_eq(&self, other: Self, epsilon: f64) -> bool {
+        (self.x - other.x).abs() <= epsilon && (self.y - other.y).abs() <= epsilon
+    }
+
+    pub fn to_f32(&self) -> Vec2 {
+        Vec2::new(self.x as f32, self.y as f32)
+    }
+}
+
+impl From<Vec2> for DVec2 {
+    fn from(vector: Vec2) -> Self {
+        DVec2::new(vector.x as f64, vector.y as f64)
+    }
+}
+
+impl std::ops::Add<DVec2> for DVec2 {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl std::ops::AddAssign<DVec2> for DVec2 {
+    fn add_assign(&mut self, rhs: DVec2) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl std::ops::Sub<DVec2> for DVec2 {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl std::ops::SubAssign<DVec2> for DVec2 {
+    fn sub_assign(&mut self, rhs: DVec2) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
+impl std::ops::Mul<DVec2> for f64 {
+    type Output = DVec2;
+
+    fn mul(self, rhs: DVec2) -> Self::Output {
+        DVec2::new(rhs.x * self, rhs.y * self)
+    }
+}
+
+impl std::ops::Mul<f64> for DVec2 {
+    type Output = DVec2;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl std::ops::Mul<DVec2> for DVec2 {
+    type Output = DVec2;
+
+    fn mul(self, rhs: DVec2) -> Self::Output {
+        DVec2::new(self.x * rhs.x, self.y * rhs.y)
+    }
+}
+
+impl std::ops::MulAssign<f64> for DVec2 {
+    fn mul_assign(&mut self, rhs: f64) {
+        self.x *= rhs;
+        self.y *= rhs;
+    }
+}
+
+impl std::ops::MulAssign<DVec2> for DVec2 {
+    fn mul_assign(&mut self, rhs: DVec2) {
+        self.x *= rhs.x;
+        self.y *= rhs.y;
+    }
+}
+
+impl std::ops::Div<f64> for DVec2 {
+    type Output = DVec2;
+
+    fn div(self, rhs: f64) -> Self::Output {
+        DVec2::new(self.x / rhs, self.y / rhs)
+    }
+}
+
+impl std::ops::DivAssign<f64> for DVec2 {
+    fn div_assign(&mut self, rhs: f64) {
+        self.x /= rhs;
+        self.y /= rhs;
+    }
+}
+
+impl std::ops::Div<DVec2> for DVec2 {
+    type Output = DVec2;
+
+    fn div(self, rhs: DVec2) -> Self::Output {
+        DVec2::new(self.x / rhs.x, self.y / rhs.y)
+    }
+}
+
+impl std::ops::DivAssign<DVec2> for DVec2 {
+    fn div_assign(&mut self, rhs: DVec2) {
+        self.x /= rhs.x;
+        self.y /= rhs.y;
+    }
+}
+
+impl std::ops::Neg for DVec2 {
+    type Output = DVec2;
+
+    fn neg(self) -> Self::Output {
+        DVec2::new(-self.x, -self.y)
+    }
+}
+
+impl std::ops::Index<usize> for DVec2 {
+    type Output = f64;
+
+    /// Gets `x` or `y` for indices `0` and `1`
+    ///
+    /// Panics if the index is above `1`
+    fn index(&self, index: usize) -> &f64 {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            _ => panic!("Index {index} is out of range for DVec2"),
+        }
+    }
+}
+
+impl std::ops::IndexMut<usize> for DVec2 {
+    fn index_mut(&mut self, index: usize) -> &mut f64 {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            _ => panic!("Index {index} is out of range for DVec2"),
+        }
+    }
+}
+
+impl std::fmt::Display for DVec2 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl From<(f64, f64)> for DVec2 {
+    fn from((x, y): (f64, f64)) -> Self {
+        DVec2::new(x, y)
+    }
+}
+
+impl From<DVec2> for (f64, f64) {
+    fn from(vector: DVec2) -> Self {
+        (vector.x, vector.y)
+    }
+}
+
+/* ==== DMat3 ==== */
+
+/// A [`Mat3`] in double precision, stored row by row so that `m[row][column]`
+/// is the entry at that row and column
+///
+/// Useful for 2D transforms which are built up a little every frame, such as
+/// the camera of a long running scrolling scene.
+///
+/// ```
+/// use farba::{DMat3, DVec2};
+///
+/// // Spin a full turn in a million small steps
+/// let step = DMat3::rotate_2d(std::f64::consts::TAU / 1_000_000.0);
+/// let mut transform = DMat3::IDENTITY;
+///
+/// for _ in 0..1_000_000 {
+///     transform = transform * step;
+/// }
+///
+/// let point = transform.transform_point(DVec2::new(100.0, 0.0));
+/// assert!(point.approx_eq(DVec2::new(100.0, 0.0), 1e-6));
+///
+/// // Ready to be used to draw
+/// let canvas_transform = transform.to_f32();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DMat3 {
+    pub m: [[f64; 3]; 3],
+}
+
+impl DMat3 {
+    #[rustfmt::skip]
+    pub const IDENTITY: DMat3 = DMat3::new([
+        [1.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0],
+        [0.0, 0.0, 1.0],
+    ]);
+
+    pub const fn new(m: [[f64; 3]; 3]) -> Self {
+        Self { m }
+    }
+
+    /// Creates a 2D transform which moves points by `(x, y)`, like
+    /// [`Mat3::translate_2d`]
+    pub const fn translate_2d(x: f64, y: f64) -> DMat3 {
+        let mut matrix = Self::IDENTITY;
+
+        matrix.m[0][2] = x;
+        matrix.m[1][2] = y;
+
+        matrix
+    }
+
+    /// Creates a 2D transform which stretches points away from the origin, like
+    /// [`Mat3::scale_2d`]
+    pub const fn scale_2d(x: f64, y: f64) -> DMat3 {
+        let mut matrix = Self::IDENTITY;
+
+        matrix.m[0][0] = x;
+        matrix.m[1][1] = y;
+
+        matrix
+    }
+
+    /// Creates a 2D transform which rotates points around the origin, like
+    /// [`Mat3::rotate_2d`]
+    pub fn rotate_2d(angle: f64) -> DMat3 {
+        Self::rotate_z(angle)
+    }
+
+    /// Applies the matrix as a 2D affine transform to `point`, like
+    /// [`Mat3::transform_point`]
+    pub fn transform_point(&self, point: DVec2) -> DVec2 {
+        let result = *self * DVec3::new(point.x, point.y, 1.0);
+
+        DVec2::new(result.x, result.y)
+    }
+
+    /// Applies the matrix as a 2D affine transform to `vector`, like
+    /// [`Mat3::transform_vector`]
+    pub fn transform_vector(&self, vector: DVec2) -> DVec2 {
+        let result = *self * DVec3::new(vector.x, vector.y, 0.0);
+
+        DVec2::new(result.x, result.y)
+    }
+
+    #[rustfmt::skip]
+    pub fn rotate_x(angle: f64) -> DMat3 {
+        let (sin, cos) = angle.sin_cos();
+
+        DMat3::new([
+            [1.0, 0.0, 0.0],
+            [0.0, cos, -sin],
+            [0.0, sin, cos],
+        ])
+    }
+
+    #[rustfmt::skip]
+    pub fn rotate_y(angle: f64) -> DMat3 {
+        let (sin, cos) = angle.sin_cos();
+
+        DMat3::new([
+            [cos,  0.0, sin],
+            [0.0,  1.0, 0.0],
+            [-sin, 0.0, cos],
+        ])
+    }
+
+    #[rustfmt::skip]
+    pub fn rotate_z(angle: f64) -> DMat3 {
+        let (sin, cos) = angle.sin_cos();
+
+        DMat3::new([
+            [cos, -sin, 0.0],
+            [sin, cos,  0.0],
+            [0.0, 0.0,  1.0],
+        ])
+    }
+
+    /// Creates a rotation from Euler angles in radians, like
+    /// [`Mat3::from_euler`]
+    pub fn from_euler(angles: DVec3) -> DMat3 {
+        Self::rotate_z(angles.z) * Self::rotate_y(angles.y) * Self::rotate_x(angles.x)
+    }
+
+    /// Finds the Euler angles of a rotation matrix, like [`Mat3::to_euler`]
+    pub fn to_euler(&self) -> DVec3 {
+        let m = &self.m;
+        let y = (-m[2][0]).clamp(-1.0, 1.0).asin();
+
+        // Gimbal lock, where `cos(y)` is zero
+        if m[2][0].abs() > 1.0 - 1e-12 {
+            return DVec3::new((-m[1][2]).atan2(m[1][1]), y, 0.0);
+        }
+
+        DVec3::new(m[2][1].atan2(m[2][2]), y, m[1][0].atan2(m[0][0]))
+    }
+
+    /// Creates a matrix from its three rows, like [`Mat3::from_rows`]
+    pub const fn from_rows(first: DVec3, second: DVec3, third: DVec3) -> DMat3 {
+        DMat3::new([
+            [first.x, first.y, first.z],
+            [second.x, second.y, second.z],
+            [third.x, third.y, third.z],
+        ])
+    }
+
+    /// Creates a matrix from its entries listed row by row
+    #[rustfmt::skip]
+    pub const fn from_rows_array(m: [f64; 9]) -> DMat3 {
+        DMat3::new([
+            [m[0], m[1], m[2]],
+            [m[3], m[4], m[5]],
+            [m[6], m[7], m[8]],
+        ])
+    }
+
+    /// Creates a matrix from its entries listed column by column, like
+    /// [`Mat3::from_cols_array`]
+    #[rustfmt::skip]
+    pub const fn from_cols_array(m: [f64; 9]) -> DMat3 {
+        DMat3::new([
+            [m[0], m[3], m[6]],
+            [m[1], m[4], m[7]],
+            [m[2], m[5], m[8]],
+        ])
+    }
+
+    /// Lists the entries of the matrix row by row
+    #[rustfmt::skip]
+    pub const fn to_rows_array(&self) -> [f64; 9] {
+        let [a, b, c] = self.m;
+
+        [
+            a[0], a[1], a[2],
+            b[0], b[1], b[2],
+            c[0], c[1], c[2],
+        ]
+    }
+
+    /// Lists the entries of the matrix column by column
+    #[rustfmt::skip]
+    pub const fn to_cols_array(&self) -> [f64; 9] {
+        let [a, b, c] = self.m;
+
+        [
+            a[0], b[0], c[0],
+            a[1], b[1], c[1],
+            a[2], b[2], c[2],
+        ]
+    }
+
+    /// Views the entries of the matrix row by row, without copying them
+    pub fn as_slice(&self) -> &[f64] {
+        self.m.as_flattened()
+    }
+
+    /// Checks whether every entry is within `epsilon` of the matching entry of
+    /// `other`
+    pub fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+        approx_eq_slices(self.as_slice(), other.as_slice(), epsilon)
+    }
+
+    #[rustfmt::skip]
+    pub fn to_f32(&self) -> Mat3 {
+        let m = self.m.map(|row| row.map(|value| value as f32));
+
+        Mat3::new(
+            m[0][0], m[0][1], m[0][2],
+            m[1][0], m[1][1], m[1][2],
+            m[2][0], m[2][1], m[2][2],
+        )
+    }
+}
+
+impl Default for DMat3 {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl From<Mat3> for DMat3 {
+    #[rustfmt::skip]
+    fn from(matrix: Mat3) -> Self {
+        DMat3::new([
+            [matrix.a as f64, matrix.b as f64, matrix.c as f64],
+            [matrix.d as f64, matrix.e as f64, matrix.f as f64],
+            [matrix.g as f64, matrix.h as f64, matrix.i as f64],
+        ])
+    }
+}
+
+impl From<[f64; 9]> for DMat3 {
+    /// Creates a matrix from its entries listed row by row
+    fn from(m: [f64; 9]) -> Self {
+        DMat3::from_rows_array(m)
+    }
+}
+
+impl From<DMat3> for [f64; 9] {
+    /// Lists the entries of the matrix row by row
+    fn from(matrix: DMat3) -> Self {
+        matrix.to_rows_array()
+    }
+}
+
+impl From<[[f64; 3]; 3]> for DMat3 {
+    /// Creates a matrix from its rows
+    fn from(m: [[f64; 3]; 3]) -> Self {
+        DMat3::new(m)
+    }
+}
+
+impl From<DMat3> for [[f64; 3]; 3] {
+    /// Gets the rows of the matrix
+    fn from(matrix: DMat3) -> Self {
+        matrix.m
+    }
+}
+
+impl std::ops::Mul<DVec3> for DMat3 {
+    type Output = DVec3;
+
+    fn mul(self, rhs: DVec3) -> Self::Output {
+        let row = |r: &[f64; 3]| r[0] * rhs.x + r[1] * rhs.y + r[2] * rhs.z;
+
+        DVec3::new(row(&self.m[0]), row(&self.m[1]), row(&self.m[2]))
+    }
+}
+
+impl std::ops::Mul<DMat3> for DMat3 {
+    type Output = DMat3;
+
+    fn mul(self, rhs: DMat3) -> Self::Output {
+        let mut result = [[0.0; 3]; 3];
+
+        for (row, output) in result.iter_mut().enumerate() {
+            for (column, value) in output.iter_mut().enumerate() {
+                *value = (0..3).map(|k| self.m[row][k] * rhs.m[k][column]).sum();
+            }
+        }
+
+        DMat3::new(result)
+    }
+}
+
+/* ===== DVec4 ===== */
+
+/// A [`Vec4`] in double precision
+///
+/// ```
+/// use farba::{DMat4, DVec4};
+///
+/// // Far from the origin, small offsets survive where `f32` loses them
+/// let transform = DMat4::translate(1e9, 0.0, 0.0);
+/// let point = transform * DVec4::new(0.25, 0.0, 0.0, 1.0);
+///
+/// assert_eq!(point.x, 1e9 + 0.25);
+/// assert_eq!(1e9f32 + 0.25, 1e9f32);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct DVec4 {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub w: f64,
+}
+
+impl DVec4 {
+    pub const ZERO: Self = Self::new(0.0, 0.0, 0.0, 0.0);
+
+    pub const fn new(x: f64, y: f64, z: f64, w: f64) -> Self {
+        Self { x, y, z, w }
+    }
+
+    /// Creates a vector from the components of `vector` and `w`
+    pub const fn from_vec3(vector: DVec3, w: f64) -> Self {
+        Self::new(vector.x, vector.y, vector.z, w)
+    }
+
+    /// Drops the `w` component
+    pub const fn xyz(&self) -> DVec3 {
+        DVec3::new(self.x, self.y, self.z)
+    }
+
+    pub fn dot(&self, other: &Self) -> f64 {
+        self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
+    }
+
+    /// Divides `x`, `y` and `z` by `w`, like [`Vec4::perspective_divide`]
+    pub fn perspective_divide(&self) -> DVec3 {
+        DVec3::new(self.x / self.w, self.y / self.w, self.z / self.w)
+    }
+
+    /// Checks whether every component is within `epsilon` of the matching
+    /// component of `other`
+    pub fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.xyz().approx_eq(&other.xyz(), epsilon) && (self.w - other.w).abs() <= epsilon
+    }
+
+    pub fn to_f32(&self) -> Vec4 {
+        Vec4::new(self.x as f32, self.y as f32, self.z as f32, self.w as f32)
+    }
+}
+
+impl From<Vec4> for DVec4 {
+    fn from(vector: Vec4) -> Self {
+        DVec4::new(
+            vector.x as f64,
+            vector.y as f64,
+            vector.z as f64,
+            vector.w as f64,
+        )
+    }
+}
+
+impl std::ops::Add<DVec4> for DVec4 {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(
+            self.x + rhs.x,
+            self.y + rhs.y,
+            self.z + rhs.z,
+            self.w + rhs.w,
+        )
+    }
+}
+
+impl std::ops::Sub<DVec4> for DVec4 {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(
+            self.x - rhs.x,
+            self.y - rhs.y,
+            self.z - rhs.z,
+            self.w - rhs.w,
+        )
+    }
+}
+
+impl std::ops::Mul<DVec4> for f64 {
+    type Output = DVec4;
+
+    fn mul(self, rhs: DVec4) -> Self::Output {
+        DVec4::new(rhs.x * self, rhs.y * self, rhs.z * self, rhs.w * self)
+    }
+}
+
+impl std::ops::Mul<f64> for DVec4 {
+    type Output = DVec4;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        rhs * self
+    }
+}
+
+/* ==== DMat4 ==== */
+
+/// A [`Mat4`] in double precision, stored row by row so that `m[row][column]`
+/// is the entry at that row and column
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DMat4 {
+    pub m: [[f64; 4]; 4],
+}
+
+impl DMat4 {
+    #[rustfmt::skip]
+    pub const IDENTITY: DMat4 = DMat4::new([
+        [1.0, 0.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0, 0.0],
+        [0.0, 0.0, 1.0, 0.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ]);
+
+    pub const fn new(m: [[f64; 4]; 4]) -> Self {
+        Self { m }
+    }
+
+    /// Creates a matrix which moves points by `(x, y, z)`
+    pub const fn translate(x: f64, y: f64, z: f64) -> DMat4 {
+        let mut matrix = Self::IDENTITY;
+
+        matrix.m[0][3] = x;
+        matrix.m[1][3] = y;
+        matrix.m[2][3] = z;
+
+        matrix
+    }
+
+    /// Creates a matrix which scales points along each axis
+    pub const fn scale(x: f64, y: f64, z: f64) -> DMat4 {
+        let mut matrix = Self::IDENTITY;
+
+        matrix.m[0][0] = x;
+        matrix.m[1][1] = y;
+        matrix.m[2][2] = z;
+
+        matrix
+    }
+
+    pub fn rotate_x(angle: f64) -> DMat4 {
+        DMat3::rotate_x(angle).into()
+    }
+
+    pub fn rotate_y(angle: f64) -> DMat4 {
+        DMat3::rotate_y(angle).into()
+    }
+
+    pub fn rotate_z(angle: f64) -> DMat4 {
+        DMat3::rotate_z(angle).into()
+    }
+
+    /// Creates a view matrix for a camera at `eye` looking towards `target`,
+    /// like [`Mat4::look_at`]
+    #[rustfmt::skip]
+    pub fn look_at(eye: DVec3, target: DVec3, up: DVec3) -> DMat4 {
+        let forward = (target - eye).normalize();
+        let right = up.cross(&forward).normalize();
+        let up = forward.cross(&right);
+
+        DMat4::new([
+            [right.x,   right.y,   right.z,   -right.dot(&eye)],
+            [up.x,      up.y,      up.z,      -up.dot(&eye)],
+            [forward.x, forward.y, forward.z, -forward.dot(&eye)],
+            [0.0,       0.0,       0.0,       1.0],
+        ])
+    }
+
+    /// Creates a perspective projection, like [`Mat4::perspective`]
+    #[rustfmt::skip]
+    pub fn perspective(fov_y: f64, aspect: f64, near: f64, far: f64) -> DMat4 {
+        let focal = 1.0 / (fov_y / 2.0).tan();
+        let depth = far / (far - near);
+
+        DMat4::new([
+            [focal / aspect, 0.0,   0.0,   0.0],
+            [0.0,            focal, 0.0,   0.0],
+            [0.0,            0.0,   depth, -near * depth],
+            [0.0,            0.0,   1.0,   0.0],
+        ])
+    }
+
+    /// Creates an orthographic projection, like [`Mat4::orthographic`]
+    #[rustfmt::skip]
+    pub fn orthographic(left: f64, right: f64, bottom: f64, top: f64, near: f64, far: f64) -> DMat4 {
+        let width = right - left;
+        let height = top - bottom;
+        let depth = far - near;
+
+        DMat4::new([
+            [2.0 / width, 0.0,          0.0,         -(right + left) / width],
+            [0.0,         2.0 / height, 0.0,         -(top + bottom) / height],
+            [0.0,         0.0,          1.0 / depth, -near / depth],
+            [0.0,         0.0,          0.0,         1.0],
+        ])
+    }
+
+    /// Transforms `point` by the matrix and maps it onto a canvas `width` by
+    /// `height` pixels in size, like [`Mat4::project_point`]
+    pub fn project_point(&self, point: DVec3, width: usize, height: usize) -> Option<DVec3> {
+        let clip = *self * DVec4::from_vec3(point, 1.0);
+
+        if clip.w <= 0.0 {
+            return None;
+        }
+
+        let ndc = clip.perspective_divide();
+
+        Some(DVec3::new(
+            (ndc.x + 1.0) / 2.0 * width as f64,
+            (1.0 - ndc.y) / 2.0 * height as f64,
+            ndc.z,
+        ))
+    }
+
+    /// Applies the matrix to `point`, treating it as `(x, y, z, 1)`, without
+    /// any perspective divide
+    pub fn transform_point(&self, point: DVec3) -> DVec3 {
+        (*self * DVec4::from_vec3(point, 1.0)).xyz()
+    }
+
+    /// Swaps the rows and columns of the matrix
+    pub fn transpose(&self) -> DMat4 {
+        let mut result = [[0.0; 4]; 4];
+
+        for (row, output) in result.iter_mut().enumerate() {
+            for (column, value) in output.iter_mut().enumerate() {
+                *value = self.m[column][row];
+            }
+        }
+
+        DMat4::new(result)
+    }
+
+    /// Finds the matrix which undoes this one, or `None` if it can't be undone,
+    /// the same way as [`Mat4::inverse`]
+    pub fn inverse(&self) -> Option<DMat4> {
+        let mut m = self.m;
+        let mut inverse = Self::IDENTITY.m;
+
+        for column in 0..4 {
+            let pivot = (column..4)
+                .max_by(|&a, &b| m[a][column].abs().total_cmp(&m[b][column].abs()))
+                .unwrap_or(column);
+
+            if m[pivot][column].abs() <= f64::EPSILON * f64::EPSILON {
+                return None;
+            }
+
+            m.swap(column, pivot);
+            inverse.swap(column, pivot);
+
+            let scale = 1.0 / m[column][column];
+
+            for k in 0..4 {
+                m[column][k] *= scale;
+                inverse[column][k] *= scale;
+            }
+
+            for row in 0..4 {
+                let factor = m[row][column];
+
+                if row == column || factor == 0.0 {
+                    continue;
+                }
+
+                for k in 0..4 {
+                    m[row][k] -= factor * m[column][k];
+                    inverse[row][k] -= factor * inverse[column][k];
+                }
+            }
+        }
+
+        Some(DMat4::new(inverse))
+    }
+
+    /// Creates a matrix from its entries listed row by row
+    pub fn from_rows_array(m: [f64; 16]) -> DMat4 {
+        DMat4::new(std::array::from_fn(|row| {
+            std::array::from_fn(|column| m[row * 4 + column])
+        }))
+    }
+
+    /// Creates a matrix from its entries listed column by column, like
+    /// [`Mat4::from_cols_array`]
+    pub fn from_cols_array(m: [f64; 16]) -> DMat4 {
+        DMat4::new(std::array::from_fn(|row| {
+            std::array::from_fn(|column| m[column * 4 + row])
+        }))
+    }
+
+    /// Lists the entries of the matrix row by row
+    pub fn to_rows_array(&self) -> [f64; 16] {
+        *self.m.as_flattened().first_chunk().unwrap()
+    }
+
+    /// Lists the entries of the matrix column by column
+    pub fn to_cols_array(&self) -> [f64; 16] {
+        std::array::from_fn(|k| self.m[k % 4][k / 4])
+    }
+
+    /// Views the entries of the matrix row by row, without copying them
+    pub fn as_slice(&self) -> &[f64] {
+        self.m.as_flattened()
+    }
+
+    /// Checks whether every entry is within `epsilon` of the matching entry of
+    /// `other`
+    pub fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+        approx_eq_slices(self.as_slice(), other.as_slice(), epsilon)
+    }
+
+    pub fn to_f32(&self) -> Mat4 {
+        Mat4::new(self.m.map(|row| row.map(|value| value as f32)))
+    }
+}
+
+impl Default for DMat4 {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl From<Mat4> for DMat4 {
+    fn from(matrix: Mat4) -> Self {
+        DMat4::new(matrix.m.map(|row| row.map(|value| value as f64)))
+    }
+}
+
+impl From<[f64; 16]> for DMat4 {
+    /// Creates a matrix from its entries listed row by row
+    fn from(m: [f64; 16]) -> Self {
+        DMat4::from_rows_array(m)
+    }
+}
+
+impl From<DMat4> for [f64; 16] {
+    /// Lists the entries of the matrix row by row
+    fn from(matrix: DMat4) -> Self {
+        matrix.to_rows_array()
+    }
+}
+
+impl From<[[f64; 4]; 4]> for DMat4 {
+    /// Creates a matrix from its rows
+    fn from(m: [[f64; 4]; 4]) -> Self {
+        DMat4::new(m)
+    }
+}
+
+impl From<DMat4> for [[f64; 4]; 4] {
+    /// Gets the rows of the matrix
+    fn from(matrix: DMat4) -> Self {
+        matrix.m
+    }
+}
+
+impl From<DMat3> for DMat4 {
+    /// Places `matrix` in the top left corner of the identity, giving the same
+    /// linear transform without any translation
+    #[rustfmt::skip]
+    fn from(matrix: DMat3) -> Self {
+        let [a, b, c] = matrix.m;
+
+        DMat4::new([
+            [a[0], a[1], a[2], 0.0],
+            [b[0], b[1], b[2], 0.0],
+            [c[0], c[1], c[2], 0.0],
+            [0.0,  0.0,  0.0,  1.0],
+        ])
+    }
+}
+
+impl std::ops::Mul<DVec4> for DMat4 {
+    type Output = DVec4;
+
+    fn mul(self, rhs: DVec4) -> Self::Output {
+        let row = |r: &[f64; 4]| r[0] * rhs.x + r[1] * rhs.y + r[2] * rhs.z + r[3] * rhs.w;
+
+        DVec4::new(
+            row(&self.m[0]),
+            row(&self.m[1]),
+            row(&self.m[2]),
+            row(&self.m[3]),
+        )
+    }
+}
+
+impl std::ops::Mul<Vec4> for DMat4 {
+    type Output = Vec4;
+
+    /// Transforms a single precision vector, doing the math in double precision
+    fn mul(self, rhs: Vec4) -> Self::Output {
+        (self * DVec4::from(rhs)).to_f32()
+    }
+}
+
+impl std::ops::Mul<DMat4> for DMat4 {
+    type Output = DMat4;
+
+    fn mul(self, rhs: DMat4) -> Self::Output {
+        let mut result = [[0.0; 4]; 4];
+
+        for (row, output) in result.iter_mut().enumerate() {
+            for (column, value) in output.iter_mut().enumerate() {
+                *value = (0..4).map(|k| self.m[row][k] * rhs.m[k][column]).sum();
+            }
+        }
+
+        DMat4::new(result)
+    }
+}
+
+/// Checks whether every value of `a` is within `epsilon` of the matching value
+/// of `b`
+fn approx_eq_slices(a: &[f64], b: &[f64], epsilon: f64) -> bool {
+    a.iter().zip(b).all(|(a, b)| (a - b).abs() <= epsilon)
+}
+
+/* ==== DQuat ==== */
+
+/// A [`Quat`] in double precision
+///
+/// ```
+/// use farba::{DQuat, DVec3};
+///
+/// // Nudge a rotation forward a little every frame for a long time
+/// let step = DQuat::from_axis_angle(DVec3::new(0.0, 0.0, 1.0), std::f64::consts::TAU / 1_000_000.0);
+/// let mut rotation = DQuat::IDENTITY;
+///
+/// for _ in 0..1_000_000 {
+///     rotation = rotation * step;
+/// }
+///
+/// // A full turn later, points end up back where they started
+/// let point = rotation * DVec3::new(100.0, 0.0, 0.0);
+/// assert!(point.approx_eq(&DVec3::new(100.0, 0.0, 0.0), 1e-6));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DQuat {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub w: f64,
+}
+
+impl DQuat {
+    pub const IDENTITY: DQuat = DQuat::new(0.0, 0.0, 0.0, 1.0);
+
+    pub const fn new(x: f64, y: f64, z: f64, w: f64) -> Self {
+        Self { x, y, z, w }
+    }
+
+    /// Creates a rotation of `angle` radians around `axis`, like
+    /// [`Quat::from_axis_angle`]
+    pub fn from_axis_angle(axis: DVec3, angle: f64) -> DQuat {
+        let (sin, cos) = (angle * 0.5).sin_cos();
+        let axis = axis.normalize() * sin;
+
+        DQuat::new(axis.x, axis.y, axis.z, cos)
+    }
+
+    /// Creates a rotation from Euler angles in radians, like
+    /// [`Quat::from_euler`]
+    pub fn from_euler(angles: DVec3) -> DQuat {
+        DQuat::from_axis_angle(DVec3::new(0.0, 0.0, 1.0), angles.z)
+            * DQuat::from_axis_angle(DVec3::new(0.0, 1.0, 0.0), angles.y)
+            * DQuat::from_axis_angle(DVec3::new(1.0, 0.0, 0.0), angles.x)
+    }
+
+    /// Finds the Euler angles of the rotation, like [`Quat::to_euler`]
+    pub fn to_euler(&self) -> DVec3 {
+        self.to_mat3().to_euler()
+    }
+
+    /// Finds the rotation of a pure rotation matrix, like [`Quat::from_mat3`]
+    pub fn from_mat3(matrix: &DMat3) -> DQuat {
+        let [[a, b, c], [d, e, f], [g, h, i]] = matrix.m;
+        let trace = a + e + i;
+
+        // Work from the largest of the four components to keep precision
+        let quat = if trace > 0.0 {
+            let s = (trace + 1.0).sqrt() * 2.0;
+            DQuat::new((h - f) / s, (c - g) / s, (d - b) / s, s / 4.0)
+        } else if a > e && a > i {
+            let s = (1.0 + a - e - i).sqrt() * 2.0;
+            DQuat::new(s / 4.0, (b + d) / s, (c + g) / s, (h - f) / s)
+        } else if e > i {
+            let s = (1.0 + e - a - i).sqrt() * 2.0;
+            DQuat::new((b + d) / s, s / 4.0, (f + h) / s, (c - g) / s)
+        } else {
+            let s = (1.0 + i - a - e).sqrt() * 2.0;
+            DQuat::new((c + g) / s, (f + h) / s, s / 4.0, (d - b) / s)
+        };
+
+        quat.normalize()
+    }
+
+    #[rustfmt::skip]
+    pub fn to_mat3(&self) -> DMat3 {
+        let DQuat { x, y, z, w } = *self;
+
+        DMat3::new([
+            [1.0 - 2.0 * (y * y + z * z), 2.0 * (x * y - z * w),       2.0 * (x * z + y * w)],
+            [2.0 * (x * y + z * w),       1.0 - 2.0 * (x * x + z * z), 2.0 * (y * z - x * w)],
+            [2.0 * (x * z - y * w),       2.0 * (y * z + x * w),       1.0 - 2.0 * (x * x + y * y)],
+        ])
+    }
+
+    pub fn dot(&self, other: &Self) -> f64 {
+        self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
+    }
+
+    pub fn magnitude(&self) -> f64 {
+        self.dot(self).sqrt()
+    }
+
+    pub fn normalize(&self) -> DQuat {
+        let scale = 1.0 / self.magnitude();
+
+        DQuat::new(
+            self.x * scale,
+            self.y * scale,
+            self.z * scale,
+            self.w * scale,
+        )
+    }
+
+    /// Gets the opposite rotation
+    pub fn conjugate(&self) -> DQuat {
+        DQuat::new(-self.x, -self.y, -self.z, self.w)
+    }
+
+    pub fn to_f32(&self) -> Quat {
+        Quat::new(self.x as f32, self.y as f32, self.z as f32, self.w as f32)
+    }
+}
+
+impl Default for DQuat {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl From<Quat> for DQuat {
+    fn from(quat: Quat) -> Self {
+        DQuat::new(quat.x as f64, quat.y as f64, quat.z as f64, quat.w as f64)
+    }
+}
+
+impl From<DQuat> for DMat3 {
+    fn from(quat: DQuat) -> Self {
+        quat.to_mat3()
+    }
+}
+
+impl From<DQuat> for DMat4 {
+    fn from(quat: DQuat) -> Self {
+        quat.to_mat3().into()
+    }
+}
+
+impl std::ops::Mul<DQuat> for DQuat {
+    type Output = DQuat;
+
+    fn mul(self, rhs: DQuat) -> Self::Output {
+        DQuat::new(
+            self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+            self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x,
+            self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w,
+            self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z,
+        )
+    }
+}
+
+impl std::ops::Mul<DVec3> for DQuat {
+    type Output = DVec3;
+
+    /// Rotates `rhs` by the quaternion
+    fn mul(self, rhs: DVec3) -> Self::Output {
+        let axis = DVec3::new(self.x, self.y, self.z);
+        let t = axis.cross(&rhs) * 2.0;
+
+        rhs + t * self.w + axis.cross(&t)
+    }
+}
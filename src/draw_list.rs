@@ -0,0 +1,468 @@
+use crate::{Canvas, Color, FillRule, LineCap, LineJoin, Mat3, Path, RGBAColor, Vec2};
+
+/// A single draw call recorded by a [`DrawList`], with the same meaning as the
+/// [`Canvas`] method of the same name
+#[derive(Debug, Clone)]
+pub enum DrawCommand {
+    Fill(RGBAColor),
+    Rect {
+        x: i32,
+        y: i32,
+        width: i32,
+        height: i32,
+        color: RGBAColor,
+    },
+    RoundedRect {
+        x: i32,
+        y: i32,
+        width: i32,
+        height: i32,
+        corner_radius: i32,
+        color: RGBAColor,
+    },
+    Circle {
+        center_x: i32,
+        center_y: i32,
+        radius: i32,
+        color: RGBAColor,
+    },
+    Ellipse {
+        center_x: i32,
+        center_y: i32,
+        radius_x: i32,
+        radius_y: i32,
+        angle: f32,
+        color: RGBAColor,
+    },
+    Triangle {
+        x1: i32,
+        y1: i32,
+        x2: i32,
+        y2: i32,
+        x3: i32,
+        y3: i32,
+        color: RGBAColor,
+    },
+    Line {
+        x1: i32,
+        y1: i32,
+        x2: i32,
+        y2: i32,
+        color: RGBAColor,
+    },
+    ThickLine {
+        p1: Vec2,
+        p2: Vec2,
+        width: f32,
+        cap: LineCap,
+        color: RGBAColor,
+    },
+    Polyline {
+        points: Vec<Vec2>,
+        color: RGBAColor,
+    },
+    FillPolygon {
+        points: Vec<Vec2>,
+        rule: FillRule,
+        color: RGBAColor,
+    },
+    FillPath {
+        path: Path,
+        rule: FillRule,
+        color: RGBAColor,
+    },
+    StrokePath {
+        path: Path,
+        width: f32,
+        join: LineJoin,
+        cap: LineCap,
+        color: RGBAColor,
+    },
+    PushTransform(Mat3),
+    PopTransform,
+    Translate(f32, f32),
+    Rotate(f32),
+    Scale(f32, f32),
+    SetClipRect {
+        x: i32,
+        y: i32,
+        width: i32,
+        height: i32,
+    },
+    ClearClip,
+}
+
+/// A recording of draw calls which can be replayed onto any canvas later
+///
+/// Recording a static scene once and replaying it is cheaper than rebuilding
+/// it, and since the commands are replayed through the current transform of the
+/// canvas they land on, the same list can be rendered at any resolution. The
+/// recorded commands can also be read back with [`DrawList::get_commands`] to
+/// turn them into other formats.
+///
+/// ```
+/// use farba::{Canvas, DrawList, RGBAColor};
+///
+/// let mut list = DrawList::new();
+/// list.fill(RGBAColor::BLACK);
+/// list.rect(10, 10, 30, 30, RGBAColor::RED);
+///
+/// let mut small = Canvas::new(50, 50);
+/// list.replay(&mut small);
+///
+/// // Replaying at twice the size
+/// let mut large = Canvas::new(100, 100);
+/// large.scale(2.0, 2.0);
+/// list.replay(&mut large);
+///
+/// assert_eq!(*small.get_pixel(39, 39), RGBAColor::RED.into());
+/// assert_eq!(*large.get_pixel(79, 79), RGBAColor::RED.into());
+/// assert_eq!(*large.get_pixel(80, 80), RGBAColor::BLACK.into());
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct DrawList {
+    commands: Vec<DrawCommand>,
+}
+
+impl DrawList {
+    /// Creates a new draw list without any commands
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get_commands(&self) -> &[DrawCommand] {
+        &self.commands
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.commands.is_empty()
+    }
+
+    /// Removes every recorded command
+    pub fn clear(&mut self) {
+        self.commands.clear();
+    }
+
+    /// Records an arbitrary command
+    pub fn push(&mut self, command: DrawCommand) -> &mut Self {
+        self.commands.push(command);
+        self
+    }
+
+    pub fn fill<C: Color>(&mut self, color: C) -> &mut Self {
+        self.push(DrawCommand::Fill(color.pack().into()))
+    }
+
+    pub fn rect<C: Color>(
+        &mut self,
+        x: i32,
+        y: i32,
+        width: i32,
+        height: i32,
+        color: C,
+    ) -> &mut Self {
+        self.push(DrawCommand::Rect {
+            x,
+            y,
+            width,
+            height,
+            color: color.pack().into(),
+        })
+    }
+
+    pub fn rounded_rect<C: Color>(
+        &mut self,
+        x: i32,
+        y: i32,
+        width: i32,
+        height: i32,
+        corner_radius: i32,
+        color: C,
+    ) -> &mut Self {
+        self.push(DrawCommand::RoundedRect {
+            x,
+            y,
+            width,
+            height,
+            corner_radius,
+            color: color.pack().into(),
+        })
+    }
+
+    pub fn circle<C: Color>(
+        &mut self,
+        center_x: i32,
+        center_y: i32,
+        radius: i32,
+        color: C,
+    ) -> &mut Self {
+        self.push(DrawCommand::Circle {
+            center_x,
+            center_y,
+            radius,
+            color: color.pack().into(),
+        })
+    }
+
+    pub fn ellipse<C: Color>(
+        &mut self,
+        center_x: i32,
+        center_y: i32,
+        radius_x: i32,
+        radius_y: i32,
+        color: C,
+    ) -> &mut Self {
+        self.rotated_ellipse(center_x, center_y, radius_x, radius_y, 0.0, color)
+    }
+
+    pub fn rotated_ellipse<C: Color>(
+        &mut self,
+        center_x: i32,
+        center_y: i32,
+        radius_x: i32,
+        radius_y: i32,
+        angle: f32,
+        color: C,
+    ) -> &mut Self {
+        self.push(DrawCommand::Ellipse {
+            center_x,
+            center_y,
+            radius_x,
+            radius_y,
+            angle,
+            color: color.pack().into(),
+        })
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn triangle<C: Color>(
+        &mut self,
+        x1: i32,
+        y1: i32,
+        x2: i32,
+        y2: i32,
+        x3: i32,
+        y3: i32,
+        color: C,
+    ) -> &mut Self {
+        self.push(DrawCommand::Triangle {
+            x1,
+            y1,
+            x2,
+            y2,
+            x3,
+            y3,
+            color: color.pack().into(),
+        })
+    }
+
+    pub fn line<C: Color>(&mut self, x1: i32, y1: i32, x2: i32, y2: i32, color: C) -> &mut Self {
+        self.push(DrawCommand::Line {
+            x1,
+            y1,
+            x2,
+            y2,
+            color: color.pack().into(),
+        })
+    }
+
+    pub fn thick_line<C: Color>(
+        &mut self,
+        p1: Vec2,
+        p2: Vec2,
+        width: f32,
+        cap: LineCap,
+        color: C,
+    ) -> &mut Self {
+        self.push(DrawCommand::ThickLine {
+            p1,
+            p2,
+            width,
+            cap,
+            color: color.pack().into(),
+        })
+    }
+
+    pub fn polyline<C: Color>(&mut self, points: &[Vec2], color: C) -> &mut Self {
+        self.push(DrawCommand::Polyline {
+            points: points.to_vec(),
+            color: color.pack().into(),
+        })
+    }
+
+    pub fn fill_polygon<C: Color>(
+        &mut self,
+        points: &[Vec2],
+        rule: FillRule,
+        color: C,
+    ) -> &mut Self {
+        self.push(DrawCommand::FillPolygon {
+            points: points.to_vec(),
+            rule,
+            color: color.pack().into(),
+        })
+    }
+
+    pub fn fill_path<C: Color>(&mut self, path: &Path, rule: FillRule, color: C) -> &mut Self {
+        self.push(DrawCommand::FillPath {
+            path: path.clone(),
+            rule,
+            color: color.pack().into(),
+        })
+    }
+
+    pub fn stroke_path<C: Color>(
+        &mut self,
+        path: &Path,
+        width: f32,
+        join: LineJoin,
+        cap: LineCap,
+        color: C,
+    ) -> &mut Self {
+        self.push(DrawCommand::StrokePath {
+            path: path.clone(),
+            width,
+            join,
+            cap,
+            color: color.pack().into(),
+        })
+    }
+
+    pub fn push_transform(&mut self, transform: Mat3) -> &mut Self {
+        self.push(DrawCommand::PushTransform(transform))
+    }
+
+    pub fn pop_transform(&mut self) -> &mut Self {
+        self.push(DrawCommand::PopTransform)
+    }
+
+    pub fn translate(&mut self, x: f32, y: f32) -> &mut Self {
+        self.push(DrawCommand::Translate(x, y))
+    }
+
+    pub fn rotate(&mut self, angle: f32) -> &mut Self {
+        self.push(DrawCommand::Rotate(angle))
+    }
+
+    pub fn scale(&mut self, x: f32, y: f32) -> &mut Self {
+        self.push(DrawCommand::Scale(x, y))
+    }
+
+    pub fn set_clip_rect(&mut self, x: i32, y: i32, width: i32, height: i32) -> &mut Self {
+        self.push(DrawCommand::SetClipRect {
+            x,
+            y,
+            width,
+            height,
+        })
+    }
+
+    pub fn clear_clip(&mut self) -> &mut Self {
+        self.push(DrawCommand::ClearClip)
+    }
+
+    /// Draws every recorded command onto `canvas` in order
+    ///
+    /// Commands are drawn through whatever transform the canvas already has, and
+    /// the transform is restored once the list has been drawn, even if the list
+    /// left some of its own transforms pushed. Clip rectangles are always in the
+    /// pixels of the canvas, like when they are set directly.
+    pub fn replay(&self, canvas: &mut Canvas) {
+        let transforms = canvas.transforms.clone();
+
+        for command in &self.commands {
+            canvas.draw_command(command);
+        }
+
+        canvas.transforms = transforms;
+    }
+}
+
+impl Canvas {
+    /// Draws a single command recorded by a [`DrawList`]
+    pub fn draw_command(&mut self, command: &DrawCommand) {
+        match command {
+            DrawCommand::Fill(color) => self.fill(color.pack()),
+            &DrawCommand::Rect {
+                x,
+                y,
+                width,
+                height,
+                ref color,
+            } => self.rect(x, y, width, height, color.pack()),
+            &DrawCommand::RoundedRect {
+                x,
+                y,
+                width,
+                height,
+                corner_radius,
+                ref color,
+            } => self.rounded_rect(x, y, width, height, corner_radius, color.pack()),
+            &DrawCommand::Circle {
+                center_x,
+                center_y,
+                radius,
+                ref color,
+            } => self.circle(center_x, center_y, radius, color.pack()),
+            &DrawCommand::Ellipse {
+                center_x,
+                center_y,
+                radius_x,
+                radius_y,
+                angle,
+                ref color,
+            } => self.rotated_ellipse(center_x, center_y, radius_x, radius_y, angle, color.pack()),
+            &DrawCommand::Triangle {
+                x1,
+                y1,
+                x2,
+                y2,
+                x3,
+                y3,
+                ref color,
+            } => self.triangle(x1, y1, x2, y2, x3, y3, color.pack()),
+            &DrawCommand::Line {
+                x1,
+                y1,
+                x2,
+                y2,
+                ref color,
+            } => self.line(x1, y1, x2, y2, color.pack()),
+            &DrawCommand::ThickLine {
+                p1,
+                p2,
+                width,
+                cap,
+                ref color,
+            } => self.thick_line(p1, p2, width, cap, color.pack()),
+            DrawCommand::Polyline { points, color } => self.polyline(points, color.pack()),
+            DrawCommand::FillPolygon {
+                points,
+                rule,
+                color,
+            } => self.fill_polygon(points, *rule, color.pack()),
+            DrawCommand::FillPath { path, rule, color } => {
+                self.fill_path(path, *rule, color.pack())
+            }
+            &DrawCommand::StrokePath {
+                ref path,
+                width,
+                join,
+                cap,
+                ref color,
+            } => self.stroke_path(path, width, join, cap, color.pack()),
+            &DrawCommand::PushTransform(transform) => self.push_transform(transform),
+            DrawCommand::PopTransform => self.pop_transform(),
+            &DrawCommand::Translate(x, y) => self.translate(x, y),
+            &DrawCommand::Rotate(angle) => self.rotate(angle),
+            &DrawCommand::Scale(x, y) => self.scale(x, y),
+            &DrawCommand::SetClipRect {
+                x,
+                y,
+                width,
+                height,
+            } => self.set_clip_rect(x, y, width, height),
+            DrawCommand::ClearClip => self.clear_clip(),
+        }
+    }
+}
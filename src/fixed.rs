@@ -0,0 +1,611 @@
+//! Fixed point versions of the math types and a triangle rasterizer built on
+//! them, for targets without a floating point unit where every `f32` operation
+//! goes through slow software routines
+//!
+//! Conversions from and to `f32` are provided for setting things up, but none
+//! of the operations on the types themselves touch floating point.
+
+use crate::{Canvas, Color, Mat3, Vec2, Vec3};
+
+/* ===== Fixed ===== */
+
+/// A signed 16.16 fixed point number, with 16 bits for the whole part and 16
+/// bits for the fraction
+///
+/// Covers the range from `-32768` to just under `32768` in steps of `1 / 65536`.
+/// Addition and subtraction wrap on overflow like integers do, while
+/// multiplication and division go through 64 bits before being cut back down.
+///
+/// ```
+/// use farba::Fixed;
+///
+/// let a = Fixed::from_int(3);
+/// let b = Fixed::from_f32(0.25);
+///
+/// assert_eq!(a * b, Fixed::from_f32(0.75));
+/// assert_eq!(a / b, Fixed::from_int(12));
+/// assert_eq!((a + b).floor(), 3);
+/// assert!((Fixed::from_int(2).sqrt().to_f32() - std::f32::consts::SQRT_2).abs() < 1e-4);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Fixed(i32);
+
+impl Fixed {
+    /// Number of bits holding the fraction
+    pub const FRACTION_BITS: u32 = 16;
+
+    pub const ZERO: Fixed = Fixed(0);
+    pub const ONE: Fixed = Fixed(1 << Self::FRACTION_BITS);
+    pub const HALF: Fixed = Fixed(1 << (Self::FRACTION_BITS - 1));
+    pub const MIN: Fixed = Fixed(i32::MIN);
+    pub const MAX: Fixed = Fixed(i32::MAX);
+
+    /// Creates a number from its raw bits, where `65536` is `1`
+    pub const fn from_bits(bits: i32) -> Self {
+        Self(bits)
+    }
+
+    /// Gets the raw bits of the number, where `65536` is `1`
+    pub const fn to_bits(self) -> i32 {
+        self.0
+    }
+
+    pub const fn from_int(value: i32) -> Self {
+        Self(value << Self::FRACTION_BITS)
+    }
+
+    /// Converts an `f32` to the nearest fixed point number, saturating at the
+    /// ends of the range
+    pub fn from_f32(value: f32) -> Self {
+        Self((value * Self::ONE.0 as f32).round() as i32)
+    }
+
+    pub fn to_f32(self) -> f32 {
+        self.0 as f32 / Self::ONE.0 as f32
+    }
+
+    /// Rounds down to the nearest integer
+    pub const fn floor(self) -> i32 {
+        self.0 >> Self::FRACTION_BITS
+    }
+
+    /// Rounds up to the nearest integer
+    pub const fn ceil(self) -> i32 {
+        ((self.0 as i64 + Self::ONE.0 as i64 - 1) >> Self::FRACTION_BITS) as i32
+    }
+
+    /// Rounds to the nearest integer, with halves rounding up
+    pub const fn round(self) -> i32 {
+        ((self.0 as i64 + Self::HALF.0 as i64) >> Self::FRACTION_BITS) as i32
+    }
+
+    /// Gets the part of the number after the point, which is never negative
+    pub const fn fract(self) -> Fixed {
+        Self(self.0 & (Self::ONE.0 - 1))
+    }
+
+    pub const fn abs(self) -> Fixed {
+        Self(self.0.wrapping_abs())
+    }
+
+    pub fn min(self, other: Fixed) -> Fixed {
+        Ord::min(self, other)
+    }
+
+    pub fn max(self, other: Fixed) -> Fixed {
+        Ord::max(self, other)
+    }
+
+    /// Gets the square root, or zero for negative numbers
+    pub fn sqrt(self) -> Fixed {
+        if self.0 <= 0 {
+            return Self::ZERO;
+        }
+
+        // The root of `bits << 16` is the root of the number in 16.16
+        let mut value = (self.0 as u64) << Self::FRACTION_BITS;
+        let mut root = 0u64;
+        let mut bit = 1u64 << 62;
+
+        while bit > value {
+            bit >>= 2;
+        }
+
+        while bit != 0 {
+            if value >= root + bit {
+                value -= root + bit;
+                root = (root >> 1) + bit;
+            } else {
+                root >>= 1;
+            }
+
+            bit >>= 2;
+        }
+
+        Self(root as i32)
+    }
+}
+
+impl From<i32> for Fixed {
+    fn from(value: i32) -> Self {
+        Fixed::from_int(value)
+    }
+}
+
+impl std::fmt::Display for Fixed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_f32())
+    }
+}
+
+impl std::ops::Add<Fixed> for Fixed {
+    type Output = Fixed;
+
+    fn add(self, rhs: Fixed) -> Self::Output {
+        Fixed(self.0.wrapping_add(rhs.0))
+    }
+}
+
+impl std::ops::AddAssign<Fixed> for Fixed {
+    fn add_assign(&mut self, rhs: Fixed) {
+        *self = *self + rhs;
+    }
+}
+
+impl std::ops::Sub<Fixed> for Fixed {
+    type Output = Fixed;
+
+    fn sub(self, rhs: Fixed) -> Self::Output {
+        Fixed(self.0.wrapping_sub(rhs.0))
+    }
+}
+
+impl std::ops::SubAssign<Fixed> for Fixed {
+    fn sub_assign(&mut self, rhs: Fixed) {
+        *self = *self - rhs;
+    }
+}
+
+impl std::ops::Mul<Fixed> for Fixed {
+    type Output = Fixed;
+
+    fn mul(self, rhs: Fixed) -> Self::Output {
+        Fixed(((self.0 as i64 * rhs.0 as i64) >> Fixed::FRACTION_BITS) as i32)
+    }
+}
+
+impl std::ops::MulAssign<Fixed> for Fixed {
+    fn mul_assign(&mut self, rhs: Fixed) {
+        *self = *self * rhs;
+    }
+}
+
+impl std::ops::Div<Fixed> for Fixed {
+    type Output = Fixed;
+
+    /// Panics if `rhs` is zero
+    fn div(self, rhs: Fixed) -> Self::Output {
+        Fixed((((self.0 as i64) << Fixed::FRACTION_BITS) / rhs.0 as i64) as i32)
+    }
+}
+
+impl std::ops::DivAssign<Fixed> for Fixed {
+    fn div_assign(&mut self, rhs: Fixed) {
+        *self = *self / rhs;
+    }
+}
+
+impl std::ops::Neg for Fixed {
+    type Output = Fixed;
+
+    fn neg(self) -> Self::Output {
+        Fixed(self.0.wrapping_neg())
+    }
+}
+
+/* ===== FixedVec2 ===== */
+
+/// A [`Vec2`] made of [`Fixed`] numbers
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FixedVec2 {
+    pub x: Fixed,
+    pub y: Fixed,
+}
+
+impl FixedVec2 {
+    pub const ZERO: Self = Self::new(Fixed::ZERO, Fixed::ZERO);
+
+    pub const fn new(x: Fixed, y: Fixed) -> Self {
+        Self { x, y }
+    }
+
+    pub const fn from_int(x: i32, y: i32) -> Self {
+        Self::new(Fixed::from_int(x), Fixed::from_int(y))
+    }
+
+    pub fn dot(&self, other: Self) -> Fixed {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// Gets the z component of the cross product of the two vectors
+    pub fn cross(&self, other: Self) -> Fixed {
+        self.x * other.y - self.y * other.x
+    }
+
+    pub fn magnitude(&self) -> Fixed {
+        self.dot(*self).sqrt()
+    }
+
+    pub fn to_vec2(&self) -> Vec2 {
+        Vec2::new(self.x.to_f32(), self.y.to_f32())
+    }
+}
+
+impl From<Vec2> for FixedVec2 {
+    fn from(vector: Vec2) -> Self {
+        FixedVec2::new(Fixed::from_f32(vector.x), Fixed::from_f32(vector.y))
+    }
+}
+
+impl std::ops::Add<FixedVec2> for FixedVec2 {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl std::ops::Sub<FixedVec2> for FixedVec2 {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl std::ops::Mul<Fixed> for FixedVec2 {
+    type Output = FixedVec2;
+
+    fn mul(self, rhs: Fixed) -> Self::Output {
+        FixedVec2::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl std::ops::Neg for FixedVec2 {
+    type Output = FixedVec2;
+
+    fn neg(self) -> Self::Output {
+        FixedVec2::new(-self.x, -self.y)
+    }
+}
+
+/* ===== FixedVec3 ===== */
+
+/// A [`Vec3`] made of [`Fixed`] numbers
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FixedVec3 {
+    pub x: Fixed,
+    pub y: Fixed,
+    pub z: Fixed,
+}
+
+impl FixedVec3 {
+    pub const ZERO: Self = Self::new(Fixed::ZERO, Fixed::ZERO, Fixed::ZERO);
+
+    pub const fn new(x: Fixed, y: Fixed, z: Fixed) -> Self {
+        Self { x, y, z }
+    }
+
+    pub const fn from_int(x: i32, y: i32, z: i32) -> Self {
+        Self::new(Fixed::from_int(x), Fixed::from_int(y), Fixed::from_int(z))
+    }
+
+    pub fn dot(&self, other: &Self) -> Fixed {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    pub fn cross(&self, other: &Self) -> FixedVec3 {
+        FixedVec3::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+
+    pub fn magnitude(&self) -> Fixed {
+        self.dot(self).sqrt()
+    }
+
+    pub fn to_vec3(&self) -> Vec3 {
+        Vec3::new(self.x.to_f32(), self.y.to_f32(), self.z.to_f32())
+    }
+}
+
+impl From<Vec3> for FixedVec3 {
+    fn from(vector: Vec3) -> Self {
+        FixedVec3::new(
+            Fixed::from_f32(vector.x),
+            Fixed::from_f32(vector.y),
+            Fixed::from_f32(vector.z),
+        )
+    }
+}
+
+impl std::ops::Add<FixedVec3> for FixedVec3 {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl std::ops::Sub<FixedVec3> for FixedVec3 {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl std::ops::Mul<Fixed> for FixedVec3 {
+    type Output = FixedVec3;
+
+    fn mul(self, rhs: Fixed) -> Self::Output {
+        FixedVec3::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl std::ops::Neg for FixedVec3 {
+    type Output = FixedVec3;
+
+    fn neg(self) -> Self::Output {
+        FixedVec3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+/* ==== FixedMat3 ==== */
+
+/// A [`Mat3`] made of [`Fixed`] numbers, stored row by row so that
+/// `m[row][column]` is the entry at that row and column
+///
+/// Rotations need `sin` and `cos`, so they are best built once as a [`Mat3`]
+/// and converted, after which transforming points stays in fixed point.
+///
+/// ```
+/// use farba::{Fixed, FixedMat3, FixedVec2, Mat3};
+///
+/// let transform = FixedMat3::translate_2d(Fixed::from_int(10), Fixed::from_int(20))
+///     * FixedMat3::from(Mat3::rotate_2d(std::f32::consts::FRAC_PI_2));
+///
+/// let point = transform.transform_point(FixedVec2::from_int(5, 0));
+/// assert_eq!((point.x.round(), point.y.round()), (10, 25));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FixedMat3 {
+    pub m: [[Fixed; 3]; 3],
+}
+
+impl FixedMat3 {
+    #[rustfmt::skip]
+    pub const IDENTITY: FixedMat3 = FixedMat3::new([
+        [Fixed::ONE,  Fixed::ZERO, Fixed::ZERO],
+        [Fixed::ZERO, Fixed::ONE,  Fixed::ZERO],
+        [Fixed::ZERO, Fixed::ZERO, Fixed::ONE],
+    ]);
+
+    pub const fn new(m: [[Fixed; 3]; 3]) -> Self {
+        Self { m }
+    }
+
+    /// Creates a 2D transform which moves points by `(x, y)`, like
+    /// [`Mat3::translate_2d`]
+    pub fn translate_2d(x: Fixed, y: Fixed) -> FixedMat3 {
+        let mut matrix = Self::IDENTITY;
+
+        matrix.m[0][2] = x;
+        matrix.m[1][2] = y;
+
+        matrix
+    }
+
+    /// Creates a 2D transform which stretches points away from the origin, like
+    /// [`Mat3::scale_2d`]
+    pub fn scale_2d(x: Fixed, y: Fixed) -> FixedMat3 {
+        let mut matrix = Self::IDENTITY;
+
+        matrix.m[0][0] = x;
+        matrix.m[1][1] = y;
+
+        matrix
+    }
+
+    /// Applies the matrix as a 2D affine transform to `point`, like
+    /// [`Mat3::transform_point`]
+    pub fn transform_point(&self, point: FixedVec2) -> FixedVec2 {
+        let row = |r: &[Fixed; 3]| r[0] * point.x + r[1] * point.y + r[2];
+
+        FixedVec2::new(row(&self.m[0]), row(&self.m[1]))
+    }
+
+    /// Applies the matrix as a 2D affine transform to `vector`, leaving out the
+    /// translation, like [`Mat3::transform_vector`]
+    pub fn transform_vector(&self, vector: FixedVec2) -> FixedVec2 {
+        let row = |r: &[Fixed; 3]| r[0] * vector.x + r[1] * vector.y;
+
+        FixedVec2::new(row(&self.m[0]), row(&self.m[1]))
+    }
+
+    #[rustfmt::skip]
+    pub fn to_mat3(&self) -> Mat3 {
+        let m = self.m.map(|row| row.map(Fixed::to_f32));
+
+        Mat3::new(
+            m[0][0], m[0][1], m[0][2],
+            m[1][0], m[1][1], m[1][2],
+            m[2][0], m[2][1], m[2][2],
+        )
+    }
+}
+
+impl Default for FixedMat3 {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl From<Mat3> for FixedMat3 {
+    #[rustfmt::skip]
+    fn from(matrix: Mat3) -> Self {
+        let fixed = Fixed::from_f32;
+
+        FixedMat3::new([
+            [fixed(matrix.a), fixed(matrix.b), fixed(matrix.c)],
+            [fixed(matrix.d), fixed(matrix.e), fixed(matrix.f)],
+            [fixed(matrix.g), fixed(matrix.h), fixed(matrix.i)],
+        ])
+    }
+}
+
+impl std::ops::Mul<FixedMat3> for FixedMat3 {
+    type Output = FixedMat3;
+
+    fn mul(self, rhs: FixedMat3) -> Self::Output {
+        let mut result = [[Fixed::ZERO; 3]; 3];
+
+        for (row, output) in result.iter_mut().enumerate() {
+            for (column, value) in output.iter_mut().enumerate() {
+                *value = (0..3).fold(Fixed::ZERO, |sum, k| {
+                    sum + self.m[row][k] * rhs.m[k][column]
+                });
+            }
+        }
+
+        FixedMat3::new(result)
+    }
+}
+
+impl std::ops::Mul<FixedVec3> for FixedMat3 {
+    type Output = FixedVec3;
+
+    fn mul(self, rhs: FixedVec3) -> Self::Output {
+        let row = |r: &[Fixed; 3]| r[0] * rhs.x + r[1] * rhs.y + r[2] * rhs.z;
+
+        FixedVec3::new(row(&self.m[0]), row(&self.m[1]), row(&self.m[2]))
+    }
+}
+
+/* ==== Rasterizer ==== */
+
+/// Bits of sub-pixel precision kept by the rasterizer, which is enough to place
+/// vertices smoothly while keeping the edge functions well within 64 bits
+const SUBPIXEL_BITS: u32 = 8;
+
+impl Canvas {
+    /// Fills a triangle with `color`, using only integer math
+    ///
+    /// Vertices keep their sub-pixel position, and a pixel is filled when its
+    /// center falls inside the triangle. Pixel centers falling exactly on an
+    /// edge shared by two triangles are only filled by one of them, so meshes
+    /// don't double up on their seams. Vertices may be supplied in any order.
+    /// Like blits, the vertices are placed in the coordinates of the canvas
+    /// without going through the current transform.
+    ///
+    /// With the default blending settings and no clip mask, drawing opaque
+    /// colors does all of its per pixel work in integer math.
+    ///
+    /// ```
+    /// use farba::{Canvas, FixedVec2, RGBAColor};
+    ///
+    /// let mut canvas = Canvas::new(10, 10);
+    ///
+    /// // Two halves of a square sharing the diagonal
+    /// let (a, b) = (FixedVec2::from_int(0, 0), FixedVec2::from_int(10, 0));
+    /// let (c, d) = (FixedVec2::from_int(10, 10), FixedVec2::from_int(0, 10));
+    ///
+    /// canvas.triangle_fixed(a, b, c, RGBAColor::RED);
+    /// canvas.triangle_fixed(a, c, d, RGBAColor::BLUE);
+    ///
+    /// assert_eq!(*canvas.get_pixel(8, 1), RGBAColor::RED.into());
+    /// assert_eq!(*canvas.get_pixel(1, 8), RGBAColor::BLUE.into());
+    ///
+    /// // Pixels on the diagonal belong to exactly one of the two
+    /// assert!(canvas.get_pixels().iter().all(|&pixel| pixel != 0));
+    /// ```
+    pub fn triangle_fixed<C: Color>(
+        &mut self,
+        v1: FixedVec2,
+        v2: FixedVec2,
+        v3: FixedVec2,
+        color: C,
+    ) {
+        const SHIFT: u32 = Fixed::FRACTION_BITS - SUBPIXEL_BITS;
+        const PIXEL: i64 = 1 << SUBPIXEL_BITS;
+
+        let subpixel = |v: FixedVec2| {
+            (
+                (v.x.to_bits() >> SHIFT) as i64,
+                (v.y.to_bits() >> SHIFT) as i64,
+            )
+        };
+        let (a, mut b, mut c) = (subpixel(v1), subpixel(v2), subpixel(v3));
+
+        let area = (b.0 - a.0) * (c.1 - a.1) - (b.1 - a.1) * (c.0 - a.0);
+
+        if area == 0 {
+            return;
+        }
+
+        // Wind the vertices so that the inside of every edge is positive
+        if area < 0 {
+            std::mem::swap(&mut b, &mut c);
+        }
+
+        let (left, top, right, bottom) = self.clip_bounds();
+
+        let min_x = (a.0.min(b.0).min(c.0) >> SUBPIXEL_BITS).max(left as i64);
+        let max_x = (a.0.max(b.0).max(c.0) >> SUBPIXEL_BITS).min(right as i64);
+        let min_y = (a.1.min(b.1).min(c.1) >> SUBPIXEL_BITS).max(top as i64);
+        let max_y = (a.1.max(b.1).max(c.1) >> SUBPIXEL_BITS).min(bottom as i64);
+
+        if min_x > max_x || min_y > max_y {
+            return;
+        }
+
+        // Center of the first pixel
+        let (start_x, start_y) = (min_x * PIXEL + PIXEL / 2, min_y * PIXEL + PIXEL / 2);
+
+        // Each edge as its value at the first pixel center, and how much it
+        // changes with each step along x and y
+        let edges = [(a, b), (b, c), (c, a)].map(|(from, to)| {
+            let (dx, dy) = (to.0 - from.0, to.1 - from.1);
+
+            // Centers on top and left edges count as inside, while those on
+            // the other edges are left to the neighboring triangle
+            let bias = if dy < 0 || (dy == 0 && dx > 0) { 0 } else { -1 };
+            let value = dx * (start_y - from.1) - dy * (start_x - from.0) + bias;
+
+            (value, -dy * PIXEL, dx * PIXEL)
+        });
+
+        let pixel_color = color.pack();
+        let mut row_values = edges.map(|(value, _, _)| value);
+
+        for y in min_y as i32..=max_y as i32 {
+            let mut values = row_values;
+
+            for x in min_x as i32..=max_x as i32 {
+                if values.iter().all(|&value| value >= 0) {
+                    self.write_pixel(x, y, pixel_color);
+                }
+
+                for (value, (_, step_x, _)) in values.iter_mut().zip(&edges) {
+                    *value += step_x;
+                }
+            }
+
+            for (value, (_, _, step_y)) in row_values.iter_mut().zip(&edges) {
+                *value += step_y;
+            }
+        }
+    }
+}
@@ -0,0 +1,673 @@
+use std::{collections::HashMap, fmt, path::Path, sync::OnceLock};
+
+use crate::{Canvas, Color};
+
+/// Width and height in pixels of every glyph in the built-in font, before
+/// scaling
+pub const GLYPH_SIZE: i32 = 8;
+
+/// Errors that can occur while loading a [`BitmapFont`]
+#[derive(Debug)]
+pub enum FontError {
+    /// The data is neither a PSF nor a BDF font
+    UnknownFormat,
+    /// The data ended before all of the glyphs it describes
+    Truncated,
+    /// The header of a PSF font describes empty glyphs or more glyphs than a
+    /// font can hold
+    InvalidPsf,
+    /// A line of a BDF font could not be understood, counting from 1
+    InvalidBdf {
+        line: usize,
+    },
+    Io(std::io::Error),
+}
+
+impl fmt::Display for FontError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FontError::UnknownFormat => write!(f, "font is neither a PSF nor a BDF font"),
+            FontError::Truncated => write!(f, "font data ends before all of its glyphs"),
+            FontError::InvalidPsf => write!(f, "invalid PSF font header"),
+            FontError::InvalidBdf { line } => write!(f, "invalid BDF font on line {line}"),
+            FontError::Io(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl std::error::Error for FontError {}
+
+impl From<std::io::Error> for FontError {
+    fn from(value: std::io::Error) -> Self {
+        Self::Io(value)
+    }
+}
+
+/// The bitmap of a single character in a [`BitmapFont`]
+#[derive(Debug, Clone)]
+pub(crate) struct Glyph {
+    pub(crate) width: usize,
+    pub(crate) height: usize,
+    /// Offset of the left edge of the bitmap from the pen position
+    pub(crate) left: i32,
+    /// Offset of the top edge of the bitmap from the top of the line
+    pub(crate) top: i32,
+    /// How far the pen moves to the right after drawing the glyph
+    pub(crate) advance: i32,
+    /// Whether each pixel of the bitmap is set, row by row
+    pub(crate) pixels: Vec<bool>,
+}
+
+impl Glyph {
+    /// Creates a glyph filling a whole `width` by `height` cell, where
+    /// `is_set(x, y)` tells which of its pixels are set
+    fn cell<F: Fn(usize, usize) -> bool>(width: usize, height: usize, is_set: F) -> Self {
+        let pixels = (0..width * height)
+            .map(|i| is_set(i % width, i / width))
+            .collect();
+
+        Self {
+            width,
+            height,
+            left: 0,
+            top: 0,
+            advance: width as i32,
+            pixels,
+        }
+    }
+}
+
+/// A font made of one bitmap per character, which can be drawn with
+/// [`Canvas::text_with_font`]
+///
+/// Fonts can be loaded from PSF (versions 1 and 2) and BDF files, or cut out of
+/// a canvas holding every glyph in a grid. Characters the font doesn't have are
+/// drawn as its `?` glyph, if it has one.
+///
+/// ```
+/// use farba::{BitmapFont, Canvas, RGBAColor};
+///
+/// let bdf = "\
+/// STARTFONT 2.1
+/// FONTBOUNDINGBOX 3 3 0 0
+/// CHARS 1
+/// STARTCHAR plus
+/// ENCODING 43
+/// DWIDTH 4 0
+/// BBX 3 3 0 0
+/// BITMAP
+/// 40
+/// E0
+/// 40
+/// ENDCHAR
+/// ENDFONT
+/// ";
+///
+/// let font = BitmapFont::from_bdf(bdf).unwrap();
+///
+/// let mut canvas = Canvas::new(10, 5);
+/// canvas.text_with_font(&font, 0, 0, "++", 1, RGBAColor::WHITE);
+///
+/// // The centers of both plus signs, 4 pixels apart
+/// assert_eq!(*canvas.get_pixel(1, 1), RGBAColor::WHITE.into());
+/// assert_eq!(*canvas.get_pixel(5, 1), RGBAColor::WHITE.into());
+/// assert_eq!(*canvas.get_pixel(4, 0), 0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct BitmapFont {
+    glyphs: HashMap<char, Glyph>,
+    line_height: i32,
+}
+
+impl BitmapFont {
+    /// The monospaced 8x8 font used by [`Canvas::text`], which covers printable
+    /// ASCII
+    pub fn builtin() -> &'static BitmapFont {
+        static BUILTIN: OnceLock<BitmapFont> = OnceLock::new();
+
+        BUILTIN.get_or_init(|| {
+            let size = GLYPH_SIZE as usize;
+
+            // The lowest bit of each row is its leftmost pixel
+            let glyphs = FONT.iter().zip(' '..='~').map(|(rows, character)| {
+                let glyph = Glyph::cell(size, size, |x, y| rows[y] & (1 << x) != 0);
+                (character, glyph)
+            });
+
+            BitmapFont {
+                glyphs: glyphs.collect(),
+                line_height: GLYPH_SIZE,
+            }
+        })
+    }
+
+    /// Loads a PSF or BDF font from the file at `path`, telling them apart by
+    /// their contents
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, FontError> {
+        let data = std::fs::read(path)?;
+
+        if data.starts_with(&PSF1_MAGIC) || data.starts_with(&PSF2_MAGIC) {
+            return Self::from_psf(&data);
+        }
+
+        match std::str::from_utf8(&data) {
+            Ok(source) => Self::from_bdf(source),
+            Err(_) => Err(FontError::UnknownFormat),
+        }
+    }
+
+    /// Reads a PC Screen Font, either version 1 or 2
+    ///
+    /// Glyphs are matched to characters with the unicode table of the font.
+    /// Fonts without one are assumed to be in the order of Latin-1.
+    pub fn from_psf(data: &[u8]) -> Result<Self, FontError> {
+        if data.starts_with(&PSF1_MAGIC) {
+            let (mode, height) = match data.get(2..4) {
+                Some(&[mode, height]) => (mode, height as usize),
+                _ => return Err(FontError::Truncated),
+            };
+
+            let count = if mode & 0x01 != 0 { 512 } else { 256 };
+            let has_table = mode & 0x06 != 0;
+
+            let glyphs = psf_glyphs(data, 4, count, 8, height)?;
+
+            let table = match has_table {
+                true => psf1_table(&data[4 + count * height..], count),
+                false => latin1_table(count),
+            };
+
+            Ok(Self::from_cells(glyphs, table, height))
+        } else if data.starts_with(&PSF2_MAGIC) {
+            let field = |index: usize| {
+                let bytes = data.get(4 + index * 4..8 + index * 4)?;
+                Some(u32::from_le_bytes(bytes.try_into().ok()?) as usize)
+            };
+
+            let (Some(header_size), Some(flags), Some(count), Some(height), Some(width)) =
+                (field(1), field(2), field(3), field(5), field(6))
+            else {
+                return Err(FontError::Truncated);
+            };
+
+            let glyphs = psf_glyphs(data, header_size, count, width, height)?;
+
+            let table = match flags & 0x01 != 0 {
+                true => {
+                    let glyph_size = width.div_ceil(8) * height;
+                    psf2_table(&data[header_size + count * glyph_size..], count)
+                }
+                false => latin1_table(count),
+            };
+
+            Ok(Self::from_cells(glyphs, table, height))
+        } else {
+            Err(FontError::UnknownFormat)
+        }
+    }
+
+    /// Reads a font in the Glyph Bitmap Distribution Format
+    ///
+    /// Glyphs may have any size and advance, and are placed on the baseline
+    /// according to their bounding boxes. Glyphs without a unicode encoding are
+    /// left out.
+    pub fn from_bdf(source: &str) -> Result<Self, FontError> {
+        let mut lines = source.lines().enumerate();
+
+        match lines.next() {
+            Some((_, line)) if line.starts_with("STARTFONT") => {}
+            _ => return Err(FontError::UnknownFormat),
+        }
+
+        let mut bounding_box = None;
+        let mut ascent = None;
+        let mut descent = None;
+
+        // Glyphs with their tops measured from the baseline, until the ascent
+        // of the font is known
+        let mut glyphs = Vec::new();
+
+        while let Some((index, line)) = lines.next() {
+            let invalid = || FontError::InvalidBdf { line: index + 1 };
+            let mut words = line.split_whitespace();
+
+            match words.next() {
+                Some("FONTBOUNDINGBOX") => bounding_box = Some(bdf_metrics::<4>(words, index)?),
+                Some("FONT_ASCENT") => ascent = Some(bdf_metrics::<1>(words, index)?[0]),
+                Some("FONT_DESCENT") => descent = Some(bdf_metrics::<1>(words, index)?[0]),
+                Some("STARTCHAR") => {
+                    let mut encoding = None;
+                    let mut advance = None;
+                    let mut bounds = None;
+
+                    loop {
+                        let (index, line) = lines.next().ok_or(FontError::Truncated)?;
+                        let mut words = line.split_whitespace();
+
+                        match words.next() {
+                            Some("ENCODING") => {
+                                encoding = Some(bdf_numbers::<1>(words, index)?[0]);
+                            }
+                            Some("DWIDTH") => advance = Some(bdf_metrics::<2>(words, index)?[0]),
+                            Some("BBX") => bounds = Some(bdf_metrics::<4>(words, index)?),
+                            Some("BITMAP") => break,
+                            Some("ENDCHAR") => {
+                                return Err(FontError::InvalidBdf { line: index + 1 })
+                            }
+                            _ => {}
+                        }
+                    }
+
+                    let [width, height, left, bottom] = bounds.ok_or_else(invalid)?;
+                    let (width, height) = (width.max(0), height.max(0));
+
+                    let top = bottom.checked_add(height).and_then(i32::checked_neg);
+                    let advance = advance.or(width.checked_add(left));
+                    let (Some(top), Some(advance)) = (top, advance) else {
+                        return Err(invalid());
+                    };
+
+                    let (width, height) = (width as usize, height as usize);
+                    let mut pixels = Vec::new();
+
+                    for _ in 0..height {
+                        let (index, row) = lines.next().ok_or(FontError::Truncated)?;
+                        let row = row.trim();
+
+                        // Each row needs a hex digit for every four pixels, so
+                        // the bitmap can't be larger than the file describes
+                        if row.len() < width.div_ceil(4) {
+                            return Err(FontError::InvalidBdf { line: index + 1 });
+                        }
+
+                        for x in 0..width {
+                            let digit = row
+                                .get(x / 4..x / 4 + 1)
+                                .and_then(|digit| u8::from_str_radix(digit, 16).ok())
+                                .ok_or(FontError::InvalidBdf { line: index + 1 })?;
+
+                            pixels.push(digit & (0x8 >> (x % 4)) != 0);
+                        }
+                    }
+
+                    let character = encoding
+                        .and_then(|encoding| u32::try_from(encoding).ok())
+                        .and_then(char::from_u32);
+
+                    if let Some(character) = character {
+                        let glyph = Glyph {
+                            width,
+                            height,
+                            left,
+                            top,
+                            advance,
+                            pixels,
+                        };
+
+                        glyphs.push((character, glyph));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        // Without the font wide metrics, the line is just tall enough for every
+        // glyph
+        let highest = glyphs.iter().map(|(_, glyph)| -glyph.top).max();
+        let lowest = glyphs
+            .iter()
+            .map(|(_, glyph)| -(glyph.top + glyph.height as i32))
+            .min();
+
+        let ascent = ascent
+            .or(bounding_box.map(|[_, height, _, bottom]| height + bottom))
+            .or(highest)
+            .unwrap_or(0);
+        let descent = descent
+            .or(bounding_box.map(|[_, _, _, bottom]| -bottom))
+            .or(lowest.map(|lowest| -lowest))
+            .unwrap_or(0);
+
+        let glyphs = glyphs.into_iter().map(|(character, mut glyph)| {
+            glyph.top += ascent;
+            (character, glyph)
+        });
+
+        Ok(Self {
+            glyphs: glyphs.collect(),
+            line_height: ascent + descent,
+        })
+    }
+
+    /// Cuts a monospaced font out of `atlas`, which holds a glyph for every one
+    /// of `characters` in a grid of `glyph_width` by `glyph_height` cells,
+    /// ordered left to right and then top to bottom
+    ///
+    /// Pixels of the atlas which are at least half opaque are part of the
+    /// glyphs.
+    pub fn from_atlas(
+        atlas: &Canvas,
+        glyph_width: usize,
+        glyph_height: usize,
+        characters: &str,
+    ) -> Self {
+        let mut font = Self {
+            glyphs: HashMap::new(),
+            line_height: glyph_height as i32,
+        };
+
+        if glyph_width == 0 || glyph_height == 0 {
+            return font;
+        }
+
+        let columns = atlas.get_width() / glyph_width;
+        let rows = atlas.get_height() / glyph_height;
+
+        for (i, character) in characters.chars().take(columns * rows).enumerate() {
+            let cell_x = (i % columns * glyph_width) as i32;
+            let cell_y = (i / columns * glyph_height) as i32;
+
+            let glyph = Glyph::cell(glyph_width, glyph_height, |x, y| {
+                atlas
+                    .get_pixel(cell_x + x as i32, cell_y + y as i32)
+                    .alpha()
+                    >= 128
+            });
+
+            font.glyphs.insert(character, glyph);
+        }
+
+        font
+    }
+
+    /// Builds a font from the bitmaps of a monospaced font and the characters
+    /// each of them is used for
+    fn from_cells(cells: Vec<Glyph>, table: Vec<Vec<char>>, height: usize) -> Self {
+        let mut glyphs = HashMap::new();
+
+        for (glyph, characters) in cells.into_iter().zip(table) {
+            for character in characters {
+                glyphs.entry(character).or_insert_with(|| glyph.clone());
+            }
+        }
+
+        Self {
+            glyphs,
+            line_height: height as i32,
+        }
+    }
+
+    /// Distance in pixels between the tops of consecutive lines of text
+    pub fn get_line_height(&self) -> i32 {
+        self.line_height
+    }
+
+    pub fn has_glyph(&self, character: char) -> bool {
+        self.glyphs.contains_key(&character)
+    }
+
+    /// Width in pixels of a single line of `text` drawn with every pixel of the
+    /// font scaled up `size` times
+    ///
+    /// This is how far the pen moves along the line, which glyphs reaching past
+    /// their advance may stick out of slightly.
+    pub fn text_width(&self, text: &str, size: u32) -> i32 {
+        let advance: i32 = text
+            .chars()
+            .filter_map(|character| self.glyph(character))
+            .map(|glyph| glyph.advance)
+            .sum();
+
+        advance * size as i32
+    }
+
+    /// Every character the font has along with its glyph
+    pub(crate) fn glyphs(&self) -> impl Iterator<Item = (char, &Glyph)> {
+        self.glyphs
+            .iter()
+            .map(|(character, glyph)| (*character, glyph))
+    }
+
+    /// Gets the glyph drawn for `character`, which is the `?` glyph for
+    /// characters the font doesn't have
+    pub(crate) fn glyph(&self, character: char) -> Option<&Glyph> {
+        self.glyphs
+            .get(&character)
+            .or_else(|| self.glyphs.get(&'?'))
+    }
+}
+
+const PSF1_MAGIC: [u8; 2] = [0x36, 0x04];
+const PSF2_MAGIC: [u8; 4] = [0x72, 0xB5, 0x4A, 0x86];
+
+/// The most glyphs a PSF font can hold, enough for every 16 bit code point
+const PSF_GLYPH_LIMIT: usize = 65536;
+
+/// Reads `count` glyph bitmaps starting at `offset`, with each row padded to a
+/// whole number of bytes and the highest bit of each byte on the left
+///
+/// Glyphs must have a size and there can be at most [`PSF_GLYPH_LIMIT`] of
+/// them, which keeps a forged header from allocating glyphs it doesn't contain.
+fn psf_glyphs(
+    data: &[u8],
+    offset: usize,
+    count: usize,
+    width: usize,
+    height: usize,
+) -> Result<Vec<Glyph>, FontError> {
+    if width == 0 || height == 0 || count > PSF_GLYPH_LIMIT {
+        return Err(FontError::InvalidPsf);
+    }
+
+    let row_size = width.div_ceil(8);
+    let glyph_size = row_size * height;
+
+    let end = glyph_size
+        .checked_mul(count)
+        .and_then(|size| size.checked_add(offset))
+        .ok_or(FontError::Truncated)?;
+
+    let bitmaps = data.get(offset..end).ok_or(FontError::Truncated)?;
+
+    let glyphs = (0..count).map(|i| {
+        let bitmap = &bitmaps[i * glyph_size..(i + 1) * glyph_size];
+
+        Glyph::cell(width, height, |x, y| {
+            bitmap[y * row_size + x / 8] & (0x80 >> (x % 8)) != 0
+        })
+    });
+
+    Ok(glyphs.collect())
+}
+
+/// Characters for each of `count` glyphs stored in the order of Latin-1
+fn latin1_table(count: usize) -> Vec<Vec<char>> {
+    (0..count as u32)
+        .map(|i| char::from_u32(i).into_iter().collect())
+        .collect()
+}
+
+/// Reads the unicode table of a PSF1 font, which lists 16 bit code points for
+/// every glyph ending with `0xFFFF`
+fn psf1_table(data: &[u8], count: usize) -> Vec<Vec<char>> {
+    let mut values = data
+        .chunks_exact(2)
+        .map(|pair| u16::from_le_bytes([pair[0], pair[1]]));
+
+    (0..count)
+        .map(|_| {
+            let entry: Vec<u16> = values
+                .by_ref()
+                .take_while(|&value| value != 0xFFFF)
+                .collect();
+
+            // Sequences of several code points start with `0xFFFE`, and can't be
+            // drawn as a single character
+            entry
+                .into_iter()
+                .take_while(|&value| value != 0xFFFE)
+                .filter_map(|value| char::from_u32(value as u32))
+                .collect()
+        })
+        .collect()
+}
+
+/// Reads the unicode table of a PSF2 font, which lists UTF-8 characters for
+/// every glyph ending with `0xFF`
+fn psf2_table(data: &[u8], count: usize) -> Vec<Vec<char>> {
+    let mut entries = data.split(|&byte| byte == 0xFF);
+
+    (0..count)
+        .map(|_| {
+            let entry = entries.next().unwrap_or_default();
+
+            // Sequences of several characters start with `0xFE`
+            let singles = entry.split(|&byte| byte == 0xFE).next().unwrap_or_default();
+
+            match std::str::from_utf8(singles) {
+                Ok(characters) => characters.chars().collect(),
+                Err(_) => Vec::new(),
+            }
+        })
+        .collect()
+}
+
+/// The largest size or offset accepted in a BDF font, far beyond any real font,
+/// which keeps the glyph metrics from overflowing as they are combined
+const BDF_METRIC_LIMIT: i32 = 1 << 16;
+
+/// Parses the `N` sizes or offsets following a BDF keyword on the line at
+/// `index`, rejecting any beyond [`BDF_METRIC_LIMIT`]
+fn bdf_metrics<'a, const N: usize>(
+    words: impl Iterator<Item = &'a str>,
+    index: usize,
+) -> Result<[i32; N], FontError> {
+    let numbers = bdf_numbers::<N>(words, index)?;
+
+    if numbers
+        .iter()
+        .any(|number| number.unsigned_abs() > BDF_METRIC_LIMIT as u32)
+    {
+        return Err(FontError::InvalidBdf { line: index + 1 });
+    }
+
+    Ok(numbers)
+}
+
+/// Parses the `N` numbers following a BDF keyword on the line at `index`
+fn bdf_numbers<'a, const N: usize>(
+    mut words: impl Iterator<Item = &'a str>,
+    index: usize,
+) -> Result<[i32; N], FontError> {
+    let mut numbers = [0; N];
+
+    for number in &mut numbers {
+        *number = words
+            .next()
+            .and_then(|word| word.parse().ok())
+            .ok_or(FontError::InvalidBdf { line: index + 1 })?;
+    }
+
+    Ok(numbers)
+}
+
+/// Glyphs for the printable ASCII characters, from the public domain font8x8
+/// by Daniel Hepper, which is based on the IBM PC BIOS font
+#[rustfmt::skip]
+const FONT: [[u8; 8]; 95] = [
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // ' '
+    [0x18, 0x3C, 0x3C, 0x18, 0x18, 0x00, 0x18, 0x00], // '!'
+    [0x36, 0x36, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // '"'
+    [0x36, 0x36, 0x7F, 0x36, 0x7F, 0x36, 0x36, 0x00], // '#'
+    [0x0C, 0x3E, 0x03, 0x1E, 0x30, 0x1F, 0x0C, 0x00], // '$'
+    [0x00, 0x63, 0x33, 0x18, 0x0C, 0x66, 0x63, 0x00], // '%'
+    [0x1C, 0x36, 0x1C, 0x6E, 0x3B, 0x33, 0x6E, 0x00], // '&'
+    [0x06, 0x06, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00], // '''
+    [0x18, 0x0C, 0x06, 0x06, 0x06, 0x0C, 0x18, 0x00], // '('
+    [0x06, 0x0C, 0x18, 0x18, 0x18, 0x0C, 0x06, 0x00], // ')'
+    [0x00, 0x66, 0x3C, 0xFF, 0x3C, 0x66, 0x00, 0x00], // '*'
+    [0x00, 0x0C, 0x0C, 0x3F, 0x0C, 0x0C, 0x00, 0x00], // '+'
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x0C, 0x0C, 0x06], // ','
+    [0x00, 0x00, 0x00, 0x3F, 0x00, 0x00, 0x00, 0x00], // '-'
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x0C, 0x0C, 0x00], // '.'
+    [0x60, 0x30, 0x18, 0x0C, 0x06, 0x03, 0x01, 0x00], // '/'
+    [0x3E, 0x63, 0x73, 0x7B, 0x6F, 0x67, 0x3E, 0x00], // '0'
+    [0x0C, 0x0E, 0x0C, 0x0C, 0x0C, 0x0C, 0x3F, 0x00], // '1'
+    [0x1E, 0x33, 0x30, 0x1C, 0x06, 0x33, 0x3F, 0x00], // '2'
+    [0x1E, 0x33, 0x30, 0x1C, 0x30, 0x33, 0x1E, 0x00], // '3'
+    [0x38, 0x3C, 0x36, 0x33, 0x7F, 0x30, 0x78, 0x00], // '4'
+    [0x3F, 0x03, 0x1F, 0x30, 0x30, 0x33, 0x1E, 0x00], // '5'
+    [0x1C, 0x06, 0x03, 0x1F, 0x33, 0x33, 0x1E, 0x00], // '6'
+    [0x3F, 0x33, 0x30, 0x18, 0x0C, 0x0C, 0x0C, 0x00], // '7'
+    [0x1E, 0x33, 0x33, 0x1E, 0x33, 0x33, 0x1E, 0x00], // '8'
+    [0x1E, 0x33, 0x33, 0x3E, 0x30, 0x18, 0x0E, 0x00], // '9'
+    [0x00, 0x0C, 0x0C, 0x00, 0x00, 0x0C, 0x0C, 0x00], // ':'
+    [0x00, 0x0C, 0x0C, 0x00, 0x00, 0x0C, 0x0C, 0x06], // ';'
+    [0x18, 0x0C, 0x06, 0x03, 0x06, 0x0C, 0x18, 0x00], // '<'
+    [0x00, 0x00, 0x3F, 0x00, 0x00, 0x3F, 0x00, 0x00], // '='
+    [0x06, 0x0C, 0x18, 0x30, 0x18, 0x0C, 0x06, 0x00], // '>'
+    [0x1E, 0x33, 0x30, 0x18, 0x0C, 0x00, 0x0C, 0x00], // '?'
+    [0x3E, 0x63, 0x7B, 0x7B, 0x7B, 0x03, 0x1E, 0x00], // '@'
+    [0x0C, 0x1E, 0x33, 0x33, 0x3F, 0x33, 0x33, 0x00], // 'A'
+    [0x3F, 0x66, 0x66, 0x3E, 0x66, 0x66, 0x3F, 0x00], // 'B'
+    [0x3C, 0x66, 0x03, 0x03, 0x03, 0x66, 0x3C, 0x00], // 'C'
+    [0x1F, 0x36, 0x66, 0x66, 0x66, 0x36, 0x1F, 0x00], // 'D'
+    [0x7F, 0x46, 0x16, 0x1E, 0x16, 0x46, 0x7F, 0x00], // 'E'
+    [0x7F, 0x46, 0x16, 0x1E, 0x16, 0x06, 0x0F, 0x00], // 'F'
+    [0x3C, 0x66, 0x03, 0x03, 0x73, 0x66, 0x7C, 0x00], // 'G'
+    [0x33, 0x33, 0x33, 0x3F, 0x33, 0x33, 0x33, 0x00], // 'H'
+    [0x1E, 0x0C, 0x0C, 0x0C, 0x0C, 0x0C, 0x1E, 0x00], // 'I'
+    [0x78, 0x30, 0x30, 0x30, 0x33, 0x33, 0x1E, 0x00], // 'J'
+    [0x67, 0x66, 0x36, 0x1E, 0x36, 0x66, 0x67, 0x00], // 'K'
+    [0x0F, 0x06, 0x06, 0x06, 0x46, 0x66, 0x7F, 0x00], // 'L'
+    [0x63, 0x77, 0x7F, 0x7F, 0x6B, 0x63, 0x63, 0x00], // 'M'
+    [0x63, 0x67, 0x6F, 0x7B, 0x73, 0x63, 0x63, 0x00], // 'N'
+    [0x1C, 0x36, 0x63, 0x63, 0x63, 0x36, 0x1C, 0x00], // 'O'
+    [0x3F, 0x66, 0x66, 0x3E, 0x06, 0x06, 0x0F, 0x00], // 'P'
+    [0x1E, 0x33, 0x33, 0x33, 0x3B, 0x1E, 0x38, 0x00], // 'Q'
+    [0x3F, 0x66, 0x66, 0x3E, 0x36, 0x66, 0x67, 0x00], // 'R'
+    [0x1E, 0x33, 0x07, 0x0E, 0x38, 0x33, 0x1E, 0x00], // 'S'
+    [0x3F, 0x2D, 0x0C, 0x0C, 0x0C, 0x0C, 0x1E, 0x00], // 'T'
+    [0x33, 0x33, 0x33, 0x33, 0x33, 0x33, 0x3F, 0x00], // 'U'
+    [0x33, 0x33, 0x33, 0x33, 0x33, 0x1E, 0x0C, 0x00], // 'V'
+    [0x63, 0x63, 0x63, 0x6B, 0x7F, 0x77, 0x63, 0x00], // 'W'
+    [0x63, 0x63, 0x36, 0x1C, 0x1C, 0x36, 0x63, 0x00], // 'X'
+    [0x33, 0x33, 0x33, 0x1E, 0x0C, 0x0C, 0x1E, 0x00], // 'Y'
+    [0x7F, 0x63, 0x31, 0x18, 0x4C, 0x66, 0x7F, 0x00], // 'Z'
+    [0x1E, 0x06, 0x06, 0x06, 0x06, 0x06, 0x1E, 0x00], // '['
+    [0x03, 0x06, 0x0C, 0x18, 0x30, 0x60, 0x40, 0x00], // '\'
+    [0x1E, 0x18, 0x18, 0x18, 0x18, 0x18, 0x1E, 0x00], // ']'
+    [0x08, 0x1C, 0x36, 0x63, 0x00, 0x00, 0x00, 0x00], // '^'
+    [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF], // '_'
+    [0x0C, 0x0C, 0x18, 0x00, 0x00, 0x00, 0x00, 0x00], // '`'
+    [0x00, 0x00, 0x1E, 0x30, 0x3E, 0x33, 0x6E, 0x00], // 'a'
+    [0x07, 0x06, 0x06, 0x3E, 0x66, 0x66, 0x3B, 0x00], // 'b'
+    [0x00, 0x00, 0x1E, 0x33, 0x03, 0x33, 0x1E, 0x00], // 'c'
+    [0x38, 0x30, 0x30, 0x3E, 0x33, 0x33, 0x6E, 0x00], // 'd'
+    [0x00, 0x00, 0x1E, 0x33, 0x3F, 0x03, 0x1E, 0x00], // 'e'
+    [0x1C, 0x36, 0x06, 0x0F, 0x06, 0x06, 0x0F, 0x00], // 'f'
+    [0x00, 0x00, 0x6E, 0x33, 0x33, 0x3E, 0x30, 0x1F], // 'g'
+    [0x07, 0x06, 0x36, 0x6E, 0x66, 0x66, 0x67, 0x00], // 'h'
+    [0x0C, 0x00, 0x0E, 0x0C, 0x0C, 0x0C, 0x1E, 0x00], // 'i'
+    [0x30, 0x00, 0x30, 0x30, 0x30, 0x33, 0x33, 0x1E], // 'j'
+    [0x07, 0x06, 0x66, 0x36, 0x1E, 0x36, 0x67, 0x00], // 'k'
+    [0x0E, 0x0C, 0x0C, 0x0C, 0x0C, 0x0C, 0x1E, 0x00], // 'l'
+    [0x00, 0x00, 0x33, 0x7F, 0x7F, 0x6B, 0x63, 0x00], // 'm'
+    [0x00, 0x00, 0x1F, 0x33, 0x33, 0x33, 0x33, 0x00], // 'n'
+    [0x00, 0x00, 0x1E, 0x33, 0x33, 0x33, 0x1E, 0x00], // 'o'
+    [0x00, 0x00, 0x3B, 0x66, 0x66, 0x3E, 0x06, 0x0F], // 'p'
+    [0x00, 0x00, 0x6E, 0x33, 0x33, 0x3E, 0x30, 0x78], // 'q'
+    [0x00, 0x00, 0x3B, 0x6E, 0x66, 0x06, 0x0F, 0x00], // 'r'
+    [0x00, 0x00, 0x3E, 0x03, 0x1E, 0x30, 0x1F, 0x00], // 's'
+    [0x08, 0x0C, 0x3E, 0x0C, 0x0C, 0x2C, 0x18, 0x00], // 't'
+    [0x00, 0x00, 0x33, 0x33, 0x33, 0x33, 0x6E, 0x00], // 'u'
+    [0x00, 0x00, 0x33, 0x33, 0x33, 0x1E, 0x0C, 0x00], // 'v'
+    [0x00, 0x00, 0x63, 0x6B, 0x7F, 0x7F, 0x36, 0x00], // 'w'
+    [0x00, 0x00, 0x63, 0x36, 0x1C, 0x36, 0x63, 0x00], // 'x'
+    [0x00, 0x00, 0x33, 0x33, 0x33, 0x3E, 0x30, 0x1F], // 'y'
+    [0x00, 0x00, 0x3F, 0x19, 0x0C, 0x26, 0x3F, 0x00], // 'z'
+    [0x38, 0x0C, 0x0C, 0x07, 0x0C, 0x0C, 0x38, 0x00], // '{'
+    [0x18, 0x18, 0x18, 0x00, 0x18, 0x18, 0x18, 0x00], // '|'
+    [0x07, 0x0C, 0x0C, 0x38, 0x0C, 0x0C, 0x07, 0x00], // '}'
+    [0x6E, 0x3B, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00], // '~'
+];
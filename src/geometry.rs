@@ -0,0 +1,756 @@
+use crate::{normalize_rect, Mat4, NormalizedRect, Vec2, Vec3, Vec4};
+
+/// An axis aligned rectangle with its top left corner at `(x, y)`, for hit
+/// testing and tracking the areas of floating point shapes
+///
+/// The width and height are expected not to be negative, which
+/// [`Rect::from_corners`] takes care of. Rectangles include their top and
+/// left edges but not their bottom and right ones, so that rectangles placed
+/// side by side never both contain the same point.
+///
+/// ```
+/// use farba::{Rect, Vec2};
+///
+/// let a = Rect::new(0.0, 0.0, 10.0, 10.0);
+/// let b = Rect::new(5.0, 5.0, 10.0, 10.0);
+///
+/// assert!(a.overlaps(&b));
+/// assert_eq!(a.intersection(&b), Some(Rect::new(5.0, 5.0, 5.0, 5.0)));
+/// assert_eq!(a.union(&b), Rect::new(0.0, 0.0, 15.0, 15.0));
+///
+/// assert!(a.contains_point(Vec2::new(2.5, 9.0)));
+/// assert!(!a.contains_point(Vec2::new(10.0, 5.0)));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Rect {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl Rect {
+    pub const fn new(x: f32, y: f32, width: f32, height: f32) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// Creates the rectangle spanning between two opposite corners, in either
+    /// order
+    pub fn from_corners(a: Vec2, b: Vec2) -> Self {
+        let min = a.min(b);
+        let max = a.max(b);
+
+        Self::new(min.x, min.y, max.x - min.x, max.y - min.y)
+    }
+
+    /// Creates the smallest rectangle containing every one of `points`, or
+    /// `None` if there are none
+    pub fn from_points<I: IntoIterator<Item = Vec2>>(points: I) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+
+        let (min, max) = points.fold((first, first), |(min, max), point| {
+            (min.min(point), max.max(point))
+        });
+
+        Some(Self::from_corners(min, max))
+    }
+
+    /// Gets the top left corner
+    pub fn min(&self) -> Vec2 {
+        Vec2::new(self.x, self.y)
+    }
+
+    /// Gets the bottom right corner
+    pub fn max(&self) -> Vec2 {
+        Vec2::new(self.x + self.width, self.y + self.height)
+    }
+
+    pub fn center(&self) -> Vec2 {
+        Vec2::new(self.x + self.width / 2.0, self.y + self.height / 2.0)
+    }
+
+    /// Checks whether the rectangle has no area
+    pub fn is_empty(&self) -> bool {
+        self.width <= 0.0 || self.height <= 0.0
+    }
+
+    pub fn contains_point(&self, point: Vec2) -> bool {
+        point.x >= self.x
+            && point.x < self.x + self.width
+            && point.y >= self.y
+            && point.y < self.y + self.height
+    }
+
+    /// Checks whether the two rectangles share any area, where touching edges
+    /// don't count
+    pub fn overlaps(&self, other: &Rect) -> bool {
+        self.x < other.x + other.width
+            && other.x < self.x + self.width
+            && self.y < other.y + other.height
+            && other.y < self.y + self.height
+    }
+
+    /// Gets the area shared by the two rectangles, or `None` if they don't
+    /// overlap
+    pub fn intersection(&self, other: &Rect) -> Option<Rect> {
+        if !self.overlaps(other) {
+            return None;
+        }
+
+        Some(Rect::from_corners(
+            self.min().max(other.min()),
+            self.max().min(other.max()),
+        ))
+    }
+
+    /// Gets the smallest rectangle containing both rectangles
+    pub fn union(&self, other: &Rect) -> Rect {
+        Rect::from_corners(self.min().min(other.min()), self.max().max(other.max()))
+    }
+
+    /// Grows the rectangle just enough to contain `point`
+    pub fn include_point(&self, point: Vec2) -> Rect {
+        Rect::from_corners(self.min().min(point), self.max().max(point))
+    }
+
+    /// Gets the smallest pixel rectangle covering every pixel this rectangle
+    /// touches
+    pub fn to_irect(&self) -> IRect {
+        let min = (self.x.floor() as i32, self.y.floor() as i32);
+        let max = (self.max().x.ceil() as i32, self.max().y.ceil() as i32);
+
+        IRect::new(min.0, min.1, max.0 - min.0, max.1 - min.1)
+    }
+}
+
+/// An axis aligned rectangle of whole pixels, with its top left pixel at
+/// `(x, y)`, for dirty rects and the areas passed to drawing and blitting
+///
+/// Converts to and from the `(x, y, width, height)` tuples taken by functions
+/// such as [`Canvas::blit_scaled`](crate::Canvas::blit_scaled), and can be
+/// clipped to a canvas with [`IRect::normalize`].
+///
+/// ```
+/// use farba::IRect;
+///
+/// let mut dirty = IRect::new(10, 10, 5, 5);
+/// dirty = dirty.union(&IRect::new(30, 12, 2, 2));
+/// assert_eq!(dirty, IRect::new(10, 10, 22, 5));
+///
+/// // Only the part of the rect on a 20x20 canvas is left to redraw
+/// let visible = dirty.normalize(20, 20).unwrap().visible_rect();
+/// assert_eq!(visible, IRect::new(10, 10, 10, 5));
+/// assert!(visible.contains(19, 14));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IRect {
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
+}
+
+impl IRect {
+    pub const fn new(x: i32, y: i32, width: i32, height: i32) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// Creates the rectangle covering both pixels and every pixel between them
+    pub fn from_corners(a: (i32, i32), b: (i32, i32)) -> Self {
+        let (x1, x2) = (a.0.min(b.0), a.0.max(b.0));
+        let (y1, y2) = (a.1.min(b.1), a.1.max(b.1));
+
+        Self::new(x1, y1, x2 - x1 + 1, y2 - y1 + 1)
+    }
+
+    /// Checks whether the rectangle covers no pixels
+    pub fn is_empty(&self) -> bool {
+        self.width <= 0 || self.height <= 0
+    }
+
+    /// Checks whether the pixel at `(x, y)` is inside of the rectangle
+    pub fn contains(&self, x: i32, y: i32) -> bool {
+        x >= self.x && x < self.x + self.width && y >= self.y && y < self.y + self.height
+    }
+
+    /// Checks whether the two rectangles share any pixels
+    pub fn overlaps(&self, other: &IRect) -> bool {
+        self.x < other.x + other.width
+            && other.x < self.x + self.width
+            && self.y < other.y + other.height
+            && other.y < self.y + self.height
+    }
+
+    /// Gets the pixels shared by the two rectangles, or `None` if they don't
+    /// overlap
+    pub fn intersection(&self, other: &IRect) -> Option<IRect> {
+        if !self.overlaps(other) {
+            return None;
+        }
+
+        let x = self.x.max(other.x);
+        let y = self.y.max(other.y);
+        let right = (self.x + self.width).min(other.x + other.width);
+        let bottom = (self.y + self.height).min(other.y + other.height);
+
+        Some(IRect::new(x, y, right - x, bottom - y))
+    }
+
+    /// Gets the smallest rectangle containing both rectangles, where empty
+    /// rectangles are ignored
+    pub fn union(&self, other: &IRect) -> IRect {
+        if self.is_empty() {
+            return *other;
+        }
+        if other.is_empty() {
+            return *self;
+        }
+
+        let x = self.x.min(other.x);
+        let y = self.y.min(other.y);
+        let right = (self.x + self.width).max(other.x + other.width);
+        let bottom = (self.y + self.height).max(other.y + other.height);
+
+        IRect::new(x, y, right - x, bottom - y)
+    }
+
+    /// Clips the rectangle to a canvas of the given size with
+    /// [`normalize_rect`], giving `None` if none of it is on the canvas
+    pub fn normalize(&self, canvas_width: i32, canvas_height: i32) -> Option<NormalizedRect> {
+        normalize_rect(
+            self.x,
+            self.y,
+            self.width,
+            self.height,
+            canvas_width,
+            canvas_height,
+        )
+    }
+}
+
+impl From<(i32, i32, i32, i32)> for IRect {
+    fn from((x, y, width, height): (i32, i32, i32, i32)) -> Self {
+        IRect::new(x, y, width, height)
+    }
+}
+
+impl From<IRect> for (i32, i32, i32, i32) {
+    fn from(rect: IRect) -> Self {
+        (rect.x, rect.y, rect.width, rect.height)
+    }
+}
+
+impl From<IRect> for Rect {
+    fn from(rect: IRect) -> Self {
+        Rect::new(
+            rect.x as f32,
+            rect.y as f32,
+            rect.width as f32,
+            rect.height as f32,
+        )
+    }
+}
+
+impl NormalizedRect {
+    /// Gets the part of the rectangle which is on the canvas
+    pub fn visible_rect(&self) -> IRect {
+        IRect::from_corners((self.x1, self.y1), (self.x2, self.y2))
+    }
+
+    /// Gets the whole rectangle before it was clipped to the canvas, with its
+    /// width and height made positive
+    pub fn original_rect(&self) -> IRect {
+        IRect::from_corners((self.orig_x1, self.orig_y1), (self.orig_x2, self.orig_y2))
+    }
+}
+
+/// An axis aligned box in 3D space between the corners `min` and `max`, for
+/// culling models and bounding meshes
+///
+/// Unlike [`Rect`], boxes include all of their faces, so that flat boxes
+/// around flat meshes still contain their points.
+///
+/// ```
+/// use farba::{Aabb3, Vec3};
+///
+/// let cube = Aabb3::from_points([Vec3::new(-1.0, -1.0, -1.0), Vec3::new(1.0, 1.0, 1.0)]);
+/// let shifted = Aabb3::new(Vec3::new(0.5, 0.5, 0.5), Vec3::new(3.0, 3.0, 3.0));
+///
+/// assert!(cube.overlaps(&shifted));
+/// assert!(cube.contains_point(Vec3::new(1.0, 0.0, -1.0)));
+///
+/// let shared = cube.intersection(&shifted).unwrap();
+/// assert_eq!(shared.min, Vec3::new(0.5, 0.5, 0.5));
+/// assert_eq!(shared.max, Vec3::new(1.0, 1.0, 1.0));
+///
+/// assert_eq!(cube.union(&shifted).size(), Vec3::new(4.0, 4.0, 4.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Aabb3 {
+    pub min: Vec3,
+    pub max: Vec3,
+}
+
+impl Aabb3 {
+    /// A box containing nothing, which grows to fit whatever is added to it
+    pub const EMPTY: Aabb3 = Aabb3::new(
+        Vec3::new(f32::INFINITY, f32::INFINITY, f32::INFINITY),
+        Vec3::new(f32::NEG_INFINITY, f32::NEG_INFINITY, f32::NEG_INFINITY),
+    );
+
+    pub const fn new(min: Vec3, max: Vec3) -> Self {
+        Self { min, max }
+    }
+
+    /// Creates the smallest box containing every one of `points`, which is
+    /// [`Aabb3::EMPTY`] if there are none
+    pub fn from_points<I: IntoIterator<Item = Vec3>>(points: I) -> Self {
+        points
+            .into_iter()
+            .fold(Self::EMPTY, |bounds, point| bounds.include_point(point))
+    }
+
+    /// Checks whether the box contains nothing, which is the case when `min`
+    /// is past `max` along any axis
+    pub fn is_empty(&self) -> bool {
+        self.min.x > self.max.x || self.min.y > self.max.y || self.min.z > self.max.z
+    }
+
+    pub fn center(&self) -> Vec3 {
+        (self.min + self.max) * 0.5
+    }
+
+    pub fn size(&self) -> Vec3 {
+        self.max - self.min
+    }
+
+    pub fn contains_point(&self, point: Vec3) -> bool {
+        (0..3).all(|axis| point[axis] >= self.min[axis] && point[axis] <= self.max[axis])
+    }
+
+    /// Checks whether the two boxes share any space, including touching faces
+    pub fn overlaps(&self, other: &Aabb3) -> bool {
+        (0..3).all(|axis| self.min[axis] <= other.max[axis] && other.min[axis] <= self.max[axis])
+    }
+
+    /// Gets the space shared by the two boxes, or `None` if they don't overlap
+    pub fn intersection(&self, other: &Aabb3) -> Option<Aabb3> {
+        if !self.overlaps(other) {
+            return None;
+        }
+
+        Some(Aabb3::new(
+            self.min.max(&other.min),
+            self.max.min(&other.max),
+        ))
+    }
+
+    /// Gets the smallest box containing both boxes
+    pub fn union(&self, other: &Aabb3) -> Aabb3 {
+        Aabb3::new(self.min.min(&other.min), self.max.max(&other.max))
+    }
+
+    /// Grows the box just enough to contain `point`
+    pub fn include_point(&self, point: Vec3) -> Aabb3 {
+        Aabb3::new(self.min.min(&point), self.max.max(&point))
+    }
+}
+
+impl Default for Aabb3 {
+    fn default() -> Self {
+        Self::EMPTY
+    }
+}
+
+/// A half line starting at `origin` and heading along `direction`, for picking
+/// and casting rays against 3D geometry
+///
+/// Points along the ray are found with [`Ray::at`], where the distance is
+/// measured in lengths of `direction`. Intersection tests give the distance to
+/// the nearest hit in front of the origin.
+///
+/// ```
+/// use farba::{Aabb3, Ray, Vec3};
+///
+/// let ray = Ray::new(Vec3::new(0.0, 0.0, -5.0), Vec3::new(0.0, 0.0, 1.0));
+///
+/// let cube = Aabb3::new(Vec3::new(-1.0, -1.0, -1.0), Vec3::new(1.0, 1.0, 1.0));
+/// assert_eq!(ray.intersect_aabb(&cube), Some(4.0));
+///
+/// let hit = ray.intersect_triangle(
+///     Vec3::new(-1.0, -1.0, 2.0),
+///     Vec3::new(1.0, -1.0, 2.0),
+///     Vec3::new(0.0, 1.0, 2.0),
+/// );
+/// assert_eq!(hit, Some(7.0));
+/// assert_eq!(ray.at(7.0), Vec3::new(0.0, 0.0, 2.0));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Ray {
+    pub origin: Vec3,
+    pub direction: Vec3,
+}
+
+impl Ray {
+    pub const fn new(origin: Vec3, direction: Vec3) -> Self {
+        Self { origin, direction }
+    }
+
+    /// Creates the ray under a point on a canvas `width` by `height` pixels in
+    /// size, running from the near plane to the far plane of the camera, for
+    /// picking what is under the mouse
+    ///
+    /// `view_projection` is the projection matrix times the view matrix used
+    /// to draw the scene, which is undone to find the ray in world space. The
+    /// direction is normalized. Gives `None` if the matrix can't be inverted.
+    pub fn from_screen_point(
+        x: f32,
+        y: f32,
+        width: usize,
+        height: usize,
+        view_projection: &Mat4,
+    ) -> Option<Self> {
+        let inverse = view_projection.inverse()?;
+
+        // Pixels back into normalized device coordinates, as done by
+        // `Mat4::project_point` in reverse
+        let ndc_x = x / width as f32 * 2.0 - 1.0;
+        let ndc_y = 1.0 - y / height as f32 * 2.0;
+
+        let near = (inverse * Vec4::new(ndc_x, ndc_y, 0.0, 1.0)).perspective_divide();
+        let far = (inverse * Vec4::new(ndc_x, ndc_y, 1.0, 1.0)).perspective_divide();
+
+        Some(Self::new(near, (far - near).normalize()))
+    }
+
+    /// Gets the point `distance` lengths of the direction along the ray
+    pub fn at(&self, distance: f32) -> Vec3 {
+        self.origin + self.direction * distance
+    }
+
+    /// Finds where the ray crosses `plane` from either side, giving the distance
+    /// along the ray or `None` if it runs parallel to the plane or points away
+    /// from it
+    pub fn intersect_plane(&self, plane: &Plane) -> Option<f32> {
+        let speed = plane.normal.dot(&self.direction);
+
+        if speed.abs() < f32::EPSILON {
+            return None;
+        }
+
+        let distance = -plane.signed_distance(self.origin) / speed;
+
+        (distance >= 0.0).then_some(distance)
+    }
+
+    /// Finds where the ray passes through the triangle `a`, `b`, `c` from
+    /// either side, giving the distance along the ray or `None` if it misses
+    ///
+    /// This is the Möller–Trumbore algorithm, which doesn't need the plane of
+    /// the triangle ahead of time.
+    pub fn intersect_triangle(&self, a: Vec3, b: Vec3, c: Vec3) -> Option<f32> {
+        let edge1 = b - a;
+        let edge2 = c - a;
+
+        let p = self.direction.cross(&edge2);
+        let determinant = edge1.dot(&p);
+
+        // The ray runs parallel to the triangle
+        if determinant.abs() < f32::EPSILON {
+            return None;
+        }
+
+        let inverse_determinant = 1.0 / determinant;
+        let offset = self.origin - a;
+
+        let u = offset.dot(&p) * inverse_determinant;
+        if !(0.0..=1.0).contains(&u) {
+            return None;
+        }
+
+        let q = offset.cross(&edge1);
+        let v = self.direction.dot(&q) * inverse_determinant;
+        if v < 0.0 || u + v > 1.0 {
+            return None;
+        }
+
+        let distance = edge2.dot(&q) * inverse_determinant;
+
+        (distance >= 0.0).then_some(distance)
+    }
+
+    /// Finds where the ray enters `bounds`, giving the distance along the ray
+    /// or `None` if it misses
+    ///
+    /// Rays starting inside of the box hit it at a distance of `0`.
+    pub fn intersect_aabb(&self, bounds: &Aabb3) -> Option<f32> {
+        // Slab test, narrowing down the stretch of the ray which lies between
+        // the two planes of the box along every axis
+        let mut enter = 0.0f32;
+        let mut exit = f32::INFINITY;
+
+        for axis in 0..3 {
+            let inverse = 1.0 / self.direction[axis];
+
+            let mut near = (bounds.min[axis] - self.origin[axis]) * inverse;
+            let mut far = (bounds.max[axis] - self.origin[axis]) * inverse;
+
+            if near > far {
+                std::mem::swap(&mut near, &mut far);
+            }
+
+            // NaN shows up for rays lying exactly on a face, which count as
+            // hitting it
+            enter = enter.max(if near.is_nan() {
+                f32::NEG_INFINITY
+            } else {
+                near
+            });
+            exit = exit.min(if far.is_nan() { f32::INFINITY } else { far });
+
+            if enter > exit {
+                return None;
+            }
+        }
+
+        Some(enter)
+    }
+}
+
+/// A flat plane splitting space in two, made of every point `p` where
+/// `normal.dot(p) + distance` is zero
+///
+/// Points on the side `normal` points towards are in front of the plane and
+/// have a positive signed distance. Clipping keeps the parts of shapes in
+/// front of the plane, such as the parts of triangles past the near plane of
+/// a camera.
+///
+/// ```
+/// use farba::{Plane, Vec3};
+///
+/// // The near plane of a camera looking down the z axis
+/// let near = Plane::from_point_normal(Vec3::new(0.0, 0.0, 1.0), Vec3::new(0.0, 0.0, 1.0));
+/// assert_eq!(near.signed_distance(Vec3::new(5.0, 2.0, 4.0)), 3.0);
+///
+/// // Clipping a triangle with one corner behind the plane leaves a quad,
+/// // split into two triangles
+/// let pieces = near.clip_triangle(
+///     Vec3::new(0.0, 0.0, -1.0),
+///     Vec3::new(1.0, 0.0, 3.0),
+///     Vec3::new(-1.0, 0.0, 3.0),
+/// );
+/// assert_eq!(pieces.len(), 2);
+/// assert!(pieces.iter().flatten().all(|point| point.z >= 1.0));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Plane {
+    pub normal: Vec3,
+    pub distance: f32,
+}
+
+impl Plane {
+    pub const fn new(normal: Vec3, distance: f32) -> Self {
+        Self { normal, distance }
+    }
+
+    /// Creates the plane through `point` facing `normal`, which should be
+    /// normalized
+    pub fn from_point_normal(point: Vec3, normal: Vec3) -> Self {
+        Self::new(normal, -normal.dot(&point))
+    }
+
+    /// Creates the plane through three points, facing along the cross product
+    /// of `b - a` and `c - a`
+    pub fn from_points(a: Vec3, b: Vec3, c: Vec3) -> Self {
+        let normal = (b - a).cross(&(c - a)).normalize();
+
+        Self::from_point_normal(a, normal)
+    }
+
+    /// Scales the plane so that its normal has a length of one, which makes
+    /// signed distances true distances
+    pub fn normalize(&self) -> Plane {
+        let length = self.normal.magnitude();
+
+        Plane::new(self.normal / length, self.distance / length)
+    }
+
+    /// Gets how far `point` is in front of the plane, which is negative for
+    /// points behind it
+    ///
+    /// The distance is measured in lengths of the normal, so it is only a true
+    /// distance for normalized planes.
+    pub fn signed_distance(&self, point: Vec3) -> f32 {
+        self.normal.dot(&point) + self.distance
+    }
+
+    /// Gets the point where the segment from `a` to `b` crosses the plane,
+    /// given their signed distances
+    fn crossing(a: Vec3, b: Vec3, distance_a: f32, distance_b: f32) -> Vec3 {
+        let t = distance_a / (distance_a - distance_b);
+
+        a + (b - a) * t
+    }
+
+    /// Cuts the segment from `a` to `b` down to the part in front of the
+    /// plane, or `None` if all of it is behind
+    pub fn clip_segment(&self, a: Vec3, b: Vec3) -> Option<(Vec3, Vec3)> {
+        let distance_a = self.signed_distance(a);
+        let distance_b = self.signed_distance(b);
+
+        match (distance_a >= 0.0, distance_b >= 0.0) {
+            (true, true) => Some((a, b)),
+            (false, false) => None,
+            (true, false) => Some((a, Self::crossing(a, b, distance_a, distance_b))),
+            (false, true) => Some((Self::crossing(a, b, distance_a, distance_b), b)),
+        }
+    }
+
+    /// Cuts the triangle `a`, `b`, `c` down to the part in front of the plane,
+    /// giving up to two triangles which keep its winding order
+    ///
+    /// No triangles are left if it is entirely behind the plane, one if it is
+    /// entirely in front or only one corner is in front, and two if a single
+    /// corner is cut off.
+    pub fn clip_triangle(&self, a: Vec3, b: Vec3, c: Vec3) -> Vec<[Vec3; 3]> {
+        let corners = [a, b, c];
+        let distances = corners.map(|corner| self.signed_distance(corner));
+
+        // Walk around the triangle keeping the corners in front and adding a
+        // point wherever an edge crosses the plane
+        let mut polygon = Vec::with_capacity(4);
+
+        for i in 0..3 {
+            let j = (i + 1) % 3;
+
+            if distances[i] >= 0.0 {
+                polygon.push(corners[i]);
+            }
+
+            if (distances[i] >= 0.0) != (distances[j] >= 0.0) {
+                polygon.push(Self::crossing(
+                    corners[i],
+                    corners[j],
+                    distances[i],
+                    distances[j],
+                ));
+            }
+        }
+
+        (1..polygon.len().saturating_sub(1))
+            .map(|i| [polygon[0], polygon[i], polygon[i + 1]])
+            .collect()
+    }
+}
+
+/// The volume a camera can see, bounded by six planes facing inwards, for
+/// culling whatever is out of view before it is drawn
+///
+/// Tests are conservative, so shapes near the corners of the frustum can be
+/// reported as visible when they aren't, but visible shapes are never culled.
+///
+/// ```
+/// use farba::{Aabb3, Frustum, Mat4, Vec3};
+///
+/// let projection = Mat4::perspective(90f32.to_radians(), 1.0, 0.1, 100.0);
+/// let view = Mat4::look_at(Vec3::ZERO, Vec3::new(0.0, 0.0, 1.0), Vec3::new(0.0, 1.0, 0.0));
+/// let frustum = Frustum::from_matrix(&(projection * view));
+///
+/// assert!(frustum.contains_point(Vec3::new(0.0, 0.0, 10.0)));
+/// assert!(!frustum.contains_point(Vec3::new(0.0, 0.0, -10.0)));
+///
+/// // A box straddling the edge of the view is still visible
+/// let edge = Aabb3::new(Vec3::new(9.0, -1.0, 9.0), Vec3::new(11.0, 1.0, 11.0));
+/// assert!(frustum.intersects_aabb(&edge));
+///
+/// // A sphere far off to the side isn't
+/// assert!(!frustum.intersects_sphere(Vec3::new(50.0, 0.0, 10.0), 5.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Frustum {
+    /// The left, right, bottom, top, near and far planes, in that order
+    pub planes: [Plane; 6],
+}
+
+impl Frustum {
+    /// Extracts the frustum of a projection matrix, or a projection times a
+    /// view matrix to get the frustum in world space
+    ///
+    /// The matrix is expected to follow the conventions of
+    /// [`Mat4::perspective`] and [`Mat4::orthographic`], where visible depths
+    /// run from `0` to `1`.
+    pub fn from_matrix(matrix: &Mat4) -> Self {
+        let row = |i: usize| {
+            Vec4::new(
+                matrix.m[i][0],
+                matrix.m[i][1],
+                matrix.m[i][2],
+                matrix.m[i][3],
+            )
+        };
+        let plane = |v: Vec4| Plane::new(v.xyz(), v.w).normalize();
+
+        let (x, y, z, w) = (row(0), row(1), row(2), row(3));
+
+        Self {
+            planes: [
+                plane(w + x),
+                plane(w - x),
+                plane(w + y),
+                plane(w - y),
+                plane(z),
+                plane(w - z),
+            ],
+        }
+    }
+
+    pub fn contains_point(&self, point: Vec3) -> bool {
+        self.planes
+            .iter()
+            .all(|plane| plane.signed_distance(point) >= 0.0)
+    }
+
+    /// Checks whether any part of the sphere might be inside the frustum
+    pub fn intersects_sphere(&self, center: Vec3, radius: f32) -> bool {
+        self.planes
+            .iter()
+            .all(|plane| plane.signed_distance(center) >= -radius)
+    }
+
+    /// Checks whether any part of the box might be inside the frustum
+    pub fn intersects_aabb(&self, bounds: &Aabb3) -> bool {
+        self.planes.iter().all(|plane| {
+            // The corner of the box furthest along the normal of the plane
+            let corner = Vec3::new(
+                if plane.normal.x >= 0.0 {
+                    bounds.max.x
+                } else {
+                    bounds.min.x
+                },
+                if plane.normal.y >= 0.0 {
+                    bounds.max.y
+                } else {
+                    bounds.min.y
+                },
+                if plane.normal.z >= 0.0 {
+                    bounds.max.z
+                } else {
+                    bounds.min.z
+                },
+            );
+
+            plane.signed_distance(corner) >= 0.0
+        })
+    }
+}
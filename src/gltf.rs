@@ -0,0 +1,951 @@
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    color::linear_to_srgb, json::Json, Mat4, Material, Mesh, Quat, RGBAColor, Submesh, Vec2, Vec3,
+    Vec4,
+};
+
+/// Errors that can occur while loading a glTF file into a [`Mesh`]
+#[derive(Debug)]
+pub enum GltfError {
+    /// The JSON of a glTF file could not be understood, counting from 1
+    InvalidJson {
+        line: usize,
+    },
+    /// The header or chunks of a GLB file don't fit in the file
+    InvalidGlb,
+    /// The default scene doesn't exist, or a scene lists a node which doesn't
+    /// exist
+    InvalidScene,
+    /// A node has a malformed transform, refers to a mesh or child which
+    /// doesn't exist, or has more than one parent
+    InvalidNode {
+        node: usize,
+    },
+    /// A mesh has no list of primitives, or its primitives refer to accessors
+    /// or materials which don't exist
+    InvalidMesh {
+        mesh: usize,
+    },
+    /// An accessor has an unknown layout, reaches outside of its buffer, or
+    /// doesn't hold what the attribute using it expects
+    InvalidAccessor {
+        accessor: usize,
+    },
+    /// A material has a malformed color, or refers to a texture or image which
+    /// doesn't exist
+    InvalidMaterial {
+        material: usize,
+    },
+    /// A buffer is kept in a file of its own, which only [`Mesh::load_gltf`]
+    /// knows where to find
+    ExternalBuffer {
+        buffer: usize,
+    },
+    /// A buffer is missing from a GLB file, or is a `data:` URI which isn't
+    /// valid base64
+    InvalidBuffer {
+        buffer: usize,
+    },
+    Io(std::io::Error),
+}
+
+impl fmt::Display for GltfError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GltfError::InvalidJson { line } => write!(f, "invalid glTF JSON on line {line}"),
+            GltfError::InvalidGlb => write!(f, "invalid GLB container"),
+            GltfError::InvalidScene => write!(f, "invalid glTF scene"),
+            GltfError::InvalidNode { node } => write!(f, "invalid glTF node {node}"),
+            GltfError::InvalidMesh { mesh } => write!(f, "invalid glTF mesh {mesh}"),
+            GltfError::InvalidAccessor { accessor } => {
+                write!(f, "invalid glTF accessor {accessor}")
+            }
+            GltfError::InvalidMaterial { material } => {
+                write!(f, "invalid glTF material {material}")
+            }
+            GltfError::ExternalBuffer { buffer } => {
+                write!(f, "glTF buffer {buffer} is stored in a separate file")
+            }
+            GltfError::InvalidBuffer { buffer } => write!(f, "invalid glTF buffer {buffer}"),
+            GltfError::Io(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl std::error::Error for GltfError {}
+
+impl From<std::io::Error> for GltfError {
+    fn from(value: std::io::Error) -> Self {
+        Self::Io(value)
+    }
+}
+
+impl Mesh {
+    /// Loads the glTF or GLB file at `path`
+    ///
+    /// Buffers and textures kept in separate files are looked for relative to
+    /// the folder holding the file.
+    pub fn load_gltf<P: AsRef<Path>>(path: P) -> Result<Mesh, GltfError> {
+        let path = path.as_ref();
+        let folder = path.parent().unwrap_or(Path::new(""));
+
+        read_gltf(&std::fs::read(path)?, Some(folder))
+    }
+
+    /// Reads a glTF or GLB file whose buffers are all inside it, either as the
+    /// binary chunk of a GLB file or as base64 `data:` URIs
+    ///
+    /// The triangles of every mesh in the default scene are merged into one
+    /// mesh, placed by the transforms of their nodes. Each glTF primitive
+    /// becomes a [`Submesh`] named after its mesh. Positions, indices,
+    /// normals, the first set of texture coordinates and the first set of
+    /// vertex colors are kept. If some primitives have no normals, smooth
+    /// normals are computed for the whole mesh.
+    ///
+    /// glTF is right handed while this crate is left handed, so z is flipped
+    /// and the triangles are wound the other way, keeping the model from
+    /// being mirrored.
+    ///
+    /// Each glTF material becomes a [`Material`] with its base color, and the
+    /// path of its base color texture when that is a separate image file.
+    /// Images embedded in the file aren't decoded, so those materials get no
+    /// texture.
+    ///
+    /// ```
+    /// use farba::{Mesh, Vec3};
+    ///
+    /// let mesh = Mesh::from_gltf(br#"{
+    ///     "asset": { "version": "2.0" },
+    ///     "scene": 0,
+    ///     "scenes": [{ "nodes": [0] }],
+    ///     "nodes": [{ "mesh": 0, "translation": [0, 0, 2] }],
+    ///     "meshes": [{
+    ///         "name": "triangle",
+    ///         "primitives": [{ "attributes": { "POSITION": 0 }, "material": 0 }]
+    ///     }],
+    ///     "materials": [{ "pbrMetallicRoughness": { "baseColorFactor": [1, 0, 0, 1] } }],
+    ///     "accessors": [{
+    ///         "bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3",
+    ///         "min": [0, 0, 0], "max": [1, 1, 0]
+    ///     }],
+    ///     "bufferViews": [{ "buffer": 0, "byteLength": 36 }],
+    ///     "buffers": [{
+    ///         "byteLength": 36,
+    ///         "uri": "data:application/octet-stream;base64,AAAAAAAAAAAAAAAAAACAPwAAAAAAAAAAAAAAAAAAgD8AAAAA"
+    ///     }]
+    /// }"#)
+    /// .unwrap();
+    ///
+    /// assert_eq!(mesh.triangle_count(), 1);
+    /// assert_eq!(mesh.submeshes[0].name, "triangle");
+    /// assert_eq!(mesh.triangle_material(0).unwrap().diffuse.red, 255);
+    ///
+    /// // The node moves the triangle along z, which is flipped
+    /// assert_eq!(mesh.positions[1], Vec3::new(1.0, 0.0, -2.0));
+    /// ```
+    pub fn from_gltf(data: &[u8]) -> Result<Mesh, GltfError> {
+        read_gltf(data, None)
+    }
+}
+
+/// The magic number at the start of a GLB file
+const GLB_MAGIC: [u8; 4] = *b"glTF";
+/// The types of the JSON and binary chunks of a GLB file
+const GLB_JSON: u32 = 0x4E4F534A;
+const GLB_BIN: u32 = 0x004E4942;
+
+/// glTF component types, named after the OpenGL types they match
+const BYTE: usize = 5120;
+const UNSIGNED_BYTE: usize = 5121;
+const SHORT: usize = 5122;
+const UNSIGNED_SHORT: usize = 5123;
+const UNSIGNED_INT: usize = 5125;
+const FLOAT: usize = 5126;
+
+/// The primitive mode of a list of triangles
+const TRIANGLES: usize = 4;
+
+/// The most elements an accessor without a buffer view can have, since its
+/// zeros take no room in the file
+const ZERO_ACCESSOR_LIMIT: usize = 1 << 24;
+
+/// Reads a glTF or GLB file, finding separate files in `folder` if there is one
+fn read_gltf(data: &[u8], folder: Option<&Path>) -> Result<Mesh, GltfError> {
+    let (json, blob) = match data.starts_with(&GLB_MAGIC) {
+        true => read_glb(data)?,
+        false => (data, None),
+    };
+
+    let document = Json::parse(json).map_err(|line| GltfError::InvalidJson { line })?;
+
+    let buffers = list(&document, "buffers")
+        .iter()
+        .enumerate()
+        .map(|(index, buffer)| read_buffer(index, buffer, blob, folder))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut mesh = Mesh {
+        materials: list(&document, "materials")
+            .iter()
+            .enumerate()
+            .map(|(index, material)| read_material(&document, index, material, folder))
+            .collect::<Result<_, _>>()?,
+        material_indices: Some(Vec::new()),
+        ..Default::default()
+    };
+
+    let mut reader = MeshReader {
+        document: &document,
+        buffers,
+        material_count: mesh.materials.len(),
+        default_material: None,
+        normals: Vec::new(),
+        uvs: Vec::new(),
+        colors: Vec::new(),
+        has_normals: true,
+        has_uvs: false,
+        has_colors: false,
+    };
+
+    let scenes = list(&document, "scenes");
+
+    let scene = match document.get("scene") {
+        Some(scene) => {
+            let scene = scene.as_usize().and_then(|scene| scenes.get(scene));
+            Some(scene.ok_or(GltfError::InvalidScene)?)
+        }
+        None => scenes.first(),
+    };
+
+    match scene {
+        Some(scene) => {
+            let roots = indices(scene.get("nodes")).ok_or(GltfError::InvalidScene)?;
+            reader.read_nodes(&mut mesh, &roots)?;
+        }
+        // Without any scenes there is nothing to place the meshes, so they
+        // are all read as they are
+        None => {
+            for (index, gltf_mesh) in list(&document, "meshes").iter().enumerate() {
+                reader.read_mesh(&mut mesh, index, gltf_mesh, Mat4::IDENTITY)?;
+            }
+        }
+    }
+
+    finish_mesh(&mut mesh, reader);
+
+    Ok(mesh)
+}
+
+/// Splits a GLB file into its JSON and its binary chunk, if it has one
+fn read_glb(data: &[u8]) -> Result<(&[u8], Option<&[u8]>), GltfError> {
+    let word = |data: &[u8], offset: usize| {
+        let bytes = data.get(offset..offset.checked_add(4)?)?;
+        Some(u32::from_le_bytes(bytes.try_into().ok()?))
+    };
+
+    // The header holds the magic number, the version and the length of the file
+    let (Some(2), Some(length)) = (word(data, 4), word(data, 8)) else {
+        return Err(GltfError::InvalidGlb);
+    };
+
+    let data = data.get(..length as usize).ok_or(GltfError::InvalidGlb)?;
+
+    let (mut json, mut blob) = (None, None);
+    let mut offset = 12;
+
+    while offset < data.len() {
+        let (Some(size), Some(kind)) = (word(data, offset), word(data, offset + 4)) else {
+            return Err(GltfError::InvalidGlb);
+        };
+
+        let chunk = data
+            .get(offset + 8..)
+            .and_then(|rest| rest.get(..size as usize))
+            .ok_or(GltfError::InvalidGlb)?;
+
+        // Chunks of unknown types belong to extensions, and are skipped
+        match kind {
+            GLB_JSON if json.is_none() => json = Some(chunk),
+            GLB_BIN if blob.is_none() => blob = Some(chunk),
+            _ => {}
+        }
+
+        offset += 8 + chunk.len();
+    }
+
+    Ok((json.ok_or(GltfError::InvalidGlb)?, blob))
+}
+
+/// Gathers the vertex attributes of every primitive while the scene is walked
+struct MeshReader<'a> {
+    document: &'a Json,
+    buffers: Vec<Vec<u8>>,
+    /// How many materials the file has, not counting the default material
+    material_count: usize,
+    /// Index of the material added for primitives which don't name one
+    default_material: Option<usize>,
+    normals: Vec<Vec3>,
+    uvs: Vec<Vec2>,
+    colors: Vec<RGBAColor>,
+    has_normals: bool,
+    has_uvs: bool,
+    has_colors: bool,
+}
+
+impl MeshReader<'_> {
+    /// Reads the meshes of the nodes at `roots` and all of their descendants,
+    /// placed by the transforms of the nodes
+    fn read_nodes(&mut self, mesh: &mut Mesh, roots: &[usize]) -> Result<(), GltfError> {
+        let nodes = list(self.document, "nodes");
+        let meshes = list(self.document, "meshes");
+
+        if roots.iter().any(|&root| root >= nodes.len()) {
+            return Err(GltfError::InvalidScene);
+        }
+
+        // The tree is walked with a stack of its own rather than recursion, so
+        // deeply nested nodes can't overflow the stack
+        let mut stack: Vec<(usize, Mat4)> = roots
+            .iter()
+            .rev()
+            .map(|&root| (root, Mat4::IDENTITY))
+            .collect();
+        let mut visited = vec![false; nodes.len()];
+
+        while let Some((index, parent)) = stack.pop() {
+            let invalid = || GltfError::InvalidNode { node: index };
+            let node = &nodes[index];
+
+            // Nodes form trees, so a node reached twice has two parents or is
+            // its own ancestor
+            if std::mem::replace(&mut visited[index], true) {
+                return Err(invalid());
+            }
+
+            let transform = parent * node_transform(node).ok_or_else(invalid)?;
+
+            if let Some(mesh_index) = optional_usize(node, "mesh").ok_or_else(invalid)? {
+                let gltf_mesh = meshes.get(mesh_index).ok_or_else(invalid)?;
+                self.read_mesh(mesh, mesh_index, gltf_mesh, transform)?;
+            }
+
+            let children = indices(node.get("children")).ok_or_else(invalid)?;
+
+            if children.iter().any(|&child| child >= nodes.len()) {
+                return Err(invalid());
+            }
+
+            stack.extend(children.iter().rev().map(|&child| (child, transform)));
+        }
+
+        Ok(())
+    }
+
+    fn read_mesh(
+        &mut self,
+        mesh: &mut Mesh,
+        index: usize,
+        gltf_mesh: &Json,
+        transform: Mat4,
+    ) -> Result<(), GltfError> {
+        let invalid = || GltfError::InvalidMesh { mesh: index };
+
+        // Normals are moved by the inverse transpose, so they stay at right
+        // angles to scaled surfaces
+        let normal_transform = transform.inverse().unwrap_or(transform).transpose();
+
+        // Transforms which mirror the mesh also turn its triangles inside out
+        let [x, y, z] = [0, 1, 2].map(|col| {
+            Vec3::new(
+                transform.m[0][col],
+                transform.m[1][col],
+                transform.m[2][col],
+            )
+        });
+        let mirrored = x.dot(&y.cross(&z)) < 0.0;
+
+        let name = gltf_mesh
+            .get("name")
+            .and_then(Json::as_str)
+            .unwrap_or_default();
+
+        let primitives = gltf_mesh
+            .get("primitives")
+            .and_then(Json::as_array)
+            .ok_or_else(invalid)?;
+
+        for primitive in primitives {
+            let mode = optional_usize(primitive, "mode").ok_or_else(invalid)?;
+
+            if mode.unwrap_or(TRIANGLES) != TRIANGLES {
+                continue;
+            }
+
+            let attributes = primitive.get("attributes").ok_or_else(invalid)?;
+            let attribute = |name: &str| optional_usize(attributes, name).ok_or_else(invalid);
+
+            let Some(positions) = attribute("POSITION")? else {
+                continue;
+            };
+
+            let first = mesh.positions.len() as u32;
+
+            let (positions, _) = self.read_accessor(positions, &["VEC3"], false)?;
+
+            mesh.positions
+                .extend(positions.chunks_exact(3).map(|position| {
+                    let position = transform
+                        * Vec4::new(
+                            position[0] as f32,
+                            position[1] as f32,
+                            position[2] as f32,
+                            1.0,
+                        );
+                    flip_z(position.xyz())
+                }));
+
+            let count = mesh.positions.len() - first as usize;
+
+            match attribute("NORMAL")? {
+                Some(normals) => {
+                    let (normals, _) = self.read_accessor(normals, &["VEC3"], false)?;
+
+                    if normals.len() != count * 3 {
+                        return Err(invalid());
+                    }
+
+                    self.normals.extend(normals.chunks_exact(3).map(|normal| {
+                        let normal = normal_transform
+                            * Vec4::new(normal[0] as f32, normal[1] as f32, normal[2] as f32, 0.0);
+                        let normal = normal.xyz();
+
+                        match normal.magnitude_squared() > 0.0 {
+                            true => flip_z(normal.normalize()),
+                            false => Vec3::ZERO,
+                        }
+                    }));
+                }
+                None => self.has_normals = false,
+            }
+
+            match attribute("TEXCOORD_0")? {
+                Some(uvs) => {
+                    let (uvs, _) = self.read_accessor(uvs, &["VEC2"], true)?;
+
+                    if uvs.len() != count * 2 {
+                        return Err(invalid());
+                    }
+
+                    self.has_uvs = true;
+                    self.uvs.extend(
+                        uvs.chunks_exact(2)
+                            .map(|uv| Vec2::new(uv[0] as f32, uv[1] as f32)),
+                    );
+                }
+                None => self.uvs.extend(std::iter::repeat_n(Vec2::ZERO, count)),
+            }
+
+            match attribute("COLOR_0")? {
+                Some(colors) => {
+                    let (colors, components) =
+                        self.read_accessor(colors, &["VEC3", "VEC4"], true)?;
+
+                    if colors.len() != count * components {
+                        return Err(invalid());
+                    }
+
+                    // Colors without alpha are opaque
+                    self.has_colors = true;
+                    self.colors
+                        .extend(colors.chunks_exact(components).map(|color| {
+                            linear_color(
+                                [0, 1, 2, 3].map(|i| color.get(i).copied().unwrap_or(1.0) as f32),
+                            )
+                        }));
+                }
+                None => self
+                    .colors
+                    .extend(std::iter::repeat_n(RGBAColor::WHITE, count)),
+            }
+
+            let indices: Vec<u32> =
+                match optional_usize(primitive, "indices").ok_or_else(invalid)? {
+                    Some(accessor) => {
+                        let (indices, _) = self.read_accessor(accessor, &["SCALAR"], false)?;
+
+                        // Triangles can only use the vertices of their own primitive
+                        if indices.iter().any(|&i| !(0.0..count as f64).contains(&i)) {
+                            return Err(GltfError::InvalidAccessor { accessor });
+                        }
+
+                        indices.into_iter().map(|i| i as u32).collect()
+                    }
+                    None => (0..count as u32).collect(),
+                };
+
+            let start = mesh.indices.len();
+
+            // Flipping z winds the triangles the other way, so two of their
+            // corners are swapped back unless the transform flipped them too
+            mesh.indices.extend(indices.chunks_exact(3).map(|triangle| {
+                let [a, b, c] = [triangle[0], triangle[1], triangle[2]].map(|i| first + i);
+
+                match mirrored {
+                    true => [a, b, c],
+                    false => [a, c, b],
+                }
+            }));
+
+            mesh.submeshes.push(Submesh {
+                name: name.to_string(),
+                triangles: start..mesh.indices.len(),
+            });
+
+            let material = match optional_usize(primitive, "material").ok_or_else(invalid)? {
+                Some(material) if material < self.material_count => material,
+                Some(_) => return Err(invalid()),
+                None => *self.default_material.get_or_insert_with(|| {
+                    mesh.materials.push(Material::default());
+                    mesh.materials.len() - 1
+                }),
+            };
+
+            if let Some(material_indices) = mesh.material_indices.as_mut() {
+                material_indices.resize(mesh.indices.len(), material);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Reads every component of accessor `index` as `f64`, giving them along
+    /// with the number of components in each element
+    ///
+    /// The accessor must have one of the element `types`. Integers are scaled
+    /// into `0..=1` (or `-1..=1` when signed) if the accessor is normalized,
+    /// or always when `normalize` is set.
+    fn read_accessor(
+        &self,
+        index: usize,
+        types: &[&str],
+        normalize: bool,
+    ) -> Result<(Vec<f64>, usize), GltfError> {
+        let invalid = || GltfError::InvalidAccessor { accessor: index };
+
+        let accessor = list(self.document, "accessors")
+            .get(index)
+            .ok_or_else(invalid)?;
+
+        let components = match accessor.get("type").and_then(Json::as_str) {
+            Some(kind) if types.contains(&kind) => match kind {
+                "SCALAR" => 1,
+                "VEC2" => 2,
+                "VEC3" => 3,
+                _ => 4,
+            },
+            _ => return Err(invalid()),
+        };
+
+        let component_type = accessor
+            .get("componentType")
+            .and_then(Json::as_usize)
+            .ok_or_else(invalid)?;
+
+        let count = accessor
+            .get("count")
+            .and_then(Json::as_usize)
+            .ok_or_else(invalid)?;
+
+        let offset = optional_usize(accessor, "byteOffset")
+            .ok_or_else(invalid)?
+            .unwrap_or(0);
+
+        let mut values = match optional_usize(accessor, "bufferView").ok_or_else(invalid)? {
+            Some(view) => self
+                .read_view(view, offset, count, components, component_type)
+                .ok_or_else(invalid)?,
+            // Accessors without a buffer view are zeros, apart from any values
+            // replaced by a sparse accessor
+            None if count <= ZERO_ACCESSOR_LIMIT && component_size(component_type).is_some() => {
+                vec![0.0; count * components]
+            }
+            None => return Err(invalid()),
+        };
+
+        if let Some(sparse) = accessor.get("sparse") {
+            self.read_sparse(sparse, &mut values, components, component_type)
+                .ok_or_else(invalid)?;
+        }
+
+        let normalized = accessor.get("normalized").and_then(Json::as_bool);
+
+        if normalize || normalized == Some(true) {
+            for value in &mut values {
+                *value = normalize_component(*value, component_type);
+            }
+        }
+
+        Ok((values, components))
+    }
+
+    /// Reads `count` elements of `components` components each from buffer view
+    /// `index`, starting `offset` bytes into it
+    ///
+    /// Elements are packed tightly unless the view gives a stride. Gives `None`
+    /// if the view doesn't exist or the elements don't fit in it.
+    fn read_view(
+        &self,
+        index: usize,
+        offset: usize,
+        count: usize,
+        components: usize,
+        component_type: usize,
+    ) -> Option<Vec<f64>> {
+        let view = list(self.document, "bufferViews").get(index)?;
+
+        let buffer = self.buffers.get(view.get("buffer")?.as_usize()?)?;
+        let start = optional_usize(view, "byteOffset")?.unwrap_or(0);
+        let length = view.get("byteLength")?.as_usize()?;
+        let bytes = buffer.get(start..start.checked_add(length)?)?;
+
+        let size = component_size(component_type)?;
+        let element_size = size * components;
+
+        // Overlapping elements would let a tiny view claim any number of them
+        let stride = optional_usize(view, "byteStride")?.unwrap_or(element_size);
+        if stride < element_size {
+            return None;
+        }
+
+        if let Some(last) = count.checked_sub(1) {
+            let end = last
+                .checked_mul(stride)?
+                .checked_add(offset)?
+                .checked_add(element_size)?;
+
+            if end > bytes.len() {
+                return None;
+            }
+        }
+
+        let mut values = Vec::with_capacity(count * components);
+
+        for element in 0..count {
+            let start = offset + element * stride;
+
+            values.extend(
+                (0..components).map(|i| read_component(&bytes[start + i * size..], component_type)),
+            );
+        }
+
+        Some(values)
+    }
+
+    /// Replaces the elements of `values` listed by a sparse accessor
+    fn read_sparse(
+        &self,
+        sparse: &Json,
+        values: &mut [f64],
+        components: usize,
+        component_type: usize,
+    ) -> Option<()> {
+        let count = sparse.get("count")?.as_usize()?;
+        let (indices, replacements) = (sparse.get("indices")?, sparse.get("values")?);
+
+        let index_type = indices.get("componentType")?.as_usize()?;
+        if !matches!(index_type, UNSIGNED_BYTE | UNSIGNED_SHORT | UNSIGNED_INT) {
+            return None;
+        }
+
+        let indices = self.read_view(
+            indices.get("bufferView")?.as_usize()?,
+            optional_usize(indices, "byteOffset")?.unwrap_or(0),
+            count,
+            1,
+            index_type,
+        )?;
+
+        let replacements = self.read_view(
+            replacements.get("bufferView")?.as_usize()?,
+            optional_usize(replacements, "byteOffset")?.unwrap_or(0),
+            count,
+            components,
+            component_type,
+        )?;
+
+        for (&index, replacement) in indices.iter().zip(replacements.chunks_exact(components)) {
+            let start = index as usize * components;
+
+            values
+                .get_mut(start..start + components)?
+                .copy_from_slice(replacement);
+        }
+
+        Some(())
+    }
+}
+
+/// Moves the gathered vertex attributes into `mesh`, keeping only those which
+/// the file actually gave
+fn finish_mesh(mesh: &mut Mesh, reader: MeshReader) {
+    if reader.has_normals {
+        mesh.normals = Some(reader.normals);
+    } else {
+        mesh.compute_normals();
+    }
+
+    if reader.has_uvs {
+        mesh.uvs = Some(reader.uvs);
+    }
+
+    if reader.has_colors {
+        mesh.colors = Some(reader.colors);
+    }
+}
+
+/// Gets the contents of a glTF buffer
+fn read_buffer(
+    index: usize,
+    buffer: &Json,
+    blob: Option<&[u8]>,
+    folder: Option<&Path>,
+) -> Result<Vec<u8>, GltfError> {
+    let invalid = GltfError::InvalidBuffer { buffer: index };
+
+    match buffer.get("uri").map(Json::as_str) {
+        // Buffers without a URI are the binary chunk of a GLB file
+        None => blob.map(<[u8]>::to_vec).ok_or(invalid),
+        Some(Some(uri)) if uri.starts_with("data:") => decode_data_uri(uri).ok_or(invalid),
+        Some(Some(uri)) => match folder {
+            Some(folder) => Ok(std::fs::read(folder.join(decode_uri_path(uri)))?),
+            None => Err(GltfError::ExternalBuffer { buffer: index }),
+        },
+        Some(None) => Err(invalid),
+    }
+}
+
+/// Converts a glTF material, which only keeps its base color and texture
+fn read_material(
+    document: &Json,
+    index: usize,
+    material: &Json,
+    folder: Option<&Path>,
+) -> Result<Material, GltfError> {
+    let invalid = || GltfError::InvalidMaterial { material: index };
+
+    let pbr = material.get("pbrMetallicRoughness");
+    let base_color =
+        numbers(pbr.and_then(|pbr| pbr.get("baseColorFactor")), [1.0; 4]).ok_or_else(invalid)?;
+
+    let image = match pbr.and_then(|pbr| pbr.get("baseColorTexture")) {
+        Some(info) => {
+            let texture = info
+                .get("index")
+                .and_then(Json::as_usize)
+                .and_then(|texture| list(document, "textures").get(texture))
+                .ok_or_else(invalid)?;
+
+            // Textures without a source come from an extension, such as one
+            // for compressed images
+            match optional_usize(texture, "source").ok_or_else(invalid)? {
+                Some(image) => Some(list(document, "images").get(image).ok_or_else(invalid)?),
+                None => None,
+            }
+        }
+        None => None,
+    };
+
+    let diffuse_texture = image
+        .and_then(|image| image.get("uri"))
+        .and_then(Json::as_str)
+        .filter(|uri| !uri.starts_with("data:"))
+        .map(|uri| {
+            let path = decode_uri_path(uri);
+
+            match folder {
+                Some(folder) => folder.join(path),
+                None => path,
+            }
+        });
+
+    Ok(Material {
+        name: material
+            .get("name")
+            .and_then(Json::as_str)
+            .unwrap_or_default()
+            .to_string(),
+        diffuse: linear_color(base_color),
+        diffuse_texture,
+    })
+}
+
+/// Gets the transform of a node, given either as a matrix or as a translation,
+/// rotation and scale
+fn node_transform(node: &Json) -> Option<Mat4> {
+    if let Some(matrix) = node.get("matrix") {
+        // glTF gives matrices column by column
+        return numbers(Some(matrix), [0.0; 16]).map(Mat4::from_cols_array);
+    }
+
+    let [tx, ty, tz] = numbers(node.get("translation"), [0.0; 3])?;
+    let [x, y, z, w] = numbers(node.get("rotation"), [0.0, 0.0, 0.0, 1.0])?;
+    let [sx, sy, sz] = numbers(node.get("scale"), [1.0; 3])?;
+
+    Some(Mat4::translate(tx, ty, tz) * Mat4::from(Quat::new(x, y, z, w)) * Mat4::scale(sx, sy, sz))
+}
+
+/// Gets the array named `key` of an object, which glTF leaves out when empty
+fn list<'a>(object: &'a Json, key: &str) -> &'a [Json] {
+    object.get(key).and_then(Json::as_array).unwrap_or_default()
+}
+
+/// Gets an optional whole number property, giving `None` if it holds anything
+/// else
+fn optional_usize(object: &Json, key: &str) -> Option<Option<usize>> {
+    match object.get(key) {
+        Some(value) => value.as_usize().map(Some),
+        None => Some(None),
+    }
+}
+
+/// Reads a list of indices, such as the children of a node, where a missing
+/// list is empty
+fn indices(value: Option<&Json>) -> Option<Vec<usize>> {
+    match value {
+        Some(value) => value.as_array()?.iter().map(Json::as_usize).collect(),
+        None => Some(Vec::new()),
+    }
+}
+
+/// Reads a list of exactly `N` numbers, or `default` when it is missing
+fn numbers<const N: usize>(value: Option<&Json>, default: [f32; N]) -> Option<[f32; N]> {
+    let Some(value) = value else {
+        return Some(default);
+    };
+
+    let values: &[Json; N] = value.as_array()?.try_into().ok()?;
+    let mut numbers = default;
+
+    for (number, value) in numbers.iter_mut().zip(values) {
+        *number = value.as_f64()? as f32;
+    }
+
+    Some(numbers)
+}
+
+/// Gets the size in bytes of a glTF component type
+fn component_size(component_type: usize) -> Option<usize> {
+    match component_type {
+        BYTE | UNSIGNED_BYTE => Some(1),
+        SHORT | UNSIGNED_SHORT => Some(2),
+        UNSIGNED_INT | FLOAT => Some(4),
+        _ => None,
+    }
+}
+
+/// Reads the little endian component at the start of `bytes`
+fn read_component(bytes: &[u8], component_type: usize) -> f64 {
+    match component_type {
+        BYTE => bytes[0] as i8 as f64,
+        UNSIGNED_BYTE => bytes[0] as f64,
+        SHORT => i16::from_le_bytes([bytes[0], bytes[1]]) as f64,
+        UNSIGNED_SHORT => u16::from_le_bytes([bytes[0], bytes[1]]) as f64,
+        UNSIGNED_INT => u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as f64,
+        _ => f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as f64,
+    }
+}
+
+/// Scales a normalized integer component into `0..=1`, or `-1..=1` for the
+/// signed types
+fn normalize_component(value: f64, component_type: usize) -> f64 {
+    match component_type {
+        BYTE => (value / i8::MAX as f64).max(-1.0),
+        UNSIGNED_BYTE => value / u8::MAX as f64,
+        SHORT => (value / i16::MAX as f64).max(-1.0),
+        UNSIGNED_SHORT => value / u16::MAX as f64,
+        UNSIGNED_INT => value / u32::MAX as f64,
+        _ => value,
+    }
+}
+
+/// Converts a glTF position or normal to the left handed coordinates of the
+/// crate
+fn flip_z(vector: Vec3) -> Vec3 {
+    Vec3::new(vector.x, vector.y, -vector.z)
+}
+
+/// Converts a color in linear light, as glTF stores them, to sRGB
+fn linear_color([r, g, b, a]: [f32; 4]) -> RGBAColor {
+    RGBAColor::from_rgba(
+        linear_to_srgb(r),
+        linear_to_srgb(g),
+        linear_to_srgb(b),
+        (a.clamp(0.0, 1.0) * 255.0).round() as u8,
+    )
+}
+
+/// Turns a relative URI into a path, decoding escapes such as `%20`
+fn decode_uri_path(uri: &str) -> PathBuf {
+    let bytes = uri.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let escaped = bytes
+            .get(i + 1..i + 3)
+            .filter(|_| bytes[i] == b'%')
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+
+        match escaped {
+            Some(byte) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            None => {
+                decoded.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+
+    PathBuf::from(String::from_utf8_lossy(&decoded).into_owned())
+}
+
+/// Decodes the contents of a base64 `data:` URI
+fn decode_data_uri(uri: &str) -> Option<Vec<u8>> {
+    let (header, data) = uri.split_once(',')?;
+
+    if !header.ends_with(";base64") {
+        return None;
+    }
+
+    let mut decoded = Vec::with_capacity(data.len() / 4 * 3);
+    let mut bits = 0u32;
+    let mut bit_count = 0;
+
+    for byte in data.bytes().take_while(|&byte| byte != b'=') {
+        let value = match byte {
+            b'A'..=b'Z' => byte - b'A',
+            b'a'..=b'z' => byte - b'a' + 26,
+            b'0'..=b'9' => byte - b'0' + 52,
+            b'+' | b'-' => 62,
+            b'/' | b'_' => 63,
+            _ => return None,
+        };
+
+        bits = bits << 6 | value as u32;
+        bit_count += 6;
+
+        if bit_count >= 8 {
+            bit_count -= 8;
+            decoded.push((bits >> bit_count) as u8);
+        }
+    }
+
+    Some(decoded)
+}
@@ -0,0 +1,79 @@
+use crate::{rgba, Canvas, Color};
+
+/// Standard weights for how much the red, green and blue channels of a color
+/// each add to its brightness (luma)
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum LumaWeights {
+    /// The weights of Rec. 601 (`0.299`, `0.587`, `0.114`), used by standard
+    /// definition video and JPEG
+    Rec601,
+    /// The weights of Rec. 709 (`0.2126`, `0.7152`, `0.0722`), which match the
+    /// primaries of sRGB
+    #[default]
+    Rec709,
+}
+
+impl LumaWeights {
+    /// Gets the weights of the red, green and blue channels, which add up to `1`
+    pub fn weights(&self) -> [f32; 3] {
+        match self {
+            LumaWeights::Rec601 => [0.299, 0.587, 0.114],
+            LumaWeights::Rec709 => [0.2126, 0.7152, 0.0722],
+        }
+    }
+
+    /// Gets the brightness of `color` as a weighted sum of its channels
+    pub fn luma<C: Color>(&self, color: C) -> u8 {
+        let [red, green, blue] = self.weights();
+
+        (color.red() as f32 * red + color.green() as f32 * green + color.blue() as f32 * blue)
+            .round()
+            .min(255.0) as u8
+    }
+}
+
+impl Canvas {
+    /// Replaces every pixel on the canvas with the gray of the same brightness,
+    /// keeping its alpha
+    ///
+    /// ```
+    /// use farba::{Canvas, Color, LumaWeights, RGBAColor};
+    ///
+    /// let mut canvas = Canvas::new(1, 1);
+    /// canvas.fill(RGBAColor::GREEN);
+    ///
+    /// // Green is far brighter than an even average of the channels suggests
+    /// let rec601 = canvas.to_grayscale(LumaWeights::Rec601);
+    /// let rec709 = canvas.to_grayscale(LumaWeights::Rec709);
+    ///
+    /// assert_eq!(rec601.get_pixel(0, 0).red(), 150);
+    /// assert_eq!(rec709.get_pixel(0, 0).red(), 182);
+    /// assert_eq!(rec709.get_pixel(0, 0).blue(), 182);
+    ///
+    /// canvas.grayscale(LumaWeights::Rec709);
+    /// assert_eq!(canvas, rec709);
+    /// ```
+    pub fn grayscale(&mut self, weights: LumaWeights) {
+        for y in 0..self.get_height() as i32 {
+            for x in 0..self.get_width() as i32 {
+                let pixel = self.straight_pixel(x, y);
+                let luma = weights.luma(pixel);
+
+                *self.get_pixel_mut(x, y) =
+                    self.stored_color(rgba!(luma, luma, luma, pixel.alpha()));
+            }
+        }
+    }
+
+    /// Creates a grayscale copy of the canvas like [`Canvas::grayscale`],
+    /// leaving the canvas itself untouched
+    pub fn to_grayscale(&self, weights: LumaWeights) -> Canvas {
+        let mut copy = Canvas::new(self.get_width(), self.get_height());
+        copy.set_premultiplied(self.is_premultiplied());
+        copy.get_pixels_mut().copy_from_slice(self.get_pixels());
+
+        copy.grayscale(weights);
+
+        copy
+    }
+}
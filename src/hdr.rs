@@ -0,0 +1,191 @@
+use crate::{linear_to_srgb, rgba, srgb_to_linear, Canvas, Color};
+
+/// A way of squeezing the unbounded light of a [`CanvasF32`] into the `[0, 1]`
+/// range a [`Canvas`] can store
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Tonemap {
+    /// Cuts off everything brighter than `1.0`, which blows out bright areas
+    /// but leaves everything else untouched
+    #[default]
+    Clamp,
+    /// Maps `x` onto `x / (1 + x)`, which never quite reaches white and
+    /// darkens midtones
+    Reinhard,
+    /// A fit of the ACES filmic curve, with more contrast than
+    /// [`Tonemap::Reinhard`] and highlights which roll off into white
+    Aces,
+}
+
+impl Tonemap {
+    /// Maps a single channel of linear light onto `[0, 1]`
+    pub fn apply(&self, value: f32) -> f32 {
+        let value = value.max(0.0);
+
+        match self {
+            Tonemap::Clamp => value.min(1.0),
+            Tonemap::Reinhard => value / (1.0 + value),
+            Tonemap::Aces => {
+                let mapped =
+                    (value * (2.51 * value + 0.03)) / (value * (2.43 * value + 0.59) + 0.14);
+
+                mapped.clamp(0.0, 1.0)
+            }
+        }
+    }
+}
+
+/// A canvas storing every pixel as four `f32` channels of linear light, which
+/// are free to go above `1.0`
+///
+/// Light from many sources can be added together without clipping, and is only
+/// brought back into range when the result is turned into a [`Canvas`] with
+/// [`CanvasF32::to_canvas`]. Alpha is straight and stays in `[0, 1]`.
+///
+/// ```
+/// use farba::{CanvasF32, Color, Tonemap};
+///
+/// let mut hdr = CanvasF32::new(2, 1);
+/// hdr.fill([0.0, 0.0, 0.0, 1.0]);
+///
+/// // Three lights land on the first pixel, adding up to more than white
+/// for _ in 0..3 {
+///     hdr.add_pixel(0, 0, [0.8, 0.8, 0.8, 0.0]);
+/// }
+/// hdr.add_pixel(1, 0, [0.2, 0.2, 0.2, 0.0]);
+///
+/// assert_eq!(hdr.get_pixel(0, 0)[0], 2.4);
+///
+/// let clamped = hdr.to_canvas(Tonemap::Clamp, 1.0);
+/// let mapped = hdr.to_canvas(Tonemap::Reinhard, 1.0);
+///
+/// assert_eq!(clamped.get_pixel(0, 0).red(), 255);
+/// assert!(mapped.get_pixel(0, 0).red() < 255);
+/// assert!(mapped.get_pixel(1, 0).red() < mapped.get_pixel(0, 0).red());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CanvasF32 {
+    pixels: Vec<[f32; 4]>,
+    width: usize,
+    height: usize,
+}
+
+impl CanvasF32 {
+    /// Creates a new canvas with the specified width and height, where every
+    /// pixel is transparent black
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            pixels: vec![[0.0; 4]; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Creates a canvas holding the same image as `canvas`, with its sRGB
+    /// colors converted to linear light
+    pub fn from_canvas(canvas: &Canvas) -> Self {
+        let mut hdr = Self::new(canvas.get_width(), canvas.get_height());
+
+        for y in 0..hdr.height {
+            for x in 0..hdr.width {
+                let pixel = canvas.straight_pixel(x as i32, y as i32);
+
+                hdr.pixels[y * hdr.width + x] = [
+                    srgb_to_linear(pixel.red()),
+                    srgb_to_linear(pixel.green()),
+                    srgb_to_linear(pixel.blue()),
+                    pixel.alpha() as f32 / 255.0,
+                ];
+            }
+        }
+
+        hdr
+    }
+
+    pub fn get_width(&self) -> usize {
+        self.width
+    }
+
+    pub fn get_height(&self) -> usize {
+        self.height
+    }
+
+    /// Gets the underlying pixel buffer as `[red, green, blue, alpha]` per pixel
+    pub fn get_pixels(&self) -> &[[f32; 4]] {
+        self.pixels.as_slice()
+    }
+
+    /// Gets the underlying pixel buffer mutably
+    pub fn get_pixels_mut(&mut self) -> &mut [[f32; 4]] {
+        self.pixels.as_mut_slice()
+    }
+
+    pub fn in_bounds(&self, x: i32, y: i32) -> bool {
+        x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height
+    }
+
+    /// Gets the pixel at `(x, y)`
+    ///
+    /// Panics if the position is outside of the canvas
+    pub fn get_pixel(&self, x: i32, y: i32) -> &[f32; 4] {
+        if !self.in_bounds(x, y) {
+            panic!("Pixel ({x}, {y}) is outside of the canvas")
+        }
+
+        &self.pixels[y as usize * self.width + x as usize]
+    }
+
+    /// Replaces the pixel at `(x, y)`, doing nothing if it is outside of the canvas
+    pub fn set_pixel(&mut self, x: i32, y: i32, color: [f32; 4]) {
+        if self.in_bounds(x, y) {
+            self.pixels[y as usize * self.width + x as usize] = color;
+        }
+    }
+
+    /// Adds `color` onto the pixel at `(x, y)` channel by channel, keeping the
+    /// alpha within `[0, 1]`, or does nothing if it is outside of the canvas
+    pub fn add_pixel(&mut self, x: i32, y: i32, color: [f32; 4]) {
+        if !self.in_bounds(x, y) {
+            return;
+        }
+
+        let pixel = &mut self.pixels[y as usize * self.width + x as usize];
+
+        for channel in 0..3 {
+            pixel[channel] += color[channel];
+        }
+        pixel[3] = (pixel[3] + color[3]).clamp(0.0, 1.0);
+    }
+
+    /// Sets every pixel on the canvas to `color`
+    pub fn fill(&mut self, color: [f32; 4]) {
+        self.pixels.fill(color);
+    }
+
+    /// Multiplies the color channels of every pixel by `factor`
+    pub fn scale(&mut self, factor: f32) {
+        for pixel in self.pixels.iter_mut() {
+            for channel in &mut pixel[..3] {
+                *channel *= factor;
+            }
+        }
+    }
+
+    /// Brings the canvas back into range with `tonemap` after multiplying its
+    /// light by `exposure`, and encodes the result as sRGB
+    pub fn to_canvas(&self, tonemap: Tonemap, exposure: f32) -> Canvas {
+        let mut canvas = Canvas::new(self.width, self.height);
+
+        for (pixel, light) in canvas.get_pixels_mut().iter_mut().zip(&self.pixels) {
+            let channel = |value: f32| linear_to_srgb(tonemap.apply(value * exposure));
+
+            *pixel = rgba!(
+                channel(light[0]),
+                channel(light[1]),
+                channel(light[2]),
+                (light[3].clamp(0.0, 1.0) * 255.0).round()
+            );
+        }
+
+        canvas
+    }
+}
@@ -0,0 +1,229 @@
+use crate::{
+    clipped_line_pixels, dither_pixels, normalize_rect, palette_spread, Canvas, Color, Dither,
+    Palette,
+};
+
+/// A canvas which stores every pixel as an index into a [`Palette`] of up to
+/// 256 colors, the way retro hardware and GIF images do
+///
+/// Drawing writes palette indices rather than colors, so there is no blending.
+/// Changing the palette recolors everything drawn so far, and
+/// [`IndexedCanvas::resolve`] turns the canvas into a true color [`Canvas`].
+///
+/// ```
+/// use farba::{IndexedCanvas, Palette, RGBAColor};
+///
+/// let palette = Palette::new(vec![RGBAColor::BLACK, RGBAColor::RED, RGBAColor::BLUE]);
+///
+/// let mut canvas = IndexedCanvas::new(10, 10, palette);
+/// canvas.rect(0, 0, 5, 10, 1);
+/// canvas.line(0, 9, 9, 9, 2);
+///
+/// assert_eq!(canvas.get_pixel(2, 2), 1);
+///
+/// let resolved = canvas.resolve();
+/// assert_eq!(*resolved.get_pixel(2, 2), RGBAColor::RED.into());
+/// assert_eq!(*resolved.get_pixel(7, 2), RGBAColor::BLACK.into());
+/// assert_eq!(*resolved.get_pixel(2, 9), RGBAColor::BLUE.into());
+/// ```
+#[derive(Debug, Clone)]
+pub struct IndexedCanvas {
+    indices: Vec<u8>,
+    width: usize,
+    height: usize,
+    palette: Palette,
+}
+
+impl IndexedCanvas {
+    /// Creates a new canvas with the specified width and height, where every
+    /// pixel is index `0` of `palette`
+    ///
+    /// Panics if the palette has more than 256 colors
+    pub fn new(width: usize, height: usize, palette: Palette) -> Self {
+        if palette.len() > 256 {
+            panic!("Indexed canvases can't use more than 256 colors")
+        }
+
+        Self {
+            indices: vec![0; width * height],
+            width,
+            height,
+            palette,
+        }
+    }
+
+    /// Creates an indexed canvas holding the image on `canvas`, with every pixel
+    /// mapped onto its nearest color in `palette` and the error spread around
+    /// according to `dither`
+    ///
+    /// Panics if the palette is empty or has more than 256 colors
+    pub fn from_canvas(canvas: &Canvas, palette: Palette, dither: Dither) -> Self {
+        let mut indexed = Self::new(canvas.get_width(), canvas.get_height(), palette);
+        let pixels = canvas.straight_pixels();
+
+        let spread = palette_spread(indexed.palette.len());
+
+        dither_pixels(&pixels, indexed.width, dither, spread, |index, target| {
+            let nearest = indexed.palette.nearest(target);
+            indexed.indices[index] = nearest as u8;
+
+            indexed.palette.get_colors()[nearest].pack()
+        });
+
+        indexed
+    }
+
+    pub fn get_width(&self) -> usize {
+        self.width
+    }
+
+    pub fn get_height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get_palette(&self) -> &Palette {
+        &self.palette
+    }
+
+    /// Replaces the palette, which recolors every pixel already drawn
+    ///
+    /// Panics if the palette has more than 256 colors
+    pub fn set_palette(&mut self, palette: Palette) {
+        if palette.len() > 256 {
+            panic!("Indexed canvases can't use more than 256 colors")
+        }
+
+        self.palette = palette;
+    }
+
+    /// Gets the palette index of every pixel, row by row
+    pub fn get_indices(&self) -> &[u8] {
+        self.indices.as_slice()
+    }
+
+    /// Gets the palette index of every pixel mutably
+    pub fn get_indices_mut(&mut self) -> &mut [u8] {
+        self.indices.as_mut_slice()
+    }
+
+    pub fn in_bounds(&self, x: i32, y: i32) -> bool {
+        x >= 0 && x < self.width as i32 && y >= 0 && y < self.height as i32
+    }
+
+    /// Gets the palette index of the pixel at `(x, y)`
+    ///
+    /// Panics if the position is outside of the canvas
+    pub fn get_pixel(&self, x: i32, y: i32) -> u8 {
+        if !self.in_bounds(x, y) {
+            panic!("Pixel ({x}, {y}) is outside of the canvas")
+        }
+
+        self.indices[y as usize * self.width + x as usize]
+    }
+
+    /// Sets the pixel at `(x, y)` to palette entry `index`, doing nothing if it
+    /// is outside of the canvas
+    pub fn set_pixel(&mut self, x: i32, y: i32, index: u8) {
+        if self.in_bounds(x, y) {
+            self.indices[y as usize * self.width + x as usize] = index;
+        }
+    }
+
+    /// Sets every pixel on the canvas to palette entry `index`
+    pub fn fill(&mut self, index: u8) {
+        self.indices.fill(index);
+    }
+
+    /// Fills a rectangle with palette entry `index`, following the same rules as
+    /// [`Canvas::rect`]
+    pub fn rect(&mut self, x: i32, y: i32, width: i32, height: i32, index: u8) {
+        let Some(nr) = normalize_rect(x, y, width, height, self.width as i32, self.height as i32)
+        else {
+            return;
+        };
+
+        for y in nr.y1..=nr.y2 {
+            let row = y as usize * self.width;
+
+            self.indices[row + nr.x1 as usize..=row + nr.x2 as usize].fill(index);
+        }
+    }
+
+    /// Draws a one pixel wide line between two points with palette entry `index`
+    pub fn line(&mut self, x1: i32, y1: i32, x2: i32, y2: i32, index: u8) {
+        let bounds = (0, 0, self.width as i32 - 1, self.height as i32 - 1);
+
+        clipped_line_pixels(x1, y1, x2, y2, bounds, |x, y| {
+            self.indices[y as usize * self.width + x as usize] = index;
+        });
+    }
+
+    /// Fills a circle with palette entry `index`, covering the same pixels as
+    /// [`Canvas::circle`]
+    pub fn circle(&mut self, center_x: i32, center_y: i32, radius: i32, index: u8) {
+        let Some(nr) = normalize_rect(
+            center_x - radius,
+            center_y - radius,
+            radius * 2,
+            radius * 2,
+            self.width as i32,
+            self.height as i32,
+        ) else {
+            return;
+        };
+
+        for y in nr.y1..=nr.y2 {
+            for x in nr.x1..=nr.x2 {
+                let dx = center_x - x;
+                let dy = center_y - y;
+
+                if dx * dx + dy * dy < radius * radius {
+                    self.indices[y as usize * self.width + x as usize] = index;
+                }
+            }
+        }
+    }
+
+    /// Creates a true color canvas with every index replaced by its palette
+    /// color
+    ///
+    /// Indices past the end of the palette become transparent black.
+    pub fn resolve(&self) -> Canvas {
+        let mut canvas = Canvas::new(self.width, self.height);
+        let colors = self.palette.get_colors();
+
+        for (pixel, &index) in canvas.get_pixels_mut().iter_mut().zip(&self.indices) {
+            *pixel = colors.get(index as usize).map_or(0, |color| color.into());
+        }
+
+        canvas
+    }
+}
+
+impl Canvas {
+    /// Reduces the canvas to at most `max_colors` colors, giving an indexed
+    /// canvas with a palette chosen by [`Palette::median_cut`] and the error
+    /// spread around according to `dither`
+    ///
+    /// `max_colors` is clamped between 1 and 256.
+    ///
+    /// ```
+    /// use farba::{Canvas, Color, Dither, RGBAColor};
+    ///
+    /// let mut canvas = Canvas::new(100, 100);
+    /// canvas.fill(RGBAColor::WHITE);
+    /// canvas.circle(50, 50, 30, RGBAColor::RED);
+    /// canvas.rect(0, 0, 10, 10, RGBAColor::BLUE);
+    ///
+    /// let indexed = canvas.to_indexed(256, Dither::None);
+    /// assert_eq!(indexed.get_palette().len(), 3);
+    ///
+    /// // Every color was in the palette, so nothing was lost
+    /// assert_eq!(indexed.resolve(), canvas);
+    /// ```
+    pub fn to_indexed(&self, max_colors: usize, dither: Dither) -> IndexedCanvas {
+        let palette = Palette::median_cut(self, max_colors.clamp(1, 256));
+
+        IndexedCanvas::from_canvas(self, palette, dither)
+    }
+}
@@ -0,0 +1,290 @@
+/// A parsed JSON value, as read from the text of a glTF file
+///
+/// Objects keep their members in the order they were written.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Json {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+/// How deeply arrays and objects can be nested, far beyond what any glTF file
+/// needs, which keeps a forged file from overflowing the stack
+const MAX_DEPTH: usize = 128;
+
+impl Json {
+    /// Parses a JSON document, giving the line of the first error, counting
+    /// from 1, if it isn't valid
+    pub(crate) fn parse(source: &[u8]) -> Result<Json, usize> {
+        let line = |position: usize| {
+            source[..position]
+                .iter()
+                .filter(|&&byte| byte == b'\n')
+                .count()
+                + 1
+        };
+
+        let text = std::str::from_utf8(source).map_err(|e| line(e.valid_up_to()))?;
+
+        // Byte order marks aren't part of JSON, but some exporters write them
+        let start = match text.starts_with('\u{FEFF}') {
+            true => '\u{FEFF}'.len_utf8(),
+            false => 0,
+        };
+
+        let mut parser = Parser {
+            text,
+            position: start,
+        };
+
+        let value = parser.value(0).ok_or_else(|| line(parser.position))?;
+
+        parser.skip_whitespace();
+
+        match parser.position == text.len() {
+            true => Ok(value),
+            false => Err(line(parser.position)),
+        }
+    }
+
+    /// Gets the member named `key` of an object
+    pub(crate) fn get(&self, key: &str) -> Option<&Json> {
+        match self {
+            Json::Object(members) => members
+                .iter()
+                .find(|(name, _)| name == key)
+                .map(|(_, value)| value),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn as_f64(&self) -> Option<f64> {
+        match self {
+            Json::Number(number) => Some(*number),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn as_bool(&self) -> Option<bool> {
+        match self {
+            Json::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn as_str(&self) -> Option<&str> {
+        match self {
+            Json::String(string) => Some(string),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn as_array(&self) -> Option<&[Json]> {
+        match self {
+            Json::Array(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    /// Gets a whole number which can be used to index or size something
+    pub(crate) fn as_usize(&self) -> Option<usize> {
+        let number = self.as_f64()?;
+
+        (number >= 0.0 && number.fract() == 0.0 && number <= u32::MAX as f64)
+            .then_some(number as usize)
+    }
+}
+
+/// Reads JSON values from the text one after another
+struct Parser<'a> {
+    text: &'a str,
+    position: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.text.as_bytes().get(self.position).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(b' ' | b'\t' | b'\n' | b'\r') = self.peek() {
+            self.position += 1;
+        }
+    }
+
+    /// Moves past `expected` if it comes next
+    fn eat(&mut self, expected: &str) -> bool {
+        let found = self.text[self.position..].starts_with(expected);
+
+        if found {
+            self.position += expected.len();
+        }
+
+        found
+    }
+
+    /// Reads the next value, which is nested in `depth` arrays or objects
+    fn value(&mut self, depth: usize) -> Option<Json> {
+        self.skip_whitespace();
+
+        match self.peek()? {
+            b'{' | b'[' if depth >= MAX_DEPTH => None,
+            b'{' => self.object(depth + 1),
+            b'[' => self.array(depth + 1),
+            b'"' => self.string().map(Json::String),
+            b'-' | b'0'..=b'9' => self.number(),
+            _ if self.eat("null") => Some(Json::Null),
+            _ if self.eat("true") => Some(Json::Bool(true)),
+            _ if self.eat("false") => Some(Json::Bool(false)),
+            _ => None,
+        }
+    }
+
+    fn object(&mut self, depth: usize) -> Option<Json> {
+        self.eat("{");
+        self.skip_whitespace();
+
+        let mut members = Vec::new();
+
+        if self.eat("}") {
+            return Some(Json::Object(members));
+        }
+
+        loop {
+            self.skip_whitespace();
+            let name = self.string()?;
+
+            self.skip_whitespace();
+            if !self.eat(":") {
+                return None;
+            }
+
+            members.push((name, self.value(depth)?));
+
+            self.skip_whitespace();
+            if self.eat("}") {
+                return Some(Json::Object(members));
+            }
+            if !self.eat(",") {
+                return None;
+            }
+        }
+    }
+
+    fn array(&mut self, depth: usize) -> Option<Json> {
+        self.eat("[");
+        self.skip_whitespace();
+
+        let mut values = Vec::new();
+
+        if self.eat("]") {
+            return Some(Json::Array(values));
+        }
+
+        loop {
+            values.push(self.value(depth)?);
+
+            self.skip_whitespace();
+            if self.eat("]") {
+                return Some(Json::Array(values));
+            }
+            if !self.eat(",") {
+                return None;
+            }
+        }
+    }
+
+    fn number(&mut self) -> Option<Json> {
+        let start = self.position;
+
+        while let Some(b'-' | b'+' | b'.' | b'e' | b'E' | b'0'..=b'9') = self.peek() {
+            self.position += 1;
+        }
+
+        self.text[start..self.position]
+            .parse()
+            .ok()
+            .map(Json::Number)
+    }
+
+    fn string(&mut self) -> Option<String> {
+        if !self.eat("\"") {
+            return None;
+        }
+
+        let mut string = String::new();
+
+        loop {
+            // Copy everything up to the next quote or escape as it is
+            let rest = &self.text[self.position..];
+            let end = rest.find(['"', '\\'])?;
+
+            if rest[..end].contains(|c: char| c < ' ') {
+                return None;
+            }
+
+            string.push_str(&rest[..end]);
+            self.position += end;
+
+            if self.eat("\"") {
+                return Some(string);
+            }
+
+            self.eat("\\");
+
+            let escaped = match self.peek()? {
+                b'"' => '"',
+                b'\\' => '\\',
+                b'/' => '/',
+                b'b' => '\u{8}',
+                b'f' => '\u{C}',
+                b'n' => '\n',
+                b'r' => '\r',
+                b't' => '\t',
+                b'u' => {
+                    self.position += 1;
+                    let unit = self.hex_unit()?;
+
+                    // Characters outside of the basic plane are written as a
+                    // pair of surrogates
+                    let code = if (0xD800..0xDC00).contains(&unit) {
+                        if !self.eat("\\u") {
+                            return None;
+                        }
+
+                        let low = self.hex_unit()?;
+                        if !(0xDC00..0xE000).contains(&low) {
+                            return None;
+                        }
+
+                        0x10000 + ((unit - 0xD800) << 10) + (low - 0xDC00)
+                    } else {
+                        unit
+                    };
+
+                    string.push(char::from_u32(code)?);
+                    continue;
+                }
+                _ => return None,
+            };
+
+            string.push(escaped);
+            self.position += 1;
+        }
+    }
+
+    /// Reads the four hex digits of a `\u` escape
+    fn hex_unit(&mut self) -> Option<u32> {
+        let digits = self.text.get(self.position..self.position + 4)?;
+
+        if !digits.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+            return None;
+        }
+
+        self.position += 4;
+        u32::from_str_radix(digits, 16).ok()
+    }
+}
@@ -1,9 +1,92 @@
+pub use antialias::*;
+pub use blit::*;
 pub use canvas::*;
 pub use color::*;
+pub use color_matrix::*;
+pub use colormap::*;
+pub use composite::*;
+pub use depth::*;
+pub use dither::*;
+pub use double::*;
+pub use draw_list::*;
+#[cfg(feature = "fixed")]
+pub use fixed::*;
+pub use font::*;
+pub use geometry::*;
+#[cfg(feature = "gltf")]
+pub use gltf::GltfError;
+pub use grayscale::*;
+pub use hdr::*;
+pub use indexed::*;
+pub use mask::*;
+pub use material::*;
 pub use math::*;
+pub use mesh::*;
+pub use noise::*;
 pub use normal::*;
+pub use obj::*;
+pub use oklab::*;
+pub use paint::*;
+pub use palette::*;
+pub use path::*;
+pub use pixel_format::*;
+pub use raster::*;
+#[cfg(feature = "gif")]
+pub use recorder::*;
+pub use sdf::*;
+pub use sprite::*;
+pub use stl::*;
+pub use stroke::*;
+pub use text::*;
+#[cfg(feature = "ttf")]
+pub use ttf::*;
 
+mod antialias;
+mod blit;
 mod canvas;
 mod color;
+mod color_matrix;
+mod colormap;
+mod composite;
+mod curve;
+mod depth;
+mod dither;
+mod double;
+mod draw_list;
+#[cfg(feature = "fixed")]
+mod fixed;
+mod font;
+mod geometry;
+#[cfg(feature = "gltf")]
+mod gltf;
+mod grayscale;
+mod hdr;
+mod indexed;
+#[cfg(feature = "gltf")]
+mod json;
+mod mask;
+mod material;
 mod math;
+mod mesh;
+mod mesh_shapes;
+mod named_colors;
+mod noise;
 mod normal;
+mod obj;
+mod oklab;
+mod paint;
+mod palette;
+mod path;
+mod pixel_format;
+mod raster;
+#[cfg(feature = "gif")]
+mod recorder;
+mod sdf;
+mod sprite;
+mod stl;
+mod stroke;
+mod supersample;
+mod text;
+mod transform;
+#[cfg(feature = "ttf")]
+mod ttf;
@@ -0,0 +1,346 @@
+use crate::{
+    clipped_line_pixels, contour_spans, normalize_rect, rgba, Canvas, Color, FillRule, Paint, Path,
+    Vec2,
+};
+
+/// A single channel canvas holding an intensity from `0` to `255` for every
+/// pixel, for clip masks, cached glyphs and soft shadows
+///
+/// Shapes are drawn into the mask with an intensity instead of a color,
+/// replacing whatever was there. Anything the full [`Canvas`] can draw can be
+/// drawn into the mask through [`MaskCanvas::draw`]. Masks can then be used as a
+/// stencil to fill a canvas with [`Canvas::fill_mask`], or to clip drawing with
+/// [`Canvas::set_clip_mask`].
+///
+/// ```
+/// use farba::{Canvas, MaskCanvas, RGBAColor};
+///
+/// // A soft shadow under a square
+/// let mut shadow = MaskCanvas::new(40, 40);
+/// shadow.rect(10, 10, 20, 20, 160);
+/// shadow.blur(4);
+///
+/// let mut canvas = Canvas::new(100, 100);
+/// canvas.fill(RGBAColor::WHITE);
+/// canvas.set_alpha_blending(true);
+/// canvas.fill_mask(&shadow, 34, 34, RGBAColor::BLACK);
+/// canvas.rect(40, 40, 20, 20, RGBAColor::RED);
+///
+/// assert_eq!(*canvas.get_pixel(50, 50), RGBAColor::RED.into());
+/// assert!(*canvas.get_pixel(62, 62) != RGBAColor::WHITE.into());
+/// assert_eq!(*canvas.get_pixel(5, 5), RGBAColor::WHITE.into());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct MaskCanvas {
+    values: Vec<u8>,
+    width: usize,
+    height: usize,
+}
+
+impl MaskCanvas {
+    /// Creates a new mask with the specified width and height, where every
+    /// pixel has an intensity of `0`
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            values: vec![0; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Creates a mask from the alpha channel of `canvas`
+    pub fn from_alpha(canvas: &Canvas) -> Self {
+        Self {
+            values: canvas
+                .get_pixels()
+                .iter()
+                .map(|pixel| pixel.alpha())
+                .collect(),
+            width: canvas.get_width(),
+            height: canvas.get_height(),
+        }
+    }
+
+    pub fn get_width(&self) -> usize {
+        self.width
+    }
+
+    pub fn get_height(&self) -> usize {
+        self.height
+    }
+
+    /// Gets the intensity of every pixel, row by row
+    pub fn get_pixels(&self) -> &[u8] {
+        self.values.as_slice()
+    }
+
+    /// Gets the intensity of every pixel mutably
+    pub fn get_pixels_mut(&mut self) -> &mut [u8] {
+        self.values.as_mut_slice()
+    }
+
+    pub fn in_bounds(&self, x: i32, y: i32) -> bool {
+        x >= 0 && x < self.width as i32 && y >= 0 && y < self.height as i32
+    }
+
+    /// Gets the intensity of the pixel at `(x, y)`
+    ///
+    /// Panics if the position is outside of the mask
+    pub fn get_pixel(&self, x: i32, y: i32) -> u8 {
+        if !self.in_bounds(x, y) {
+            panic!("Pixel ({x}, {y}) is outside of the mask")
+        }
+
+        self.values[y as usize * self.width + x as usize]
+    }
+
+    /// Sets the intensity of the pixel at `(x, y)`, doing nothing if it is
+    /// outside of the mask
+    pub fn set_pixel(&mut self, x: i32, y: i32, intensity: u8) {
+        if self.in_bounds(x, y) {
+            self.values[y as usize * self.width + x as usize] = intensity;
+        }
+    }
+
+    /// Sets every pixel of the mask to `intensity`
+    pub fn fill(&mut self, intensity: u8) {
+        self.values.fill(intensity);
+    }
+
+    /// Fills a rectangle with `intensity`, following the same rules as
+    /// [`Canvas::rect`]
+    pub fn rect(&mut self, x: i32, y: i32, width: i32, height: i32, intensity: u8) {
+        let Some(nr) = normalize_rect(x, y, width, height, self.width as i32, self.height as i32)
+        else {
+            return;
+        };
+
+        for y in nr.y1..=nr.y2 {
+            let row = y as usize * self.width;
+
+            self.values[row + nr.x1 as usize..=row + nr.x2 as usize].fill(intensity);
+        }
+    }
+
+    /// Draws a one pixel wide line between two points with `intensity`
+    pub fn line(&mut self, x1: i32, y1: i32, x2: i32, y2: i32, intensity: u8) {
+        let bounds = (0, 0, self.width as i32 - 1, self.height as i32 - 1);
+
+        clipped_line_pixels(x1, y1, x2, y2, bounds, |x, y| {
+            self.values[y as usize * self.width + x as usize] = intensity;
+        });
+    }
+
+    /// Fills a circle with `intensity`, covering the same pixels as
+    /// [`Canvas::circle`]
+    pub fn circle(&mut self, center_x: i32, center_y: i32, radius: i32, intensity: u8) {
+        let Some(nr) = normalize_rect(
+            center_x - radius,
+            center_y - radius,
+            radius * 2,
+            radius * 2,
+            self.width as i32,
+            self.height as i32,
+        ) else {
+            return;
+        };
+
+        for y in nr.y1..=nr.y2 {
+            for x in nr.x1..=nr.x2 {
+                let dx = center_x - x;
+                let dy = center_y - y;
+
+                if dx * dx + dy * dy < radius * radius {
+                    self.values[y as usize * self.width + x as usize] = intensity;
+                }
+            }
+        }
+    }
+
+    /// Fills the inside of `path` with `intensity`, following the same rules as
+    /// [`Canvas::fill_path`]
+    pub fn fill_path(&mut self, path: &Path, rule: FillRule, intensity: u8) {
+        let contours: Vec<Vec<Vec2>> = path
+            .flatten()
+            .into_iter()
+            .map(|(points, _)| points)
+            .collect();
+
+        contour_spans(&contours, rule, self.width, self.height, |y, x1, x2| {
+            let row = y as usize * self.width;
+
+            self.values[row + x1 as usize..=row + x2 as usize].fill(intensity);
+        });
+    }
+
+    /// Draws into the mask with anything a [`Canvas`] can draw, using the alpha
+    /// of what is drawn as the intensity
+    ///
+    /// `draw` is given a canvas holding the mask as the alpha of white pixels,
+    /// with alpha blending turned on, and the alpha it ends up with is copied
+    /// back into the mask.
+    ///
+    /// ```
+    /// use farba::{AntiAliasing, MaskCanvas, RGBAColor, Vec2};
+    ///
+    /// let mut mask = MaskCanvas::new(100, 100);
+    ///
+    /// mask.draw(|canvas| {
+    ///     let center = Vec2::new(50.0, 50.0);
+    ///     canvas.circle_antialiased(center, 20.0, RGBAColor::WHITE, AntiAliasing::default());
+    ///     canvas.text(10, 80, "mask", 1, RGBAColor::WHITE);
+    /// });
+    ///
+    /// assert_eq!(mask.get_pixel(50, 50), 255);
+    /// assert_eq!(mask.get_pixel(5, 5), 0);
+    /// ```
+    pub fn draw<F: FnOnce(&mut Canvas)>(&mut self, draw: F) {
+        let mut canvas = Canvas::new(self.width, self.height);
+
+        for (pixel, &value) in canvas.get_pixels_mut().iter_mut().zip(&self.values) {
+            *pixel = rgba!(255, 255, 255, value);
+        }
+
+        canvas.set_alpha_blending(true);
+        draw(&mut canvas);
+
+        for (value, pixel) in self.values.iter_mut().zip(canvas.get_pixels()) {
+            *value = pixel.alpha();
+        }
+    }
+
+    /// Flips the intensity of every pixel, so that `0` becomes `255` and the
+    /// other way around
+    pub fn invert(&mut self) {
+        for value in self.values.iter_mut() {
+            *value = 255 - *value;
+        }
+    }
+
+    /// Softens the mask by averaging every pixel with its neighbors up to
+    /// `radius` pixels away
+    ///
+    /// Three passes of a box blur are made in each direction, which looks much
+    /// like a gaussian blur. Pixels past the edges count as `0`.
+    pub fn blur(&mut self, radius: usize) {
+        if radius == 0 || self.width == 0 || self.height == 0 {
+            return;
+        }
+
+        let mut scratch = vec![0u8; self.values.len()];
+
+        for _ in 0..3 {
+            box_blur(
+                &self.values,
+                &mut scratch,
+                self.width,
+                self.height,
+                radius,
+                true,
+            );
+            box_blur(
+                &scratch,
+                &mut self.values,
+                self.width,
+                self.height,
+                radius,
+                false,
+            );
+        }
+    }
+}
+
+/// Averages every pixel of `source` with the `radius` pixels on either side of
+/// it along rows (`horizontal`) or columns, writing the result into `target`
+fn box_blur(
+    source: &[u8],
+    target: &mut [u8],
+    width: usize,
+    height: usize,
+    radius: usize,
+    horizontal: bool,
+) {
+    let (lines, length) = if horizontal {
+        (height, width)
+    } else {
+        (width, height)
+    };
+
+    let index = |line: usize, i: usize| {
+        if horizontal {
+            line * width + i
+        } else {
+            i * width + line
+        }
+    };
+
+    let window = (2 * radius + 1) as u32;
+
+    for line in 0..lines {
+        // Running sum over the window around `i`
+        let mut sum: u32 = (0..=radius.min(length - 1))
+            .map(|i| source[index(line, i)] as u32)
+            .sum();
+
+        for i in 0..length {
+            target[index(line, i)] = ((sum + window / 2) / window) as u8;
+
+            if i + radius + 1 < length {
+                sum += source[index(line, i + radius + 1)] as u32;
+            }
+            if i >= radius {
+                sum -= source[index(line, i - radius)] as u32;
+            }
+        }
+    }
+}
+
+impl From<&Canvas> for MaskCanvas {
+    fn from(canvas: &Canvas) -> Self {
+        MaskCanvas::from_alpha(canvas)
+    }
+}
+
+impl From<&MaskCanvas> for MaskCanvas {
+    fn from(mask: &MaskCanvas) -> Self {
+        mask.clone()
+    }
+}
+
+impl Canvas {
+    /// Fills the canvas with `paint` through `mask`, placed with its top left
+    /// corner on `(x, y)`, using the intensity of each mask pixel as the
+    /// coverage of that pixel
+    ///
+    /// Like blits, the mask is placed in the coordinates of the canvas without
+    /// going through the current transform, while the paint is sampled at the
+    /// center of each pixel of the canvas.
+    pub fn fill_mask<P: Paint>(&mut self, mask: &MaskCanvas, x: i32, y: i32, paint: P) {
+        let (left, top, right, bottom) = self.clip_bounds();
+
+        for mask_y in 0..mask.get_height() as i32 {
+            let canvas_y = y + mask_y;
+
+            if canvas_y < top || canvas_y > bottom {
+                continue;
+            }
+
+            for mask_x in 0..mask.get_width() as i32 {
+                let canvas_x = x + mask_x;
+
+                if canvas_x < left || canvas_x > right {
+                    continue;
+                }
+
+                let intensity = mask.get_pixel(mask_x, mask_y);
+
+                if intensity == 0 {
+                    continue;
+                }
+
+                let color = paint.color_at(canvas_x as f32 + 0.5, canvas_y as f32 + 0.5);
+                self.cover_pixel(canvas_x, canvas_y, color, intensity as f32 / 255.0);
+            }
+        }
+    }
+}
@@ -0,0 +1,210 @@
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+};
+
+use crate::RGBAColor;
+
+/// Errors that can occur while loading [`Material`]s
+#[derive(Debug)]
+pub enum MaterialError {
+    /// A line of an MTL file could not be understood, counting from 1
+    InvalidMtl {
+        line: usize,
+    },
+    Io(std::io::Error),
+}
+
+impl fmt::Display for MaterialError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MaterialError::InvalidMtl { line } => write!(f, "invalid MTL file on line {line}"),
+            MaterialError::Io(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl std::error::Error for MaterialError {}
+
+impl From<std::io::Error> for MaterialError {
+    fn from(value: std::io::Error) -> Self {
+        Self::Io(value)
+    }
+}
+
+/// How the surface of part of a [`Mesh`](crate::Mesh) looks
+///
+/// [`Mesh::load_obj`](crate::Mesh::load_obj) loads the materials an OBJ file
+/// uses from the MTL files it names.
+#[derive(Debug, Clone)]
+pub struct Material {
+    pub name: String,
+    /// The color of the surface, which is multiplied with the texture if there
+    /// is one
+    pub diffuse: RGBAColor,
+    /// The path of the image giving the color across the surface
+    pub diffuse_texture: Option<PathBuf>,
+}
+
+impl Default for Material {
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            diffuse: RGBAColor::WHITE,
+            diffuse_texture: None,
+        }
+    }
+}
+
+impl Material {
+    /// Loads the materials of the Wavefront MTL file at `path`
+    ///
+    /// Texture paths are made relative to the folder holding the file.
+    pub fn load_mtl<P: AsRef<Path>>(path: P) -> Result<Vec<Material>, MaterialError> {
+        let path = path.as_ref();
+        let mut materials = Self::parse_mtl(&std::fs::read_to_string(path)?)?;
+
+        if let Some(folder) = path.parent() {
+            for texture in materials
+                .iter_mut()
+                .filter_map(|m| m.diffuse_texture.as_mut())
+            {
+                *texture = folder.join(&texture);
+            }
+        }
+
+        Ok(materials)
+    }
+
+    /// Reads the materials of a Wavefront MTL file
+    ///
+    /// Only the diffuse color (`Kd`), opacity (`d` or `Tr`) and diffuse texture
+    /// (`map_Kd`) are kept, while every other statement is skipped. Diffuse
+    /// colors given as a spectral curve or in CIE XYZ are skipped too, leaving
+    /// the material white.
+    ///
+    /// ```
+    /// use farba::Material;
+    ///
+    /// let materials = Material::parse_mtl(
+    ///     "# Two materials
+    ///      newmtl red
+    ///      Kd 1.0 0.0 0.0
+    ///
+    ///      newmtl crate
+    ///      Kd spectral crate.rfl
+    ///      Kd xyz 0.9 1.0 1.1
+    ///      d 0.5
+    ///      map_Kd -bm 1.0 textures/crate.png
+    ///
+    ///      newmtl poster
+    ///      map_Kd -o 0.5 0.5 -clamp on my textures/poster.png",
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(materials[0].name, "red");
+    /// assert_eq!(materials[0].diffuse.red, 255);
+    /// assert_eq!(materials[1].diffuse.green, 255);
+    /// assert_eq!(materials[1].diffuse.alpha, 128);
+    /// assert_eq!(materials[1].diffuse_texture.as_deref().unwrap().to_str(), Some("textures/crate.png"));
+    /// assert_eq!(materials[2].diffuse_texture.as_deref().unwrap().to_str(), Some("my textures/poster.png"));
+    /// ```
+    pub fn parse_mtl(source: &str) -> Result<Vec<Material>, MaterialError> {
+        let mut materials: Vec<Material> = Vec::new();
+
+        for (index, line) in source.lines().enumerate() {
+            let invalid = || MaterialError::InvalidMtl { line: index + 1 };
+
+            let line = line.split('#').next().unwrap_or_default().trim();
+            let mut words = line.split_whitespace();
+
+            let Some(keyword) = words.next() else {
+                continue;
+            };
+
+            if keyword == "newmtl" {
+                materials.push(Material {
+                    name: words.collect::<Vec<_>>().join(" "),
+                    ..Default::default()
+                });
+                continue;
+            }
+
+            let Some(material) = materials.last_mut() else {
+                continue;
+            };
+
+            let channel = |value: f32| (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+
+            match keyword {
+                // Spectral curves and CIE XYZ colors can't be turned into a
+                // diffuse color here, so they are skipped
+                "Kd" if matches!(words.clone().next(), Some("spectral" | "xyz")) => {}
+                "Kd" => {
+                    let values = words
+                        .map(|word| word.parse::<f32>().map_err(|_| invalid()))
+                        .collect::<Result<Vec<_>, _>>()?;
+
+                    let [r, g, b] = match values[..] {
+                        [r, g, b] => [r, g, b],
+                        [gray] => [gray; 3],
+                        _ => return Err(invalid()),
+                    };
+
+                    material.diffuse.red = channel(r);
+                    material.diffuse.green = channel(g);
+                    material.diffuse.blue = channel(b);
+                }
+                "d" | "Tr" => {
+                    let value: f32 = words
+                        .next()
+                        .and_then(|word| word.parse().ok())
+                        .ok_or_else(invalid)?;
+
+                    // `Tr` is transparency rather than opacity
+                    let opacity = if keyword == "d" { value } else { 1.0 - value };
+                    material.diffuse.alpha = channel(opacity);
+                }
+                "map_Kd" => {
+                    let path = texture_path(words).ok_or_else(invalid)?;
+                    material.diffuse_texture = Some(PathBuf::from(path));
+                }
+                _ => {}
+            }
+        }
+
+        Ok(materials)
+    }
+}
+
+/// Reads the path of a texture statement, which comes after any options
+///
+/// Options start with a `-` and are followed by their values, which are up to
+/// three numbers for the offset, scale and turbulence options, two numbers for
+/// `-mm` and a single value for the rest. Whatever is left is the path, which
+/// may contain spaces.
+fn texture_path<'a>(words: impl Iterator<Item = &'a str>) -> Option<String> {
+    let mut words = words.peekable();
+
+    while let Some(option) = words.next_if(|word| word.starts_with('-')) {
+        let (min, max) = match option {
+            "-o" | "-s" | "-t" => (1, 3),
+            "-mm" => (2, 2),
+            _ => (1, 1),
+        };
+
+        for count in 0..max {
+            let is_number = words.peek()?.parse::<f32>().is_ok();
+
+            // The vector options take as many numbers as they are given
+            if count >= min && !is_number {
+                break;
+            }
+
+            words.next();
+        }
+    }
+
+    let path = words.collect::<Vec<_>>().join(" ");
+    (!path.is_empty()).then_some(path)
+}
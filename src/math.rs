@@ -1,6 +1,6 @@
 /* ===== Vec3 ===== */
 
-#[derive(Debug, Default, Clone, Copy)]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub struct Vec3 {
     pub x: f32,
     pub y: f32,
@@ -27,7 +27,7 @@ impl Vec3 {
     }
 
     pub fn magnitude_squared(&self) -> f32 {
-        self.x * self.x + self.y + self.y * self.z * self.z
+        self.x * self.x + self.y * self.y + self.z * self.z
     }
 
     pub fn magnitude(&self) -> f32 {
@@ -39,6 +39,119 @@ impl Vec3 {
 
         Vec3::new(self.x / mag, self.y / mag, self.z / mag)
     }
+
+    /// Reflects the vector off a surface facing `normal`, like light bouncing
+    /// off a mirror
+    ///
+    /// `normal` should be normalized.
+    ///
+    /// ```
+    /// use farba::Vec3;
+    ///
+    /// // A ray heading down and to the right bounces off the floor
+    /// let bounced = Vec3::new(1.0, -1.0, 0.0).reflect(&Vec3::new(0.0, 1.0, 0.0));
+    /// assert_eq!(bounced, Vec3::new(1.0, 1.0, 0.0));
+    ///
+    /// let along = Vec3::new(3.0, 4.0, 0.0).project_onto(&Vec3::new(2.0, 0.0, 0.0));
+    /// assert_eq!(along, Vec3::new(3.0, 0.0, 0.0));
+    ///
+    /// let angle = Vec3::new(1.0, 0.0, 0.0).angle_between(&Vec3::new(0.0, 0.0, 5.0));
+    /// assert!((angle - std::f32::consts::FRAC_PI_2).abs() < 1e-6);
+    /// ```
+    pub fn reflect(&self, normal: &Self) -> Vec3 {
+        *self - 2.0 * self.dot(normal) * *normal
+    }
+
+    /// Gets the part of the vector which points along `other`
+    ///
+    /// Projecting onto a zero vector gives a zero vector.
+    pub fn project_onto(&self, other: &Self) -> Vec3 {
+        let length_squared = other.magnitude_squared();
+
+        if length_squared == 0.0 {
+            return Vec3::ZERO;
+        }
+
+        (self.dot(other) / length_squared) * *other
+    }
+
+    /// Gets the angle between the two vectors in radians, from `0` when they
+    /// point the same way to `PI` when they point in opposite directions
+    ///
+    /// The angle to a zero vector is `0`.
+    pub fn angle_between(&self, other: &Self) -> f32 {
+        let lengths = (self.magnitude_squared() * other.magnitude_squared()).sqrt();
+
+        if lengths == 0.0 {
+            return 0.0;
+        }
+
+        (self.dot(other) / lengths).clamp(-1.0, 1.0).acos()
+    }
+
+    /// Gets the distance between the two vectors treated as points
+    pub fn distance(&self, other: &Self) -> f32 {
+        (*self - *other).magnitude()
+    }
+
+    /// Takes the smaller of each component of the two vectors
+    pub fn min(&self, other: &Self) -> Vec3 {
+        Vec3::new(
+            self.x.min(other.x),
+            self.y.min(other.y),
+            self.z.min(other.z),
+        )
+    }
+
+    /// Takes the larger of each component of the two vectors
+    pub fn max(&self, other: &Self) -> Vec3 {
+        Vec3::new(
+            self.x.max(other.x),
+            self.y.max(other.y),
+            self.z.max(other.z),
+        )
+    }
+
+    /// Takes the absolute value of each component
+    pub fn abs(&self) -> Vec3 {
+        Vec3::new(self.x.abs(), self.y.abs(), self.z.abs())
+    }
+
+    /// Clamps each component between the matching components of `min` and
+    /// `max`
+    ///
+    /// ```
+    /// use farba::Vec3;
+    ///
+    /// let clamped = Vec3::new(-2.0, 0.5, 7.0).clamp(&Vec3::ZERO, &Vec3::new(1.0, 1.0, 1.0));
+    /// assert_eq!(clamped, Vec3::new(0.0, 0.5, 1.0));
+    ///
+    /// // Vectors can be used like numbers and indexed like arrays
+    /// let v = -(clamped / 0.5);
+    /// assert_eq!((v[1], v[2]), (-1.0, -2.0));
+    /// assert_eq!(clamped.to_string(), "(0, 0.5, 1)");
+    /// ```
+    pub fn clamp(&self, min: &Self, max: &Self) -> Vec3 {
+        self.max(min).min(max)
+    }
+
+    /// Checks whether every component is within `epsilon` of the matching
+    /// component of `other`, for comparing results which have picked up
+    /// rounding errors along the way
+    ///
+    /// ```
+    /// use farba::{Mat3, Vec3};
+    ///
+    /// let rotated = Mat3::rotate_z(std::f32::consts::FRAC_PI_2) * Vec3::new(1.0, 0.0, 0.0);
+    ///
+    /// assert_ne!(rotated, Vec3::new(0.0, 1.0, 0.0));
+    /// assert!(rotated.approx_eq(&Vec3::new(0.0, 1.0, 0.0), 1e-6));
+    /// ```
+    pub fn approx_eq(&self, other: &Self, epsilon: f32) -> bool {
+        (self.x - other.x).abs() <= epsilon
+            && (self.y - other.y).abs() <= epsilon
+            && (self.z - other.z).abs() <= epsilon
+    }
 }
 
 impl std::ops::Add<Vec3> for Vec3 {
@@ -105,9 +218,90 @@ impl std::ops::MulAssign<Vec3> for Vec3 {
     }
 }
 
+impl std::ops::Mul<Vec3> for Vec3 {
+    type Output = Vec3;
+
+    fn mul(self, rhs: Vec3) -> Self::Output {
+        Vec3::new(self.x * rhs.x, self.y * rhs.y, self.z * rhs.z)
+    }
+}
+
+impl std::ops::Div<f32> for Vec3 {
+    type Output = Vec3;
+
+    fn div(self, rhs: f32) -> Self::Output {
+        Vec3::new(self.x / rhs, self.y / rhs, self.z / rhs)
+    }
+}
+
+impl std::ops::DivAssign<f32> for Vec3 {
+    fn div_assign(&mut self, rhs: f32) {
+        self.x /= rhs;
+        self.y /= rhs;
+        self.z /= rhs;
+    }
+}
+
+impl std::ops::Div<Vec3> for Vec3 {
+    type Output = Vec3;
+
+    fn div(self, rhs: Vec3) -> Self::Output {
+        Vec3::new(self.x / rhs.x, self.y / rhs.y, self.z / rhs.z)
+    }
+}
+
+impl std::ops::DivAssign<Vec3> for Vec3 {
+    fn div_assign(&mut self, rhs: Vec3) {
+        self.x /= rhs.x;
+        self.y /= rhs.y;
+        self.z /= rhs.z;
+    }
+}
+
+impl std::ops::Neg for Vec3 {
+    type Output = Vec3;
+
+    fn neg(self) -> Self::Output {
+        Vec3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl std::ops::Index<usize> for Vec3 {
+    type Output = f32;
+
+    /// Gets `x`, `y` or `z` for indices `0`, `1` and `2`
+    ///
+    /// Panics if the index is above `2`
+    fn index(&self, index: usize) -> &f32 {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            _ => panic!("Index {index} is out of range for Vec3"),
+        }
+    }
+}
+
+impl std::ops::IndexMut<usize> for Vec3 {
+    fn index_mut(&mut self, index: usize) -> &mut f32 {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            _ => panic!("Index {index} is out of range for Vec3"),
+        }
+    }
+}
+
+impl std::fmt::Display for Vec3 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
 /* ===== Vec2 ===== */
 
-#[derive(Debug, Default, Clone, Copy)]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub struct Vec2 {
     pub x: f32,
     pub y: f32,
@@ -116,7 +310,7 @@ pub struct Vec2 {
 impl Vec2 {
     pub const ZERO: Self = Self::new(0.0, 0.0);
 
-    const fn new(x: f32, y: f32) -> Self {
+    pub const fn new(x: f32, y: f32) -> Self {
         Self { x, y }
     }
 
@@ -124,9 +318,106 @@ impl Vec2 {
         self.x * other.x + self.y * other.y
     }
 
+    /// Gets the `z` of the cross product of the two vectors extended into 3D,
+    /// which is positive when `other` is clockwise from this vector on screen
+    pub fn cross(&self, other: Self) -> f32 {
+        self.x * other.y - self.y * other.x
+    }
+
+    #[allow(clippy::should_implement_trait)]
     pub fn add(self, rhs: Self) -> Self {
         Self::new(self.x + rhs.x, self.y + rhs.y)
     }
+
+    pub fn magnitude_squared(&self) -> f32 {
+        self.x * self.x + self.y * self.y
+    }
+
+    pub fn magnitude(&self) -> f32 {
+        self.magnitude_squared().sqrt()
+    }
+
+    pub fn normalize(&self) -> Vec2 {
+        let mag = self.magnitude();
+
+        Vec2::new(self.x / mag, self.y / mag)
+    }
+
+    /// Gets the vector turned a quarter turn, counterclockwise on screen where
+    /// `y` points down
+    ///
+    /// ```
+    /// use farba::Vec2;
+    ///
+    /// let right = Vec2::new(1.0, 0.0);
+    /// assert_eq!(right.perp(), Vec2::new(0.0, -1.0));
+    ///
+    /// let turned = right.rotate(std::f32::consts::FRAC_PI_2);
+    /// assert!(turned.distance(Vec2::new(0.0, 1.0)) < 1e-6);
+    ///
+    /// // Vectors can be used like numbers
+    /// let mut v = (Vec2::new(3.0, 4.0) - Vec2::new(1.0, 1.0)) * Vec2::new(2.0, 1.0);
+    /// v /= 2.0;
+    /// assert_eq!(v, Vec2::new(2.0, 1.5));
+    /// assert_eq!(Vec2::new(3.0, 4.0).magnitude(), 5.0);
+    /// ```
+    pub fn perp(&self) -> Vec2 {
+        Vec2::new(self.y, -self.x)
+    }
+
+    /// Rotates the vector by `angle` radians around the origin, clockwise on
+    /// screen where `y` points down, the same way as [`Mat3::rotate_2d`]
+    pub fn rotate(&self, angle: f32) -> Vec2 {
+        let (sin, cos) = angle.sin_cos();
+
+        Vec2::new(self.x * cos - self.y * sin, self.x * sin + self.y * cos)
+    }
+
+    /// Gets the distance between the two vectors treated as points
+    pub fn distance(&self, other: Self) -> f32 {
+        (*self - other).magnitude()
+    }
+
+    /// Gets the angle between the two vectors in radians, from `0` when they
+    /// point the same way to `PI` when they point in opposite directions
+    ///
+    /// The angle to a zero vector is `0`.
+    pub fn angle_between(&self, other: Self) -> f32 {
+        let lengths = (self.magnitude_squared() * other.magnitude_squared()).sqrt();
+
+        if lengths == 0.0 {
+            return 0.0;
+        }
+
+        (self.dot(other) / lengths).clamp(-1.0, 1.0).acos()
+    }
+
+    /// Takes the smaller of each component of the two vectors
+    pub fn min(&self, other: Self) -> Vec2 {
+        Vec2::new(self.x.min(other.x), self.y.min(other.y))
+    }
+
+    /// Takes the larger of each component of the two vectors
+    pub fn max(&self, other: Self) -> Vec2 {
+        Vec2::new(self.x.max(other.x), self.y.max(other.y))
+    }
+
+    /// Takes the absolute value of each component
+    pub fn abs(&self) -> Vec2 {
+        Vec2::new(self.x.abs(), self.y.abs())
+    }
+
+    /// Clamps each component between the matching components of `min` and
+    /// `max`
+    pub fn clamp(&self, min: Self, max: Self) -> Vec2 {
+        self.max(min).min(max)
+    }
+
+    /// Checks whether every component is within `epsilon` of the matching
+    /// component of `other`
+    pub fn approx_eq(&self, other: Self, epsilon: f32) -> bool {
+        (self.x - other.x).abs() <= epsilon && (self.y - other.y).abs() <= epsilon
+    }
 }
 
 impl std::ops::Add<Vec2> for Vec2 {
@@ -137,6 +428,13 @@ impl std::ops::Add<Vec2> for Vec2 {
     }
 }
 
+impl std::ops::AddAssign<Vec2> for Vec2 {
+    fn add_assign(&mut self, rhs: Vec2) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
 impl std::ops::Sub<Vec2> for Vec2 {
     type Output = Self;
 
@@ -145,6 +443,13 @@ impl std::ops::Sub<Vec2> for Vec2 {
     }
 }
 
+impl std::ops::SubAssign<Vec2> for Vec2 {
+    fn sub_assign(&mut self, rhs: Vec2) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
 impl std::ops::Mul<Vec2> for f32 {
     type Output = Vec2;
 
@@ -161,6 +466,109 @@ impl std::ops::Mul<f32> for Vec2 {
     }
 }
 
+impl std::ops::Mul<Vec2> for Vec2 {
+    type Output = Vec2;
+
+    fn mul(self, rhs: Vec2) -> Self::Output {
+        Vec2::new(self.x * rhs.x, self.y * rhs.y)
+    }
+}
+
+impl std::ops::MulAssign<f32> for Vec2 {
+    fn mul_assign(&mut self, rhs: f32) {
+        self.x *= rhs;
+        self.y *= rhs;
+    }
+}
+
+impl std::ops::MulAssign<Vec2> for Vec2 {
+    fn mul_assign(&mut self, rhs: Vec2) {
+        self.x *= rhs.x;
+        self.y *= rhs.y;
+    }
+}
+
+impl std::ops::Div<f32> for Vec2 {
+    type Output = Vec2;
+
+    fn div(self, rhs: f32) -> Self::Output {
+        Vec2::new(self.x / rhs, self.y / rhs)
+    }
+}
+
+impl std::ops::DivAssign<f32> for Vec2 {
+    fn div_assign(&mut self, rhs: f32) {
+        self.x /= rhs;
+        self.y /= rhs;
+    }
+}
+
+impl std::ops::Div<Vec2> for Vec2 {
+    type Output = Vec2;
+
+    fn div(self, rhs: Vec2) -> Self::Output {
+        Vec2::new(self.x / rhs.x, self.y / rhs.y)
+    }
+}
+
+impl std::ops::DivAssign<Vec2> for Vec2 {
+    fn div_assign(&mut self, rhs: Vec2) {
+        self.x /= rhs.x;
+        self.y /= rhs.y;
+    }
+}
+
+impl std::ops::Neg for Vec2 {
+    type Output = Vec2;
+
+    fn neg(self) -> Self::Output {
+        Vec2::new(-self.x, -self.y)
+    }
+}
+
+impl std::ops::Index<usize> for Vec2 {
+    type Output = f32;
+
+    /// Gets `x` or `y` for indices `0` and `1`
+    ///
+    /// Panics if the index is above `1`
+    fn index(&self, index: usize) -> &f32 {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            _ => panic!("Index {index} is out of range for Vec2"),
+        }
+    }
+}
+
+impl std::ops::IndexMut<usize> for Vec2 {
+    fn index_mut(&mut self, index: usize) -> &mut f32 {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            _ => panic!("Index {index} is out of range for Vec2"),
+        }
+    }
+}
+
+impl std::fmt::Display for Vec2 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl From<(f32, f32)> for Vec2 {
+    fn from((x, y): (f32, f32)) -> Self {
+        Vec2::new(x, y)
+    }
+}
+
+impl From<Vec2> for (f32, f32) {
+    fn from(vector: Vec2) -> Self {
+        (vector.x, vector.y)
+    }
+}
+
 /* ==== Mat3 ==== */
 
 /// Represents the 3x3 matrix with the following values:
@@ -168,7 +576,8 @@ impl std::ops::Mul<f32> for Vec2 {
 /// | a, b, c |
 /// | d, e, f |
 /// | g, h, i |
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(C)]
 pub struct Mat3 {
     pub a: f32,
     pub b: f32,
@@ -182,7 +591,26 @@ pub struct Mat3 {
 }
 
 impl Mat3 {
-    pub fn new(a: f32, b: f32, c: f32, d: f32, e: f32, f: f32, g: f32, h: f32, i: f32) -> Self {
+    /// The transform which leaves every point where it is
+    #[rustfmt::skip]
+    pub const IDENTITY: Mat3 = Mat3::new(
+        1.0, 0.0, 0.0,
+        0.0, 1.0, 0.0,
+        0.0, 0.0, 1.0,
+    );
+
+    #[allow(clippy::too_many_arguments)]
+    pub const fn new(
+        a: f32,
+        b: f32,
+        c: f32,
+        d: f32,
+        e: f32,
+        f: f32,
+        g: f32,
+        h: f32,
+        i: f32,
+    ) -> Self {
         Self {
             a,
             b,
@@ -196,6 +624,182 @@ impl Mat3 {
         }
     }
 
+    /// Creates a matrix from its three rows
+    ///
+    /// Like the other constructors which don't need any trigonometry, this can
+    /// be used to build matrices in constants and statics.
+    ///
+    /// ```
+    /// use farba::{Mat3, Vec2, Vec3};
+    ///
+    /// // Flips y to point up, with the origin at the bottom of a 480 pixel
+    /// // tall canvas
+    /// const FLIP_Y: Mat3 = Mat3::from_rows(
+    ///     Vec3::new(1.0, 0.0, 0.0),
+    ///     Vec3::new(0.0, -1.0, 480.0),
+    ///     Vec3::new(0.0, 0.0, 1.0),
+    /// );
+    ///
+    /// static CENTERED: Mat3 = Mat3::translate_2d(320.0, 240.0);
+    ///
+    /// let point = FLIP_Y.transform_point(Vec2::new(10.0, 0.0));
+    /// assert_eq!(point, Vec2::new(10.0, 480.0));
+    ///
+    /// let origin = CENTERED.transform_point(Vec2::ZERO);
+    /// assert_eq!(origin, Vec2::new(320.0, 240.0));
+    /// assert_eq!(Mat3::default(), Mat3::IDENTITY);
+    /// ```
+    #[rustfmt::skip]
+    pub const fn from_rows(first: Vec3, second: Vec3, third: Vec3) -> Mat3 {
+        Mat3::new(
+            first.x,  first.y,  first.z,
+            second.x, second.y, second.z,
+            third.x,  third.y,  third.z,
+        )
+    }
+
+    /// Creates a matrix from its entries listed row by row, the order used by
+    /// the fields of the matrix
+    #[rustfmt::skip]
+    pub const fn from_rows_array(m: [f32; 9]) -> Mat3 {
+        Mat3::new(
+            m[0], m[1], m[2],
+            m[3], m[4], m[5],
+            m[6], m[7], m[8],
+        )
+    }
+
+    /// Creates a matrix from its entries listed column by column, the order
+    /// used by libraries such as glam and nalgebra, and by OpenGL
+    ///
+    /// ```
+    /// use farba::Mat3;
+    ///
+    /// let matrix = Mat3::translate_2d(10.0, 20.0);
+    ///
+    /// // The translation is in the last column
+    /// let columns = matrix.to_cols_array();
+    /// assert_eq!(&columns[6..], &[10.0, 20.0, 1.0]);
+    /// assert_eq!(Mat3::from_cols_array(columns), matrix);
+    ///
+    /// // While row by row it is at the end of the first two rows
+    /// let rows: [f32; 9] = matrix.into();
+    /// assert_eq!((rows[2], rows[5]), (10.0, 20.0));
+    /// assert_eq!(matrix.as_slice(), &rows);
+    /// ```
+    #[rustfmt::skip]
+    pub const fn from_cols_array(m: [f32; 9]) -> Mat3 {
+        Mat3::new(
+            m[0], m[3], m[6],
+            m[1], m[4], m[7],
+            m[2], m[5], m[8],
+        )
+    }
+
+    /// Lists the entries of the matrix row by row
+    #[rustfmt::skip]
+    pub const fn to_rows_array(&self) -> [f32; 9] {
+        [
+            self.a, self.b, self.c,
+            self.d, self.e, self.f,
+            self.g, self.h, self.i,
+        ]
+    }
+
+    /// Lists the entries of the matrix column by column
+    #[rustfmt::skip]
+    pub const fn to_cols_array(&self) -> [f32; 9] {
+        [
+            self.a, self.d, self.g,
+            self.b, self.e, self.h,
+            self.c, self.f, self.i,
+        ]
+    }
+
+    /// Views the entries of the matrix row by row, without copying them
+    pub fn as_slice(&self) -> &[f32] {
+        // `Mat3` is `repr(C)` with nine `f32` fields, laid out like `[f32; 9]`
+        unsafe { std::slice::from_raw_parts(self as *const Mat3 as *const f32, 9) }
+    }
+
+    /// Checks whether every entry is within `epsilon` of the matching entry of
+    /// `other`
+    ///
+    /// ```
+    /// use farba::Mat3;
+    ///
+    /// let turn = Mat3::rotate_2d(0.1);
+    /// let mut spun = Mat3::IDENTITY;
+    ///
+    /// for _ in 0..10 {
+    ///     spun = spun * turn;
+    /// }
+    ///
+    /// assert!(spun.approx_eq(&Mat3::rotate_2d(1.0), 1e-5));
+    /// ```
+    pub fn approx_eq(&self, other: &Self, epsilon: f32) -> bool {
+        approx_eq_slices(self.as_slice(), other.as_slice(), epsilon)
+    }
+
+    /// Creates a 2D transform which moves points by `(x, y)`, treating the
+    /// matrix as an affine transform of points `(x, y, 1)`
+    #[rustfmt::skip]
+    pub const fn translate_2d(x: f32, y: f32) -> Mat3 {
+        Self {
+            a: 1.0, b: 0.0, c: x,
+            d: 0.0, e: 1.0, f: y,
+            g: 0.0, h: 0.0, i: 1.0,
+        }
+    }
+
+    /// Creates a 2D transform which stretches points by `x` horizontally and
+    /// `y` vertically away from the origin
+    #[rustfmt::skip]
+    pub const fn scale_2d(x: f32, y: f32) -> Mat3 {
+        Self {
+            a: x,   b: 0.0, c: 0.0,
+            d: 0.0, e: y,   f: 0.0,
+            g: 0.0, h: 0.0, i: 1.0,
+        }
+    }
+
+    /// Creates a 2D transform which rotates points by `angle` radians around
+    /// the origin, clockwise on screen where `y` points down
+    ///
+    /// This is the same matrix as [`Mat3::rotate_z`].
+    pub fn rotate_2d(angle: f32) -> Mat3 {
+        Self::rotate_z(angle)
+    }
+
+    /// Applies the matrix as a 2D affine transform to `point`, treating it as
+    /// `(x, y, 1)` so that it is moved by translations
+    ///
+    /// ```
+    /// use farba::{Mat3, Vec2};
+    ///
+    /// let transform = Mat3::translate_2d(10.0, 5.0) * Mat3::scale_2d(2.0, 3.0);
+    ///
+    /// let point = transform.transform_point(Vec2::new(1.0, 1.0));
+    /// assert_eq!((point.x, point.y), (12.0, 8.0));
+    ///
+    /// // Vectors are only scaled, not moved
+    /// let vector = transform.transform_vector(Vec2::new(1.0, 1.0));
+    /// assert_eq!((vector.x, vector.y), (2.0, 3.0));
+    /// ```
+    pub fn transform_point(&self, point: Vec2) -> Vec2 {
+        let result = *self * Vec3::new(point.x, point.y, 1.0);
+
+        Vec2::new(result.x, result.y)
+    }
+
+    /// Applies the matrix as a 2D affine transform to `vector`, treating it as
+    /// `(x, y, 0)` so that it is rotated and scaled but not moved
+    pub fn transform_vector(&self, vector: Vec2) -> Vec2 {
+        let result = *self * Vec3::new(vector.x, vector.y, 0.0);
+
+        Vec2::new(result.x, result.y)
+    }
+
     #[rustfmt::skip]
     pub fn rotate_x(angle: f32) -> Mat3 {
         Self {
@@ -222,6 +826,70 @@ impl Mat3 {
             g: 0.0,             h: 0.0,              i: 1.0,
         }
     }
+
+    /// Creates a rotation from Euler angles in radians, given as the rotation
+    /// around each axis
+    ///
+    /// The rotations are applied around the x axis first, then the y axis and
+    /// finally the z axis, all around the fixed axes of the world. This is the
+    /// same matrix as `rotate_z(z) * rotate_y(y) * rotate_x(x)`.
+    ///
+    /// ```
+    /// use farba::{Mat3, Vec3};
+    ///
+    /// let angles = Vec3::new(0.3, -0.8, 2.0);
+    /// let rotation = Mat3::from_euler(angles);
+    ///
+    /// assert_eq!(rotation, Mat3::rotate_z(2.0) * Mat3::rotate_y(-0.8) * Mat3::rotate_x(0.3));
+    /// assert!(rotation.to_euler().approx_eq(&angles, 1e-5));
+    /// ```
+    pub fn from_euler(angles: Vec3) -> Mat3 {
+        Self::rotate_z(angles.z) * Self::rotate_y(angles.y) * Self::rotate_x(angles.x)
+    }
+
+    /// Finds the Euler angles of a rotation matrix, in the order used by
+    /// [`Mat3::from_euler`]
+    ///
+    /// The angle around the y axis is kept between `-π/2` and `π/2`, while the
+    /// others are between `-π` and `π`. When the y angle is `±π/2` the x and z
+    /// rotations turn around the same axis, so the z angle is given as `0`.
+    pub fn to_euler(&self) -> Vec3 {
+        let y = (-self.g).clamp(-1.0, 1.0).asin();
+
+        // Gimbal lock, where `cos(y)` is zero
+        if self.g.abs() > 1.0 - 1e-6 {
+            return Vec3::new((-self.f).atan2(self.e), y, 0.0);
+        }
+
+        Vec3::new(self.h.atan2(self.i), y, self.d.atan2(self.a))
+    }
+}
+
+impl Default for Mat3 {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl From<[f32; 9]> for Mat3 {
+    /// Creates a matrix from its entries listed row by row
+    fn from(m: [f32; 9]) -> Self {
+        Mat3::from_rows_array(m)
+    }
+}
+
+impl From<Mat3> for [f32; 9] {
+    /// Lists the entries of the matrix row by row
+    fn from(matrix: Mat3) -> Self {
+        matrix.to_rows_array()
+    }
+}
+
+impl From<[[f32; 3]; 3]> for Mat3 {
+    /// Creates a matrix from its rows
+    fn from(m: [[f32; 3]; 3]) -> Self {
+        Mat3::from_rows_array(*m.as_flattened().first_chunk().unwrap())
+    }
 }
 
 impl std::ops::Mul<Vec3> for Mat3 {
@@ -252,6 +920,708 @@ impl std::ops::Mul<Mat3> for Mat3 {
     }
 }
 
+/* ===== Vec4 ===== */
+
+/// A vector in homogeneous coordinates, as transformed by [`Mat4`]
+///
+/// Points have a `w` of `1` so that they are moved by translations, while
+/// directions have a `w` of `0`.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Vec4 {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub w: f32,
+}
+
+impl Vec4 {
+    pub const ZERO: Self = Self::new(0.0, 0.0, 0.0, 0.0);
+
+    pub const fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
+        Self { x, y, z, w }
+    }
+
+    /// Creates a vector from the components of `vector` and `w`
+    pub const fn from_vec3(vector: Vec3, w: f32) -> Self {
+        Self::new(vector.x, vector.y, vector.z, w)
+    }
+
+    /// Drops the `w` component
+    pub const fn xyz(&self) -> Vec3 {
+        Vec3::new(self.x, self.y, self.z)
+    }
+
+    pub fn dot(&self, other: &Self) -> f32 {
+        self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
+    }
+
+    /// Divides `x`, `y` and `z` by `w`, turning a point in clip space into
+    /// normalized device coordinates
+    pub fn perspective_divide(&self) -> Vec3 {
+        Vec3::new(self.x / self.w, self.y / self.w, self.z / self.w)
+    }
+
+    /// Checks whether every component is within `epsilon` of the matching
+    /// component of `other`
+    pub fn approx_eq(&self, other: &Self, epsilon: f32) -> bool {
+        self.xyz().approx_eq(&other.xyz(), epsilon) && (self.w - other.w).abs() <= epsilon
+    }
+}
+
+impl std::ops::Add<Vec4> for Vec4 {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(
+            self.x + rhs.x,
+            self.y + rhs.y,
+            self.z + rhs.z,
+            self.w + rhs.w,
+        )
+    }
+}
+
+impl std::ops::Sub<Vec4> for Vec4 {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(
+            self.x - rhs.x,
+            self.y - rhs.y,
+            self.z - rhs.z,
+            self.w - rhs.w,
+        )
+    }
+}
+
+impl std::ops::Mul<Vec4> for f32 {
+    type Output = Vec4;
+
+    fn mul(self, rhs: Vec4) -> Self::Output {
+        Vec4::new(rhs.x * self, rhs.y * self, rhs.z * self, rhs.w * self)
+    }
+}
+
+impl std::ops::Mul<f32> for Vec4 {
+    type Output = Vec4;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        rhs * self
+    }
+}
+
+/* ==== Mat4 ==== */
+
+/// A 4x4 matrix for transforming 3D points in homogeneous coordinates, stored
+/// row by row so that `m[row][column]` is the entry at that row and column
+///
+/// Matrices transform column vectors, so `a * b` applies `b` first and then
+/// `a`.
+///
+/// ```
+/// use farba::{Mat4, Vec4};
+///
+/// let transform = Mat4::translate(10.0, 0.0, 0.0) * Mat4::scale(2.0, 2.0, 2.0);
+/// let point = transform * Vec4::new(1.0, 2.0, 3.0, 1.0);
+///
+/// assert_eq!(point, Vec4::new(12.0, 4.0, 6.0, 1.0));
+///
+/// // The inverse undoes the transform
+/// let inverse = transform.inverse().unwrap();
+/// assert_eq!(inverse * point, Vec4::new(1.0, 2.0, 3.0, 1.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Mat4 {
+    pub m: [[f32; 4]; 4],
+}
+
+impl Mat4 {
+    #[rustfmt::skip]
+    pub const IDENTITY: Mat4 = Mat4::new([
+        [1.0, 0.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0, 0.0],
+        [0.0, 0.0, 1.0, 0.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ]);
+
+    pub const fn new(m: [[f32; 4]; 4]) -> Self {
+        Self { m }
+    }
+
+    /// Creates a matrix from its entries listed row by row
+    pub fn from_rows_array(m: [f32; 16]) -> Mat4 {
+        Mat4::new(std::array::from_fn(|row| {
+            std::array::from_fn(|column| m[row * 4 + column])
+        }))
+    }
+
+    /// Creates a matrix from its entries listed column by column, the order
+    /// used by libraries such as glam and nalgebra, and by OpenGL
+    ///
+    /// ```
+    /// use farba::Mat4;
+    ///
+    /// let matrix = Mat4::translate(1.0, 2.0, 3.0);
+    ///
+    /// let columns = matrix.to_cols_array();
+    /// assert_eq!(&columns[12..], &[1.0, 2.0, 3.0, 1.0]);
+    /// assert_eq!(Mat4::from_cols_array(columns), matrix);
+    ///
+    /// let rows: [f32; 16] = matrix.into();
+    /// assert_eq!((rows[3], rows[7], rows[11]), (1.0, 2.0, 3.0));
+    /// assert_eq!(matrix.as_slice(), &rows);
+    /// ```
+    pub fn from_cols_array(m: [f32; 16]) -> Mat4 {
+        Mat4::new(std::array::from_fn(|row| {
+            std::array::from_fn(|column| m[column * 4 + row])
+        }))
+    }
+
+    /// Lists the entries of the matrix row by row
+    pub fn to_rows_array(&self) -> [f32; 16] {
+        *self.m.as_flattened().first_chunk().unwrap()
+    }
+
+    /// Lists the entries of the matrix column by column
+    pub fn to_cols_array(&self) -> [f32; 16] {
+        self.transpose().to_rows_array()
+    }
+
+    /// Views the entries of the matrix row by row, without copying them
+    pub fn as_slice(&self) -> &[f32] {
+        self.m.as_flattened()
+    }
+
+    /// Checks whether every entry is within `epsilon` of the matching entry of
+    /// `other`
+    pub fn approx_eq(&self, other: &Self, epsilon: f32) -> bool {
+        approx_eq_slices(self.as_slice(), other.as_slice(), epsilon)
+    }
+
+    /// Creates a matrix which moves points by `(x, y, z)`
+    pub const fn translate(x: f32, y: f32, z: f32) -> Mat4 {
+        let mut matrix = Self::IDENTITY;
+
+        matrix.m[0][3] = x;
+        matrix.m[1][3] = y;
+        matrix.m[2][3] = z;
+
+        matrix
+    }
+
+    /// Creates a matrix which scales points along each axis
+    pub const fn scale(x: f32, y: f32, z: f32) -> Mat4 {
+        let mut matrix = Self::IDENTITY;
+
+        matrix.m[0][0] = x;
+        matrix.m[1][1] = y;
+        matrix.m[2][2] = z;
+
+        matrix
+    }
+
+    pub fn rotate_x(angle: f32) -> Mat4 {
+        Mat3::rotate_x(angle).into()
+    }
+
+    pub fn rotate_y(angle: f32) -> Mat4 {
+        Mat3::rotate_y(angle).into()
+    }
+
+    pub fn rotate_z(angle: f32) -> Mat4 {
+        Mat3::rotate_z(angle).into()
+    }
+
+    /// Creates a view matrix for a camera at `eye` looking towards `target`,
+    /// which moves the world so that the camera sits at the origin looking down
+    /// the positive `z` axis, ready for [`Mat4::perspective`] or
+    /// [`Mat4::orthographic`]
+    ///
+    /// `up` is the direction which should point up on screen, and only needs to
+    /// be roughly upwards as long as it isn't parallel to the view direction.
+    ///
+    /// ```
+    /// use farba::{Mat4, Vec3, Vec4};
+    ///
+    /// let eye = Vec3::new(10.0, 0.0, 0.0);
+    /// let view = Mat4::look_at(eye, Vec3::ZERO, Vec3::new(0.0, 1.0, 0.0));
+    ///
+    /// // The target ends up straight ahead of the camera
+    /// let target = view * Vec4::new(0.0, 0.0, 0.0, 1.0);
+    /// assert!(target.x.abs() < 1e-6 && target.y.abs() < 1e-6);
+    /// assert!((target.z - 10.0).abs() < 1e-6);
+    /// ```
+    #[rustfmt::skip]
+    pub fn look_at(eye: Vec3, target: Vec3, up: Vec3) -> Mat4 {
+        let forward = (target - eye).normalize();
+        let right = up.cross(&forward).normalize();
+        let up = forward.cross(&right);
+
+        Mat4::new([
+            [right.x,   right.y,   right.z,   -right.dot(&eye)],
+            [up.x,      up.y,      up.z,      -up.dot(&eye)],
+            [forward.x, forward.y, forward.z, -forward.dot(&eye)],
+            [0.0,       0.0,       0.0,       1.0],
+        ])
+    }
+
+    /// Creates a perspective projection for a camera looking down the positive
+    /// `z` axis with `y` up, which maps the view volume between the `near` and
+    /// `far` planes into clip space
+    ///
+    /// `fov_y` is the vertical field of view in radians and `aspect` is the
+    /// width of the view divided by its height. After the perspective divide,
+    /// `x` and `y` run from `-1` to `1` across the view while `z` runs from `0`
+    /// at the near plane to `1` at the far plane, so that nearer surfaces have
+    /// smaller depths like [`DepthBuffer`](crate::DepthBuffer) expects.
+    ///
+    /// ```
+    /// use farba::{Mat4, Vec3};
+    ///
+    /// let projection = Mat4::perspective(90f32.to_radians(), 2.0, 1.0, 100.0);
+    ///
+    /// // A point straight ahead lands in the middle of a 200x100 canvas
+    /// let center = projection.project_point(Vec3::new(0.0, 0.0, 10.0), 200, 100).unwrap();
+    /// assert_eq!((center.x, center.y), (100.0, 50.0));
+    ///
+    /// // Points on the edge of the field of view land on the edge of the canvas
+    /// let top = projection.project_point(Vec3::new(0.0, 10.0, 10.0), 200, 100).unwrap();
+    /// assert!(top.y.abs() < 1e-4);
+    ///
+    /// // Points behind the camera can't be projected
+    /// assert!(projection.project_point(Vec3::new(0.0, 0.0, -1.0), 200, 100).is_none());
+    /// ```
+    #[rustfmt::skip]
+    pub fn perspective(fov_y: f32, aspect: f32, near: f32, far: f32) -> Mat4 {
+        let focal = 1.0 / (fov_y / 2.0).tan();
+        let depth = far / (far - near);
+
+        Mat4::new([
+            [focal / aspect, 0.0,   0.0,   0.0],
+            [0.0,            focal, 0.0,   0.0],
+            [0.0,            0.0,   depth, -near * depth],
+            [0.0,            0.0,   1.0,   0.0],
+        ])
+    }
+
+    /// Creates an orthographic projection, which maps the box between `left`
+    /// and `right`, `bottom` and `top`, and the `near` and `far` planes into
+    /// clip space without any perspective
+    ///
+    /// Parallel lines stay parallel and sizes don't shrink with distance, as
+    /// needed for CAD drawings and isometric scenes. The camera looks down the
+    /// positive `z` axis with `y` up, and depths run from `0` at the near plane
+    /// to `1` at the far plane, the same as [`Mat4::perspective`].
+    ///
+    /// ```
+    /// use farba::{Mat4, Vec3};
+    ///
+    /// let projection = Mat4::orthographic(-10.0, 10.0, -10.0, 10.0, 0.0, 100.0);
+    ///
+    /// // Distance doesn't change where a point lands, only its depth
+    /// let near = projection.project_point(Vec3::new(5.0, 5.0, 10.0), 100, 100).unwrap();
+    /// let far = projection.project_point(Vec3::new(5.0, 5.0, 90.0), 100, 100).unwrap();
+    ///
+    /// assert_eq!((near.x, near.y), (75.0, 25.0));
+    /// assert_eq!((far.x, far.y), (75.0, 25.0));
+    /// assert!(near.z < far.z);
+    /// ```
+    #[rustfmt::skip]
+    pub fn orthographic(left: f32, right: f32, bottom: f32, top: f32, near: f32, far: f32) -> Mat4 {
+        let width = right - left;
+        let height = top - bottom;
+        let depth = far - near;
+
+        Mat4::new([
+            [2.0 / width, 0.0,          0.0,         -(right + left) / width],
+            [0.0,         2.0 / height, 0.0,         -(top + bottom) / height],
+            [0.0,         0.0,          1.0 / depth, -near / depth],
+            [0.0,         0.0,          0.0,         1.0],
+        ])
+    }
+
+    /// Transforms `point` by the matrix and maps it onto a canvas `width` by
+    /// `height` pixels in size, giving the pixel coordinates in `x` and `y` and
+    /// the depth in `z`
+    ///
+    /// This performs the perspective divide and turns normalized device
+    /// coordinates into pixels, with `y` flipped so that up is towards the top
+    /// of the canvas. Points on or behind the camera plane give `None`.
+    pub fn project_point(&self, point: Vec3, width: usize, height: usize) -> Option<Vec3> {
+        let clip = *self * Vec4::from_vec3(point, 1.0);
+
+        if clip.w <= 0.0 {
+            return None;
+        }
+
+        let ndc = clip.perspective_divide();
+
+        Some(Vec3::new(
+            (ndc.x + 1.0) / 2.0 * width as f32,
+            (1.0 - ndc.y) / 2.0 * height as f32,
+            ndc.z,
+        ))
+    }
+
+    /// Swaps the rows and columns of the matrix
+    pub fn transpose(&self) -> Mat4 {
+        let mut result = [[0.0; 4]; 4];
+
+        for (row, output) in result.iter_mut().enumerate() {
+            for (column, value) in output.iter_mut().enumerate() {
+                *value = self.m[column][row];
+            }
+        }
+
+        Mat4::new(result)
+    }
+
+    /// Finds the matrix which undoes this one, or `None` if the matrix squashes
+    /// space flat and can't be undone
+    pub fn inverse(&self) -> Option<Mat4> {
+        // Gauss-Jordan elimination, turning `m` into the identity while the
+        // same row operations turn the identity into the inverse
+        let mut m = self.m;
+        let mut inverse = Self::IDENTITY.m;
+
+        for column in 0..4 {
+            // Pivot on the largest entry left in the column for stability
+            let pivot = (column..4)
+                .max_by(|&a, &b| m[a][column].abs().total_cmp(&m[b][column].abs()))
+                .unwrap_or(column);
+
+            if m[pivot][column].abs() <= f32::EPSILON * f32::EPSILON {
+                return None;
+            }
+
+            m.swap(column, pivot);
+            inverse.swap(column, pivot);
+
+            let scale = 1.0 / m[column][column];
+
+            for k in 0..4 {
+                m[column][k] *= scale;
+                inverse[column][k] *= scale;
+            }
+
+            for row in 0..4 {
+                let factor = m[row][column];
+
+                if row == column || factor == 0.0 {
+                    continue;
+                }
+
+                for k in 0..4 {
+                    m[row][k] -= factor * m[column][k];
+                    inverse[row][k] -= factor * inverse[column][k];
+                }
+            }
+        }
+
+        Some(Mat4::new(inverse))
+    }
+}
+
+impl Default for Mat4 {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl From<[f32; 16]> for Mat4 {
+    /// Creates a matrix from its entries listed row by row
+    fn from(m: [f32; 16]) -> Self {
+        Mat4::from_rows_array(m)
+    }
+}
+
+impl From<Mat4> for [f32; 16] {
+    /// Lists the entries of the matrix row by row
+    fn from(matrix: Mat4) -> Self {
+        matrix.to_rows_array()
+    }
+}
+
+impl From<[[f32; 4]; 4]> for Mat4 {
+    /// Creates a matrix from its rows
+    fn from(m: [[f32; 4]; 4]) -> Self {
+        Mat4::new(m)
+    }
+}
+
+impl From<Mat4> for [[f32; 4]; 4] {
+    /// Gets the rows of the matrix
+    fn from(matrix: Mat4) -> Self {
+        matrix.m
+    }
+}
+
+impl From<Mat3> for Mat4 {
+    /// Places `matrix` in the top left corner of the identity, giving the same
+    /// linear transform without any translation
+    #[rustfmt::skip]
+    fn from(matrix: Mat3) -> Self {
+        Mat4::new([
+            [matrix.a, matrix.b, matrix.c, 0.0],
+            [matrix.d, matrix.e, matrix.f, 0.0],
+            [matrix.g, matrix.h, matrix.i, 0.0],
+            [0.0,      0.0,      0.0,      1.0],
+        ])
+    }
+}
+
+impl std::ops::Mul<Vec4> for Mat4 {
+    type Output = Vec4;
+
+    fn mul(self, rhs: Vec4) -> Self::Output {
+        let row = |r: &[f32; 4]| r[0] * rhs.x + r[1] * rhs.y + r[2] * rhs.z + r[3] * rhs.w;
+
+        Vec4::new(
+            row(&self.m[0]),
+            row(&self.m[1]),
+            row(&self.m[2]),
+            row(&self.m[3]),
+        )
+    }
+}
+
+impl std::ops::Mul<Mat4> for Mat4 {
+    type Output = Mat4;
+
+    fn mul(self, rhs: Mat4) -> Self::Output {
+        let mut result = [[0.0; 4]; 4];
+
+        for (row, output) in result.iter_mut().enumerate() {
+            for (column, value) in output.iter_mut().enumerate() {
+                *value = (0..4).map(|k| self.m[row][k] * rhs.m[k][column]).sum();
+            }
+        }
+
+        Mat4::new(result)
+    }
+}
+
+/* ==== Quat ==== */
+
+/// A unit quaternion representing a rotation in 3D
+///
+/// Quaternions take less space than a rotation matrix and can be combined
+/// without slowly drifting away from a pure rotation. Multiplying two
+/// quaternions gives the rotation of the right one followed by the left one,
+/// the same as with matrices.
+///
+/// ```
+/// use farba::{Mat3, Quat, Vec3};
+///
+/// let angles = Vec3::new(0.5, 1.0, -0.25);
+/// let rotation = Quat::from_euler(angles);
+///
+/// // Each form converts into the others
+/// let matrix = Mat3::from(rotation);
+/// assert!(Quat::from_mat3(&matrix).dot(&rotation).abs() > 1.0 - 1e-6);
+/// assert!(rotation.to_euler().approx_eq(&angles, 1e-5));
+///
+/// // And rotates points the same way
+/// let point = Vec3::new(1.0, 2.0, 3.0);
+/// assert!((rotation * point).approx_eq(&(Mat3::from_euler(angles) * point), 1e-5));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quat {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub w: f32,
+}
+
+impl Quat {
+    pub const IDENTITY: Quat = Quat::new(0.0, 0.0, 0.0, 1.0);
+
+    pub const fn new(x: f32, y: f32, z: f32, w: f32) -> Self {
+        Self { x, y, z, w }
+    }
+
+    /// Creates a rotation of `angle` radians around `axis`, which doesn't need
+    /// to be normalized
+    pub fn from_axis_angle(axis: Vec3, angle: f32) -> Quat {
+        let (sin, cos) = (angle * 0.5).sin_cos();
+        let axis = axis.normalize() * sin;
+
+        Quat::new(axis.x, axis.y, axis.z, cos)
+    }
+
+    /// Creates a rotation from Euler angles in radians, in the order used by
+    /// [`Mat3::from_euler`]
+    pub fn from_euler(angles: Vec3) -> Quat {
+        Quat::from_axis_angle(Vec3::new(0.0, 0.0, 1.0), angles.z)
+            * Quat::from_axis_angle(Vec3::new(0.0, 1.0, 0.0), angles.y)
+            * Quat::from_axis_angle(Vec3::new(1.0, 0.0, 0.0), angles.x)
+    }
+
+    /// Finds the Euler angles of the rotation, in the order used by
+    /// [`Mat3::from_euler`]
+    pub fn to_euler(&self) -> Vec3 {
+        self.to_mat3().to_euler()
+    }
+
+    /// Finds the rotation of a pure rotation matrix
+    pub fn from_mat3(matrix: &Mat3) -> Quat {
+        let m = matrix;
+        let trace = m.a + m.e + m.i;
+
+        // Work from the largest of the four components to keep precision
+        let quat = if trace > 0.0 {
+            let s = (trace + 1.0).sqrt() * 2.0;
+            Quat::new((m.h - m.f) / s, (m.c - m.g) / s, (m.d - m.b) / s, s / 4.0)
+        } else if m.a > m.e && m.a > m.i {
+            let s = (1.0 + m.a - m.e - m.i).sqrt() * 2.0;
+            Quat::new(s / 4.0, (m.b + m.d) / s, (m.c + m.g) / s, (m.h - m.f) / s)
+        } else if m.e > m.i {
+            let s = (1.0 + m.e - m.a - m.i).sqrt() * 2.0;
+            Quat::new((m.b + m.d) / s, s / 4.0, (m.f + m.h) / s, (m.c - m.g) / s)
+        } else {
+            let s = (1.0 + m.i - m.a - m.e).sqrt() * 2.0;
+            Quat::new((m.c + m.g) / s, (m.f + m.h) / s, s / 4.0, (m.d - m.b) / s)
+        };
+
+        quat.normalize()
+    }
+
+    #[rustfmt::skip]
+    pub fn to_mat3(&self) -> Mat3 {
+        let Quat { x, y, z, w } = *self;
+
+        Mat3::new(
+            1.0 - 2.0 * (y * y + z * z), 2.0 * (x * y - z * w),       2.0 * (x * z + y * w),
+            2.0 * (x * y + z * w),       1.0 - 2.0 * (x * x + z * z), 2.0 * (y * z - x * w),
+            2.0 * (x * z - y * w),       2.0 * (y * z + x * w),       1.0 - 2.0 * (x * x + y * y),
+        )
+    }
+
+    pub fn dot(&self, other: &Self) -> f32 {
+        self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
+    }
+
+    pub fn magnitude(&self) -> f32 {
+        self.dot(self).sqrt()
+    }
+
+    pub fn normalize(&self) -> Quat {
+        let scale = 1.0 / self.magnitude();
+
+        Quat::new(
+            self.x * scale,
+            self.y * scale,
+            self.z * scale,
+            self.w * scale,
+        )
+    }
+
+    /// Gets the opposite rotation
+    pub fn conjugate(&self) -> Quat {
+        Quat::new(-self.x, -self.y, -self.z, self.w)
+    }
+}
+
+impl Default for Quat {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl From<Quat> for Mat3 {
+    fn from(quat: Quat) -> Self {
+        quat.to_mat3()
+    }
+}
+
+impl From<Quat> for Mat4 {
+    fn from(quat: Quat) -> Self {
+        quat.to_mat3().into()
+    }
+}
+
+impl std::ops::Mul<Quat> for Quat {
+    type Output = Quat;
+
+    fn mul(self, rhs: Quat) -> Self::Output {
+        Quat::new(
+            self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+            self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x,
+            self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w,
+            self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z,
+        )
+    }
+}
+
+impl std::ops::Mul<Vec3> for Quat {
+    type Output = Vec3;
+
+    /// Rotates `rhs` by the quaternion
+    fn mul(self, rhs: Vec3) -> Self::Output {
+        let axis = Vec3::new(self.x, self.y, self.z);
+        let t = axis.cross(&rhs) * 2.0;
+
+        rhs + t * self.w + axis.cross(&t)
+    }
+}
+
+/// Checks whether every value of `a` is within `epsilon` of the matching value
+/// of `b`
+fn approx_eq_slices(a: &[f32], b: &[f32], epsilon: f32) -> bool {
+    a.iter().zip(b).all(|(a, b)| (a - b).abs() <= epsilon)
+}
+
+/* ==== Barycentric Coordinates ==== */
+
+/// Finds the barycentric coordinates of `p` in the triangle `a`, `b`, `c`,
+/// which are the weights of each corner that add up to `p`
+///
+/// The weights always add up to one, and are all positive exactly when `p` is
+/// inside of the triangle, whichever way it is wound. Blending attributes of
+/// the corners with the weights interpolates them smoothly across the
+/// triangle. Degenerate triangles with no area give non finite weights.
+///
+/// ```
+/// use farba::{barycentric, Vec2};
+///
+/// let (a, b, c) = (Vec2::new(0.0, 0.0), Vec2::new(10.0, 0.0), Vec2::new(0.0, 10.0));
+///
+/// let weights = barycentric(Vec2::new(5.0, 0.0), a, b, c);
+/// assert_eq!((weights.x, weights.y, weights.z), (0.5, 0.5, 0.0));
+///
+/// let outside = barycentric(Vec2::new(10.0, 10.0), a, b, c);
+/// assert!(outside.x < 0.0);
+/// ```
+pub fn barycentric(p: Vec2, a: Vec2, b: Vec2, c: Vec2) -> Vec3 {
+    let area = (b - a).cross(c - a);
+
+    Vec3::new(
+        (c - b).cross(p - b) / area,
+        (a - c).cross(p - c) / area,
+        (b - a).cross(p - a) / area,
+    )
+}
+
+/// Finds the barycentric coordinates of `p` in the triangle `a`, `b`, `c` in
+/// 3D, like [`barycentric`]
+///
+/// Points off of the plane of the triangle are treated as the nearest point on
+/// it, so this works for points found by a [`Ray`](crate::Ray) hitting the
+/// triangle despite rounding errors.
+pub fn barycentric_3d(p: Vec3, a: Vec3, b: Vec3, c: Vec3) -> Vec3 {
+    let (ab, ac, ap) = (b - a, c - a, p - a);
+
+    let (d00, d01, d11) = (ab.dot(&ab), ab.dot(&ac), ac.dot(&ac));
+    let (d20, d21) = (ap.dot(&ab), ap.dot(&ac));
+
+    let denominator = d00 * d11 - d01 * d01;
+
+    let v = (d11 * d20 - d01 * d21) / denominator;
+    let w = (d00 * d21 - d01 * d20) / denominator;
+
+    Vec3::new(1.0 - v - w, v, w)
+}
+
 /* ==== Math Helpers */
 
 // https://www.desmos.com/calculator/s2gr8e2ajh
@@ -259,17 +1629,153 @@ pub mod interpolation {
     pub fn lerp(t: f32, a: f32, b: f32) -> f32 {
         a * (1.0 - t) + b * t
     }
-    
+
+    /// Finds how far `value` is between `a` and `b`, undoing [`lerp`] so that
+    /// `lerp(inverse_lerp(value, a, b), a, b) == value`
+    ///
+    /// Values outside of the range give results outside of `[0, 1]`, and an
+    /// empty range gives `0`.
+    pub fn inverse_lerp(value: f32, a: f32, b: f32) -> f32 {
+        if a == b {
+            return 0.0;
+        }
+
+        (value - a) / (b - a)
+    }
+
+    /// Maps `value` from the range `from_a` to `from_b` onto the range `to_a`
+    /// to `to_b`
+    ///
+    /// ```
+    /// use farba::interpolation::{inverse_lerp, lerp, remap, smoothstep};
+    ///
+    /// assert_eq!(lerp(0.25, 10.0, 20.0), 12.5);
+    /// assert_eq!(inverse_lerp(12.5, 10.0, 20.0), 0.25);
+    ///
+    /// // Celsius to Fahrenheit
+    /// assert_eq!(remap(100.0, 0.0, 100.0, 32.0, 212.0), 212.0);
+    ///
+    /// assert_eq!(smoothstep(5.0, 0.0, 10.0), 0.5);
+    /// assert_eq!(smoothstep(12.0, 0.0, 10.0), 1.0);
+    /// ```
+    pub fn remap(value: f32, from_a: f32, from_b: f32, to_a: f32, to_b: f32) -> f32 {
+        lerp(inverse_lerp(value, from_a, from_b), to_a, to_b)
+    }
+
+    /// Smoothly goes from `0` when `value` is at `edge0` to `1` when it is at
+    /// `edge1`, clamped outside of that range
+    ///
+    /// This is the `smoothstep` of shading languages, with the value moved to
+    /// the front to match the other functions here.
+    pub fn smoothstep(value: f32, edge0: f32, edge1: f32) -> f32 {
+        let t = inverse_lerp(value, edge0, edge1).clamp(0.0, 1.0);
+
+        t * t * (3.0 - 2.0 * t)
+    }
+
+    /// A curve for shaping the progress of an animation or gradient, mapping
+    /// `t` from `0` to `1` onto an eased value which also starts at `0` and
+    /// ends at `1`
+    ///
+    /// `In` curves start slowly, `Out` curves end slowly and `InOut` curves do
+    /// both.
+    ///
+    /// ```
+    /// use farba::interpolation::{lerp, Easing};
+    ///
+    /// assert_eq!(Easing::QuadIn.apply(0.5), 0.25);
+    /// assert_eq!(Easing::QuadOut.apply(0.5), 0.75);
+    /// assert_eq!(Easing::BounceOut.apply(1.0), 1.0);
+    ///
+    /// // Easing the position of an animated object
+    /// let x = lerp(Easing::CubicInOut.apply(0.25), 0.0, 100.0);
+    /// assert_eq!(x, 6.25);
+    /// ```
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    pub enum Easing {
+        #[default]
+        Linear,
+        QuadIn,
+        QuadOut,
+        QuadInOut,
+        CubicIn,
+        CubicOut,
+        CubicInOut,
+        SineIn,
+        SineOut,
+        SineInOut,
+        /// Bounces a few times off of the start before heading to the end
+        BounceIn,
+        /// Overshoots the end and bounces off of it a few times, like a dropped
+        /// ball
+        BounceOut,
+        BounceInOut,
+    }
+
+    impl Easing {
+        /// Eases `t`, which is clamped between `0` and `1`
+        pub fn apply(&self, t: f32) -> f32 {
+            use std::f32::consts::PI;
+
+            let t = t.clamp(0.0, 1.0);
+
+            match self {
+                Easing::Linear => t,
+                Easing::QuadIn => t * t,
+                Easing::QuadOut => 1.0 - (1.0 - t) * (1.0 - t),
+                Easing::QuadInOut => in_out(t, |t| t * t),
+                Easing::CubicIn => t * t * t,
+                Easing::CubicOut => 1.0 - (1.0 - t).powi(3),
+                Easing::CubicInOut => in_out(t, |t| t * t * t),
+                Easing::SineIn => 1.0 - (t * PI / 2.0).cos(),
+                Easing::SineOut => (t * PI / 2.0).sin(),
+                Easing::SineInOut => (1.0 - (t * PI).cos()) / 2.0,
+                Easing::BounceIn => 1.0 - bounce_out(1.0 - t),
+                Easing::BounceOut => bounce_out(t),
+                Easing::BounceInOut => in_out(t, |t| 1.0 - bounce_out(1.0 - t)),
+            }
+        }
+    }
+
+    /// Builds an `InOut` curve from the first half of an `In` curve and the
+    /// mirrored second half
+    fn in_out<F: Fn(f32) -> f32>(t: f32, ease_in: F) -> f32 {
+        if t < 0.5 {
+            ease_in(t * 2.0) / 2.0
+        } else {
+            1.0 - ease_in((1.0 - t) * 2.0) / 2.0
+        }
+    }
+
+    /// Four parabolic arcs of shrinking height, like a ball coming to rest
+    fn bounce_out(t: f32) -> f32 {
+        const STRENGTH: f32 = 7.5625;
+        const WIDTH: f32 = 2.75;
+
+        if t < 1.0 / WIDTH {
+            STRENGTH * t * t
+        } else if t < 2.0 / WIDTH {
+            let t = t - 1.5 / WIDTH;
+            STRENGTH * t * t + 0.75
+        } else if t < 2.5 / WIDTH {
+            let t = t - 2.25 / WIDTH;
+            STRENGTH * t * t + 0.9375
+        } else {
+            let t = t - 2.625 / WIDTH;
+            STRENGTH * t * t + 0.984375
+        }
+    }
+
     pub fn bilinear(t: f32, a: f32, b: f32, c: f32, d: f32) -> f32 {
         let lerp_ab = lerp(t, a, b);
         let lerp_cd = lerp(t, c, d);
-    
+
         lerp(t, lerp_ab, lerp_cd)
     }
-    
+
     pub fn cosine(t: f32, a: f32, b: f32) -> f32 {
         use std::f32::consts::PI;
-    
+
         // -cos(t * pi) / 2 + 0.5
         lerp(-f32::cos(t * PI) / 2.0 + 0.5, a, b)
     }
@@ -279,7 +1785,7 @@ pub mod interpolation {
     pub fn sooth_step(t: f32, a: f32, b: f32) -> f32 {
         lerp(t * t * (3.0 - 2.0 * t), a, b)
     }
-    
+
     pub fn acceleration(t: f32, a: f32, b: f32) -> f32 {
         lerp(t * t, a, b)
     }
@@ -311,4 +1817,4 @@ pub mod interpolation {
 
         b
     }
-}
\ No newline at end of file
+}
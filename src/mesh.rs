@@ -0,0 +1,314 @@
+use std::{fmt, ops::Range};
+
+use crate::{Aabb3, Material, RGBAColor, Vec2, Vec3};
+
+/// Problems found by [`Mesh::validate`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MeshError {
+    /// A triangle refers to a vertex past the end of the positions
+    IndexOutOfRange { triangle: usize, index: u32 },
+    /// An attribute doesn't have exactly one value for every vertex, or for
+    /// every triangle in the case of material indices
+    AttributeLength {
+        attribute: &'static str,
+        expected: usize,
+        found: usize,
+    },
+    /// A submesh covers triangles past the end of the mesh
+    SubmeshOutOfRange { submesh: usize },
+    /// A triangle uses a material past the end of the materials
+    MaterialOutOfRange { triangle: usize, material: usize },
+}
+
+impl fmt::Display for MeshError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MeshError::IndexOutOfRange { triangle, index } => {
+                write!(f, "triangle {triangle} refers to missing vertex {index}")
+            }
+            MeshError::AttributeLength {
+                attribute,
+                expected,
+                found,
+            } => write!(f, "mesh has {found} {attribute} instead of {expected}"),
+            MeshError::SubmeshOutOfRange { submesh } => {
+                write!(
+                    f,
+                    "submesh {submesh} covers triangles past the end of the mesh"
+                )
+            }
+            MeshError::MaterialOutOfRange { triangle, material } => {
+                write!(f, "triangle {triangle} uses missing material {material}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for MeshError {}
+
+/// A named run of triangles within a [`Mesh`], such as one part of a model
+/// which is drawn with its own color or texture
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submesh {
+    pub name: String,
+    /// Indices of the triangles in the mesh which make up the submesh
+    pub triangles: Range<usize>,
+}
+
+/// A triangle mesh, made of vertices shared between indexed triangles
+///
+/// Every vertex has a position, and may also have a normal, texture
+/// coordinates and a color. Each of these optional attributes is either left
+/// out entirely or given for every vertex. Triangles list the indices of their
+/// three vertices, and may be grouped into [`Submesh`]es. Each triangle can also
+/// be given one of the [`Material`]s of the mesh.
+///
+/// Positions are in the left handed coordinates used by the rest of the crate.
+/// Loaders for right handed formats, such as OBJ, STL and glTF, mirror z as
+/// they read them, the same way as [`Mesh::mirror_z`].
+///
+/// ```
+/// use farba::{Mesh, Submesh, Vec3};
+///
+/// // A square made of two triangles
+/// let mut mesh = Mesh::new(
+///     vec![
+///         Vec3::new(0.0, 0.0, 0.0),
+///         Vec3::new(1.0, 0.0, 0.0),
+///         Vec3::new(1.0, 1.0, 0.0),
+///         Vec3::new(0.0, 1.0, 0.0),
+///     ],
+///     vec![[0, 1, 2], [0, 2, 3]],
+/// );
+///
+/// mesh.compute_normals();
+/// assert_eq!(mesh.normals.as_ref().unwrap()[0], Vec3::new(0.0, 0.0, 1.0));
+///
+/// mesh.submeshes.push(Submesh {
+///     name: "top half".to_string(),
+///     triangles: 1..2,
+/// });
+/// assert!(mesh.validate().is_ok());
+///
+/// let corners: Vec<[Vec3; 3]> = mesh.submesh_triangles(&mesh.submeshes[0]).collect();
+/// assert_eq!(corners[0][2], Vec3::new(0.0, 1.0, 0.0));
+///
+/// // Triangles may only refer to vertices which exist
+/// mesh.indices.push([0, 1, 4]);
+/// assert!(mesh.validate().is_err());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Mesh {
+    pub positions: Vec<Vec3>,
+    /// The vertices of each triangle, as indices into the vertex attributes
+    pub indices: Vec<[u32; 3]>,
+    pub normals: Option<Vec<Vec3>>,
+    /// Texture coordinates, from `(0, 0)` in the top left corner of a texture
+    /// to `(1, 1)` in the bottom right
+    pub uvs: Option<Vec<Vec2>>,
+    pub colors: Option<Vec<RGBAColor>>,
+    pub submeshes: Vec<Submesh>,
+    pub materials: Vec<Material>,
+    /// The index into `materials` used by each triangle
+    pub material_indices: Option<Vec<usize>>,
+}
+
+impl Mesh {
+    /// Creates a mesh with only positions and triangles
+    pub fn new(positions: Vec<Vec3>, indices: Vec<[u32; 3]>) -> Self {
+        Self {
+            positions,
+            indices,
+            ..Default::default()
+        }
+    }
+
+    pub fn vertex_count(&self) -> usize {
+        self.positions.len()
+    }
+
+    pub fn triangle_count(&self) -> usize {
+        self.indices.len()
+    }
+
+    /// Checks that every triangle refers to existing vertices and materials,
+    /// that every attribute has a value for every vertex or triangle and that
+    /// every submesh only covers existing triangles
+    pub fn validate(&self) -> Result<(), MeshError> {
+        let expected = self.positions.len();
+
+        for (triangle, indices) in self.indices.iter().enumerate() {
+            if let Some(&index) = indices.iter().find(|&&index| index as usize >= expected) {
+                return Err(MeshError::IndexOutOfRange { triangle, index });
+            }
+        }
+
+        let attributes = [
+            ("normals", self.normals.as_ref().map(Vec::len)),
+            ("uvs", self.uvs.as_ref().map(Vec::len)),
+            ("colors", self.colors.as_ref().map(Vec::len)),
+        ];
+
+        for (attribute, found) in attributes {
+            match found {
+                Some(found) if found != expected => {
+                    return Err(MeshError::AttributeLength {
+                        attribute,
+                        expected,
+                        found,
+                    })
+                }
+                _ => {}
+            }
+        }
+
+        for (submesh, Submesh { triangles, .. }) in self.submeshes.iter().enumerate() {
+            if triangles.start > triangles.end || triangles.end > self.indices.len() {
+                return Err(MeshError::SubmeshOutOfRange { submesh });
+            }
+        }
+
+        if let Some(material_indices) = &self.material_indices {
+            if material_indices.len() != self.indices.len() {
+                return Err(MeshError::AttributeLength {
+                    attribute: "material indices",
+                    expected: self.indices.len(),
+                    found: material_indices.len(),
+                });
+            }
+
+            for (triangle, &material) in material_indices.iter().enumerate() {
+                if material >= self.materials.len() {
+                    return Err(MeshError::MaterialOutOfRange { triangle, material });
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Gets the positions of the corners of the triangle at `index`
+    ///
+    /// Panics if the triangle or its vertices don't exist
+    pub fn triangle(&self, index: usize) -> [Vec3; 3] {
+        self.indices[index].map(|vertex| self.positions[vertex as usize])
+    }
+
+    /// Gets the material of the triangle at `index`, if it has one
+    ///
+    /// ```
+    /// use farba::{Material, Mesh, Vec3};
+    ///
+    /// let mut mesh = Mesh::new(
+    ///     vec![Vec3::ZERO, Vec3::new(1.0, 0.0, 0.0), Vec3::new(0.0, 1.0, 0.0)],
+    ///     vec![[0, 1, 2]],
+    /// );
+    ///
+    /// mesh.materials = Material::parse_mtl("newmtl blue\nKd 0 0 1").unwrap();
+    /// mesh.material_indices = Some(vec![0]);
+    /// assert!(mesh.validate().is_ok());
+    ///
+    /// let material = mesh.triangle_material(0).unwrap();
+    /// assert_eq!(material.diffuse.blue, 255);
+    /// ```
+    pub fn triangle_material(&self, index: usize) -> Option<&Material> {
+        let material = *self.material_indices.as_ref()?.get(index)?;
+
+        self.materials.get(material)
+    }
+
+    /// Iterates over the positions of the corners of every triangle
+    pub fn triangles(&self) -> impl Iterator<Item = [Vec3; 3]> + '_ {
+        (0..self.indices.len()).map(|index| self.triangle(index))
+    }
+
+    /// Iterates over the positions of the corners of every triangle in
+    /// `submesh`
+    pub fn submesh_triangles<'a>(
+        &'a self,
+        submesh: &Submesh,
+    ) -> impl Iterator<Item = [Vec3; 3]> + 'a {
+        submesh.triangles.clone().map(|index| self.triangle(index))
+    }
+
+    /// Gets the normal of the triangle at `index` with corners `a`, `b` and
+    /// `c`, pointing along `(b - a) × (c - a)`, or zero if the triangle has no
+    /// area
+    pub fn face_normal(&self, index: usize) -> Vec3 {
+        let [a, b, c] = self.triangle(index);
+        let normal = (b - a).cross(&(c - a));
+
+        if normal.magnitude_squared() == 0.0 {
+            Vec3::ZERO
+        } else {
+            normal.normalize()
+        }
+    }
+
+    /// Replaces the normals with smooth ones, averaging the normals of the
+    /// triangles around each vertex weighted by their areas
+    ///
+    /// Vertices which aren't part of any triangle get a zero normal. For sharp
+    /// edges, such as those of a cube, the triangles on either side of the edge
+    /// need their own copies of the vertices.
+    pub fn compute_normals(&mut self) {
+        let mut normals = vec![Vec3::ZERO; self.positions.len()];
+
+        for index in 0..self.indices.len() {
+            let [a, b, c] = self.triangle(index);
+
+            // The length of the cross product is twice the area
+            let weighted = (b - a).cross(&(c - a));
+
+            for vertex in self.indices[index] {
+                normals[vertex as usize] += weighted;
+            }
+        }
+
+        for normal in normals.iter_mut() {
+            if normal.magnitude_squared() > 0.0 {
+                *normal = normal.normalize();
+            }
+        }
+
+        self.normals = Some(normals);
+    }
+
+    /// Mirrors the mesh along the z axis, rewinding its triangles so that they
+    /// keep facing the same way
+    ///
+    /// This converts a mesh between right handed coordinates, which most file
+    /// formats use, and the left handed coordinates of this crate.
+    ///
+    /// ```
+    /// use farba::{Mesh, Vec3};
+    ///
+    /// let mut mesh = Mesh::new(
+    ///     vec![Vec3::ZERO, Vec3::new(1.0, 0.0, 1.0), Vec3::new(0.0, 1.0, 1.0)],
+    ///     vec![[0, 1, 2]],
+    /// );
+    /// let normal = mesh.face_normal(0);
+    ///
+    /// mesh.mirror_z();
+    /// assert_eq!(mesh.positions[1], Vec3::new(1.0, 0.0, -1.0));
+    /// assert_eq!(mesh.face_normal(0), Vec3::new(normal.x, normal.y, -normal.z));
+    /// ```
+    pub fn mirror_z(&mut self) {
+        for position in self.positions.iter_mut() {
+            position.z = -position.z;
+        }
+
+        for normal in self.normals.iter_mut().flatten() {
+            normal.z = -normal.z;
+        }
+
+        for [_, b, c] in self.indices.iter_mut() {
+            std::mem::swap(b, c);
+        }
+    }
+
+    /// Gets the smallest box holding every vertex
+    pub fn bounds(&self) -> Aabb3 {
+        Aabb3::from_points(self.positions.iter().copied())
+    }
+}
@@ -0,0 +1,304 @@
+use std::f32::consts::{PI, TAU};
+
+use crate::{Mesh, Vec2, Vec3};
+
+// Ready made meshes, each with normals and texture coordinates. Every shape is
+// centered on the origin and reaches from -1 to 1 along its widest axes, with
+// +y as up. Triangles are wound so that their face normals point out of the
+// shape, and textures read upright when looking at a side from outside.
+impl Mesh {
+    /// Creates a cube reaching from -1 to 1 along every axis
+    ///
+    /// Each face has its own four vertices so that the edges stay sharp, and
+    /// the whole texture is stretched across it. The faces come in the order
+    /// -z, +x, +z, -x, +y and -y, two triangles each, so that they can be told
+    /// apart by their vertex or triangle indices.
+    ///
+    /// ```
+    /// use farba::{Mesh, Vec3};
+    ///
+    /// let cube = Mesh::cube();
+    /// assert_eq!(cube.vertex_count(), 24);
+    /// assert_eq!(cube.triangle_count(), 12);
+    ///
+    /// // The first face looks down -z
+    /// assert_eq!(cube.face_normal(0), Vec3::new(0.0, 0.0, -1.0));
+    /// ```
+    pub fn cube() -> Mesh {
+        // The normal of every face, with the direction which is up in its texture
+        let faces = [
+            (Vec3::new(0.0, 0.0, -1.0), Vec3::new(0.0, 1.0, 0.0)),
+            (Vec3::new(1.0, 0.0, 0.0), Vec3::new(0.0, 1.0, 0.0)),
+            (Vec3::new(0.0, 0.0, 1.0), Vec3::new(0.0, 1.0, 0.0)),
+            (Vec3::new(-1.0, 0.0, 0.0), Vec3::new(0.0, 1.0, 0.0)),
+            (Vec3::new(0.0, 1.0, 0.0), Vec3::new(0.0, 0.0, 1.0)),
+            (Vec3::new(0.0, -1.0, 0.0), Vec3::new(0.0, 0.0, -1.0)),
+        ];
+
+        let mut shape = Shape::default();
+
+        for (normal, up) in faces {
+            let right = normal.cross(&up);
+
+            shape.grid(1, 1, |u, v| {
+                let position = normal + right * (2.0 * u - 1.0) + up * (1.0 - 2.0 * v);
+                (position, normal)
+            });
+        }
+
+        shape.into_mesh()
+    }
+
+    /// Creates a sphere of radius 1 out of `latitudes` bands from pole to pole,
+    /// each split into `longitudes` pieces around the y axis
+    ///
+    /// The texture is wrapped around the sphere with its top edge at the north
+    /// pole, so the vertices along the seam where its edges meet are doubled.
+    /// Fewer than 2 latitudes or 3 longitudes are rounded up.
+    ///
+    /// ```
+    /// use farba::Mesh;
+    ///
+    /// let sphere = Mesh::uv_sphere(8, 16);
+    /// assert!(sphere.validate().is_ok());
+    ///
+    /// // Every vertex is on the surface, with a normal pointing out of it
+    /// let normals = sphere.normals.as_ref().unwrap();
+    ///
+    /// for (position, normal) in sphere.positions.iter().zip(normals) {
+    ///     assert!((position.magnitude() - 1.0).abs() < 1e-6);
+    ///     assert!(position.approx_eq(normal, 1e-6));
+    /// }
+    ///
+    /// // Triangles face outwards too, and none are left pinched at the poles
+    /// assert_eq!(sphere.triangle_count(), 16 * (8 - 1) * 2);
+    ///
+    /// for index in 0..sphere.triangle_count() {
+    ///     let [a, ..] = sphere.triangle(index);
+    ///     assert!(sphere.face_normal(index).dot(&a) > 0.0);
+    /// }
+    /// ```
+    pub fn uv_sphere(latitudes: usize, longitudes: usize) -> Mesh {
+        let mut shape = Shape::default();
+
+        shape.grid(longitudes.max(3), latitudes.max(2), |u, v| {
+            let (sin, cos) = (u * TAU).sin_cos();
+
+            // The poles are placed exactly, so that the triangles pinched to a
+            // point there are left out
+            let ring = if v > 0.0 && v < 1.0 {
+                (v * PI).sin()
+            } else {
+                0.0
+            };
+
+            let position = Vec3::new(cos * ring, (v * PI).cos(), sin * ring);
+            (position, position)
+        });
+
+        shape.into_mesh()
+    }
+
+    /// Creates a cylinder of radius 1 around the y axis, reaching from -1 to 1,
+    /// with its round side split into `segments` pieces
+    ///
+    /// The texture is wrapped once around the side, and each flat end gets its
+    /// own copy of the texture cut down to a circle. Fewer than 3 segments
+    /// are rounded up to 3.
+    pub fn cylinder(segments: usize) -> Mesh {
+        let segments = segments.max(3);
+        let mut shape = Shape::default();
+
+        shape.grid(segments, 1, |u, v| {
+            let (sin, cos) = (u * TAU).sin_cos();
+            let normal = Vec3::new(cos, 0.0, sin);
+
+            (normal + Vec3::new(0.0, 1.0 - 2.0 * v, 0.0), normal)
+        });
+
+        shape.disc(segments, 1.0);
+        shape.disc(segments, -1.0);
+
+        shape.into_mesh()
+    }
+
+    /// Creates a cone with a base of radius 1 at y = -1 and its tip at y = 1,
+    /// with its round side split into `segments` pieces
+    ///
+    /// The texture is wrapped once around the side with its top edge at the
+    /// tip, while the base gets its own copy of the texture cut down to a
+    /// circle. Fewer than 3 segments are rounded up to 3.
+    pub fn cone(segments: usize) -> Mesh {
+        let segments = segments.max(3);
+        let mut shape = Shape::default();
+
+        shape.grid(segments, 1, |u, v| {
+            let (sin, cos) = (u * TAU).sin_cos();
+
+            // The side rises by 2 while moving in by 1, so it leans back by
+            // half as much as it is out from the axis
+            let normal = Vec3::new(2.0 * cos, 1.0, 2.0 * sin).normalize();
+
+            (Vec3::new(cos * v, 1.0 - 2.0 * v, sin * v), normal)
+        });
+
+        shape.disc(segments, -1.0);
+
+        shape.into_mesh()
+    }
+
+    /// Creates a torus around the y axis, made of a tube of radius
+    /// `tube_radius` running along a circle of radius 1
+    ///
+    /// The tube is split into `segments` pieces along its length and `sides`
+    /// pieces around it. The texture is wrapped along the tube with its left
+    /// edge on the +x axis, and around the tube with its top edge along the top
+    /// of the torus. Fewer than 3 segments or sides are rounded up to 3.
+    pub fn torus(tube_radius: f32, segments: usize, sides: usize) -> Mesh {
+        let mut shape = Shape::default();
+
+        shape.grid(segments.max(3), sides.max(3), |u, v| {
+            let (sin, cos) = (u * TAU).sin_cos();
+            let outwards = Vec3::new(cos, 0.0, sin);
+
+            let (tube_sin, tube_cos) = (v * TAU).sin_cos();
+            let normal = outwards * tube_sin + Vec3::new(0.0, tube_cos, 0.0);
+
+            (outwards + normal * tube_radius, normal)
+        });
+
+        shape.into_mesh()
+    }
+
+    /// Creates a flat square facing up the y axis, reaching from -1 to 1 along
+    /// x and z and split into `subdivisions` rows and columns of squares
+    ///
+    /// The texture is stretched across the whole square, with its top edge at
+    /// +z. A plane always has at least 1 subdivision.
+    ///
+    /// ```
+    /// use farba::{Mesh, Vec2, Vec3};
+    ///
+    /// let plane = Mesh::plane(4);
+    /// assert_eq!(plane.vertex_count(), 25);
+    /// assert_eq!(plane.triangle_count(), 32);
+    ///
+    /// assert_eq!(plane.positions[0], Vec3::new(-1.0, 0.0, 1.0));
+    /// assert_eq!(plane.uvs.as_ref().unwrap()[24], Vec2::new(1.0, 1.0));
+    /// assert_eq!(plane.face_normal(0), Vec3::new(0.0, 1.0, 0.0));
+    /// ```
+    pub fn plane(subdivisions: usize) -> Mesh {
+        let subdivisions = subdivisions.max(1);
+        let mut shape = Shape::default();
+
+        shape.grid(subdivisions, subdivisions, |u, v| {
+            let position = Vec3::new(2.0 * u - 1.0, 0.0, 1.0 - 2.0 * v);
+            (position, Vec3::new(0.0, 1.0, 0.0))
+        });
+
+        shape.into_mesh()
+    }
+}
+
+/// The attributes of a mesh while it is being built
+#[derive(Default)]
+struct Shape {
+    positions: Vec<Vec3>,
+    normals: Vec<Vec3>,
+    uvs: Vec<Vec2>,
+    indices: Vec<[u32; 3]>,
+}
+
+impl Shape {
+    /// Adds a surface made of `columns` by `rows` squares, with `vertex` giving
+    /// the position and normal at each texture coordinate
+    ///
+    /// Seen from outside, increasing `u` must move right and increasing `v`
+    /// must move down, the same way as across the texture. Triangles with two
+    /// corners in the same place are left out, such as where a row is pinched
+    /// to a point.
+    fn grid(&mut self, columns: usize, rows: usize, vertex: impl Fn(f32, f32) -> (Vec3, Vec3)) {
+        let first = self.positions.len() as u32;
+
+        for row in 0..=rows {
+            for column in 0..=columns {
+                let u = column as f32 / columns as f32;
+                let v = row as f32 / rows as f32;
+
+                let (position, normal) = vertex(u, v);
+
+                self.positions.push(position);
+                self.normals.push(normal);
+                self.uvs.push(Vec2::new(u, v));
+            }
+        }
+
+        let index = |column: usize, row: usize| first + (row * (columns + 1) + column) as u32;
+
+        for row in 0..rows {
+            for column in 0..columns {
+                let top_left = index(column, row);
+                let top_right = index(column + 1, row);
+                let bottom_right = index(column + 1, row + 1);
+                let bottom_left = index(column, row + 1);
+
+                for triangle in [
+                    [top_left, top_right, bottom_right],
+                    [top_left, bottom_right, bottom_left],
+                ] {
+                    let [a, b, c] = triangle.map(|index| self.positions[index as usize]);
+
+                    if a != b && b != c && c != a {
+                        self.indices.push(triangle);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Adds a flat disc of radius 1 around the y axis at `y`, facing up the
+    /// axis if `y` is positive and down it otherwise
+    ///
+    /// The texture is cut down to a circle, reading upright when seen from the
+    /// side it faces with -z at the bottom.
+    fn disc(&mut self, segments: usize, y: f32) {
+        let facing = y.signum();
+        let normal = Vec3::new(0.0, facing, 0.0);
+
+        let center = self.positions.len() as u32;
+
+        self.positions.push(Vec3::new(0.0, y, 0.0));
+        self.normals.push(normal);
+        self.uvs.push(Vec2::new(0.5, 0.5));
+
+        for segment in 0..segments {
+            let (sin, cos) = (segment as f32 / segments as f32 * TAU).sin_cos();
+
+            self.positions.push(Vec3::new(cos, y, sin));
+            self.normals.push(normal);
+            self.uvs
+                .push(Vec2::new(0.5 + 0.5 * cos, 0.5 - 0.5 * sin * facing));
+        }
+
+        for segment in 0..segments as u32 {
+            let current = center + 1 + segment;
+            let next = center + 1 + (segment + 1) % segments as u32;
+
+            // Triangles going around the ring the way the angle grows face -y
+            self.indices.push(match facing > 0.0 {
+                true => [center, next, current],
+                false => [center, current, next],
+            });
+        }
+    }
+
+    fn into_mesh(self) -> Mesh {
+        Mesh {
+            positions: self.positions,
+            indices: self.indices,
+            normals: Some(self.normals),
+            uvs: Some(self.uvs),
+            ..Default::default()
+        }
+    }
+}
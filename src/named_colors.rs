@@ -0,0 +1,322 @@
+use crate::RGBAColor;
+
+/// The named colors of CSS, which are also the X11 colors with a few differences
+/// in the grays and greens
+///
+/// [`RGBAColor::GREEN`] predates these and stays pure green, which CSS calls
+/// `lime`. The CSS `green` is only available through [`RGBAColor::from_name`].
+impl RGBAColor {
+    pub const TRANSPARENT: RGBAColor = RGBAColor::from_rgba(0, 0, 0, 0);
+
+    pub const ALICE_BLUE: RGBAColor = RGBAColor::from_rgb(240, 248, 255);
+    pub const ANTIQUE_WHITE: RGBAColor = RGBAColor::from_rgb(250, 235, 215);
+    pub const AQUA: RGBAColor = RGBAColor::from_rgb(0, 255, 255);
+    pub const AQUAMARINE: RGBAColor = RGBAColor::from_rgb(127, 255, 212);
+    pub const AZURE: RGBAColor = RGBAColor::from_rgb(240, 255, 255);
+    pub const BEIGE: RGBAColor = RGBAColor::from_rgb(245, 245, 220);
+    pub const BISQUE: RGBAColor = RGBAColor::from_rgb(255, 228, 196);
+    pub const BLANCHED_ALMOND: RGBAColor = RGBAColor::from_rgb(255, 235, 205);
+    pub const BLUE_VIOLET: RGBAColor = RGBAColor::from_rgb(138, 43, 226);
+    pub const BROWN: RGBAColor = RGBAColor::from_rgb(165, 42, 42);
+    pub const BURLY_WOOD: RGBAColor = RGBAColor::from_rgb(222, 184, 135);
+    pub const CADET_BLUE: RGBAColor = RGBAColor::from_rgb(95, 158, 160);
+    pub const CHARTREUSE: RGBAColor = RGBAColor::from_rgb(127, 255, 0);
+    pub const CHOCOLATE: RGBAColor = RGBAColor::from_rgb(210, 105, 30);
+    pub const CORAL: RGBAColor = RGBAColor::from_rgb(255, 127, 80);
+    pub const CORNFLOWER_BLUE: RGBAColor = RGBAColor::from_rgb(100, 149, 237);
+    pub const CORNSILK: RGBAColor = RGBAColor::from_rgb(255, 248, 220);
+    pub const CRIMSON: RGBAColor = RGBAColor::from_rgb(220, 20, 60);
+    pub const DARK_BLUE: RGBAColor = RGBAColor::from_rgb(0, 0, 139);
+    pub const DARK_CYAN: RGBAColor = RGBAColor::from_rgb(0, 139, 139);
+    pub const DARK_GOLDENROD: RGBAColor = RGBAColor::from_rgb(184, 134, 11);
+    pub const DARK_GRAY: RGBAColor = RGBAColor::from_rgb(169, 169, 169);
+    pub const DARK_GREEN: RGBAColor = RGBAColor::from_rgb(0, 100, 0);
+    pub const DARK_GREY: RGBAColor = RGBAColor::from_rgb(169, 169, 169);
+    pub const DARK_KHAKI: RGBAColor = RGBAColor::from_rgb(189, 183, 107);
+    pub const DARK_MAGENTA: RGBAColor = RGBAColor::from_rgb(139, 0, 139);
+    pub const DARK_OLIVE_GREEN: RGBAColor = RGBAColor::from_rgb(85, 107, 47);
+    pub const DARK_ORANGE: RGBAColor = RGBAColor::from_rgb(255, 140, 0);
+    pub const DARK_ORCHID: RGBAColor = RGBAColor::from_rgb(153, 50, 204);
+    pub const DARK_RED: RGBAColor = RGBAColor::from_rgb(139, 0, 0);
+    pub const DARK_SALMON: RGBAColor = RGBAColor::from_rgb(233, 150, 122);
+    pub const DARK_SEA_GREEN: RGBAColor = RGBAColor::from_rgb(143, 188, 143);
+    pub const DARK_SLATE_BLUE: RGBAColor = RGBAColor::from_rgb(72, 61, 139);
+    pub const DARK_SLATE_GRAY: RGBAColor = RGBAColor::from_rgb(47, 79, 79);
+    pub const DARK_SLATE_GREY: RGBAColor = RGBAColor::from_rgb(47, 79, 79);
+    pub const DARK_TURQUOISE: RGBAColor = RGBAColor::from_rgb(0, 206, 209);
+    pub const DARK_VIOLET: RGBAColor = RGBAColor::from_rgb(148, 0, 211);
+    pub const DEEP_PINK: RGBAColor = RGBAColor::from_rgb(255, 20, 147);
+    pub const DEEP_SKY_BLUE: RGBAColor = RGBAColor::from_rgb(0, 191, 255);
+    pub const DIM_GRAY: RGBAColor = RGBAColor::from_rgb(105, 105, 105);
+    pub const DIM_GREY: RGBAColor = RGBAColor::from_rgb(105, 105, 105);
+    pub const DODGER_BLUE: RGBAColor = RGBAColor::from_rgb(30, 144, 255);
+    pub const FIREBRICK: RGBAColor = RGBAColor::from_rgb(178, 34, 34);
+    pub const FLORAL_WHITE: RGBAColor = RGBAColor::from_rgb(255, 250, 240);
+    pub const FOREST_GREEN: RGBAColor = RGBAColor::from_rgb(34, 139, 34);
+    pub const FUCHSIA: RGBAColor = RGBAColor::from_rgb(255, 0, 255);
+    pub const GAINSBORO: RGBAColor = RGBAColor::from_rgb(220, 220, 220);
+    pub const GHOST_WHITE: RGBAColor = RGBAColor::from_rgb(248, 248, 255);
+    pub const GOLD: RGBAColor = RGBAColor::from_rgb(255, 215, 0);
+    pub const GOLDENROD: RGBAColor = RGBAColor::from_rgb(218, 165, 32);
+    pub const GRAY: RGBAColor = RGBAColor::from_rgb(128, 128, 128);
+    pub const GREEN_YELLOW: RGBAColor = RGBAColor::from_rgb(173, 255, 47);
+    pub const GREY: RGBAColor = RGBAColor::from_rgb(128, 128, 128);
+    pub const HONEYDEW: RGBAColor = RGBAColor::from_rgb(240, 255, 240);
+    pub const HOT_PINK: RGBAColor = RGBAColor::from_rgb(255, 105, 180);
+    pub const INDIAN_RED: RGBAColor = RGBAColor::from_rgb(205, 92, 92);
+    pub const INDIGO: RGBAColor = RGBAColor::from_rgb(75, 0, 130);
+    pub const IVORY: RGBAColor = RGBAColor::from_rgb(255, 255, 240);
+    pub const KHAKI: RGBAColor = RGBAColor::from_rgb(240, 230, 140);
+    pub const LAVENDER: RGBAColor = RGBAColor::from_rgb(230, 230, 250);
+    pub const LAVENDER_BLUSH: RGBAColor = RGBAColor::from_rgb(255, 240, 245);
+    pub const LAWN_GREEN: RGBAColor = RGBAColor::from_rgb(124, 252, 0);
+    pub const LEMON_CHIFFON: RGBAColor = RGBAColor::from_rgb(255, 250, 205);
+    pub const LIGHT_BLUE: RGBAColor = RGBAColor::from_rgb(173, 216, 230);
+    pub const LIGHT_CORAL: RGBAColor = RGBAColor::from_rgb(240, 128, 128);
+    pub const LIGHT_CYAN: RGBAColor = RGBAColor::from_rgb(224, 255, 255);
+    pub const LIGHT_GOLDENROD_YELLOW: RGBAColor = RGBAColor::from_rgb(250, 250, 210);
+    pub const LIGHT_GRAY: RGBAColor = RGBAColor::from_rgb(211, 211, 211);
+    pub const LIGHT_GREEN: RGBAColor = RGBAColor::from_rgb(144, 238, 144);
+    pub const LIGHT_GREY: RGBAColor = RGBAColor::from_rgb(211, 211, 211);
+    pub const LIGHT_PINK: RGBAColor = RGBAColor::from_rgb(255, 182, 193);
+    pub const LIGHT_SALMON: RGBAColor = RGBAColor::from_rgb(255, 160, 122);
+    pub const LIGHT_SEA_GREEN: RGBAColor = RGBAColor::from_rgb(32, 178, 170);
+    pub const LIGHT_SKY_BLUE: RGBAColor = RGBAColor::from_rgb(135, 206, 250);
+    pub const LIGHT_SLATE_GRAY: RGBAColor = RGBAColor::from_rgb(119, 136, 153);
+    pub const LIGHT_SLATE_GREY: RGBAColor = RGBAColor::from_rgb(119, 136, 153);
+    pub const LIGHT_STEEL_BLUE: RGBAColor = RGBAColor::from_rgb(176, 196, 222);
+    pub const LIGHT_YELLOW: RGBAColor = RGBAColor::from_rgb(255, 255, 224);
+    pub const LIME: RGBAColor = RGBAColor::from_rgb(0, 255, 0);
+    pub const LIME_GREEN: RGBAColor = RGBAColor::from_rgb(50, 205, 50);
+    pub const LINEN: RGBAColor = RGBAColor::from_rgb(250, 240, 230);
+    pub const MAROON: RGBAColor = RGBAColor::from_rgb(128, 0, 0);
+    pub const MEDIUM_AQUAMARINE: RGBAColor = RGBAColor::from_rgb(102, 205, 170);
+    pub const MEDIUM_BLUE: RGBAColor = RGBAColor::from_rgb(0, 0, 205);
+    pub const MEDIUM_ORCHID: RGBAColor = RGBAColor::from_rgb(186, 85, 211);
+    pub const MEDIUM_PURPLE: RGBAColor = RGBAColor::from_rgb(147, 112, 219);
+    pub const MEDIUM_SEA_GREEN: RGBAColor = RGBAColor::from_rgb(60, 179, 113);
+    pub const MEDIUM_SLATE_BLUE: RGBAColor = RGBAColor::from_rgb(123, 104, 238);
+    pub const MEDIUM_SPRING_GREEN: RGBAColor = RGBAColor::from_rgb(0, 250, 154);
+    pub const MEDIUM_TURQUOISE: RGBAColor = RGBAColor::from_rgb(72, 209, 204);
+    pub const MEDIUM_VIOLET_RED: RGBAColor = RGBAColor::from_rgb(199, 21, 133);
+    pub const MIDNIGHT_BLUE: RGBAColor = RGBAColor::from_rgb(25, 25, 112);
+    pub const MINT_CREAM: RGBAColor = RGBAColor::from_rgb(245, 255, 250);
+    pub const MISTY_ROSE: RGBAColor = RGBAColor::from_rgb(255, 228, 225);
+    pub const MOCCASIN: RGBAColor = RGBAColor::from_rgb(255, 228, 181);
+    pub const NAVAJO_WHITE: RGBAColor = RGBAColor::from_rgb(255, 222, 173);
+    pub const NAVY: RGBAColor = RGBAColor::from_rgb(0, 0, 128);
+    pub const OLD_LACE: RGBAColor = RGBAColor::from_rgb(253, 245, 230);
+    pub const OLIVE: RGBAColor = RGBAColor::from_rgb(128, 128, 0);
+    pub const OLIVE_DRAB: RGBAColor = RGBAColor::from_rgb(107, 142, 35);
+    pub const ORANGE: RGBAColor = RGBAColor::from_rgb(255, 165, 0);
+    pub const ORANGE_RED: RGBAColor = RGBAColor::from_rgb(255, 69, 0);
+    pub const ORCHID: RGBAColor = RGBAColor::from_rgb(218, 112, 214);
+    pub const PALE_GOLDENROD: RGBAColor = RGBAColor::from_rgb(238, 232, 170);
+    pub const PALE_GREEN: RGBAColor = RGBAColor::from_rgb(152, 251, 152);
+    pub const PALE_TURQUOISE: RGBAColor = RGBAColor::from_rgb(175, 238, 238);
+    pub const PALE_VIOLET_RED: RGBAColor = RGBAColor::from_rgb(219, 112, 147);
+    pub const PAPAYA_WHIP: RGBAColor = RGBAColor::from_rgb(255, 239, 213);
+    pub const PEACH_PUFF: RGBAColor = RGBAColor::from_rgb(255, 218, 185);
+    pub const PERU: RGBAColor = RGBAColor::from_rgb(205, 133, 63);
+    pub const PINK: RGBAColor = RGBAColor::from_rgb(255, 192, 203);
+    pub const PLUM: RGBAColor = RGBAColor::from_rgb(221, 160, 221);
+    pub const POWDER_BLUE: RGBAColor = RGBAColor::from_rgb(176, 224, 230);
+    pub const PURPLE: RGBAColor = RGBAColor::from_rgb(128, 0, 128);
+    pub const REBECCA_PURPLE: RGBAColor = RGBAColor::from_rgb(102, 51, 153);
+    pub const ROSY_BROWN: RGBAColor = RGBAColor::from_rgb(188, 143, 143);
+    pub const ROYAL_BLUE: RGBAColor = RGBAColor::from_rgb(65, 105, 225);
+    pub const SADDLE_BROWN: RGBAColor = RGBAColor::from_rgb(139, 69, 19);
+    pub const SALMON: RGBAColor = RGBAColor::from_rgb(250, 128, 114);
+    pub const SANDY_BROWN: RGBAColor = RGBAColor::from_rgb(244, 164, 96);
+    pub const SEA_GREEN: RGBAColor = RGBAColor::from_rgb(46, 139, 87);
+    pub const SEASHELL: RGBAColor = RGBAColor::from_rgb(255, 245, 238);
+    pub const SIENNA: RGBAColor = RGBAColor::from_rgb(160, 82, 45);
+    pub const SILVER: RGBAColor = RGBAColor::from_rgb(192, 192, 192);
+    pub const SKY_BLUE: RGBAColor = RGBAColor::from_rgb(135, 206, 235);
+    pub const SLATE_BLUE: RGBAColor = RGBAColor::from_rgb(106, 90, 205);
+    pub const SLATE_GRAY: RGBAColor = RGBAColor::from_rgb(112, 128, 144);
+    pub const SLATE_GREY: RGBAColor = RGBAColor::from_rgb(112, 128, 144);
+    pub const SNOW: RGBAColor = RGBAColor::from_rgb(255, 250, 250);
+    pub const SPRING_GREEN: RGBAColor = RGBAColor::from_rgb(0, 255, 127);
+    pub const STEEL_BLUE: RGBAColor = RGBAColor::from_rgb(70, 130, 180);
+    pub const TAN: RGBAColor = RGBAColor::from_rgb(210, 180, 140);
+    pub const TEAL: RGBAColor = RGBAColor::from_rgb(0, 128, 128);
+    pub const THISTLE: RGBAColor = RGBAColor::from_rgb(216, 191, 216);
+    pub const TOMATO: RGBAColor = RGBAColor::from_rgb(255, 99, 71);
+    pub const TURQUOISE: RGBAColor = RGBAColor::from_rgb(64, 224, 208);
+    pub const VIOLET: RGBAColor = RGBAColor::from_rgb(238, 130, 238);
+    pub const WHEAT: RGBAColor = RGBAColor::from_rgb(245, 222, 179);
+    pub const WHITE_SMOKE: RGBAColor = RGBAColor::from_rgb(245, 245, 245);
+    pub const YELLOW_GREEN: RGBAColor = RGBAColor::from_rgb(154, 205, 50);
+
+    /// Looks up one of the named colors of CSS, ignoring case, including
+    /// `transparent`
+    ///
+    /// ```
+    /// use farba::RGBAColor;
+    ///
+    /// let color = RGBAColor::from_name("CornflowerBlue").unwrap();
+    /// assert_eq!((color.red, color.green, color.blue), (100, 149, 237));
+    ///
+    /// assert!(RGBAColor::from_name("not a color").is_none());
+    /// ```
+    pub fn from_name(name: &str) -> Option<RGBAColor> {
+        NAMED_COLORS
+            .iter()
+            .find(|(color_name, _)| color_name.eq_ignore_ascii_case(name))
+            .map(|(_, color)| color.clone())
+    }
+}
+
+/// Every named color of CSS, alphabetically
+const NAMED_COLORS: [(&str, RGBAColor); 149] = [
+    ("aliceblue", RGBAColor::ALICE_BLUE),
+    ("antiquewhite", RGBAColor::ANTIQUE_WHITE),
+    ("aqua", RGBAColor::AQUA),
+    ("aquamarine", RGBAColor::AQUAMARINE),
+    ("azure", RGBAColor::AZURE),
+    ("beige", RGBAColor::BEIGE),
+    ("bisque", RGBAColor::BISQUE),
+    ("black", RGBAColor::BLACK),
+    ("blanchedalmond", RGBAColor::BLANCHED_ALMOND),
+    ("blue", RGBAColor::BLUE),
+    ("blueviolet", RGBAColor::BLUE_VIOLET),
+    ("brown", RGBAColor::BROWN),
+    ("burlywood", RGBAColor::BURLY_WOOD),
+    ("cadetblue", RGBAColor::CADET_BLUE),
+    ("chartreuse", RGBAColor::CHARTREUSE),
+    ("chocolate", RGBAColor::CHOCOLATE),
+    ("coral", RGBAColor::CORAL),
+    ("cornflowerblue", RGBAColor::CORNFLOWER_BLUE),
+    ("cornsilk", RGBAColor::CORNSILK),
+    ("crimson", RGBAColor::CRIMSON),
+    ("cyan", RGBAColor::CYAN),
+    ("darkblue", RGBAColor::DARK_BLUE),
+    ("darkcyan", RGBAColor::DARK_CYAN),
+    ("darkgoldenrod", RGBAColor::DARK_GOLDENROD),
+    ("darkgray", RGBAColor::DARK_GRAY),
+    ("darkgreen", RGBAColor::DARK_GREEN),
+    ("darkgrey", RGBAColor::DARK_GREY),
+    ("darkkhaki", RGBAColor::DARK_KHAKI),
+    ("darkmagenta", RGBAColor::DARK_MAGENTA),
+    ("darkolivegreen", RGBAColor::DARK_OLIVE_GREEN),
+    ("darkorange", RGBAColor::DARK_ORANGE),
+    ("darkorchid", RGBAColor::DARK_ORCHID),
+    ("darkred", RGBAColor::DARK_RED),
+    ("darksalmon", RGBAColor::DARK_SALMON),
+    ("darkseagreen", RGBAColor::DARK_SEA_GREEN),
+    ("darkslateblue", RGBAColor::DARK_SLATE_BLUE),
+    ("darkslategray", RGBAColor::DARK_SLATE_GRAY),
+    ("darkslategrey", RGBAColor::DARK_SLATE_GREY),
+    ("darkturquoise", RGBAColor::DARK_TURQUOISE),
+    ("darkviolet", RGBAColor::DARK_VIOLET),
+    ("deeppink", RGBAColor::DEEP_PINK),
+    ("deepskyblue", RGBAColor::DEEP_SKY_BLUE),
+    ("dimgray", RGBAColor::DIM_GRAY),
+    ("dimgrey", RGBAColor::DIM_GREY),
+    ("dodgerblue", RGBAColor::DODGER_BLUE),
+    ("firebrick", RGBAColor::FIREBRICK),
+    ("floralwhite", RGBAColor::FLORAL_WHITE),
+    ("forestgreen", RGBAColor::FOREST_GREEN),
+    ("fuchsia", RGBAColor::FUCHSIA),
+    ("gainsboro", RGBAColor::GAINSBORO),
+    ("ghostwhite", RGBAColor::GHOST_WHITE),
+    ("gold", RGBAColor::GOLD),
+    ("goldenrod", RGBAColor::GOLDENROD),
+    ("gray", RGBAColor::GRAY),
+    ("green", RGBAColor::from_rgb(0, 128, 0)),
+    ("greenyellow", RGBAColor::GREEN_YELLOW),
+    ("grey", RGBAColor::GREY),
+    ("honeydew", RGBAColor::HONEYDEW),
+    ("hotpink", RGBAColor::HOT_PINK),
+    ("indianred", RGBAColor::INDIAN_RED),
+    ("indigo", RGBAColor::INDIGO),
+    ("ivory", RGBAColor::IVORY),
+    ("khaki", RGBAColor::KHAKI),
+    ("lavender", RGBAColor::LAVENDER),
+    ("lavenderblush", RGBAColor::LAVENDER_BLUSH),
+    ("lawngreen", RGBAColor::LAWN_GREEN),
+    ("lemonchiffon", RGBAColor::LEMON_CHIFFON),
+    ("lightblue", RGBAColor::LIGHT_BLUE),
+    ("lightcoral", RGBAColor::LIGHT_CORAL),
+    ("lightcyan", RGBAColor::LIGHT_CYAN),
+    ("lightgoldenrodyellow", RGBAColor::LIGHT_GOLDENROD_YELLOW),
+    ("lightgray", RGBAColor::LIGHT_GRAY),
+    ("lightgreen", RGBAColor::LIGHT_GREEN),
+    ("lightgrey", RGBAColor::LIGHT_GREY),
+    ("lightpink", RGBAColor::LIGHT_PINK),
+    ("lightsalmon", RGBAColor::LIGHT_SALMON),
+    ("lightseagreen", RGBAColor::LIGHT_SEA_GREEN),
+    ("lightskyblue", RGBAColor::LIGHT_SKY_BLUE),
+    ("lightslategray", RGBAColor::LIGHT_SLATE_GRAY),
+    ("lightslategrey", RGBAColor::LIGHT_SLATE_GREY),
+    ("lightsteelblue", RGBAColor::LIGHT_STEEL_BLUE),
+    ("lightyellow", RGBAColor::LIGHT_YELLOW),
+    ("lime", RGBAColor::LIME),
+    ("limegreen", RGBAColor::LIME_GREEN),
+    ("linen", RGBAColor::LINEN),
+    ("magenta", RGBAColor::MAGENTA),
+    ("maroon", RGBAColor::MAROON),
+    ("mediumaquamarine", RGBAColor::MEDIUM_AQUAMARINE),
+    ("mediumblue", RGBAColor::MEDIUM_BLUE),
+    ("mediumorchid", RGBAColor::MEDIUM_ORCHID),
+    ("mediumpurple", RGBAColor::MEDIUM_PURPLE),
+    ("mediumseagreen", RGBAColor::MEDIUM_SEA_GREEN),
+    ("mediumslateblue", RGBAColor::MEDIUM_SLATE_BLUE),
+    ("mediumspringgreen", RGBAColor::MEDIUM_SPRING_GREEN),
+    ("mediumturquoise", RGBAColor::MEDIUM_TURQUOISE),
+    ("mediumvioletred", RGBAColor::MEDIUM_VIOLET_RED),
+    ("midnightblue", RGBAColor::MIDNIGHT_BLUE),
+    ("mintcream", RGBAColor::MINT_CREAM),
+    ("mistyrose", RGBAColor::MISTY_ROSE),
+    ("moccasin", RGBAColor::MOCCASIN),
+    ("navajowhite", RGBAColor::NAVAJO_WHITE),
+    ("navy", RGBAColor::NAVY),
+    ("oldlace", RGBAColor::OLD_LACE),
+    ("olive", RGBAColor::OLIVE),
+    ("olivedrab", RGBAColor::OLIVE_DRAB),
+    ("orange", RGBAColor::ORANGE),
+    ("orangered", RGBAColor::ORANGE_RED),
+    ("orchid", RGBAColor::ORCHID),
+    ("palegoldenrod", RGBAColor::PALE_GOLDENROD),
+    ("palegreen", RGBAColor::PALE_GREEN),
+    ("paleturquoise", RGBAColor::PALE_TURQUOISE),
+    ("palevioletred", RGBAColor::PALE_VIOLET_RED),
+    ("papayawhip", RGBAColor::PAPAYA_WHIP),
+    ("peachpuff", RGBAColor::PEACH_PUFF),
+    ("peru", RGBAColor::PERU),
+    ("pink", RGBAColor::PINK),
+    ("plum", RGBAColor::PLUM),
+    ("powderblue", RGBAColor::POWDER_BLUE),
+    ("purple", RGBAColor::PURPLE),
+    ("rebeccapurple", RGBAColor::REBECCA_PURPLE),
+    ("red", RGBAColor::RED),
+    ("rosybrown", RGBAColor::ROSY_BROWN),
+    ("royalblue", RGBAColor::ROYAL_BLUE),
+    ("saddlebrown", RGBAColor::SADDLE_BROWN),
+    ("salmon", RGBAColor::SALMON),
+    ("sandybrown", RGBAColor::SANDY_BROWN),
+    ("seagreen", RGBAColor::SEA_GREEN),
+    ("seashell", RGBAColor::SEASHELL),
+    ("sienna", RGBAColor::SIENNA),
+    ("silver", RGBAColor::SILVER),
+    ("skyblue", RGBAColor::SKY_BLUE),
+    ("slateblue", RGBAColor::SLATE_BLUE),
+    ("slategray", RGBAColor::SLATE_GRAY),
+    ("slategrey", RGBAColor::SLATE_GREY),
+    ("snow", RGBAColor::SNOW),
+    ("springgreen", RGBAColor::SPRING_GREEN),
+    ("steelblue", RGBAColor::STEEL_BLUE),
+    ("tan", RGBAColor::TAN),
+    ("teal", RGBAColor::TEAL),
+    ("thistle", RGBAColor::THISTLE),
+    ("tomato", RGBAColor::TOMATO),
+    ("transparent", RGBAColor::TRANSPARENT),
+    ("turquoise", RGBAColor::TURQUOISE),
+    ("violet", RGBAColor::VIOLET),
+    ("wheat", RGBAColor::WHEAT),
+    ("white", RGBAColor::WHITE),
+    ("whitesmoke", RGBAColor::WHITE_SMOKE),
+    ("yellow", RGBAColor::YELLOW),
+    ("yellowgreen", RGBAColor::YELLOW_GREEN),
+];
@@ -0,0 +1,317 @@
+use crate::{interpolation::lerp, normalize_rect, Canvas, Colormap};
+
+/// The kind of noise sampled by a [`Noise`] generator
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum NoiseKind {
+    /// Random values on a grid blended smoothly together, which looks blocky
+    /// along the grid lines
+    Value,
+    /// Random slopes on a grid blended smoothly together, the classic look of
+    /// clouds and marble
+    #[default]
+    Perlin,
+    /// Like Perlin noise, but built on a grid of triangles, which looks the
+    /// same in every direction
+    Simplex,
+}
+
+/// A seeded generator of smooth 2D noise, for procedural textures and terrain
+///
+/// Every kind of noise returns values from `0` to `1` which change smoothly with
+/// the position, repeating every `256` units. Whole positions fall on the grid
+/// the noise is built on, so positions are usually scaled down to get features
+/// several pixels across. The same seed always gives the same noise.
+///
+/// ```
+/// use farba::{Noise, NoiseKind};
+///
+/// let noise = Noise::new(42);
+///
+/// for kind in [NoiseKind::Value, NoiseKind::Perlin, NoiseKind::Simplex] {
+///     let value = noise.sample(kind, 3.7, 1.2);
+///     assert!((0.0..=1.0).contains(&value));
+///
+///     // Nearby positions give nearby values
+///     assert!((noise.sample(kind, 3.701, 1.2) - value).abs() < 0.01);
+/// }
+///
+/// assert_eq!(Noise::new(42).perlin(3.7, 1.2), noise.perlin(3.7, 1.2));
+/// assert_ne!(Noise::new(7).perlin(3.7, 1.2), noise.perlin(3.7, 1.2));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Noise {
+    seed: u64,
+    /// A shuffle of `0..256` written out twice, so that hashes can be chained
+    /// without wrapping
+    permutation: [u8; 512],
+}
+
+/// Directions of the slopes at each grid point, for Perlin and simplex noise
+const GRADIENTS: [(f32, f32); 8] = [
+    (1.0, 0.0),
+    (-1.0, 0.0),
+    (0.0, 1.0),
+    (0.0, -1.0),
+    (
+        std::f32::consts::FRAC_1_SQRT_2,
+        std::f32::consts::FRAC_1_SQRT_2,
+    ),
+    (
+        -std::f32::consts::FRAC_1_SQRT_2,
+        std::f32::consts::FRAC_1_SQRT_2,
+    ),
+    (
+        std::f32::consts::FRAC_1_SQRT_2,
+        -std::f32::consts::FRAC_1_SQRT_2,
+    ),
+    (
+        -std::f32::consts::FRAC_1_SQRT_2,
+        -std::f32::consts::FRAC_1_SQRT_2,
+    ),
+];
+
+/// Eases `t` from `0` to `1` with a curve whose slope and curvature are zero at
+/// both ends, hiding the grid the noise is built on
+#[inline]
+fn fade(t: f32) -> f32 {
+    t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+}
+
+impl Noise {
+    pub fn new(seed: u64) -> Self {
+        let mut table: [u8; 256] = std::array::from_fn(|i| i as u8);
+
+        // Shuffle the table with splitmix64 as the source of randomness
+        let mut state = seed;
+        let mut next = || {
+            state = state.wrapping_add(0x9E3779B97F4A7C15);
+
+            let mut z = state;
+            z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+            z ^ (z >> 31)
+        };
+
+        for i in (1..table.len()).rev() {
+            let j = (next() % (i as u64 + 1)) as usize;
+            table.swap(i, j);
+        }
+
+        Self {
+            seed,
+            permutation: std::array::from_fn(|i| table[i % 256]),
+        }
+    }
+
+    pub fn get_seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Hashes a grid point to a number from `0` to `255`
+    ///
+    /// Only the lowest 8 bits of each coordinate are used, so grid points can
+    /// wrap around `i32` and still give the same noise.
+    #[inline]
+    fn hash(&self, x: i32, y: i32) -> u8 {
+        let x = self.permutation[(x & 255) as usize] as usize;
+
+        self.permutation[x + (y & 255) as usize]
+    }
+
+    /// Gets the slope at a grid point dotted with the offset `(dx, dy)` from it
+    #[inline]
+    fn gradient(&self, x: i32, y: i32, dx: f32, dy: f32) -> f32 {
+        let (gx, gy) = GRADIENTS[(self.hash(x, y) & 7) as usize];
+
+        gx * dx + gy * dy
+    }
+
+    /// Samples the noise of the given kind at `(x, y)`
+    pub fn sample(&self, kind: NoiseKind, x: f32, y: f32) -> f32 {
+        match kind {
+            NoiseKind::Value => self.value(x, y),
+            NoiseKind::Perlin => self.perlin(x, y),
+            NoiseKind::Simplex => self.simplex(x, y),
+        }
+    }
+
+    /// Samples value noise at `(x, y)`
+    pub fn value(&self, x: f32, y: f32) -> f32 {
+        let (x0, y0) = (x.floor(), y.floor());
+        let (ix, iy) = (x0 as i32, y0 as i32);
+        let (u, v) = (fade(x - x0), fade(y - y0));
+
+        let corner =
+            |dx: i32, dy: i32| self.hash(ix.wrapping_add(dx), iy.wrapping_add(dy)) as f32 / 255.0;
+
+        lerp(
+            v,
+            lerp(u, corner(0, 0), corner(1, 0)),
+            lerp(u, corner(0, 1), corner(1, 1)),
+        )
+    }
+
+    /// Samples Perlin noise at `(x, y)`
+    pub fn perlin(&self, x: f32, y: f32) -> f32 {
+        let (x0, y0) = (x.floor(), y.floor());
+        let (ix, iy) = (x0 as i32, y0 as i32);
+        let (fx, fy) = (x - x0, y - y0);
+        let (u, v) = (fade(fx), fade(fy));
+
+        let corner = |dx: i32, dy: i32| {
+            self.gradient(
+                ix.wrapping_add(dx),
+                iy.wrapping_add(dy),
+                fx - dx as f32,
+                fy - dy as f32,
+            )
+        };
+
+        let value = lerp(
+            v,
+            lerp(u, corner(0, 0), corner(1, 0)),
+            lerp(u, corner(0, 1), corner(1, 1)),
+        );
+
+        // With unit slopes the noise stays within `±1/√2`
+        (value * std::f32::consts::SQRT_2 * 0.5 + 0.5).clamp(0.0, 1.0)
+    }
+
+    /// Samples simplex noise at `(x, y)`
+    pub fn simplex(&self, x: f32, y: f32) -> f32 {
+        // Factors for skewing the square grid into triangles and back
+        let skew = 0.5 * (3.0f32.sqrt() - 1.0);
+        let unskew = (3.0 - 3.0f32.sqrt()) / 6.0;
+
+        let s = (x + y) * skew;
+        let (i, j) = ((x + s).floor(), (y + s).floor());
+
+        let t = (i + j) * unskew;
+        let (x0, y0) = (x - (i - t), y - (j - t));
+
+        // Which of the two triangles of the skewed square the point is in
+        let (i1, j1) = if x0 > y0 { (1, 0) } else { (0, 1) };
+
+        let corners = [
+            (0, 0, x0, y0),
+            (i1, j1, x0 - i1 as f32 + unskew, y0 - j1 as f32 + unskew),
+            (1, 1, x0 - 1.0 + 2.0 * unskew, y0 - 1.0 + 2.0 * unskew),
+        ];
+
+        let (i, j) = (i as i32, j as i32);
+
+        let value: f32 = corners
+            .iter()
+            .map(|&(di, dj, dx, dy)| {
+                let falloff = 0.5 - dx * dx - dy * dy;
+
+                if falloff <= 0.0 {
+                    0.0
+                } else {
+                    falloff.powi(4) * self.gradient(i.wrapping_add(di), j.wrapping_add(dj), dx, dy)
+                }
+            })
+            .sum();
+
+        // With unit slopes the sum stays within about `±1/70√2`
+        (value * 35.0 * std::f32::consts::SQRT_2 + 0.5).clamp(0.0, 1.0)
+    }
+
+    /// Samples fractal Brownian motion at `(x, y)`, which layers `octaves` copies
+    /// of the noise at doubling frequencies and halving strengths for finer and
+    /// finer detail
+    ///
+    /// The layers are averaged, so the result stays between `0` and `1`, while
+    /// zero octaves give `0.5`.
+    ///
+    /// ```
+    /// use farba::{Noise, NoiseKind};
+    ///
+    /// let noise = Noise::new(1);
+    ///
+    /// // A single octave is just the noise itself
+    /// assert_eq!(noise.fbm(NoiseKind::Simplex, 2.5, 0.5, 1), noise.simplex(2.5, 0.5));
+    ///
+    /// let terrain = noise.fbm(NoiseKind::Perlin, 2.5, 0.5, 6);
+    /// assert!((0.0..=1.0).contains(&terrain));
+    ///
+    /// // Very fine octaves land far outside of `i32`, but still give noise
+    /// let fine = noise.fbm(NoiseKind::Perlin, 0.5, 0.5, 40);
+    /// assert!((0.0..=1.0).contains(&fine));
+    /// ```
+    pub fn fbm(&self, kind: NoiseKind, x: f32, y: f32, octaves: u32) -> f32 {
+        let (mut sum, mut total) = (0.0, 0.0);
+        let (mut frequency, mut amplitude) = (1.0, 1.0);
+
+        for octave in 0..octaves {
+            // Shift each octave so that their grids don't line up at the origin
+            let offset = octave as f32 * 17.31;
+
+            sum += amplitude * self.sample(kind, x * frequency + offset, y * frequency + offset);
+            total += amplitude;
+
+            frequency *= 2.0;
+            amplitude *= 0.5;
+        }
+
+        if total == 0.0 {
+            0.5
+        } else {
+            sum / total
+        }
+    }
+}
+
+impl Canvas {
+    /// Fills `dst_rect` as `(x, y, width, height)` with noise colored through
+    /// `colormap`
+    ///
+    /// The noise is sampled at the center of each pixel with fractal Brownian
+    /// motion of `octaves` layers, where `scale` is the size in pixels of the
+    /// coarsest features. Sampling is relative to the top left corner of the
+    /// rectangle, so the same noise always looks the same wherever it is
+    /// drawn. Like blits, the rectangle is placed in the coordinates of the
+    /// canvas without going through the current transform.
+    ///
+    /// ```
+    /// use farba::{Canvas, Colormap, Noise, NoiseKind};
+    ///
+    /// let noise = Noise::new(1234);
+    ///
+    /// let mut canvas = Canvas::new(64, 64);
+    /// canvas.noise(&noise, NoiseKind::Perlin, 16.0, 4, Colormap::Grayscale, (0, 0, 64, 64));
+    ///
+    /// let pixel = canvas.get_pixel(10, 20);
+    /// let value = noise.fbm(NoiseKind::Perlin, 10.5 / 16.0, 20.5 / 16.0, 4);
+    /// assert_eq!(*pixel, Colormap::Grayscale.sample(value).into());
+    /// ```
+    pub fn noise(
+        &mut self,
+        noise: &Noise,
+        kind: NoiseKind,
+        scale: f32,
+        octaves: u32,
+        colormap: Colormap,
+        dst_rect: (i32, i32, i32, i32),
+    ) {
+        let (canvas_width, canvas_height) = (self.get_width() as i32, self.get_height() as i32);
+
+        let (x, y, width, height) = dst_rect;
+        let Some(dest) = normalize_rect(x, y, width, height, canvas_width, canvas_height) else {
+            return;
+        };
+
+        let (left, top, right, bottom) = self.clip_bounds();
+
+        for y in dest.y1.max(top)..=dest.y2.min(bottom) {
+            let noise_y = ((y - dest.orig_y1) as f32 + 0.5) / scale;
+
+            for x in dest.x1.max(left)..=dest.x2.min(right) {
+                let noise_x = ((x - dest.orig_x1) as f32 + 0.5) / scale;
+                let value = noise.fbm(kind, noise_x, noise_y, octaves);
+
+                self.write_pixel(x, y, colormap.sample_packed(value));
+            }
+        }
+    }
+}
@@ -12,9 +12,7 @@ pub struct NormalizedRect {
 
 macro_rules! swap {
     ($a:expr, $b:expr) => {
-        let tmp = $a;
-        $a = $b;
-        $b = tmp;
+        std::mem::swap(&mut $a, &mut $b);
     };
 }
 
@@ -98,7 +96,7 @@ pub fn normalize_rect(
         nr.y2 = canvas_height - 1;
     }
 
-    return Some(nr);
+    Some(nr)
 }
 
 #[derive(Debug, Default)]
@@ -112,6 +110,7 @@ pub struct NormalizedTriangle {
 /// The point of this function is to produce two ranges `left_x..=right_x` and
 ///  `top_y..=bottom_y` that are guaranteed to be safe to iterate over the
 /// canvas of size `width` by `height` without any boundary checks.
+#[allow(clippy::too_many_arguments)]
 pub fn normalize_triangle(
     width: usize,
     height: usize,
@@ -122,11 +121,14 @@ pub fn normalize_triangle(
     x3: i32,
     y3: i32,
 ) -> Option<NormalizedTriangle> {
-    let mut nt = NormalizedTriangle::default();
+    let mut nt = NormalizedTriangle {
+        left_x: x1,
+        right_x: x1,
+        top_y: y1,
+        bottom_y: y1,
+    };
 
     // Normalize the x bounds of the triangle
-    nt.left_x = x1;
-    nt.right_x = x1;
 
     if nt.left_x > x2 {
         nt.left_x = x2;
@@ -156,9 +158,6 @@ pub fn normalize_triangle(
     }
 
     // Normalize the y bounds of the triangle
-    nt.top_y = y1;
-    nt.bottom_y = y1;
-
     if nt.top_y > y2 {
         nt.top_y = y2;
     }
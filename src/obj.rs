@@ -0,0 +1,317 @@
+use std::{collections::HashMap, fmt, path::Path};
+
+use crate::{Material, MaterialError, Mesh, Submesh, Vec2, Vec3};
+
+/// Errors that can occur while loading a Wavefront OBJ file into a [`Mesh`]
+#[derive(Debug)]
+pub enum ObjError {
+    /// A line of the file could not be understood, counting from 1
+    InvalidObj {
+        line: usize,
+    },
+    /// A material library named by the file could not be loaded
+    Material(MaterialError),
+    Io(std::io::Error),
+}
+
+impl fmt::Display for ObjError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ObjError::InvalidObj { line } => write!(f, "invalid OBJ file on line {line}"),
+            ObjError::Material(e) => write!(f, "{e}"),
+            ObjError::Io(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl std::error::Error for ObjError {}
+
+impl From<std::io::Error> for ObjError {
+    fn from(value: std::io::Error) -> Self {
+        Self::Io(value)
+    }
+}
+
+impl From<MaterialError> for ObjError {
+    fn from(value: MaterialError) -> Self {
+        Self::Material(value)
+    }
+}
+
+impl Mesh {
+    /// Loads the Wavefront OBJ file at `path`, along with the materials of the
+    /// MTL files it names with `mtllib`
+    ///
+    /// Material libraries are looked for relative to the folder holding the
+    /// file. Materials used by the file but missing from its libraries keep
+    /// the default look.
+    pub fn load_obj<P: AsRef<Path>>(path: P) -> Result<Mesh, ObjError> {
+        let path = path.as_ref();
+        let (mut mesh, libraries) = read_obj(&std::fs::read_to_string(path)?)?;
+
+        let folder = path.parent().unwrap_or(Path::new(""));
+
+        for library in libraries {
+            for material in Material::load_mtl(folder.join(library))? {
+                if let Some(used) = mesh.materials.iter_mut().find(|m| m.name == material.name) {
+                    *used = material;
+                }
+            }
+        }
+
+        Ok(mesh)
+    }
+
+    /// Reads a Wavefront OBJ file, without loading its material libraries
+    ///
+    /// Faces are split into triangles fanning out from their first corner.
+    /// Every distinct combination of position, texture coordinates and normal
+    /// used by a corner becomes a vertex. Normals are computed if some corners
+    /// don't have one. Each object or group (`o` or `g`) becomes a [`Submesh`].
+    ///
+    /// Every material chosen with `usemtl` is added to the mesh with only its
+    /// name, and each triangle gets the index of the material in use at the
+    /// time. Triangles before the first `usemtl` get a default material.
+    ///
+    /// OBJ is right handed, so the mesh is mirrored with [`Mesh::mirror_z`],
+    /// and texture coordinates are flipped to start at the top of the texture.
+    ///
+    /// ```
+    /// use farba::{Mesh, Vec3};
+    ///
+    /// let mesh = Mesh::from_obj(
+    ///     "mtllib square.mtl
+    ///      o square
+    ///      v 0 0 0
+    ///      v 1 0 0
+    ///      v 1 1 0
+    ///      v 0 1 0
+    ///      vn 0 0 1
+    ///
+    ///      usemtl red
+    ///      f 1//1 2//1 3//1
+    ///      usemtl blue
+    ///      f 1//1 3//1 4//1",
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(mesh.vertex_count(), 4);
+    /// assert_eq!(mesh.triangle_count(), 2);
+    /// assert_eq!(mesh.submeshes[0].name, "square");
+    /// assert_eq!(mesh.triangle_material(1).unwrap().name, "blue");
+    ///
+    /// // z is flipped, while the triangles keep facing the way the normals do
+    /// assert_eq!(mesh.normals.as_ref().unwrap()[0], Vec3::new(0.0, 0.0, -1.0));
+    /// assert_eq!(mesh.face_normal(0), Vec3::new(0.0, 0.0, -1.0));
+    /// ```
+    pub fn from_obj(source: &str) -> Result<Mesh, ObjError> {
+        Ok(read_obj(source)?.0)
+    }
+}
+
+/// Reads an OBJ file into a mesh, along with the names of its material
+/// libraries
+fn read_obj(source: &str) -> Result<(Mesh, Vec<String>), ObjError> {
+    let mut reader = ObjReader::default();
+    let mut libraries = Vec::new();
+
+    for (index, line) in source.lines().enumerate() {
+        let invalid = || ObjError::InvalidObj { line: index + 1 };
+
+        let line = line.split('#').next().unwrap_or_default();
+        let mut words = line.split_whitespace();
+
+        match words.next() {
+            Some("v") => reader
+                .positions
+                .push(read_vector(&mut words).ok_or_else(invalid)?),
+            Some("vt") => {
+                let mut value = || words.next().map(str::parse::<f32>);
+
+                let u = value().and_then(Result::ok).ok_or_else(invalid)?;
+                let v = value().unwrap_or(Ok(0.0)).map_err(|_| invalid())?;
+
+                reader.uvs.push(Vec2::new(u, 1.0 - v));
+            }
+            Some("vn") => reader
+                .normals
+                .push(read_vector(&mut words).ok_or_else(invalid)?),
+            Some("f") => reader.face(words).ok_or_else(invalid)?,
+            Some("usemtl") => reader.use_material(words.collect::<Vec<_>>().join(" ")),
+            Some("mtllib") => libraries.extend(words.map(str::to_string)),
+            Some("o" | "g") => reader.start_submesh(words.collect::<Vec<_>>().join(" ")),
+            _ => {}
+        }
+    }
+
+    Ok((reader.finish(), libraries))
+}
+
+/// The state of an OBJ file while it is being read
+#[derive(Default)]
+struct ObjReader {
+    positions: Vec<Vec3>,
+    uvs: Vec<Vec2>,
+    normals: Vec<Vec3>,
+    mesh: Mesh,
+    mesh_uvs: Vec<Vec2>,
+    mesh_normals: Vec<Vec3>,
+    has_uvs: bool,
+    /// Whether any corner was given without a normal
+    missing_normals: bool,
+    /// The mesh vertex made for each combination of OBJ indices
+    vertices: HashMap<(usize, Option<usize>, Option<usize>), u32>,
+    material: Option<usize>,
+    material_indices: Vec<Option<usize>>,
+    /// The name and first triangle of the current object or group
+    submesh: Option<(String, usize)>,
+}
+
+impl ObjReader {
+    /// Adds the triangles of a face, whose corners are given as
+    /// `position/uv/normal` indices with the last two optional
+    fn face<'a>(&mut self, words: impl Iterator<Item = &'a str>) -> Option<()> {
+        let corners = words
+            .map(|word| self.vertex(word))
+            .collect::<Option<Vec<u32>>>()?;
+
+        if corners.len() < 3 {
+            return None;
+        }
+
+        for pair in corners[1..].windows(2) {
+            self.mesh.indices.push([corners[0], pair[0], pair[1]]);
+            self.material_indices.push(self.material);
+        }
+
+        Some(())
+    }
+
+    /// Gets the mesh vertex for a face corner, adding it if it is new
+    fn vertex(&mut self, word: &str) -> Option<u32> {
+        let mut parts = word.split('/');
+
+        let position = resolve_index(parts.next()?, self.positions.len())?;
+
+        let mut optional = |count: usize| match parts.next() {
+            None | Some("") => Some(None),
+            Some(part) => resolve_index(part, count).map(Some),
+        };
+
+        let uv = optional(self.uvs.len())?;
+        let normal = optional(self.normals.len())?;
+
+        let key = (position, uv, normal);
+
+        if let Some(&vertex) = self.vertices.get(&key) {
+            return Some(vertex);
+        }
+
+        let vertex = self.mesh.positions.len() as u32;
+
+        self.mesh.positions.push(self.positions[position]);
+        self.mesh_uvs.push(uv.map_or(Vec2::ZERO, |uv| self.uvs[uv]));
+        self.mesh_normals
+            .push(normal.map_or(Vec3::ZERO, |normal| self.normals[normal]));
+
+        self.has_uvs |= uv.is_some();
+        self.missing_normals |= normal.is_none();
+
+        self.vertices.insert(key, vertex);
+
+        Some(vertex)
+    }
+
+    fn use_material(&mut self, name: String) {
+        let materials = &mut self.mesh.materials;
+
+        let index = match materials.iter().position(|m| m.name == name) {
+            Some(index) => index,
+            None => {
+                materials.push(Material {
+                    name,
+                    ..Default::default()
+                });
+                materials.len() - 1
+            }
+        };
+
+        self.material = Some(index);
+    }
+
+    fn start_submesh(&mut self, name: String) {
+        self.end_submesh();
+        self.submesh = Some((name, self.mesh.indices.len()));
+    }
+
+    fn end_submesh(&mut self) {
+        if let Some((name, first)) = self.submesh.take() {
+            let triangles = first..self.mesh.indices.len();
+
+            if !triangles.is_empty() {
+                self.mesh.submeshes.push(Submesh { name, triangles });
+            }
+        }
+    }
+
+    fn finish(mut self) -> Mesh {
+        self.end_submesh();
+
+        let mut mesh = self.mesh;
+
+        if self.material_indices.iter().any(Option::is_some) {
+            let mut default_material = None;
+
+            let material_indices = self
+                .material_indices
+                .into_iter()
+                .map(|material| {
+                    material.unwrap_or_else(|| {
+                        *default_material.get_or_insert_with(|| {
+                            mesh.materials.push(Material::default());
+                            mesh.materials.len() - 1
+                        })
+                    })
+                })
+                .collect();
+
+            mesh.material_indices = Some(material_indices);
+        }
+
+        if self.has_uvs {
+            mesh.uvs = Some(self.mesh_uvs);
+        }
+
+        // A single corner without a normal means they are all computed, so
+        // that the mesh is shaded the same way throughout
+        if self.missing_normals {
+            mesh.compute_normals();
+        } else {
+            mesh.normals = Some(self.mesh_normals);
+        }
+
+        mesh.mirror_z();
+        mesh
+    }
+}
+
+/// Turns a 1 based OBJ index, or a negative one counting back from the end,
+/// into an index into a list of `count` values
+fn resolve_index(word: &str, count: usize) -> Option<usize> {
+    let index: i64 = word.parse().ok()?;
+
+    let index = match index {
+        0 => return None,
+        1.. => index - 1,
+        _ => count as i64 + index,
+    };
+
+    (0..count as i64).contains(&index).then_some(index as usize)
+}
+
+/// Reads the next three words as the components of a vector
+pub(crate) fn read_vector<'a, I: Iterator<Item = &'a str>>(words: &mut I) -> Option<Vec3> {
+    let mut value = || words.next()?.parse().ok();
+
+    Some(Vec3::new(value()?, value()?, value()?))
+}
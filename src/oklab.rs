@@ -0,0 +1,185 @@
+use crate::{interpolation::lerp, linear_to_srgb, rgba, srgb_to_linear, Color, RGBAColor};
+
+/// A color in the OKLab color space, where equal steps look like equal changes
+/// in color to the eye
+///
+/// `l` is the lightness from `0` (black) to `1` (white), while `a` and `b` run
+/// from green to red and from blue to yellow, staying within about `±0.4` for
+/// colors sRGB can show.
+///
+/// ```
+/// use farba::{Oklab, RGBAColor};
+///
+/// let white = Oklab::from_color(RGBAColor::WHITE);
+/// assert!((white.l - 1.0).abs() < 1e-3);
+/// assert!(white.a.abs() < 1e-3 && white.b.abs() < 1e-3);
+///
+/// let orange = Oklab::from_color(RGBAColor::from_rgb(255, 128, 0));
+/// let back = orange.to_color(255);
+/// assert_eq!((back.red, back.green, back.blue), (255, 128, 0));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Oklab {
+    pub l: f32,
+    pub a: f32,
+    pub b: f32,
+}
+
+/// A color in the cylindrical form of [`Oklab`], as lightness, chroma and hue
+///
+/// `c` is how colorful the color is, and `h` is the angle of its hue in radians,
+/// starting from pinkish red and turning towards yellow.
+///
+/// ```
+/// use farba::{ColorSpace, RGBAColor};
+///
+/// let srgb = RGBAColor::BLUE.mix(&RGBAColor::YELLOW, 0.5, ColorSpace::Srgb);
+/// let oklch = RGBAColor::BLUE.mix(&RGBAColor::YELLOW, 0.5, ColorSpace::Oklch);
+///
+/// // Opposite colors cancel out into gray in sRGB, but keep their color in OKLCH
+/// assert_eq!((srgb.red, srgb.green, srgb.blue), (128, 128, 128));
+/// assert_eq!((oklch.red, oklch.green, oklch.blue), (0, 207, 189));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Oklch {
+    pub l: f32,
+    pub c: f32,
+    pub h: f32,
+}
+
+impl Oklab {
+    pub const fn new(l: f32, a: f32, b: f32) -> Self {
+        Self { l, a, b }
+    }
+
+    /// Converts an sRGB color to OKLab, dropping its alpha
+    #[allow(clippy::excessive_precision)]
+    pub fn from_color<C: Color>(color: C) -> Self {
+        let r = srgb_to_linear(color.red());
+        let g = srgb_to_linear(color.green());
+        let b = srgb_to_linear(color.blue());
+
+        let l = (0.4122214708 * r + 0.5363325363 * g + 0.0514459929 * b).cbrt();
+        let m = (0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b).cbrt();
+        let s = (0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b).cbrt();
+
+        Self {
+            l: 0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s,
+            a: 1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s,
+            b: 0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s,
+        }
+    }
+
+    /// Converts the color back to sRGB with the given `alpha`, clamping any
+    /// channel sRGB can't show
+    pub fn to_color(&self, alpha: u8) -> RGBAColor {
+        self.pack(alpha).into()
+    }
+
+    /// Converts the color back to a packed sRGB color with the given `alpha`
+    #[allow(clippy::excessive_precision)]
+    pub(crate) fn pack(&self, alpha: u8) -> u32 {
+        let l = (self.l + 0.3963377774 * self.a + 0.2158037573 * self.b).powi(3);
+        let m = (self.l - 0.1055613458 * self.a - 0.0638541728 * self.b).powi(3);
+        let s = (self.l - 0.0894841775 * self.a - 1.2914855480 * self.b).powi(3);
+
+        rgba!(
+            linear_to_srgb(4.0767416621 * l - 3.3077115913 * m + 0.2309699292 * s),
+            linear_to_srgb(-1.2684380046 * l + 2.6097574011 * m - 0.3413193965 * s),
+            linear_to_srgb(-0.0041960863 * l - 0.7034186147 * m + 1.7076147010 * s),
+            alpha
+        )
+    }
+
+    pub fn to_oklch(&self) -> Oklch {
+        Oklch {
+            l: self.l,
+            c: self.a.hypot(self.b),
+            h: self.b.atan2(self.a),
+        }
+    }
+}
+
+impl Oklch {
+    pub const fn new(l: f32, c: f32, h: f32) -> Self {
+        Self { l, c, h }
+    }
+
+    /// Converts an sRGB color to OKLCH, dropping its alpha
+    pub fn from_color<C: Color>(color: C) -> Self {
+        Oklab::from_color(color).to_oklch()
+    }
+
+    /// Converts the color back to sRGB with the given `alpha`, clamping any
+    /// channel sRGB can't show
+    pub fn to_color(&self, alpha: u8) -> RGBAColor {
+        self.to_oklab().to_color(alpha)
+    }
+
+    pub fn to_oklab(&self) -> Oklab {
+        let (sin, cos) = self.h.sin_cos();
+
+        Oklab {
+            l: self.l,
+            a: self.c * cos,
+            b: self.c * sin,
+        }
+    }
+}
+
+impl From<Oklab> for Oklch {
+    fn from(color: Oklab) -> Self {
+        color.to_oklch()
+    }
+}
+
+impl From<Oklch> for Oklab {
+    fn from(color: Oklch) -> Self {
+        color.to_oklab()
+    }
+}
+
+/// Mixes two packed colors in OKLab, with alpha mixed linearly
+pub(crate) fn mix_oklab(a: u32, b: u32, t: f32) -> u32 {
+    let (from, to) = (Oklab::from_color(a), Oklab::from_color(b));
+
+    let mixed = Oklab {
+        l: lerp(t, from.l, to.l),
+        a: lerp(t, from.a, to.a),
+        b: lerp(t, from.b, to.b),
+    };
+
+    mixed.pack(lerp(t, a.alpha() as f32, b.alpha() as f32).round() as u8)
+}
+
+/// Mixes two packed colors in OKLCH, turning the hue the short way around, with
+/// alpha mixed linearly
+pub(crate) fn mix_oklch(a: u32, b: u32, t: f32) -> u32 {
+    use std::f32::consts::{PI, TAU};
+
+    /// Below this chroma a color is gray enough that its hue means nothing
+    const GRAY: f32 = 1e-3;
+
+    let (mut from, mut to) = (Oklch::from_color(a), Oklch::from_color(b));
+
+    // Grays take on the hue of the other color so they don't swing through
+    // an unrelated one on the way
+    if from.c < GRAY {
+        from.h = to.h;
+    }
+    if to.c < GRAY {
+        to.h = from.h;
+    }
+
+    let turn = (to.h - from.h + PI).rem_euclid(TAU) - PI;
+
+    let mixed = Oklch {
+        l: lerp(t, from.l, to.l),
+        c: lerp(t, from.c, to.c),
+        h: from.h + turn * t,
+    };
+
+    mixed
+        .to_oklab()
+        .pack(lerp(t, a.alpha() as f32, b.alpha() as f32).round() as u8)
+}
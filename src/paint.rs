@@ -0,0 +1,324 @@
+use crate::{mix_packed_in, Canvas, Color, ColorSpace, RGBAColor, Vec2};
+
+/// Something that can be used to fill the inside of a shape
+///
+/// Every [`Color`] is a paint which fills shapes with a single flat color, while
+/// gradients and patterns vary their color across the canvas.
+pub trait Paint {
+    /// Gets the packed color of the paint at the point `(x, y)` on the canvas
+    ///
+    /// Shapes sample paints at the center of each pixel, `(x + 0.5, y + 0.5)`
+    fn color_at(&self, x: f32, y: f32) -> u32;
+}
+
+impl<C: Color> Paint for C {
+    #[inline]
+    fn color_at(&self, _x: f32, _y: f32) -> u32 {
+        self.pack()
+    }
+}
+
+/// Finds the color at the position `t` along a list of gradient stops, which
+/// must be sorted by their offsets
+///
+/// Positions before the first stop or after the last one take on the color of
+/// that stop. Neighboring stops are mixed in the color `space`
+pub(crate) fn sample_stops(stops: &[(f32, RGBAColor)], space: ColorSpace, t: f32) -> u32 {
+    let (Some(first), Some(last)) = (stops.first(), stops.last()) else {
+        return 0;
+    };
+
+    if t <= first.0 {
+        return first.1.pack();
+    }
+    if t >= last.0 {
+        return last.1.pack();
+    }
+
+    for pair in stops.windows(2) {
+        let ((start, from), (end, to)) = (&pair[0], &pair[1]);
+
+        if t <= *end {
+            let span = end - start;
+
+            if span <= 0.0 {
+                return to.pack();
+            }
+
+            return mix_packed_in(space, from.pack(), to.pack(), (t - start) / span);
+        }
+    }
+
+    last.1.pack()
+}
+
+/// Sorts gradient stops by their offsets, keeping stops with equal offsets in
+/// the order they were given so they can form hard transitions
+fn sorted_stops(mut stops: Vec<(f32, RGBAColor)>) -> Vec<(f32, RGBAColor)> {
+    stops.sort_by(|a, b| a.0.total_cmp(&b.0));
+    stops
+}
+
+/// A paint which blends between color stops along the line from `start` to `end`
+///
+/// Each stop is an `(offset, color)` pair, where an offset of `0` is at `start`
+/// and an offset of `1` is at `end`. Stops must be sorted by their offsets,
+/// which [`LinearGradient::new`] takes care of. Points past either end of the
+/// line take on the color of the nearest stop. Stops are mixed in
+/// [`ColorSpace::Srgb`] unless `space` is changed.
+///
+/// ```
+/// use farba::{Canvas, Color, LinearGradient, RGBAColor, Vec2};
+///
+/// let gradient = LinearGradient::new(
+///     Vec2::new(0.0, 0.0),
+///     Vec2::new(100.0, 0.0),
+///     vec![(0.0, RGBAColor::BLACK), (1.0, RGBAColor::WHITE)],
+/// );
+///
+/// let mut canvas = Canvas::new(100, 10);
+/// canvas.rect(0, 0, 100, 10, &gradient);
+///
+/// assert_eq!(canvas.get_pixel(0, 5).red(), 1);
+/// assert_eq!(canvas.get_pixel(49, 5).red(), 126);
+/// assert_eq!(canvas.get_pixel(99, 5).red(), 254);
+/// ```
+#[derive(Debug, Clone)]
+pub struct LinearGradient {
+    pub start: Vec2,
+    pub end: Vec2,
+    pub stops: Vec<(f32, RGBAColor)>,
+    /// The color space neighboring stops are mixed in
+    pub space: ColorSpace,
+}
+
+impl LinearGradient {
+    pub fn new(start: Vec2, end: Vec2, stops: Vec<(f32, RGBAColor)>) -> Self {
+        Self {
+            start,
+            end,
+            stops: sorted_stops(stops),
+            space: ColorSpace::Srgb,
+        }
+    }
+}
+
+impl Paint for LinearGradient {
+    fn color_at(&self, x: f32, y: f32) -> u32 {
+        let direction = self.end - self.start;
+        let length_squared = direction.dot(direction);
+
+        // A gradient without a direction is stuck at its first stop
+        let t = if length_squared > 0.0 {
+            (Vec2::new(x, y) - self.start).dot(direction) / length_squared
+        } else {
+            0.0
+        };
+
+        sample_stops(&self.stops, self.space, t)
+    }
+}
+
+impl Paint for &LinearGradient {
+    #[inline]
+    fn color_at(&self, x: f32, y: f32) -> u32 {
+        (**self).color_at(x, y)
+    }
+}
+
+/// A paint which blends between color stops outwards from `center`
+///
+/// An offset of `0` is at `center` and an offset of `1` is on the circle of
+/// `radius` around it, with stops following the same rules as
+/// [`LinearGradient`].
+///
+/// ```
+/// use farba::{Canvas, Color, RadialGradient, RGBAColor, Vec2};
+///
+/// let glow = RadialGradient::new(
+///     Vec2::new(50.0, 50.0),
+///     50.0,
+///     vec![(0.0, RGBAColor::WHITE), (1.0, RGBAColor::BLACK)],
+/// );
+///
+/// let mut canvas = Canvas::new(100, 100);
+/// canvas.circle(50, 50, 50, &glow);
+///
+/// // The color only depends on the distance from the center
+/// assert_eq!(canvas.get_pixel(49, 49).red(), 251);
+/// assert_eq!(canvas.get_pixel(74, 49), canvas.get_pixel(49, 74));
+/// assert_eq!(canvas.get_pixel(74, 49).red(), 130);
+/// ```
+#[derive(Debug, Clone)]
+pub struct RadialGradient {
+    pub center: Vec2,
+    pub radius: f32,
+    pub stops: Vec<(f32, RGBAColor)>,
+    /// The color space neighboring stops are mixed in
+    pub space: ColorSpace,
+}
+
+impl RadialGradient {
+    pub fn new(center: Vec2, radius: f32, stops: Vec<(f32, RGBAColor)>) -> Self {
+        Self {
+            center,
+            radius,
+            stops: sorted_stops(stops),
+            space: ColorSpace::Srgb,
+        }
+    }
+}
+
+impl Paint for RadialGradient {
+    fn color_at(&self, x: f32, y: f32) -> u32 {
+        let offset = Vec2::new(x, y) - self.center;
+
+        // A gradient without any size is stuck at its last stop
+        let t = if self.radius > 0.0 {
+            offset.dot(offset).sqrt() / self.radius
+        } else {
+            1.0
+        };
+
+        sample_stops(&self.stops, self.space, t)
+    }
+}
+
+impl Paint for &RadialGradient {
+    #[inline]
+    fn color_at(&self, x: f32, y: f32) -> u32 {
+        (**self).color_at(x, y)
+    }
+}
+
+/// A paint which blends between color stops around `center`, sweeping clockwise
+/// (on screen) from `start_angle`
+///
+/// Angles are in radians and follow the same convention as [`Canvas::arc`]. An
+/// offset of `0` is at `start_angle` and an offset of `1` is a full turn later,
+/// so the first and last stops should match for a seamless color wheel.
+///
+/// [`Canvas::arc`]: crate::Canvas::arc
+///
+/// ```
+/// use farba::{Canvas, Color, ConicGradient, RGBAColor, Vec2};
+///
+/// let progress = ConicGradient::new(
+///     Vec2::new(50.0, 50.0),
+///     0.0,
+///     vec![(0.0, RGBAColor::BLACK), (1.0, RGBAColor::WHITE)],
+/// );
+///
+/// let mut canvas = Canvas::new(100, 100);
+/// canvas.ring(50, 50, 40, 10, &progress);
+///
+/// // A quarter turn clockwise from the positive x axis is straight down
+/// assert_eq!(canvas.get_pixel(49, 85).red(), 64);
+/// assert_eq!(canvas.get_pixel(14, 49).red(), 128);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ConicGradient {
+    pub center: Vec2,
+    pub start_angle: f32,
+    pub stops: Vec<(f32, RGBAColor)>,
+    /// The color space neighboring stops are mixed in
+    pub space: ColorSpace,
+}
+
+impl ConicGradient {
+    pub fn new(center: Vec2, start_angle: f32, stops: Vec<(f32, RGBAColor)>) -> Self {
+        Self {
+            center,
+            start_angle,
+            stops: sorted_stops(stops),
+            space: ColorSpace::Srgb,
+        }
+    }
+}
+
+impl Paint for ConicGradient {
+    fn color_at(&self, x: f32, y: f32) -> u32 {
+        use std::f32::consts::TAU;
+
+        let angle = (y - self.center.y).atan2(x - self.center.x);
+
+        let t = (angle - self.start_angle).rem_euclid(TAU) / TAU;
+
+        sample_stops(&self.stops, self.space, t)
+    }
+}
+
+impl Paint for &ConicGradient {
+    #[inline]
+    fn color_at(&self, x: f32, y: f32) -> u32 {
+        (**self).color_at(x, y)
+    }
+}
+
+/// A paint which repeats the contents of another canvas across the plane
+///
+/// The pattern is shifted so that its top left corner lands on `offset`, and
+/// every pixel of it is stretched to cover `scale` by `scale` pixels of the
+/// canvas being drawn on. Each pixel takes the color of the nearest pattern
+/// pixel.
+///
+/// ```
+/// use farba::{Canvas, Pattern, RGBAColor, Vec2};
+///
+/// // A 2x2 checkerboard
+/// let mut checker = Canvas::new(2, 2);
+/// checker.set_pixel(0, 0, RGBAColor::WHITE);
+/// checker.set_pixel(1, 1, RGBAColor::WHITE);
+///
+/// let mut canvas = Canvas::new(100, 100);
+/// canvas.rect(0, 0, 100, 100, Pattern::new(&checker, Vec2::new(0.0, 0.0), 10.0));
+///
+/// // Each square of the checkerboard is now 10 pixels wide, repeating forever
+/// assert_eq!(*canvas.get_pixel(5, 5), RGBAColor::WHITE.into());
+/// assert_eq!(*canvas.get_pixel(15, 5), 0);
+/// assert_eq!(*canvas.get_pixel(25, 5), RGBAColor::WHITE.into());
+/// assert_eq!(*canvas.get_pixel(15, 15), RGBAColor::WHITE.into());
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Pattern<'a> {
+    pub canvas: &'a Canvas,
+    pub offset: Vec2,
+    pub scale: f32,
+}
+
+impl<'a> Pattern<'a> {
+    pub fn new(canvas: &'a Canvas, offset: Vec2, scale: f32) -> Self {
+        Self {
+            canvas,
+            offset,
+            scale,
+        }
+    }
+}
+
+impl Paint for Pattern<'_> {
+    fn color_at(&self, x: f32, y: f32) -> u32 {
+        let (width, height) = (self.canvas.get_width(), self.canvas.get_height());
+
+        if width == 0 || height == 0 || self.scale <= 0.0 {
+            return 0;
+        }
+
+        // Position within the pattern, wrapped around so that it tiles in
+        // every direction
+        let u = ((x - self.offset.x) / self.scale).floor() as i64;
+        let v = ((y - self.offset.y) / self.scale).floor() as i64;
+
+        let pattern_x = u.rem_euclid(width as i64) as i32;
+        let pattern_y = v.rem_euclid(height as i64) as i32;
+
+        self.canvas.straight_pixel(pattern_x, pattern_y)
+    }
+}
+
+impl Paint for &Pattern<'_> {
+    #[inline]
+    fn color_at(&self, x: f32, y: f32) -> u32 {
+        (**self).color_at(x, y)
+    }
+}
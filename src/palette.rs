@@ -0,0 +1,365 @@
+use std::{collections::HashMap, ops::Range};
+
+use crate::{dither_pixels, palette_spread, Canvas, Color, Dither, IndexedCanvas, RGBAColor};
+
+/// An ordered set of colors which images can be mapped onto
+///
+/// Colors are kept in a k-d tree alongside the list, so that finding the
+/// nearest color stays fast for large palettes.
+#[derive(Debug, Default, Clone)]
+pub struct Palette {
+    colors: Vec<RGBAColor>,
+    /// Indices of the colors laid out as an implicit k-d tree, where the middle
+    /// of every range splits the rest of it along the channel for its depth
+    tree: Vec<usize>,
+}
+
+impl Palette {
+    /// Creates a new palette from the provided colors
+    pub fn new(colors: Vec<RGBAColor>) -> Self {
+        let mut tree: Vec<usize> = (0..colors.len()).collect();
+        build_tree(&colors, &mut tree, 0);
+
+        Self { colors, tree }
+    }
+
+    pub fn get_colors(&self) -> &[RGBAColor] {
+        self.colors.as_slice()
+    }
+
+    pub fn len(&self) -> usize {
+        self.colors.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.colors.is_empty()
+    }
+
+    /// Builds a palette of at most `max_colors` colors which represents the
+    /// pixels of `canvas` well, using median cut
+    ///
+    /// The colors of the canvas are repeatedly split into two halves along the
+    /// channel they vary the most in, until there are `max_colors` groups. Each
+    /// group then becomes the average of its colors, weighted by how many pixels
+    /// have them. Canvases with few enough distinct colors get exactly those
+    /// colors.
+    ///
+    /// ```
+    /// use farba::{Canvas, Palette, RGBAColor};
+    ///
+    /// let mut canvas = Canvas::new(256, 1);
+    /// for x in 0..256 {
+    ///     canvas.set_pixel(x, 0, RGBAColor::from_rgb(x as u8, 0, 255 - x as u8));
+    /// }
+    ///
+    /// let palette = Palette::median_cut(&canvas, 16);
+    /// assert_eq!(palette.len(), 16);
+    /// ```
+    pub fn median_cut(canvas: &Canvas, max_colors: usize) -> Self {
+        let mut counts = HashMap::new();
+
+        for y in 0..canvas.get_height() as i32 {
+            for x in 0..canvas.get_width() as i32 {
+                *counts.entry(canvas.straight_pixel(x, y)).or_insert(0u64) += 1;
+            }
+        }
+
+        // Sorted so the palette doesn't depend on the order of the hash map
+        let mut colors: Vec<(u32, u64)> = counts.into_iter().collect();
+        colors.sort_unstable();
+
+        if max_colors == 0 {
+            return Self::default();
+        }
+
+        let mut boxes = vec![colors];
+
+        while boxes.len() < max_colors {
+            // Split the box which spans the widest range of any channel
+            let widest = boxes
+                .iter()
+                .enumerate()
+                .filter(|(_, colors)| colors.len() > 1)
+                .map(|(i, colors)| (i, widest_channel(colors)))
+                .max_by_key(|&(_, (_, range))| range);
+
+            let Some((index, (channel, _))) = widest else {
+                break;
+            };
+
+            let mut colors = boxes.swap_remove(index);
+            colors.sort_by_key(|&(color, _)| channel_of(color, channel));
+
+            // Split where half of the pixels are on either side, keeping at
+            // least one color in each half
+            let total: u64 = colors.iter().map(|&(_, count)| count).sum();
+            let mut seen = 0;
+            let mut split = colors.len() - 1;
+
+            for (i, &(_, count)) in colors.iter().enumerate() {
+                seen += count;
+
+                if seen * 2 >= total {
+                    split = i + 1;
+                    break;
+                }
+            }
+
+            let upper = colors.split_off(split.clamp(1, colors.len() - 1));
+            boxes.push(colors);
+            boxes.push(upper);
+        }
+
+        let mut palette: Vec<RGBAColor> = boxes
+            .iter()
+            .filter(|colors| !colors.is_empty())
+            .map(|colors| average(colors))
+            .collect();
+        palette.sort_by_key(|color| color.pack());
+
+        Self::new(palette)
+    }
+
+    /// Finds the index of the palette entry closest to `color` by squared distance
+    /// over all four channels
+    ///
+    /// If the palette contains duplicate colors, the lowest index is returned.
+    ///
+    /// Panics if the palette is empty
+    pub fn nearest<C: Color>(&self, color: C) -> usize {
+        if self.colors.is_empty() {
+            panic!("Cannot find the nearest color in an empty palette")
+        }
+
+        let target = channels(&color);
+
+        let mut nearest = (i32::MAX, 0);
+        self.search_tree(&target, 0..self.tree.len(), 0, &mut nearest);
+
+        nearest.1
+    }
+
+    /// Looks for a color closer to `target` than `nearest` (as its squared
+    /// distance and index) within the part of the tree covering `range`
+    fn search_tree(
+        &self,
+        target: &[i32; 4],
+        range: Range<usize>,
+        depth: usize,
+        nearest: &mut (i32, usize),
+    ) {
+        if range.is_empty() {
+            return;
+        }
+
+        let middle = range.start + range.len() / 2;
+        let index = self.tree[middle];
+        let color = channels(&self.colors[index]);
+
+        let distance = (0..4).map(|c| (target[c] - color[c]).pow(2)).sum();
+
+        // Ties go to the lowest index, as if the colors were searched in order
+        if (distance, index) < *nearest {
+            *nearest = (distance, index);
+        }
+
+        let axis = depth % 4;
+        let offset = target[axis] - color[axis];
+
+        let (near, far) = if offset < 0 {
+            (range.start..middle, middle + 1..range.end)
+        } else {
+            (middle + 1..range.end, range.start..middle)
+        };
+
+        self.search_tree(target, near, depth + 1, nearest);
+
+        // The far side can only hold a closer color if the splitting plane is
+        // within reach
+        if offset * offset <= nearest.0 {
+            self.search_tree(target, far, depth + 1, nearest);
+        }
+    }
+
+    /// Maps every pixel of `canvas` onto its nearest color in the palette,
+    /// giving an indexed canvas which uses the palette
+    ///
+    /// Use [`IndexedCanvas::from_canvas`] to dither the result, or
+    /// [`Canvas::quantize`] to remap the canvas in place.
+    ///
+    /// Panics if the palette is empty or has more than 256 colors
+    ///
+    /// ```
+    /// use farba::{Canvas, Palette, RGBAColor};
+    ///
+    /// let palette = Palette::new(vec![
+    ///     RGBAColor::BLACK,
+    ///     RGBAColor::from_rgb(255, 0, 77),
+    ///     RGBAColor::from_rgb(41, 173, 255),
+    /// ]);
+    ///
+    /// let mut canvas = Canvas::new(10, 10);
+    /// canvas.fill(RGBAColor::from_rgb(10, 10, 10));
+    /// canvas.rect(0, 0, 5, 5, RGBAColor::RED);
+    /// canvas.rect(5, 5, 5, 5, RGBAColor::CYAN);
+    ///
+    /// let indexed = palette.map_canvas(&canvas);
+    ///
+    /// assert_eq!(indexed.get_pixel(2, 2), 1);
+    /// assert_eq!(indexed.get_pixel(7, 7), 2);
+    /// assert_eq!(indexed.get_pixel(7, 2), 0);
+    /// ```
+    pub fn map_canvas(&self, canvas: &Canvas) -> IndexedCanvas {
+        IndexedCanvas::from_canvas(canvas, self.clone(), Dither::None)
+    }
+}
+
+/// Gets the red, green, blue and alpha channels of a color
+fn channels<C: Color>(color: &C) -> [i32; 4] {
+    [
+        color.red() as i32,
+        color.green() as i32,
+        color.blue() as i32,
+        color.alpha() as i32,
+    ]
+}
+
+/// Arranges the color `indices` into an implicit k-d tree, splitting on the
+/// channel for `depth`
+fn build_tree(colors: &[RGBAColor], indices: &mut [usize], depth: usize) {
+    if indices.len() <= 1 {
+        return;
+    }
+
+    let axis = depth % 4;
+    let middle = indices.len() / 2;
+
+    indices.select_nth_unstable_by_key(middle, |&index| channels(&colors[index])[axis]);
+
+    let (lower, upper) = indices.split_at_mut(middle);
+    build_tree(colors, lower, depth + 1);
+    build_tree(colors, &mut upper[1..], depth + 1);
+}
+
+impl Canvas {
+    /// Replaces every pixel on the canvas with its nearest color in the palette
+    ///
+    /// When `dither` is true, the quantization error of each pixel is diffused
+    /// onto its unvisited neighbors (Floyd–Steinberg), which preserves the
+    /// average color of an area at the cost of some noise. Quantizing onto an
+    /// empty palette does nothing.
+    ///
+    /// ```
+    /// use farba::{Canvas, Color, Palette, RGBAColor};
+    ///
+    /// let palette = Palette::new(vec![RGBAColor::BLACK, RGBAColor::WHITE]);
+    ///
+    /// // Without dithering, every pixel snaps to the closer of the two colors
+    /// let mut canvas = Canvas::new(256, 1);
+    /// for x in 0..256 {
+    ///     canvas.set_pixel(x, 0, RGBAColor::from_rgb(x as u8, x as u8, x as u8));
+    /// }
+    ///
+    /// canvas.quantize(&palette, false);
+    ///
+    /// assert_eq!(canvas.get_pixel(127, 0).red(), 0);
+    /// assert_eq!(canvas.get_pixel(128, 0).red(), 255);
+    ///
+    /// // With dithering, the average brightness of the image is kept
+    /// let mut canvas = Canvas::new(64, 64);
+    /// canvas.fill(RGBAColor::from_rgb(100, 100, 100));
+    ///
+    /// canvas.quantize(&palette, true);
+    ///
+    /// let pixels = canvas.get_pixels();
+    /// let average = pixels.iter().map(|p| p.red() as f32).sum::<f32>() / pixels.len() as f32;
+    /// assert!((average - 100.0).abs() < 5.0);
+    /// ```
+    pub fn quantize(&mut self, palette: &Palette, dither: bool) {
+        let dither = if dither {
+            Dither::FloydSteinberg
+        } else {
+            Dither::None
+        };
+
+        self.quantize_with(palette, dither);
+    }
+
+    /// Replaces every pixel on the canvas with its nearest color in the palette
+    /// like [`Canvas::quantize`], spreading the error around according to
+    /// `dither`
+    ///
+    /// ```
+    /// use farba::{Canvas, Color, Dither, Palette, RGBAColor};
+    ///
+    /// let palette = Palette::new(vec![RGBAColor::BLACK, RGBAColor::WHITE]);
+    ///
+    /// let mut canvas = Canvas::new(8, 8);
+    /// canvas.fill(RGBAColor::from_rgb(128, 128, 128));
+    /// canvas.quantize_with(&palette, Dither::Bayer4);
+    ///
+    /// // Half of the pixels are white, in a pattern which repeats every 4 pixels
+    /// let white = canvas.get_pixels().iter().filter(|p| p.red() == 255).count();
+    /// assert_eq!(white, 32);
+    /// assert_eq!(canvas.get_pixel(1, 2), canvas.get_pixel(5, 6));
+    /// ```
+    pub fn quantize_with(&mut self, palette: &Palette, dither: Dither) {
+        if palette.is_empty() {
+            return;
+        }
+
+        let pixels = self.straight_pixels();
+        let spread = palette_spread(palette.len());
+
+        dither_pixels(
+            &pixels,
+            self.get_width(),
+            dither,
+            spread,
+            |index, target| {
+                let chosen = palette.get_colors()[palette.nearest(target)].pack();
+
+                let stored = self.stored_color(chosen);
+                self.get_pixels_mut()[index] = stored;
+
+                chosen
+            },
+        );
+    }
+}
+
+/// Gets the channel of a packed color by its index (`0` red through `3` alpha)
+fn channel_of(color: u32, channel: usize) -> u8 {
+    (color >> (8 * channel)) as u8
+}
+
+/// Finds the channel a list of colors varies the most in, along with how far it
+/// ranges
+fn widest_channel(colors: &[(u32, u64)]) -> (usize, u8) {
+    (0..4)
+        .map(|channel| {
+            let values = colors.iter().map(|&(color, _)| channel_of(color, channel));
+            let min = values.clone().min().unwrap_or(0);
+            let max = values.max().unwrap_or(0);
+
+            (channel, max - min)
+        })
+        .max_by_key(|&(_, range)| range)
+        .unwrap_or((0, 0))
+}
+
+/// Averages a list of colors, weighted by how many pixels have each one
+fn average(colors: &[(u32, u64)]) -> RGBAColor {
+    let total: u64 = colors.iter().map(|&(_, count)| count).sum();
+
+    let channel = |channel: usize| {
+        let sum: u64 = colors
+            .iter()
+            .map(|&(color, count)| channel_of(color, channel) as u64 * count)
+            .sum();
+
+        ((sum + total / 2) / total) as u8
+    };
+
+    RGBAColor::from_rgba(channel(0), channel(1), channel(2), channel(3))
+}
@@ -0,0 +1,205 @@
+use crate::{
+    curve::{flatten_cubic, flatten_quad},
+    stroke_outlines, Canvas, FillRule, LineCap, LineJoin, Paint, Vec2,
+};
+
+/// A single step in the outline of a [`Path`]
+#[derive(Debug, Clone, Copy)]
+pub enum PathCommand {
+    /// Starts a new subpath at the point
+    MoveTo(Vec2),
+    /// A straight line to the point
+    LineTo(Vec2),
+    /// A quadratic Bézier curve to the second point, bending towards the first
+    QuadTo(Vec2, Vec2),
+    /// A cubic Bézier curve to the third point, using the first two as control
+    /// points
+    CubicTo(Vec2, Vec2, Vec2),
+    /// A straight line back to the start of the current subpath, which also
+    /// marks it as closed
+    Close,
+}
+
+/// An outline made up of any number of subpaths, each built from straight lines
+/// and Bézier curves
+///
+/// Drawing commands continue from wherever the previous one ended. A subpath
+/// which doesn't start with [`Path::move_to`] begins where the last subpath
+/// started, or at the origin for the first one.
+///
+/// ```
+/// use farba::{Canvas, FillRule, Path, RGBAColor, Vec2};
+///
+/// let mut path = Path::new();
+/// path.move_to(Vec2::new(10.0, 90.0))
+///     .line_to(Vec2::new(10.0, 40.0))
+///     .quad_to(Vec2::new(50.0, 0.0), Vec2::new(90.0, 40.0))
+///     .line_to(Vec2::new(90.0, 90.0))
+///     .close();
+///
+/// let mut canvas = Canvas::new(100, 100);
+/// canvas.fill_path(&path, FillRule::NonZero, RGBAColor::RED);
+///
+/// // The curve rounds off the top of the shape
+/// assert_eq!(*canvas.get_pixel(50, 30), RGBAColor::RED.into());
+/// assert_eq!(*canvas.get_pixel(15, 30), 0);
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct Path {
+    commands: Vec<PathCommand>,
+}
+
+impl Path {
+    /// Creates a new path without any subpaths
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get_commands(&self) -> &[PathCommand] {
+        &self.commands
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.commands.is_empty()
+    }
+
+    /// Starts a new subpath at `point`
+    pub fn move_to(&mut self, point: Vec2) -> &mut Self {
+        self.commands.push(PathCommand::MoveTo(point));
+        self
+    }
+
+    /// Adds a straight line to `point`
+    pub fn line_to(&mut self, point: Vec2) -> &mut Self {
+        self.commands.push(PathCommand::LineTo(point));
+        self
+    }
+
+    /// Adds a quadratic Bézier curve to `point`, bending towards `ctrl`
+    pub fn quad_to(&mut self, ctrl: Vec2, point: Vec2) -> &mut Self {
+        self.commands.push(PathCommand::QuadTo(ctrl, point));
+        self
+    }
+
+    /// Adds a cubic Bézier curve to `point`, leaving towards `c0` and arriving
+    /// from `c1`
+    pub fn cubic_to(&mut self, c0: Vec2, c1: Vec2, point: Vec2) -> &mut Self {
+        self.commands.push(PathCommand::CubicTo(c0, c1, point));
+        self
+    }
+
+    /// Closes the current subpath with a straight line back to its start
+    pub fn close(&mut self) -> &mut Self {
+        self.commands.push(PathCommand::Close);
+        self
+    }
+
+    /// Approximates every subpath with line segments, giving the points of each
+    /// one along with whether it was closed
+    pub(crate) fn flatten(&self) -> Vec<(Vec<Vec2>, bool)> {
+        let mut subpaths = Vec::new();
+
+        let mut points: Vec<Vec2> = Vec::new();
+        let mut start = Vec2::new(0.0, 0.0);
+
+        for command in &self.commands {
+            // Drawing commands after a close (or at the very beginning) pick up
+            // from the start of the last subpath
+            if points.is_empty() && !matches!(command, PathCommand::MoveTo(_) | PathCommand::Close)
+            {
+                points.push(start);
+            }
+
+            let current = points.last().copied().unwrap_or(start);
+
+            match *command {
+                PathCommand::MoveTo(point) => {
+                    if !points.is_empty() {
+                        subpaths.push((std::mem::take(&mut points), false));
+                    }
+
+                    start = point;
+                    points.push(point);
+                }
+                PathCommand::LineTo(point) => points.push(point),
+                PathCommand::QuadTo(ctrl, point) => flatten_quad(current, ctrl, point, &mut points),
+                PathCommand::CubicTo(c0, c1, point) => {
+                    flatten_cubic(current, c0, c1, point, &mut points)
+                }
+                PathCommand::Close => {
+                    if !points.is_empty() {
+                        subpaths.push((std::mem::take(&mut points), true));
+                    }
+                }
+            }
+        }
+
+        if !points.is_empty() {
+            subpaths.push((points, false));
+        }
+
+        subpaths
+    }
+}
+
+impl Canvas {
+    /// Fills the inside of `path` according to the fill `rule`
+    ///
+    /// Every subpath is implicitly closed, and all of them are filled together as
+    /// one shape, so subpaths can cut holes in each other. Pixels are sampled at
+    /// their centers, `(x + 0.5, y + 0.5)`.
+    pub fn fill_path<P: Paint>(&mut self, path: &Path, rule: FillRule, paint: P) {
+        let contours: Vec<Vec<Vec2>> = path
+            .flatten()
+            .into_iter()
+            .map(|(points, _)| points)
+            .collect();
+
+        self.fill_contours(&contours, rule, &paint);
+    }
+
+    /// Draws a stroke `width` pixels wide along `path`, with its corners shaped
+    /// by `join` and the ends of any subpath which isn't closed shaped by `cap`
+    ///
+    /// The whole stroke is filled as one shape, so no pixel is drawn more than
+    /// once where segments overlap.
+    ///
+    /// ```
+    /// use farba::{Canvas, LineCap, LineJoin, Path, RGBAColor, Vec2};
+    ///
+    /// let mut path = Path::new();
+    /// path.move_to(Vec2::new(20.0, 80.0))
+    ///     .line_to(Vec2::new(20.0, 20.0))
+    ///     .line_to(Vec2::new(80.0, 20.0));
+    ///
+    /// let mut mitered = Canvas::new(100, 100);
+    /// mitered.stroke_path(&path, 10.0, LineJoin::Miter, LineCap::Butt, RGBAColor::RED);
+    ///
+    /// let mut beveled = Canvas::new(100, 100);
+    /// beveled.stroke_path(&path, 10.0, LineJoin::Bevel, LineCap::Butt, RGBAColor::RED);
+    ///
+    /// // Only the miter join fills in the outer corner
+    /// assert_eq!(*mitered.get_pixel(16, 16), RGBAColor::RED.into());
+    /// assert_eq!(*beveled.get_pixel(16, 16), 0);
+    ///
+    /// // Butt caps end exactly at the endpoints
+    /// assert_eq!(*mitered.get_pixel(20, 79), RGBAColor::RED.into());
+    /// assert_eq!(*mitered.get_pixel(20, 80), 0);
+    /// ```
+    pub fn stroke_path<P: Paint>(
+        &mut self,
+        path: &Path,
+        width: f32,
+        join: LineJoin,
+        cap: LineCap,
+        paint: P,
+    ) {
+        let outlines: Vec<Vec<Vec2>> = path
+            .flatten()
+            .iter()
+            .flat_map(|(points, closed)| stroke_outlines(points, *closed, width, join, cap))
+            .collect();
+
+        self.fill_contours(&outlines, FillRule::NonZero, &paint);
+    }
+}
@@ -0,0 +1,275 @@
+use std::fmt::Debug;
+
+use crate::{
+    blend_over, clipped_line_pixels, normalize_rect, reduce_channel, rgb, Canvas, Color,
+    LumaWeights,
+};
+
+/// A way of storing the color of a pixel, such as the 16 bit RGB565 used by
+/// many small displays
+///
+/// Formats convert to and from the packed colors used everywhere else in the
+/// crate, dropping whatever they can't store.
+pub trait PixelFormat {
+    /// What a single pixel is stored as
+    type Pixel: Copy + Default + Debug + PartialEq;
+
+    /// Converts a packed color into a pixel, rounding it to the nearest color
+    /// the format can store
+    fn encode(color: u32) -> Self::Pixel;
+
+    /// Converts a pixel back into a packed color
+    fn decode(pixel: Self::Pixel) -> u32;
+}
+
+/// 16 bit color with 5 bits of red, 6 of green and 5 of blue, from the highest
+/// bit to the lowest, and no alpha
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rgb565;
+
+impl PixelFormat for Rgb565 {
+    type Pixel = u16;
+
+    #[inline]
+    fn encode(color: u32) -> u16 {
+        let red = (reduce_channel(color.red(), 5) >> 3) as u16;
+        let green = (reduce_channel(color.green(), 6) >> 2) as u16;
+        let blue = (reduce_channel(color.blue(), 5) >> 3) as u16;
+
+        (red << 11) | (green << 5) | blue
+    }
+
+    #[inline]
+    fn decode(pixel: u16) -> u32 {
+        let red = (pixel >> 11) & 0x1F;
+        let green = (pixel >> 5) & 0x3F;
+        let blue = pixel & 0x1F;
+
+        // Repeat the high bits into the low ones so the full range is covered
+        rgb!(
+            (red << 3) | (red >> 2),
+            (green << 2) | (green >> 4),
+            (blue << 3) | (blue >> 2)
+        )
+    }
+}
+
+/// 24 bit color stored as `[red, green, blue]` bytes, without alpha
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rgb888;
+
+impl PixelFormat for Rgb888 {
+    type Pixel = [u8; 3];
+
+    #[inline]
+    fn encode(color: u32) -> [u8; 3] {
+        [color.red(), color.green(), color.blue()]
+    }
+
+    #[inline]
+    fn decode([red, green, blue]: [u8; 3]) -> u32 {
+        rgb!(red, green, blue)
+    }
+}
+
+/// 8 bit brightness, using the Rec. 709 weights of [`LumaWeights`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Gray8;
+
+impl PixelFormat for Gray8 {
+    type Pixel = u8;
+
+    #[inline]
+    fn encode(color: u32) -> u8 {
+        LumaWeights::Rec709.luma(color)
+    }
+
+    #[inline]
+    fn decode(pixel: u8) -> u32 {
+        rgb!(pixel, pixel, pixel)
+    }
+}
+
+/// A canvas which stores its pixels in the [`PixelFormat`] `F`, so that it can
+/// be drawn straight into the format a display expects
+///
+/// The formats have no alpha channel, so translucent colors are blended over
+/// the pixels already drawn while opaque ones replace them.
+///
+/// ```
+/// use farba::{Canvas565, CanvasGray8, Color, RGBAColor};
+///
+/// let mut canvas = Canvas565::new(10, 10);
+/// canvas.rect(0, 0, 5, 5, RGBAColor::RED);
+///
+/// assert_eq!(canvas.get_pixels()[0], 0xF800);
+/// assert_eq!(canvas.get_pixel(2, 2), RGBAColor::RED.into());
+///
+/// let mut gray = CanvasGray8::new(10, 10);
+/// gray.fill(RGBAColor::WHITE);
+/// gray.circle(5, 5, 3, RGBAColor::from_rgba(0, 0, 0, 128));
+///
+/// assert_eq!(gray.get_pixels()[5 * 10 + 5], 127);
+/// assert_eq!(gray.to_canvas().get_pixel(5, 5).green(), 127);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct PixelCanvas<F: PixelFormat> {
+    pixels: Vec<F::Pixel>,
+    width: usize,
+    height: usize,
+}
+
+/// A canvas of 16 bit RGB565 pixels
+pub type Canvas565 = PixelCanvas<Rgb565>;
+
+/// A canvas of 24 bit RGB pixels without alpha
+pub type Canvas888 = PixelCanvas<Rgb888>;
+
+/// A canvas of 8 bit grayscale pixels
+pub type CanvasGray8 = PixelCanvas<Gray8>;
+
+impl<F: PixelFormat> PixelCanvas<F> {
+    /// Creates a new canvas with the specified width and height, where every
+    /// pixel is black
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            pixels: vec![F::Pixel::default(); width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Creates a canvas holding the image on `canvas`, converted to the format
+    pub fn from_canvas(canvas: &Canvas) -> Self {
+        let mut converted = Self::new(canvas.get_width(), canvas.get_height());
+
+        for (pixel, color) in converted.pixels.iter_mut().zip(canvas.straight_pixels()) {
+            *pixel = F::encode(color);
+        }
+
+        converted
+    }
+
+    /// Creates a true color canvas holding the image on this canvas
+    pub fn to_canvas(&self) -> Canvas {
+        let mut canvas = Canvas::new(self.width, self.height);
+
+        for (color, &pixel) in canvas.get_pixels_mut().iter_mut().zip(&self.pixels) {
+            *color = F::decode(pixel);
+        }
+
+        canvas
+    }
+
+    pub fn get_width(&self) -> usize {
+        self.width
+    }
+
+    pub fn get_height(&self) -> usize {
+        self.height
+    }
+
+    /// Gets a slice over the raw pixel buffer owned by the canvas
+    pub fn get_pixels(&self) -> &[F::Pixel] {
+        self.pixels.as_slice()
+    }
+
+    /// Gets a mutable slice over the raw pixel buffer owned by the canvas
+    pub fn get_pixels_mut(&mut self) -> &mut [F::Pixel] {
+        self.pixels.as_mut_slice()
+    }
+
+    pub fn in_bounds(&self, x: i32, y: i32) -> bool {
+        x >= 0 && x < self.width as i32 && y >= 0 && y < self.height as i32
+    }
+
+    /// Gets the packed color of the pixel at `(x, y)`
+    ///
+    /// Panics if the position is outside of the canvas
+    pub fn get_pixel(&self, x: i32, y: i32) -> u32 {
+        if !self.in_bounds(x, y) {
+            panic!("Pixel ({x}, {y}) is outside of the canvas")
+        }
+
+        F::decode(self.pixels[y as usize * self.width + x as usize])
+    }
+
+    /// Draws a single pixel, doing nothing if it is outside of the canvas
+    pub fn set_pixel<C: Color>(&mut self, x: i32, y: i32, color: C) {
+        if self.in_bounds(x, y) {
+            self.plot(y as usize * self.width + x as usize, color.pack());
+        }
+    }
+
+    /// Blends `color` over the pixel at `index`
+    #[inline]
+    fn plot(&mut self, index: usize, color: u32) {
+        let pixel = &mut self.pixels[index];
+
+        *pixel = match color.alpha() {
+            255 => F::encode(color),
+            0 => return,
+            _ => F::encode(blend_over(F::decode(*pixel), color)),
+        };
+    }
+
+    /// Sets every pixel on the canvas to `color`, ignoring its alpha
+    pub fn fill<C: Color>(&mut self, color: C) {
+        self.pixels.fill(F::encode(color.pack()));
+    }
+
+    /// Fills a rectangle with `color`, following the same rules as
+    /// [`Canvas::rect`]
+    pub fn rect<C: Color>(&mut self, x: i32, y: i32, width: i32, height: i32, color: C) {
+        let color = color.pack();
+
+        let Some(nr) = normalize_rect(x, y, width, height, self.width as i32, self.height as i32)
+        else {
+            return;
+        };
+
+        for y in nr.y1..=nr.y2 {
+            for x in nr.x1..=nr.x2 {
+                self.plot(y as usize * self.width + x as usize, color);
+            }
+        }
+    }
+
+    /// Draws a one pixel wide line between two points
+    pub fn line<C: Color>(&mut self, x1: i32, y1: i32, x2: i32, y2: i32, color: C) {
+        let color = color.pack();
+        let bounds = (0, 0, self.width as i32 - 1, self.height as i32 - 1);
+
+        clipped_line_pixels(x1, y1, x2, y2, bounds, |x, y| {
+            self.plot(y as usize * self.width + x as usize, color);
+        });
+    }
+
+    /// Fills a circle with `color`, covering the same pixels as
+    /// [`Canvas::circle`]
+    pub fn circle<C: Color>(&mut self, center_x: i32, center_y: i32, radius: i32, color: C) {
+        let color = color.pack();
+
+        let Some(nr) = normalize_rect(
+            center_x - radius,
+            center_y - radius,
+            radius * 2,
+            radius * 2,
+            self.width as i32,
+            self.height as i32,
+        ) else {
+            return;
+        };
+
+        for y in nr.y1..=nr.y2 {
+            for x in nr.x1..=nr.x2 {
+                let dx = center_x - x;
+                let dy = center_y - y;
+
+                if dx * dx + dy * dy < radius * radius {
+                    self.plot(y as usize * self.width + x as usize, color);
+                }
+            }
+        }
+    }
+}
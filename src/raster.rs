@@ -0,0 +1,451 @@
+use crate::{normalize_triangle, Vec2};
+
+/// Decides which areas of a self-intersecting or nested shape count as inside
+///
+/// Every fill that can overlap itself takes one, such as
+/// [`Canvas::fill_polygon`] and [`Canvas::fill_path`].
+///
+/// [`Canvas::fill_polygon`]: crate::Canvas::fill_polygon
+/// [`Canvas::fill_path`]: crate::Canvas::fill_path
+///
+/// ```
+/// use farba::{Canvas, FillRule, Path, RGBAColor, Vec2};
+///
+/// // Two squares, one inside of the other, wound in the same direction
+/// let mut path = Path::new();
+/// for (min, max) in [(10.0, 90.0), (30.0, 70.0)] {
+///     path.move_to(Vec2::new(min, min))
+///         .line_to(Vec2::new(max, min))
+///         .line_to(Vec2::new(max, max))
+///         .line_to(Vec2::new(min, max))
+///         .close();
+/// }
+///
+/// let mut non_zero = Canvas::new(100, 100);
+/// non_zero.fill_path(&path, FillRule::NonZero, RGBAColor::RED);
+///
+/// let mut even_odd = Canvas::new(100, 100);
+/// even_odd.fill_path(&path, FillRule::EvenOdd, RGBAColor::RED);
+///
+/// // The inner square is wound around twice, which only counts as inside
+/// // under the non-zero rule
+/// assert_eq!(*non_zero.get_pixel(50, 50), RGBAColor::RED.into());
+/// assert_eq!(*even_odd.get_pixel(50, 50), 0);
+///
+/// // Both agree on the area only covered by the outer square
+/// assert_eq!(*non_zero.get_pixel(20, 20), RGBAColor::RED.into());
+/// assert_eq!(*even_odd.get_pixel(20, 20), RGBAColor::RED.into());
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum FillRule {
+    /// A point is inside if the outline winds around it a non-zero number of
+    /// times, counting clockwise and counter-clockwise turns against each other
+    #[default]
+    NonZero,
+    /// A point is inside if a ray from it crosses the outline an odd number of
+    /// times, which leaves holes wherever the shape overlaps itself
+    EvenOdd,
+}
+
+/// Walks the rows of the shape made up of the closed `contours` and calls
+/// `span` with `(y, x1, x2)` for every run of covered pixels, where `x1..=x2` is
+/// already clipped to a canvas of size `width` by `height`
+///
+/// A pixel is covered when its center, `(x + 0.5, y + 0.5)`, is inside of the
+/// shape according to the fill `rule`. Every contour is implicitly closed, and
+/// each pixel is visited at most once no matter how many times the shape
+/// overlaps itself.
+///
+/// Overlapping contours wound in the same direction are merged under the
+/// non-zero rule, which lets a shape be built up from simpler pieces without
+/// drawing their overlap twice. Contours wound in the opposite direction (or
+/// any overlap under the even-odd rule) cut holes instead.
+pub(crate) fn contour_spans<P: AsRef<[Vec2]>, F: FnMut(i32, i32, i32)>(
+    contours: &[P],
+    rule: FillRule,
+    width: usize,
+    height: usize,
+    mut span: F,
+) {
+    if width == 0 || height == 0 {
+        return;
+    }
+
+    let (mut min_y, mut max_y) = (f32::INFINITY, f32::NEG_INFINITY);
+    for point in contours.iter().flat_map(|contour| contour.as_ref()) {
+        min_y = min_y.min(point.y);
+        max_y = max_y.max(point.y);
+    }
+
+    if min_y >= max_y {
+        return;
+    }
+
+    // Only rows whose centers fall in [min_y, max_y) can be covered
+    let top_y = ((min_y - 0.5).ceil() as i32).max(0);
+    let bottom_y = ((max_y - 0.5).ceil() as i32 - 1).min(height as i32 - 1);
+
+    // (x, winding direction) of every edge crossing the current row
+    let mut crossings: Vec<(f32, i32)> = Vec::new();
+
+    for y in top_y..=bottom_y {
+        let sample_y = y as f32 + 0.5;
+
+        crossings.clear();
+
+        for contour in contours {
+            let points = contour.as_ref();
+
+            if points.len() < 3 {
+                continue;
+            }
+
+            for (i, a) in points.iter().enumerate() {
+                let b = &points[(i + 1) % points.len()];
+
+                // Half-open test so vertices shared by two edges are only counted once
+                let (upper, lower, direction) = if a.y < b.y { (a, b, 1) } else { (b, a, -1) };
+
+                if sample_y < upper.y || sample_y >= lower.y {
+                    continue;
+                }
+
+                let t = (sample_y - upper.y) / (lower.y - upper.y);
+                crossings.push((upper.x + t * (lower.x - upper.x), direction));
+            }
+        }
+
+        crossings.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let mut winding = 0;
+
+        for pair in crossings.windows(2) {
+            winding += match rule {
+                FillRule::NonZero => pair[0].1,
+                FillRule::EvenOdd => 1,
+            };
+
+            let inside = match rule {
+                FillRule::NonZero => winding != 0,
+                FillRule::EvenOdd => winding % 2 == 1,
+            };
+
+            if !inside {
+                continue;
+            }
+
+            // Pixels whose centers fall in [left, right)
+            let x1 = ((pair[0].0 - 0.5).ceil() as i32).max(0);
+            let x2 = ((pair[1].0 - 0.5).ceil() as i32 - 1).min(width as i32 - 1);
+
+            if x1 <= x2 {
+                span(y, x1, x2);
+            }
+        }
+    }
+}
+
+/// Calls `plot` with every pixel on the line from `(x1, y1)` to `(x2, y2)`
+/// (inclusive) in order, using Bresenham's algorithm, but only the ones inside
+/// of the inclusive `bounds` given as `(left, top, right, bottom)`
+///
+/// The line is clipped with the Cohen–Sutherland algorithm before any pixels are
+/// walked, so the cost only grows with the length of the visible part. Instead
+/// of clipping the ideal line, each end is moved to the first pixel Bresenham's
+/// algorithm would have plotted inside of the bounds, which keeps the pixels
+/// exactly the same as walking the whole line and throwing away the rest.
+pub(crate) fn clipped_line_pixels<F: FnMut(i32, i32)>(
+    x1: i32,
+    y1: i32,
+    x2: i32,
+    y2: i32,
+    bounds: (i32, i32, i32, i32),
+    plot: F,
+) {
+    const LEFT: u8 = 1;
+    const RIGHT: u8 = 2;
+    const TOP: u8 = 4;
+    const BOTTOM: u8 = 8;
+
+    let (left, top, right, bottom) = bounds;
+
+    if left > right || top > bottom {
+        return;
+    }
+
+    let outcode = |(x, y): (i32, i32)| {
+        let mut code = 0;
+
+        if x < left {
+            code |= LEFT;
+        } else if x > right {
+            code |= RIGHT;
+        }
+
+        if y < top {
+            code |= TOP;
+        } else if y > bottom {
+            code |= BOTTOM;
+        }
+
+        code
+    };
+
+    let line = BresenhamLine::new(x1, y1, x2, y2);
+
+    // The part of the line still left to draw, as a range of steps along it
+    let (mut first, mut last) = (0, line.steps);
+
+    loop {
+        // Everything was clipped away
+        if first > last {
+            return;
+        }
+
+        let first_code = outcode(line.pixel(first));
+        let last_code = outcode(line.pixel(last));
+
+        if first_code | last_code == 0 {
+            break;
+        }
+
+        // Both ends are past the same edge, so the line never crosses into the
+        // bounds
+        if first_code & last_code != 0 {
+            return;
+        }
+
+        // Move whichever end is outside onto the edge it is past. Both
+        // coordinates only ever move one way along the line, so the steps
+        // inside of each edge are always one unbroken range
+        if first_code != 0 {
+            first = match first_code {
+                code if code & LEFT != 0 => line.first_step_reaching_x(left),
+                code if code & RIGHT != 0 => line.first_step_reaching_x(right),
+                code if code & TOP != 0 => line.first_step_reaching_y(top),
+                _ => line.first_step_reaching_y(bottom),
+            };
+        } else {
+            last = match last_code {
+                code if code & LEFT != 0 => line.last_step_reaching_x(left),
+                code if code & RIGHT != 0 => line.last_step_reaching_x(right),
+                code if code & TOP != 0 => line.last_step_reaching_y(top),
+                _ => line.last_step_reaching_y(bottom),
+            };
+        }
+    }
+
+    line.walk(first, last, plot);
+}
+
+/// The state needed to walk a line with Bresenham's algorithm, or to jump
+/// straight to any step along it
+///
+/// Every step moves one pixel along the major axis (the one the line covers
+/// more distance in), and after `k` steps the minor axis has moved
+/// `floor((2 * minor * k + major) / (2 * major))` pixels, where `major` and
+/// `minor` are the distances covered along each axis.
+struct BresenhamLine {
+    x1: i32,
+    y1: i32,
+    dx: i64,
+    dy: i64,
+    step_x: i32,
+    step_y: i32,
+    /// Number of steps from one end to the other, which is one less than the
+    /// number of pixels
+    steps: i64,
+}
+
+impl BresenhamLine {
+    fn new(x1: i32, y1: i32, x2: i32, y2: i32) -> Self {
+        let dx = (x2 as i64 - x1 as i64).abs();
+        let dy = (y2 as i64 - y1 as i64).abs();
+
+        Self {
+            x1,
+            y1,
+            dx,
+            dy,
+            step_x: if x1 < x2 { 1 } else { -1 },
+            step_y: if y1 < y2 { 1 } else { -1 },
+            steps: dx.max(dy),
+        }
+    }
+
+    /// How far the line has moved along each axis after `step` steps
+    fn offset(&self, step: i64) -> (i64, i64) {
+        if self.steps == 0 {
+            return (0, 0);
+        }
+
+        let minor = |distance: i64| {
+            // Wide enough that lines spanning the whole range of `i32` can't overflow
+            let (distance, step, steps) = (distance as i128, step as i128, self.steps as i128);
+
+            ((2 * distance * step + steps).div_euclid(2 * steps)) as i64
+        };
+
+        if self.dx >= self.dy {
+            (step, minor(self.dy))
+        } else {
+            (minor(self.dx), step)
+        }
+    }
+
+    /// The pixel plotted after `step` steps
+    fn pixel(&self, step: i64) -> (i32, i32) {
+        let (x, y) = self.offset(step);
+
+        (
+            (self.x1 as i64 + x * self.step_x as i64) as i32,
+            (self.y1 as i64 + y * self.step_y as i64) as i32,
+        )
+    }
+
+    /// The first step on or past `x` in the direction the line travels
+    fn first_step_reaching_x(&self, x: i32) -> i64 {
+        let distance = (x as i64 - self.x1 as i64) * self.step_x as i64;
+
+        self.first_step_reaching(distance, self.dx, self.dy)
+    }
+
+    /// The first step on or past `y` in the direction the line travels
+    fn first_step_reaching_y(&self, y: i32) -> i64 {
+        let distance = (y as i64 - self.y1 as i64) * self.step_y as i64;
+
+        self.first_step_reaching(distance, self.dy, self.dx)
+    }
+
+    /// The last step before the line moves past `x` in the direction it travels
+    fn last_step_reaching_x(&self, x: i32) -> i64 {
+        let distance = (x as i64 - self.x1 as i64) * self.step_x as i64;
+
+        self.first_step_reaching(distance + 1, self.dx, self.dy) - 1
+    }
+
+    /// The last step before the line moves past `y` in the direction it travels
+    fn last_step_reaching_y(&self, y: i32) -> i64 {
+        let distance = (y as i64 - self.y1 as i64) * self.step_y as i64;
+
+        self.first_step_reaching(distance + 1, self.dy, self.dx) - 1
+    }
+
+    /// The first step where the offset along an axis covering `length` reaches
+    /// `distance`, given the length of the other axis
+    fn first_step_reaching(&self, distance: i64, length: i64, other: i64) -> i64 {
+        if distance <= 0 {
+            return 0;
+        }
+
+        if distance > length {
+            // Never reached, so place it past the end of the line
+            return self.steps + 1;
+        }
+
+        if length >= other {
+            // The major axis moves once per step
+            return distance;
+        }
+
+        // Solve `floor((2 * length * k + steps) / (2 * steps)) >= distance` for
+        // the smallest `k`
+        let (distance, length, steps) = (distance as i128, length as i128, self.steps as i128);
+        let numerator = (2 * distance - 1) * steps;
+
+        ((numerator + 2 * length - 1).div_euclid(2 * length)) as i64
+    }
+
+    /// Calls `plot` with the pixels from step `first` to step `last` (inclusive)
+    fn walk<F: FnMut(i32, i32)>(&self, first: i64, last: i64, mut plot: F) {
+        if first > last {
+            return;
+        }
+
+        let (moved_x, moved_y) = self.offset(first);
+        let (mut x, mut y) = self.pixel(first);
+
+        let (dx, dy) = (self.dx, -self.dy);
+
+        // Accumulated error for both axes at once, which is what allows the
+        // algorithm to handle every octant without special cases. Starting part
+        // way along the line picks it up from where it would have been
+        let mut error =
+            (dx as i128 * (moved_y as i128 + 1) + dy as i128 * (moved_x as i128 + 1)) as i64;
+
+        for step in first..=last {
+            plot(x, y);
+
+            if step == last {
+                break;
+            }
+
+            let doubled_error = 2 * error;
+
+            if doubled_error >= dy {
+                error += dy;
+                x += self.step_x;
+            }
+            if doubled_error <= dx {
+                error += dx;
+                y += self.step_y;
+            }
+        }
+    }
+}
+
+/// Walks the rows of the triangle `(x1, y1)`, `(x2, y2)`, `(x3, y3)` and calls
+/// `span` with `(y, x1, x2)` for every run of covered pixels, where `x1..=x2` is
+/// already clipped to a canvas of size `width` by `height`
+///
+/// A pixel is covered when the point `(x, y)` is on the inner side of (or
+/// exactly on) all three edges. Instead of testing every pixel of the bounding
+/// box, each edge is solved for the range of `x` it allows on the row, so the
+/// cost only grows with the height of the triangle.
+pub(crate) fn triangle_spans<F: FnMut(i32, i32, i32)>(
+    width: usize,
+    height: usize,
+    (x1, y1): (i32, i32),
+    (x2, y2): (i32, i32),
+    (x3, y3): (i32, i32),
+    mut span: F,
+) {
+    let Some(nt) = normalize_triangle(width, height, x1, y1, x2, y2, x3, y3) else {
+        return;
+    };
+
+    let edges = [
+        ((x1, y1), (x2, y2)),
+        ((x2, y2), (x3, y3)),
+        ((x3, y3), (x1, y1)),
+    ]
+    .map(|((ax, ay), (bx, by))| {
+        (
+            ax as i64,
+            ay as i64,
+            bx as i64 - ax as i64,
+            by as i64 - ay as i64,
+        )
+    });
+
+    'rows: for y in nt.top_y..=nt.bottom_y {
+        let (mut left, mut right) = (nt.left_x as i64, nt.right_x as i64);
+
+        for &(ax, ay, dx, dy) in &edges {
+            // The edge function `dx * (y - ay) - dy * (x - ax)` is non-negative
+            // wherever `dy * x <= c`
+            let c = dx * (y as i64 - ay) + dy * ax;
+
+            match dy.signum() {
+                1 => right = right.min(c.div_euclid(dy)),
+                -1 => left = left.max(-c.div_euclid(-dy)),
+                _ if c < 0 => continue 'rows,
+                _ => {}
+            }
+        }
+
+        if left <= right {
+            span(y, left as i32, right as i32);
+        }
+    }
+}
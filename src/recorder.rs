@@ -0,0 +1,187 @@
+use std::{
+    fmt,
+    fs::File,
+    io::BufWriter,
+    path::{Path, PathBuf},
+};
+
+use gif::{Encoder, Frame, Repeat};
+
+use crate::Canvas;
+
+/// Errors that can occur while recording an animated GIF
+#[derive(Debug)]
+pub enum GifError {
+    /// A frame did not have the same dimensions as the recorder
+    DimensionMismatch {
+        expected: (usize, usize),
+        found: (usize, usize),
+    },
+    /// The recorder dimensions do not fit in a GIF (max 65535x65535)
+    TooLarge,
+    /// `finish` was called before any frames were pushed
+    NoFrames,
+    Io(std::io::Error),
+    Encoding(gif::EncodingError),
+}
+
+impl fmt::Display for GifError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GifError::DimensionMismatch { expected, found } => write!(
+                f,
+                "frame is {}x{} but the recorder is {}x{}",
+                found.0, found.1, expected.0, expected.1
+            ),
+            GifError::TooLarge => write!(f, "gif dimensions cannot exceed 65535x65535"),
+            GifError::NoFrames => write!(f, "no frames were recorded"),
+            GifError::Io(e) => write!(f, "{e}"),
+            GifError::Encoding(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl std::error::Error for GifError {}
+
+impl From<std::io::Error> for GifError {
+    fn from(value: std::io::Error) -> Self {
+        Self::Io(value)
+    }
+}
+
+impl From<gif::EncodingError> for GifError {
+    fn from(value: gif::EncodingError) -> Self {
+        Self::Encoding(value)
+    }
+}
+
+/// Records a sequence of canvases and encodes them as a looping animated GIF
+///
+/// Frames are quantized to a 256 color palette as they are pushed, and the
+/// file is only written once the recorder is finished.
+///
+/// ```
+/// use farba::{Canvas, GifRecorder, RGBAColor};
+///
+/// let path = std::env::temp_dir().join("farba_recorder_doctest.gif");
+/// let mut recorder = GifRecorder::new(&path, 16, 16, 30);
+///
+/// let mut canvas = Canvas::new(16, 16);
+///
+/// canvas.fill(RGBAColor::RED);
+/// recorder.push_frame(&canvas).unwrap();
+///
+/// canvas.fill(RGBAColor::BLUE);
+/// recorder.push_frame(&canvas).unwrap();
+///
+/// recorder.finish().unwrap();
+///
+/// let bytes = std::fs::read(&path).unwrap();
+/// assert!(bytes.starts_with(b"GIF89a"));
+///
+/// let mut decoder = gif::DecodeOptions::new().read_info(bytes.as_slice()).unwrap();
+/// let mut frames = 0;
+/// while decoder.read_next_frame().unwrap().is_some() {
+///     frames += 1;
+/// }
+/// assert_eq!(frames, 2);
+/// ```
+pub struct GifRecorder {
+    path: PathBuf,
+    width: usize,
+    height: usize,
+    delay: u16,
+    frames: Vec<Frame<'static>>,
+}
+
+impl GifRecorder {
+    /// Creates a new recorder which will write a `width` by `height` GIF to
+    /// `path`, played back at roughly `fps` frames per second
+    ///
+    /// GIF frame delays are stored in hundredths of a second, so the frame rate
+    /// is rounded to the nearest representable delay
+    pub fn new<P: AsRef<Path>>(path: P, width: usize, height: usize, fps: u32) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+            width,
+            height,
+            delay: (100.0 / fps.max(1) as f32).round().max(1.0) as u16,
+            frames: Vec::new(),
+        }
+    }
+
+    /// Quantizes the canvas and appends it as the next frame of the animation
+    ///
+    /// Premultiplied canvases are converted back to straight alpha first, so
+    /// translucent pixels keep their color.
+    ///
+    /// ```
+    /// use farba::{Canvas, GifRecorder, RGBAColor};
+    ///
+    /// let path = std::env::temp_dir().join("farba_recorder_premultiplied_doctest.gif");
+    /// let mut recorder = GifRecorder::new(&path, 4, 4, 30);
+    ///
+    /// let mut canvas = Canvas::new(4, 4);
+    /// canvas.set_premultiplied(true);
+    /// canvas.fill(RGBAColor::from_rgba(255, 0, 0, 128));
+    ///
+    /// recorder.push_frame(&canvas).unwrap();
+    /// recorder.finish().unwrap();
+    ///
+    /// let bytes = std::fs::read(&path).unwrap();
+    /// let mut options = gif::DecodeOptions::new();
+    /// options.set_color_output(gif::ColorOutput::RGBA);
+    ///
+    /// let mut decoder = options.read_info(bytes.as_slice()).unwrap();
+    /// let frame = decoder.read_next_frame().unwrap().unwrap();
+    ///
+    /// // The red channel would be halved if the premultiplied pixels were saved
+    /// assert!(frame.buffer[0] > 250);
+    /// ```
+    pub fn push_frame(&mut self, canvas: &Canvas) -> Result<(), GifError> {
+        if canvas.get_width() != self.width || canvas.get_height() != self.height {
+            return Err(GifError::DimensionMismatch {
+                expected: (self.width, self.height),
+                found: (canvas.get_width(), canvas.get_height()),
+            });
+        }
+
+        let (Ok(width), Ok(height)) = (u16::try_from(self.width), u16::try_from(self.height))
+        else {
+            return Err(GifError::TooLarge);
+        };
+
+        // GIF palettes hold straight colors, even when the canvas is premultiplied
+        let mut data: Vec<u8> = canvas
+            .straight_pixels()
+            .into_iter()
+            .flat_map(u32::to_le_bytes)
+            .collect();
+
+        let mut frame = Frame::from_rgba_speed(width, height, &mut data, 10);
+        frame.delay = self.delay;
+
+        self.frames.push(frame);
+
+        Ok(())
+    }
+
+    /// Encodes all of the recorded frames and writes the GIF to disk
+    pub fn finish(self) -> Result<(), GifError> {
+        if self.frames.is_empty() {
+            return Err(GifError::NoFrames);
+        }
+
+        let file = BufWriter::new(File::create(&self.path)?);
+
+        // The dimensions were already validated when the frames were pushed
+        let mut encoder = Encoder::new(file, self.width as u16, self.height as u16, &[])?;
+        encoder.set_repeat(Repeat::Infinite)?;
+
+        for frame in &self.frames {
+            encoder.write_frame(frame)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,469 @@
+use std::collections::HashMap;
+
+use crate::{BitmapFont, Canvas, Color, RGBAColor, Vec2};
+
+/// Effect drawn behind the glyphs by [`Canvas::text_sdf`], sized in pixels of
+/// the canvas
+///
+/// Effects can only reach as far from the glyphs as the spread of the font,
+/// scaled up along with the text.
+#[derive(Debug, Default, Clone)]
+pub enum SdfEffect {
+    #[default]
+    None,
+    /// Surrounds the glyphs with a border `width` pixels wide
+    Outline { width: f32, color: RGBAColor },
+    /// Surrounds the glyphs with a halo fading out over `radius` pixels
+    Glow { radius: f32, color: RGBAColor },
+}
+
+impl SdfEffect {
+    /// Color of the effect and how much of a pixel it covers, given the signed
+    /// `distance` in pixels from the center of the pixel to the edge of the text
+    fn coverage(&self, distance: f32) -> Option<(u32, f32)> {
+        let (color, coverage) = match self {
+            SdfEffect::None => return None,
+            SdfEffect::Outline { width, color } => (color, distance + width + 0.5),
+            SdfEffect::Glow { radius, color } => (color, 1.0 + distance / radius.max(f32::EPSILON)),
+        };
+
+        let coverage = coverage.clamp(0.0, 1.0);
+        (coverage > 0.0).then(|| (color.pack(), coverage))
+    }
+}
+
+/// The distance field of a single character in an [`SdfFont`]
+#[derive(Debug, Clone)]
+struct SdfGlyph {
+    width: usize,
+    height: usize,
+    /// Offset of the left edge of the field from the pen position
+    left: i32,
+    /// Offset of the top edge of the field from the top of the line
+    top: i32,
+    /// How far the pen moves to the right after drawing the glyph
+    advance: f32,
+    /// Signed distance from the center of each pixel of the field to the edge
+    /// of the glyph, positive on the inside, row by row
+    distances: Vec<f32>,
+}
+
+/// A font storing the distance from every pixel to the outline of each glyph,
+/// which can be drawn with [`Canvas::text_sdf`] at any scale
+///
+/// Rather than storing which pixels are covered, each glyph stores how far
+/// every pixel is from its edge, up to the `spread` of the font. Blending
+/// between these distances gives smooth outlines at any scale, and outlines
+/// and glows are just other thresholds of the same distances.
+///
+/// ```
+/// use farba::{BitmapFont, Canvas, Color, RGBAColor, SdfEffect, SdfFont, Vec2};
+///
+/// let font = SdfFont::from_bitmap_font(BitmapFont::builtin(), 4);
+///
+/// let mut canvas = Canvas::new(200, 60);
+/// canvas.text_sdf(&font, 6.0, Vec2::new(4.0, 4.0), "Hi", &SdfEffect::None, RGBAColor::WHITE);
+///
+/// // The left stroke of the H, from 4 to 16 pixels across
+/// assert_eq!(canvas.get_pixel(10, 10).alpha(), 255);
+/// assert_eq!(canvas.get_pixel(20, 10).alpha(), 0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SdfFont {
+    glyphs: HashMap<char, SdfGlyph>,
+    line_height: i32,
+    spread: usize,
+}
+
+impl SdfFont {
+    /// Builds the distance fields for every glyph of a bitmap `font`, reaching
+    /// `spread` pixels of the font away from each glyph
+    pub fn from_bitmap_font(font: &BitmapFont, spread: usize) -> Self {
+        let glyphs = font.glyphs().map(|(character, glyph)| {
+            let coverage = |x: usize, y: usize| glyph.pixels[y * glyph.width + x] as u8 as f32;
+            let distances = distance_field(glyph.width, glyph.height, spread, coverage);
+
+            let glyph = SdfGlyph {
+                width: glyph.width + 2 * spread,
+                height: glyph.height + 2 * spread,
+                left: glyph.left - spread as i32,
+                top: glyph.top - spread as i32,
+                advance: glyph.advance as f32,
+                distances,
+            };
+
+            (character, glyph)
+        });
+
+        Self {
+            glyphs: glyphs.collect(),
+            line_height: font.get_line_height(),
+            spread,
+        }
+    }
+
+    /// Reads a monospaced font out of a distance field `atlas`, which holds a
+    /// field for every one of `characters` in a grid of `glyph_width` by
+    /// `glyph_height` cells, ordered left to right and then top to bottom
+    ///
+    /// The distances are stored in the alpha channel, where 128 is on the edge
+    /// of the glyph and 255 and 0 are `spread` pixels inside and outside of it.
+    /// This is the format written by most distance field font generators.
+    pub fn from_atlas(
+        atlas: &Canvas,
+        glyph_width: usize,
+        glyph_height: usize,
+        characters: &str,
+        spread: usize,
+    ) -> Self {
+        let mut font = Self {
+            glyphs: HashMap::new(),
+            line_height: glyph_height as i32,
+            spread,
+        };
+
+        if glyph_width == 0 || glyph_height == 0 {
+            return font;
+        }
+
+        let columns = atlas.get_width() / glyph_width;
+        let rows = atlas.get_height() / glyph_height;
+
+        for (i, character) in characters.chars().take(columns * rows).enumerate() {
+            let cell_x = i % columns * glyph_width;
+            let cell_y = i / columns * glyph_height;
+
+            let distances = (0..glyph_width * glyph_height)
+                .map(|i| {
+                    let x = (cell_x + i % glyph_width) as i32;
+                    let y = (cell_y + i / glyph_width) as i32;
+
+                    let alpha = atlas.get_pixel(x, y).alpha() as f32;
+                    (alpha - 128.0) / 127.0 * spread as f32
+                })
+                .collect();
+
+            let glyph = SdfGlyph {
+                width: glyph_width,
+                height: glyph_height,
+                left: 0,
+                top: 0,
+                advance: glyph_width as f32,
+                distances,
+            };
+
+            font.glyphs.insert(character, glyph);
+        }
+
+        font
+    }
+
+    /// Builds the distance fields for each of `characters` in a TrueType or
+    /// OpenType `font`, rasterized `size` pixels tall and reaching `spread`
+    /// pixels away from each glyph
+    ///
+    /// Drawing the result at a scale of 1 gives text the same size as
+    /// [`Canvas::text_ttf`] at `size`.
+    #[cfg(feature = "ttf")]
+    pub fn from_ttf<F: ab_glyph::Font>(
+        font: &F,
+        size: f32,
+        characters: &str,
+        spread: usize,
+    ) -> Self {
+        use ab_glyph::{PxScale, ScaleFont};
+
+        let font = font.as_scaled(PxScale::from(size));
+        let ascent = font.ascent();
+
+        let glyphs = characters.chars().map(|character| {
+            let id = font.glyph_id(character);
+            let advance = font.h_advance(id);
+
+            let glyph = id.with_scale_and_position(font.scale(), ab_glyph::point(0.0, ascent));
+
+            let Some(outline) = font.outline_glyph(glyph) else {
+                let glyph = SdfGlyph {
+                    width: 0,
+                    height: 0,
+                    left: 0,
+                    top: 0,
+                    advance,
+                    distances: Vec::new(),
+                };
+
+                return (character, glyph);
+            };
+
+            let bounds = outline.px_bounds();
+            let width = bounds.width() as usize;
+            let height = bounds.height() as usize;
+
+            let mut coverage = vec![0.0; width * height];
+            outline.draw(|x, y, value| {
+                if let Some(pixel) = coverage.get_mut(y as usize * width + x as usize) {
+                    *pixel = value.min(1.0);
+                }
+            });
+
+            let distances = distance_field(width, height, spread, |x, y| coverage[y * width + x]);
+
+            let glyph = SdfGlyph {
+                width: width + 2 * spread,
+                height: height + 2 * spread,
+                left: bounds.min.x as i32 - spread as i32,
+                top: bounds.min.y as i32 - spread as i32,
+                advance,
+                distances,
+            };
+
+            (character, glyph)
+        });
+
+        Self {
+            glyphs: glyphs.collect(),
+            line_height: (font.height() + font.line_gap()).round() as i32,
+            spread,
+        }
+    }
+
+    /// Distance in pixels of the font between the tops of consecutive lines of
+    /// text
+    pub fn get_line_height(&self) -> i32 {
+        self.line_height
+    }
+
+    /// How many pixels of the font away from the glyphs their distance fields
+    /// reach
+    pub fn get_spread(&self) -> usize {
+        self.spread
+    }
+
+    pub fn has_glyph(&self, character: char) -> bool {
+        self.glyphs.contains_key(&character)
+    }
+
+    /// Gets the glyph drawn for `character`, which is the `?` glyph for
+    /// characters the font doesn't have
+    fn glyph(&self, character: char) -> Option<&SdfGlyph> {
+        self.glyphs
+            .get(&character)
+            .or_else(|| self.glyphs.get(&'?'))
+    }
+}
+
+impl SdfGlyph {
+    /// Samples the distance field with bilinear filtering at `(u, v)`, in
+    /// pixels of the field
+    fn sample(&self, u: f32, v: f32) -> f32 {
+        // Shift into pixel-center space so whole coordinates land between pixels
+        let fx = u - 0.5;
+        let fy = v - 0.5;
+
+        let x0 = fx.floor() as i32;
+        let y0 = fy.floor() as i32;
+        let tx = fx - x0 as f32;
+        let ty = fy - y0 as f32;
+
+        let distance = |x: i32, y: i32| {
+            let x = x.clamp(0, self.width as i32 - 1) as usize;
+            let y = y.clamp(0, self.height as i32 - 1) as usize;
+
+            self.distances[y * self.width + x]
+        };
+
+        let top = distance(x0, y0) * (1.0 - tx) + distance(x0 + 1, y0) * tx;
+        let bottom = distance(x0, y0 + 1) * (1.0 - tx) + distance(x0 + 1, y0 + 1) * tx;
+
+        top * (1.0 - ty) + bottom * ty
+    }
+}
+
+/// Computes the signed distance from every pixel to the edge of a `width` by
+/// `height` shape, given how much of each of its pixels is covered
+///
+/// The field has a margin of `spread` pixels on every side, and distances are
+/// limited to `spread`.
+fn distance_field<F: Fn(usize, usize) -> f32>(
+    width: usize,
+    height: usize,
+    spread: usize,
+    coverage: F,
+) -> Vec<f32> {
+    let field_width = width + 2 * spread;
+    let field_height = height + 2 * spread;
+
+    let covered: Vec<f32> = (0..field_width * field_height)
+        .map(|i| {
+            let x = (i % field_width).checked_sub(spread);
+            let y = (i / field_width).checked_sub(spread);
+
+            match (x, y) {
+                (Some(x), Some(y)) if x < width && y < height => coverage(x, y),
+                _ => 0.0,
+            }
+        })
+        .collect();
+
+    let inside = |x: i32, y: i32| {
+        let in_bounds = x >= 0 && y >= 0 && x < field_width as i32 && y < field_height as i32;
+        in_bounds && covered[y as usize * field_width + x as usize] >= 0.5
+    };
+
+    let limit = spread as f32;
+    let reach = spread as i32 + 1;
+
+    (0..field_width * field_height)
+        .map(|i| {
+            let (x, y) = ((i % field_width) as i32, (i / field_width) as i32);
+            let value = covered[i];
+
+            // The edge runs through partially covered pixels, roughly as far
+            // from the center as the coverage is from half. Nearly empty or
+            // full pixels are left to the search below, since rasterizers
+            // leave a little rounding error in pixels away from the edge
+            if value > 0.01 && value < 0.99 {
+                return value - 0.5;
+            }
+
+            let is_inside = inside(x, y);
+
+            // Nearest pixel on the other side of the edge, with the edge being
+            // half a pixel before its center
+            let mut nearest = reach as f32;
+
+            for dy in -reach..=reach {
+                for dx in -reach..=reach {
+                    if inside(x + dx, y + dy) != is_inside {
+                        nearest = nearest.min(((dx * dx + dy * dy) as f32).sqrt());
+                    }
+                }
+            }
+
+            let distance = (nearest - 0.5).min(limit);
+
+            if is_inside {
+                distance
+            } else {
+                -distance
+            }
+        })
+        .collect()
+}
+
+impl Canvas {
+    /// Draws `text` with a distance field `font`, with the top left corner of
+    /// the first line at `pos` and every pixel of the font covering `scale`
+    /// pixels of the canvas
+    ///
+    /// The edges of the glyphs are smoothly blended into the canvas at any
+    /// scale, and `effect` is drawn behind them. Overlapping glyphs are merged
+    /// into one shape first, so neither the glyphs nor their effects get darker
+    /// where they overlap.
+    ///
+    /// Glyphs are drawn in the pixels of the canvas, ignoring the current
+    /// transform.
+    pub fn text_sdf<C: Color>(
+        &mut self,
+        font: &SdfFont,
+        scale: f32,
+        pos: Vec2,
+        text: &str,
+        effect: &SdfEffect,
+        color: C,
+    ) {
+        if scale.is_nan() || scale <= 0.0 {
+            return;
+        }
+
+        // Glyphs placed with the position of the top left corner of their
+        // fields on the canvas
+        let mut placed = Vec::new();
+
+        for (line_index, line) in text.lines().enumerate() {
+            let top = pos.y + (line_index as i32 * font.line_height) as f32 * scale;
+            let mut pen = pos.x;
+
+            for character in line.chars() {
+                let Some(glyph) = font.glyph(character) else {
+                    continue;
+                };
+
+                // Glyphs without an outline, like spaces, only move the pen
+                if glyph.distances.is_empty() {
+                    pen += glyph.advance * scale;
+                    continue;
+                }
+
+                let corner = Vec2::new(
+                    pen + glyph.left as f32 * scale,
+                    top + glyph.top as f32 * scale,
+                );
+
+                placed.push((glyph, corner));
+                pen += glyph.advance * scale;
+            }
+        }
+
+        let (clip_left, clip_top, clip_right, clip_bottom) = self.clip_bounds();
+
+        let (mut left, mut top) = (i32::MAX, i32::MAX);
+        let (mut right, mut bottom) = (i32::MIN, i32::MIN);
+
+        for (glyph, corner) in &placed {
+            left = left.min(corner.x.floor() as i32);
+            top = top.min(corner.y.floor() as i32);
+            right = right.max((corner.x + glyph.width as f32 * scale).ceil() as i32);
+            bottom = bottom.max((corner.y + glyph.height as f32 * scale).ceil() as i32);
+        }
+
+        let (left, top) = (left.max(clip_left), top.max(clip_top));
+        let (right, bottom) = (right.min(clip_right + 1), bottom.min(clip_bottom + 1));
+
+        if left >= right || top >= bottom {
+            return;
+        }
+
+        let width = (right - left) as usize;
+        let height = (bottom - top) as usize;
+
+        // Distance in pixels of the canvas from each pixel to the nearest glyph
+        let mut distances = vec![f32::NEG_INFINITY; width * height];
+
+        for (glyph, corner) in &placed {
+            let x1 = (corner.x.floor() as i32).max(left);
+            let y1 = (corner.y.floor() as i32).max(top);
+            let x2 = ((corner.x + glyph.width as f32 * scale).ceil() as i32).min(right);
+            let y2 = ((corner.y + glyph.height as f32 * scale).ceil() as i32).min(bottom);
+
+            for y in y1..y2 {
+                for x in x1..x2 {
+                    let u = (x as f32 + 0.5 - corner.x) / scale;
+                    let v = (y as f32 + 0.5 - corner.y) / scale;
+
+                    let distance = glyph.sample(u, v) * scale;
+
+                    let index = (y - top) as usize * width + (x - left) as usize;
+                    distances[index] = distances[index].max(distance);
+                }
+            }
+        }
+
+        let color = color.pack();
+
+        for (i, &distance) in distances.iter().enumerate() {
+            let x = left + (i % width) as i32;
+            let y = top + (i / width) as i32;
+
+            if let Some((effect_color, coverage)) = effect.coverage(distance) {
+                self.cover_pixel(x, y, effect_color, coverage);
+            }
+
+            // A pixel centered exactly on the edge is half covered
+            let coverage = (distance + 0.5).clamp(0.0, 1.0);
+            if coverage > 0.0 {
+                self.cover_pixel(x, y, color, coverage);
+            }
+        }
+    }
+}
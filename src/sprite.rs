@@ -0,0 +1,144 @@
+use std::collections::HashMap;
+
+use crate::{Canvas, Flip};
+
+/// An image holding many sprites, such as the frames of an animation, along
+/// with the area of the image each sprite covers
+///
+/// Sprites are found by their index, in the order they were added, or by the
+/// name they were given. Sheets laid out in a regular grid can have all of
+/// their sprites added at once with [`SpriteSheet::from_grid`].
+///
+/// ```
+/// use farba::{Canvas, Flip, RGBAColor, SpriteSheet};
+///
+/// // Two 2x2 frames side by side
+/// let mut image = Canvas::new(4, 2);
+/// image.rect(0, 0, 2, 2, RGBAColor::RED);
+/// image.rect(2, 0, 2, 2, RGBAColor::BLUE);
+///
+/// let mut sheet = SpriteSheet::from_grid(image, 2, 2);
+/// sheet.set_name(1, "jump");
+///
+/// let mut canvas = Canvas::new(10, 10);
+/// sheet.draw_sprite(&mut canvas, 0, 0, 0, Flip::None);
+/// sheet.draw_named(&mut canvas, "jump", 5, 5, Flip::None);
+///
+/// assert_eq!(*canvas.get_pixel(1, 1), RGBAColor::RED.into());
+/// assert_eq!(*canvas.get_pixel(6, 6), RGBAColor::BLUE.into());
+/// ```
+#[derive(Debug)]
+pub struct SpriteSheet {
+    canvas: Canvas,
+    /// Area of every sprite as `(x, y, width, height)`
+    regions: Vec<(i32, i32, i32, i32)>,
+    names: HashMap<String, usize>,
+}
+
+impl SpriteSheet {
+    /// Creates a sprite sheet from `canvas` without any sprites
+    pub fn new(canvas: Canvas) -> Self {
+        Self {
+            canvas,
+            regions: Vec::new(),
+            names: HashMap::new(),
+        }
+    }
+
+    /// Creates a sprite sheet by cutting `canvas` into a grid of sprites which
+    /// are `tile_width` by `tile_height` pixels, numbered left to right and then
+    /// top to bottom
+    ///
+    /// Partial tiles along the right and bottom edges are left out.
+    pub fn from_grid(canvas: Canvas, tile_width: usize, tile_height: usize) -> Self {
+        let mut sheet = Self::new(canvas);
+
+        if tile_width == 0 || tile_height == 0 {
+            return sheet;
+        }
+
+        let columns = sheet.canvas.get_width() / tile_width;
+        let rows = sheet.canvas.get_height() / tile_height;
+
+        for row in 0..rows {
+            for column in 0..columns {
+                sheet.add_sprite((
+                    (column * tile_width) as i32,
+                    (row * tile_height) as i32,
+                    tile_width as i32,
+                    tile_height as i32,
+                ));
+            }
+        }
+
+        sheet
+    }
+
+    pub fn get_canvas(&self) -> &Canvas {
+        &self.canvas
+    }
+
+    /// Adds a sprite covering `region` as `(x, y, width, height)`, returning its
+    /// index
+    pub fn add_sprite(&mut self, region: (i32, i32, i32, i32)) -> usize {
+        self.regions.push(region);
+        self.regions.len() - 1
+    }
+
+    /// Adds a sprite covering `region` like [`SpriteSheet::add_sprite`], which
+    /// can also be found by `name`
+    pub fn add_named_sprite(&mut self, name: &str, region: (i32, i32, i32, i32)) -> usize {
+        let index = self.add_sprite(region);
+        self.names.insert(name.to_owned(), index);
+
+        index
+    }
+
+    /// Gives the sprite at `index` a `name` it can be found by, replacing any
+    /// sprite which previously had that name
+    pub fn set_name(&mut self, index: usize, name: &str) {
+        self.names.insert(name.to_owned(), index);
+    }
+
+    /// Finds the index of the sprite called `name`
+    pub fn find(&self, name: &str) -> Option<usize> {
+        self.names.get(name).copied()
+    }
+
+    /// Gets the area covered by the sprite at `index` as `(x, y, width, height)`
+    pub fn get_region(&self, index: usize) -> Option<(i32, i32, i32, i32)> {
+        self.regions.get(index).copied()
+    }
+
+    /// Number of sprites in the sheet
+    pub fn len(&self) -> usize {
+        self.regions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.regions.is_empty()
+    }
+
+    /// Draws the sprite at `index` onto `canvas` with its top left corner on
+    /// `(x, y)`, mirrored according to `flip`
+    ///
+    /// Sprites are blended over the canvas like [`Canvas::blit_blend`], so
+    /// their transparent backgrounds are left out. Nothing is drawn if there
+    /// is no sprite at `index`.
+    pub fn draw_sprite(&self, canvas: &mut Canvas, index: usize, x: i32, y: i32, flip: Flip) {
+        let Some(region) = self.get_region(index) else {
+            return;
+        };
+
+        canvas.with_composite_op(canvas.image_composite_op(), |canvas| {
+            canvas.blit_flipped(&self.canvas, region, x, y, flip);
+        });
+    }
+
+    /// Draws the sprite called `name` like [`SpriteSheet::draw_sprite`]
+    pub fn draw_named(&self, canvas: &mut Canvas, name: &str, x: i32, y: i32, flip: Flip) {
+        if let Some(index) = self.find(name) {
+            self.draw_sprite(canvas, index, x, y, flip);
+        }
+    }
+}
@@ -0,0 +1,217 @@
+use std::{fmt, path::Path};
+
+use crate::{obj::read_vector, Mesh, Vec3};
+
+/// Errors that can occur while loading an STL file into a [`Mesh`]
+#[derive(Debug)]
+pub enum StlError {
+    /// A binary file ended before all of the triangles it describes
+    Truncated,
+    /// A line of an ASCII file could not be understood, counting from 1
+    InvalidAscii {
+        line: usize,
+    },
+    Io(std::io::Error),
+}
+
+impl fmt::Display for StlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StlError::Truncated => write!(f, "STL data ends before all of its triangles"),
+            StlError::InvalidAscii { line } => write!(f, "invalid ASCII STL on line {line}"),
+            StlError::Io(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl std::error::Error for StlError {}
+
+impl From<std::io::Error> for StlError {
+    fn from(value: std::io::Error) -> Self {
+        Self::Io(value)
+    }
+}
+
+/// Size of the header of a binary STL, before the triangle count
+const BINARY_HEADER: usize = 80;
+
+/// Size of each triangle of a binary STL, as twelve floats and a two byte
+/// attribute count
+const BINARY_TRIANGLE: usize = 50;
+
+impl Mesh {
+    /// Loads the STL file at `path`, in either the binary or the ASCII format
+    pub fn load_stl<P: AsRef<Path>>(path: P) -> Result<Mesh, StlError> {
+        Self::from_stl(&std::fs::read(path)?)
+    }
+
+    /// Reads an STL file, in either the binary or the ASCII format
+    ///
+    /// STL stores every triangle on its own, so each gets its own three
+    /// vertices, all with the normal of the triangle. That keeps the flat look
+    /// of the faces, which suits the hard edges of most CAD models and 3D
+    /// prints. Normals missing from the file are worked out from the corners.
+    ///
+    /// STL is right handed, so the mesh is mirrored with [`Mesh::mirror_z`].
+    ///
+    /// ```
+    /// use farba::{Mesh, Vec3};
+    ///
+    /// let mesh = Mesh::from_stl(
+    ///     b"solid triangle
+    ///       facet normal 0 0 0
+    ///         outer loop
+    ///           vertex 0 0 0
+    ///           vertex 1 0 0
+    ///           vertex 0 1 0
+    ///         endloop
+    ///       endfacet
+    ///     endsolid triangle",
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(mesh.triangle_count(), 1);
+    ///
+    /// // z is flipped, while the triangle keeps facing the way its normal does
+    /// assert_eq!(mesh.triangle(0)[1], Vec3::new(0.0, 1.0, 0.0));
+    /// assert_eq!(mesh.normals.as_ref().unwrap()[0], Vec3::new(0.0, 0.0, -1.0));
+    /// assert_eq!(mesh.face_normal(0), Vec3::new(0.0, 0.0, -1.0));
+    /// ```
+    ///
+    /// Binary files holding fewer triangles than their header claims are
+    /// rejected.
+    ///
+    /// ```
+    /// use farba::{Mesh, StlError};
+    ///
+    /// let mut data = vec![0; 84];
+    /// data[80..].copy_from_slice(&u32::MAX.to_le_bytes());
+    ///
+    /// assert!(matches!(Mesh::from_stl(&data), Err(StlError::Truncated)));
+    /// ```
+    pub fn from_stl(data: &[u8]) -> Result<Mesh, StlError> {
+        // Binary files are allowed to start with `solid` too, so they are also
+        // told apart by their size and by holding more than just text
+        let binary_size = data
+            .get(BINARY_HEADER..BINARY_HEADER + 4)
+            .map(|count| u32::from_le_bytes(count.try_into().unwrap()) as usize)
+            .and_then(|count| count.checked_mul(BINARY_TRIANGLE))
+            .and_then(|size| size.checked_add(BINARY_HEADER + 4));
+
+        let is_text = data
+            .iter()
+            .all(|byte| byte.is_ascii_graphic() || byte.is_ascii_whitespace());
+
+        let facets = if data.starts_with(b"solid") && is_text && binary_size != Some(data.len()) {
+            read_ascii_stl(data)?
+        } else {
+            read_binary_stl(data)?
+        };
+
+        let mut mesh = Mesh::default();
+        let mut normals = Vec::with_capacity(facets.len() * 3);
+
+        for (normal, corners) in facets {
+            let [a, b, c] = corners;
+
+            let normal = if normal.magnitude_squared() > 0.0 {
+                normal.normalize()
+            } else {
+                let normal = (b - a).cross(&(c - a));
+
+                match normal.magnitude_squared() > 0.0 {
+                    true => normal.normalize(),
+                    false => Vec3::ZERO,
+                }
+            };
+
+            let first = mesh.positions.len() as u32;
+
+            mesh.positions.extend(corners);
+            mesh.indices.push([first, first + 1, first + 2]);
+            normals.extend([normal; 3]);
+        }
+
+        mesh.normals = Some(normals);
+        mesh.mirror_z();
+
+        Ok(mesh)
+    }
+}
+
+/// Reads the normal and corners of every triangle of a binary STL
+fn read_binary_stl(data: &[u8]) -> Result<Vec<(Vec3, [Vec3; 3])>, StlError> {
+    let count = data
+        .get(BINARY_HEADER..BINARY_HEADER + 4)
+        .ok_or(StlError::Truncated)?;
+    let count = u32::from_le_bytes(count.try_into().unwrap()) as usize;
+
+    let triangles = &data[BINARY_HEADER + 4..];
+
+    // The count comes straight from the file, so it may be too large to fit
+    // in memory on 32 bit targets
+    let size = count
+        .checked_mul(BINARY_TRIANGLE)
+        .ok_or(StlError::Truncated)?;
+
+    if triangles.len() < size {
+        return Err(StlError::Truncated);
+    }
+
+    let facets = triangles
+        .chunks_exact(BINARY_TRIANGLE)
+        .take(count)
+        .map(|triangle| {
+            let float =
+                |i: usize| f32::from_le_bytes(triangle[i * 4..i * 4 + 4].try_into().unwrap());
+            let vector = |i: usize| Vec3::new(float(i * 3), float(i * 3 + 1), float(i * 3 + 2));
+
+            (vector(0), [vector(1), vector(2), vector(3)])
+        })
+        .collect();
+
+    Ok(facets)
+}
+
+/// Reads the normal and corners of every triangle of an ASCII STL
+fn read_ascii_stl(data: &[u8]) -> Result<Vec<(Vec3, [Vec3; 3])>, StlError> {
+    let source = String::from_utf8_lossy(data);
+
+    let mut facets = Vec::new();
+    let mut normal = Vec3::ZERO;
+    let mut corners = Vec::with_capacity(3);
+
+    for (index, line) in source.lines().enumerate() {
+        let invalid = || StlError::InvalidAscii { line: index + 1 };
+
+        let mut words = line.split_whitespace();
+
+        match words.next() {
+            Some("facet") => {
+                if words.next() != Some("normal") {
+                    return Err(invalid());
+                }
+
+                normal = read_vector(&mut words).ok_or_else(invalid)?;
+                corners.clear();
+            }
+            Some("vertex") => {
+                if corners.len() == 3 {
+                    return Err(invalid());
+                }
+
+                corners.push(read_vector(&mut words).ok_or_else(invalid)?);
+            }
+            Some("endfacet") => {
+                let Ok(triangle) = <[Vec3; 3]>::try_from(corners.as_slice()) else {
+                    return Err(invalid());
+                };
+
+                facets.push((normal, triangle));
+            }
+            _ => {}
+        }
+    }
+
+    Ok(facets)
+}
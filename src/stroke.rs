@@ -0,0 +1,455 @@
+use crate::{clipped_line_pixels, contour_spans, Canvas, Color, FillRule, Paint, Vec2};
+
+/// The shape drawn at the ends of a thick line
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum LineCap {
+    /// The line ends exactly at its endpoints
+    #[default]
+    Butt,
+    /// The line ends in a half circle centered on each endpoint
+    Round,
+    /// The line is extended past each endpoint by half of its width
+    Square,
+}
+
+/// The shape drawn where two segments of a thick stroke meet
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum LineJoin {
+    /// The outer edges of both segments are extended until they meet in a point.
+    /// Joins so sharp that the point would reach more than 4 times the width of
+    /// the stroke past the inner corner are beveled instead
+    #[default]
+    Miter,
+    /// The corner is rounded off with a circle centered on the joint
+    Round,
+    /// The corner is cut off with a straight line between the outer edges
+    Bevel,
+}
+
+/// How far a miter join may reach from the inner corner to its tip, relative
+/// to the width of the stroke (the same default as SVG)
+pub(crate) const MITER_LIMIT: f32 = 4.0;
+
+impl Canvas {
+    /// Draws a line between `p1` and `p2` which is `width` pixels wide, with the
+    /// ends shaped by `cap`
+    ///
+    /// The line and its caps are rasterized together as a single filled shape, so
+    /// no pixel is drawn more than once
+    pub fn thick_line<P: Paint>(&mut self, p1: Vec2, p2: Vec2, width: f32, cap: LineCap, paint: P) {
+        let Some(outline) = line_outline(p1, p2, width, cap) else {
+            return;
+        };
+
+        self.fill_contours(&[outline], FillRule::NonZero, &paint);
+    }
+
+    /// Draws connected line segments which are `width` pixels wide, rounding off
+    /// every joint and both ends
+    ///
+    /// All of the segments are filled together as one shape, so the overlap at
+    /// each joint is only drawn once
+    pub(crate) fn thick_polyline<P: Paint>(&mut self, points: &[Vec2], width: f32, paint: &P) {
+        let outlines: Vec<Vec<Vec2>> = match points {
+            [] => return,
+            [point] => line_outline(*point, *point, width, LineCap::Round)
+                .into_iter()
+                .collect(),
+            _ => points
+                .windows(2)
+                .filter_map(|segment| line_outline(segment[0], segment[1], width, LineCap::Round))
+                .collect(),
+        };
+
+        self.fill_contours(&outlines, FillRule::NonZero, paint);
+    }
+
+    /// Fills the closed `contours` together as one shape, after mapping them
+    /// through the current transform
+    pub(crate) fn fill_contours<C: AsRef<[Vec2]>, P: Paint>(
+        &mut self,
+        contours: &[C],
+        rule: FillRule,
+        paint: &P,
+    ) {
+        if self.has_transform() {
+            let transformed: Vec<Vec<Vec2>> = contours
+                .iter()
+                .map(|contour| {
+                    let points = contour.as_ref().iter();
+                    points.map(|point| self.transform_point(*point)).collect()
+                })
+                .collect();
+
+            self.fill_canvas_contours(&transformed, rule, paint);
+        } else {
+            self.fill_canvas_contours(contours, rule, paint);
+        }
+    }
+
+    /// Fills `contours` which are already in the coordinates of the canvas
+    fn fill_canvas_contours<C: AsRef<[Vec2]>, P: Paint>(
+        &mut self,
+        contours: &[C],
+        rule: FillRule,
+        paint: &P,
+    ) {
+        contour_spans(
+            contours,
+            rule,
+            self.get_width(),
+            self.get_height(),
+            |y, x1, x2| {
+                for x in x1..=x2 {
+                    self.paint_pixel(x, y, paint);
+                }
+            },
+        );
+    }
+
+    /// Draws one pixel wide line segments connecting each point to the next
+    ///
+    /// Every pixel along the polyline is drawn exactly once, so the joints
+    /// between segments are not drawn twice
+    ///
+    /// ```
+    /// use farba::{Canvas, RGBAColor, Vec2};
+    ///
+    /// let mut canvas = Canvas::new(20, 20);
+    /// canvas.fill(RGBAColor::WHITE);
+    /// canvas.set_alpha_blending(true);
+    ///
+    /// let points = [
+    ///     Vec2::new(2.5, 2.5),
+    ///     Vec2::new(12.5, 2.5),
+    ///     Vec2::new(12.5, 12.5),
+    /// ];
+    /// canvas.polyline(&points, RGBAColor::from_rgba(255, 0, 0, 128));
+    ///
+    /// // A translucent joint is blended once, just like the middle of a segment
+    /// let middle = *canvas.get_pixel(7, 2);
+    /// assert_ne!(middle, u32::from(RGBAColor::WHITE));
+    /// assert_eq!(*canvas.get_pixel(12, 2), middle);
+    /// assert_eq!(*canvas.get_pixel(12, 7), middle);
+    /// ```
+    pub fn polyline<C: Color>(&mut self, points: &[Vec2], color: C) {
+        self.connected_segments(points, false, &color.pack());
+    }
+
+    /// Draws the outline of the polygon described by `points`, including the
+    /// segment closing the last point back to the first
+    ///
+    /// Like [`Canvas::polyline`], no pixel at a joint is drawn more than once
+    ///
+    /// ```
+    /// use farba::{Canvas, RGBAColor, Vec2};
+    ///
+    /// let mut canvas = Canvas::new(20, 20);
+    /// canvas.fill(RGBAColor::WHITE);
+    /// canvas.set_alpha_blending(true);
+    ///
+    /// let points = [
+    ///     Vec2::new(2.5, 2.5),
+    ///     Vec2::new(12.5, 2.5),
+    ///     Vec2::new(12.5, 12.5),
+    /// ];
+    /// canvas.polygon_outline(&points, RGBAColor::from_rgba(255, 0, 0, 128));
+    ///
+    /// // Including the corner where the outline closes back on itself
+    /// let middle = *canvas.get_pixel(7, 2);
+    /// assert_eq!(*canvas.get_pixel(2, 2), middle);
+    /// assert_eq!(*canvas.get_pixel(12, 2), middle);
+    /// assert_eq!(*canvas.get_pixel(12, 12), middle);
+    /// ```
+    pub fn polygon_outline<C: Color>(&mut self, points: &[Vec2], color: C) {
+        // With fewer than 3 points the closing segment would just retrace the
+        // only other one
+        self.connected_segments(points, points.len() >= 3, &color.pack());
+    }
+
+    pub(crate) fn connected_segments<P: Paint>(
+        &mut self,
+        points: &[Vec2],
+        closed: bool,
+        paint: &P,
+    ) {
+        let transformed: Vec<Vec2>;
+
+        let points = if self.has_transform() {
+            transformed = points
+                .iter()
+                .map(|point| self.transform_point(*point))
+                .collect();
+            &transformed
+        } else {
+            points
+        };
+
+        let Some(first) = points.first() else {
+            return;
+        };
+
+        // Pixel (x, y) covers the area from (x, y) to (x + 1, y + 1)
+        let to_pixel = |point: &Vec2| (point.x.floor() as i32, point.y.floor() as i32);
+
+        let mut pixels: Vec<(i32, i32)> = points.iter().map(to_pixel).collect();
+        if closed {
+            pixels.push(to_pixel(first));
+        }
+
+        let bounds = self.clip_bounds();
+
+        let mut plot = |x: i32, y: i32| {
+            if self.in_bounds(x, y) {
+                self.paint_pixel(x, y, paint);
+            }
+        };
+
+        // Each segment leaves out its last pixel, since it is the first pixel of
+        // the next segment
+        for segment in pixels.windows(2) {
+            let ((x1, y1), (x2, y2)) = (segment[0], segment[1]);
+
+            clipped_line_pixels(x1, y1, x2, y2, bounds, |x, y| {
+                if (x, y) != (x2, y2) {
+                    plot(x, y)
+                }
+            });
+        }
+
+        // A closed outline ends where it started, otherwise the final point still
+        // needs to be drawn
+        if !closed {
+            let (x, y) = pixels[pixels.len() - 1];
+            plot(x, y);
+        }
+    }
+}
+
+/// Builds the outlines which together cover a stroke `width` pixels wide along
+/// `points`, to be filled as a union with the non-zero rule
+///
+/// Each segment gets its own outline, with extra pieces filling in the joints
+/// and the ends. A `closed` stroke also joins its last point back to the first,
+/// and doesn't get any caps.
+pub(crate) fn stroke_outlines(
+    points: &[Vec2],
+    closed: bool,
+    width: f32,
+    join: LineJoin,
+    cap: LineCap,
+) -> Vec<Vec<Vec2>> {
+    if width <= 0.0 {
+        return Vec::new();
+    }
+
+    let half_width = width / 2.0;
+
+    // Repeated points don't have a direction to build anything around
+    let same = |a: &Vec2, b: &Vec2| a.x == b.x && a.y == b.y;
+
+    let mut points = points.to_vec();
+    points.dedup_by(|a, b| same(a, b));
+
+    if closed && points.len() > 1 && same(&points[0], &points[points.len() - 1]) {
+        points.pop();
+    }
+
+    let mut outlines = Vec::new();
+
+    // A lone point is drawn as just its caps
+    if points.len() == 1 {
+        if cap != LineCap::Butt {
+            outlines.extend(line_outline(points[0], points[0], width, cap));
+        }
+
+        return outlines;
+    }
+
+    let segment_count = if closed && points.len() > 2 {
+        points.len()
+    } else {
+        points.len() - 1
+    };
+
+    let segment = |i: usize| (points[i % points.len()], points[(i + 1) % points.len()]);
+
+    let direction = |(a, b): (Vec2, Vec2)| (b - a).normalize();
+
+    for i in 0..segment_count {
+        let (a, b) = segment(i);
+        outlines.extend(line_outline(a, b, width, LineCap::Butt));
+    }
+
+    // Joints between each segment and the one after it
+    let joint_count = if segment_count == points.len() {
+        segment_count
+    } else {
+        segment_count - 1
+    };
+
+    for i in 0..joint_count {
+        let (_, joint) = segment(i);
+        let incoming = direction(segment(i));
+        let outgoing = direction(segment(i + 1));
+
+        // Nothing to fill in between segments heading the same way
+        if incoming.dot(outgoing) >= 1.0 - f32::EPSILON {
+            continue;
+        }
+
+        // The gap to fill is on the outside of the turn, away from where the
+        // path is heading
+        let side = if outgoing.dot(incoming.perp()) > 0.0 {
+            -1.0
+        } else {
+            1.0
+        };
+
+        let outer_in = incoming.perp() * side;
+        let outer_out = outgoing.perp() * side;
+
+        let bevel = vec![
+            joint,
+            joint + outer_in * half_width,
+            joint + outer_out * half_width,
+        ];
+
+        let outline = match join {
+            LineJoin::Round => {
+                outlines.extend(line_outline(joint, joint, width, LineCap::Round));
+                continue;
+            }
+            LineJoin::Bevel => bevel,
+            LineJoin::Miter => {
+                let bisector = outer_in + outer_out;
+                let bisector = bisector.normalize();
+
+                // Cosine of half of the angle between the two outer edges
+                let cos_half = bisector.dot(outer_in);
+
+                if cos_half * MITER_LIMIT < 1.0 {
+                    bevel
+                } else {
+                    vec![
+                        joint,
+                        joint + outer_in * half_width,
+                        joint + bisector * (half_width / cos_half),
+                        joint + outer_out * half_width,
+                    ]
+                }
+            }
+        };
+
+        outlines.push(wound_like_lines(outline));
+    }
+
+    if segment_count < points.len() && cap != LineCap::Butt {
+        let first = points[0];
+        let last = points[points.len() - 1];
+
+        // Caps only extend backwards from the start and forwards from the end
+        for (end, outwards) in [
+            (first, direction((points[1], first))),
+            (last, direction((points[points.len() - 2], last))),
+        ] {
+            let outline = match cap {
+                LineCap::Round => line_outline(end, end, width, LineCap::Round),
+                _ => line_outline(end, end + outwards * half_width, width, LineCap::Butt),
+            };
+
+            outlines.extend(outline);
+        }
+    }
+
+    outlines
+}
+
+/// Reverses `outline` if needed so that it winds in the same direction as the
+/// outlines built by `line_outline`, which is what keeps them from cancelling
+/// out when filled together
+fn wound_like_lines(mut outline: Vec<Vec2>) -> Vec<Vec2> {
+    let mut area = 0.0;
+
+    for (i, a) in outline.iter().enumerate() {
+        let b = outline[(i + 1) % outline.len()];
+        area += a.x * b.y - b.x * a.y;
+    }
+
+    if area < 0.0 {
+        outline.reverse();
+    }
+
+    outline
+}
+
+/// Builds the closed outline of a line between `p1` and `p2` which is `width`
+/// pixels wide, with the ends shaped by `cap`
+///
+/// Every outline is wound in the same direction regardless of the direction of
+/// the line, so several of them can be filled together as a union. Returns
+/// `None` if the line would not cover anything.
+pub(crate) fn line_outline(p1: Vec2, p2: Vec2, width: f32, cap: LineCap) -> Option<Vec<Vec2>> {
+    if width <= 0.0 {
+        return None;
+    }
+
+    let half_width = width / 2.0;
+
+    let delta = p2 - p1;
+    let length = delta.magnitude();
+
+    // Zero length lines don't have a direction, so pick one arbitrarily for
+    // the caps to be built around
+    let direction = if length > 0.0 {
+        delta * (1.0 / length)
+    } else if cap == LineCap::Butt {
+        return None;
+    } else {
+        Vec2::new(1.0, 0.0)
+    };
+
+    // Perpendicular to the line, pointing to its left
+    let normal = direction.perp() * half_width;
+
+    let outline = match cap {
+        LineCap::Butt => vec![p1 + normal, p2 + normal, p2 - normal, p1 - normal],
+        LineCap::Square => {
+            let extension = direction * half_width;
+
+            vec![
+                p1 + normal - extension,
+                p2 + normal + extension,
+                p2 - normal + extension,
+                p1 - normal - extension,
+            ]
+        }
+        LineCap::Round => {
+            use std::f32::consts::PI;
+
+            // Enough segments that the error from the true circle stays well
+            // under a pixel
+            let segments = ((PI * half_width).ceil() as usize).max(4);
+
+            let mut outline = Vec::with_capacity(2 * segments + 2);
+
+            // Half circle around each endpoint, sweeping from one side of the
+            // line to the other
+            for (center, start) in [(p2, normal), (p1, normal * -1.0)] {
+                for i in 0..=segments {
+                    let angle = PI * i as f32 / segments as f32;
+                    let (sin, cos) = angle.sin_cos();
+
+                    outline.push(Vec2::new(
+                        center.x + start.x * cos - start.y * sin,
+                        center.y + start.x * sin + start.y * cos,
+                    ));
+                }
+            }
+
+            outline
+        }
+    };
+
+    Some(outline)
+}
@@ -0,0 +1,81 @@
+use crate::{rgba, Canvas, Color};
+
+impl Canvas {
+    /// Downsamples a supersampled canvas to its display resolution by averaging
+    /// every block of pixels (box filtering)
+    ///
+    /// Canvases created with [`Canvas::new`] resolve to a plain copy
+    ///
+    /// ```
+    /// use farba::{Canvas, Color, RGBAColor};
+    ///
+    /// let mut canvas = Canvas::new_supersampled(10, 10, 2);
+    /// assert_eq!(canvas.get_width(), 20);
+    ///
+    /// // Cover half of one display pixel
+    /// canvas.fill(RGBAColor::BLACK);
+    /// canvas.rect(0, 0, 1, 2, RGBAColor::WHITE);
+    ///
+    /// let resolved = canvas.resolve();
+    /// assert_eq!(resolved.get_width(), 10);
+    /// assert_eq!(resolved.get_pixel(0, 0).red(), 128);
+    /// ```
+    pub fn resolve(&self) -> Canvas {
+        let factor = self.get_supersampling();
+
+        let mut target = Canvas::new(self.get_width() / factor, self.get_height() / factor);
+        target.set_premultiplied(self.is_premultiplied());
+        self.resolve_into(&mut target);
+
+        target
+    }
+
+    /// Downsamples the canvas like [`Canvas::resolve`], but into an existing
+    /// display resolution canvas so it can be reused between frames
+    ///
+    /// Panics if `target` is not the display resolution of this canvas
+    pub fn resolve_into(&self, target: &mut Canvas) {
+        let factor = self.get_supersampling();
+
+        if target.get_width() * factor != self.get_width()
+            || target.get_height() * factor != self.get_height()
+        {
+            panic!("Resolve target was not the display resolution of the canvas")
+        }
+
+        for y in 0..target.get_height() {
+            for x in 0..target.get_width() {
+                let (mut red, mut green, mut blue, mut alpha) = (0u32, 0u32, 0u32, 0u32);
+
+                // Channels are weighted by alpha so that transparent samples don't
+                // darken the result
+                for sy in y * factor..(y + 1) * factor {
+                    for sx in x * factor..(x + 1) * factor {
+                        let sample = self.straight_pixel(sx as i32, sy as i32);
+                        let weight = sample.alpha() as u32;
+
+                        red += sample.red() as u32 * weight;
+                        green += sample.green() as u32 * weight;
+                        blue += sample.blue() as u32 * weight;
+                        alpha += weight;
+                    }
+                }
+
+                let samples = (factor * factor) as u32;
+
+                let resolved = match alpha {
+                    // A block that is entirely transparent has no color to average
+                    0 => 0,
+                    _ => rgba!(
+                        (red + alpha / 2) / alpha,
+                        (green + alpha / 2) / alpha,
+                        (blue + alpha / 2) / alpha,
+                        (alpha + samples / 2) / samples
+                    ),
+                };
+
+                target.set_pixel_unchecked(x as i32, y as i32, resolved);
+            }
+        }
+    }
+}
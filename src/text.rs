@@ -0,0 +1,625 @@
+use crate::{BitmapFont, Canvas, Color, Mat3, Path, RGBAColor};
+
+/// Which part of each line of text is placed on the position it is laid out at
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum TextAlign {
+    #[default]
+    Left,
+    Center,
+    Right,
+}
+
+/// Which part of a block of text is placed on the position it is laid out at
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum TextAnchor {
+    #[default]
+    Top,
+    Middle,
+    Bottom,
+}
+
+/// How [`BitmapFont::layout`] arranges text
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct LayoutOptions {
+    pub align: TextAlign,
+    pub anchor: TextAnchor,
+    /// Width in pixels which lines are wrapped to fit within, or `None` to only
+    /// break lines at newlines
+    pub max_width: Option<i32>,
+}
+
+/// A single line of a [`TextLayout`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextLine {
+    pub text: String,
+    /// Position of the top left corner of the line
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+}
+
+/// Text broken into lines and positioned by [`BitmapFont::layout`], ready to be
+/// drawn with [`Canvas::draw_text_layout`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextLayout {
+    pub lines: Vec<TextLine>,
+    /// Smallest `(x, y, width, height)` rectangle containing every line
+    pub bounds: (i32, i32, i32, i32),
+    /// Scale the text was laid out at
+    pub size: u32,
+}
+
+/// Effect drawn along with the glyphs by [`Canvas::text_styled`]
+#[derive(Debug, Default, Clone)]
+pub enum TextEffect {
+    #[default]
+    None,
+    /// Thickens every glyph by another pixel to the right
+    Bold,
+    /// Draws the text again behind itself, moved by `offset`
+    Shadow {
+        offset: (i32, i32),
+        color: RGBAColor,
+    },
+    /// Surrounds every glyph with a one pixel border
+    Outline { color: RGBAColor },
+}
+
+/// How [`Canvas::text_styled`] draws text
+#[derive(Debug, Clone)]
+pub struct TextStyle {
+    /// How many pixels each pixel of the font covers, which may be fractional
+    pub scale: f32,
+    /// Extra pixels between consecutive characters
+    pub letter_spacing: f32,
+    /// Extra pixels between consecutive lines
+    pub line_spacing: f32,
+    pub effect: TextEffect,
+}
+
+impl Default for TextStyle {
+    fn default() -> Self {
+        Self {
+            scale: 1.0,
+            letter_spacing: 0.0,
+            line_spacing: 0.0,
+            effect: TextEffect::None,
+        }
+    }
+}
+
+impl BitmapFont {
+    /// Breaks `text` into lines and positions them around `(x, y)` according to
+    /// `options`, with every pixel of the font scaled up `size` times
+    ///
+    /// Text is always broken at newlines. When wrapping to a maximum width,
+    /// lines are also broken between words, and words too long to fit on a line
+    /// of their own are broken between characters. The spaces around the breaks
+    /// are left out, and runs of spaces between words on the same line are kept
+    /// as a single space.
+    ///
+    /// ```
+    /// use farba::{BitmapFont, Canvas, LayoutOptions, RGBAColor, TextAlign, TextAnchor};
+    ///
+    /// let font = BitmapFont::builtin();
+    /// let options = LayoutOptions {
+    ///     align: TextAlign::Center,
+    ///     anchor: TextAnchor::Middle,
+    ///     max_width: Some(80),
+    /// };
+    ///
+    /// let layout = font.layout("the quick brown fox", 50, 50, 1, &options);
+    ///
+    /// let lines: Vec<&str> = layout.lines.iter().map(|line| line.text.as_str()).collect();
+    /// assert_eq!(lines, ["the quick", "brown fox"]);
+    ///
+    /// // Both lines are 72 pixels wide and centered on the position
+    /// assert_eq!(layout.bounds, (14, 42, 72, 16));
+    ///
+    /// let mut canvas = Canvas::new(100, 100);
+    /// canvas.draw_text_layout(font, &layout, RGBAColor::WHITE);
+    /// ```
+    pub fn layout(
+        &self,
+        text: &str,
+        x: i32,
+        y: i32,
+        size: u32,
+        options: &LayoutOptions,
+    ) -> TextLayout {
+        let mut lines = Vec::new();
+
+        for paragraph in text.lines() {
+            match options.max_width {
+                Some(max_width) => self.wrap(paragraph, size, max_width, &mut lines),
+                None => lines.push(paragraph.to_owned()),
+            }
+        }
+
+        let line_height = self.get_line_height() * size as i32;
+        let height = lines.len() as i32 * line_height;
+
+        let top = match options.anchor {
+            TextAnchor::Top => y,
+            TextAnchor::Middle => y - height / 2,
+            TextAnchor::Bottom => y - height,
+        };
+
+        let lines: Vec<TextLine> = lines
+            .into_iter()
+            .enumerate()
+            .map(|(i, text)| {
+                let width = self.text_width(&text, size);
+
+                let left = match options.align {
+                    TextAlign::Left => x,
+                    TextAlign::Center => x - width / 2,
+                    TextAlign::Right => x - width,
+                };
+
+                TextLine {
+                    text,
+                    x: left,
+                    y: top + i as i32 * line_height,
+                    width,
+                }
+            })
+            .collect();
+
+        let left = lines.iter().map(|line| line.x).min().unwrap_or(x);
+        let right = lines
+            .iter()
+            .map(|line| line.x + line.width)
+            .max()
+            .unwrap_or(x);
+
+        TextLayout {
+            lines,
+            bounds: (left, top, right - left, height),
+            size,
+        }
+    }
+
+    /// Breaks a `paragraph` without newlines into lines no wider than
+    /// `max_width`, adding them to `lines`
+    fn wrap(&self, paragraph: &str, size: u32, max_width: i32, lines: &mut Vec<String>) {
+        let fits = |text: &str| self.text_width(text, size) <= max_width;
+
+        let mut line = String::new();
+
+        for word in paragraph.split_whitespace() {
+            if !line.is_empty() {
+                let joined = format!("{line} {word}");
+
+                if fits(&joined) {
+                    line = joined;
+                    continue;
+                }
+
+                lines.push(std::mem::take(&mut line));
+            }
+
+            // The word starts a new line, and is split up if it doesn't fit on
+            // one by itself
+            for character in word.chars() {
+                line.push(character);
+
+                if line.chars().count() > 1 && !fits(&line) {
+                    line.pop();
+                    lines.push(std::mem::replace(&mut line, character.to_string()));
+                }
+            }
+        }
+
+        // Blank paragraphs still take up a line
+        if !line.is_empty() || paragraph.trim().is_empty() {
+            lines.push(line);
+        }
+    }
+}
+
+impl Canvas {
+    /// Draws `text` with its top left corner at `(x, y)` using the built-in
+    /// monospaced 8x8 font, with every pixel of the font scaled up to a `size`
+    /// by `size` square
+    ///
+    /// This is [`Canvas::text_with_font`] with [`BitmapFont::builtin`], which
+    /// covers printable ASCII.
+    ///
+    /// ```
+    /// use farba::{Canvas, RGBAColor};
+    ///
+    /// let mut canvas = Canvas::new(100, 30);
+    /// canvas.text(2, 2, "Hello\nWorld", 1, RGBAColor::WHITE);
+    ///
+    /// // The left stroke of the H, and the left stroke of the W one line down
+    /// assert_eq!(*canvas.get_pixel(2, 2), RGBAColor::WHITE.into());
+    /// assert_eq!(*canvas.get_pixel(2, 10), RGBAColor::WHITE.into());
+    ///
+    /// // The gap in the middle of the H
+    /// assert_eq!(*canvas.get_pixel(4, 2), 0);
+    /// ```
+    pub fn text<C: Color>(&mut self, x: i32, y: i32, text: &str, size: u32, color: C) {
+        self.text_with_font(BitmapFont::builtin(), x, y, text, size, color);
+    }
+
+    /// Draws `text` with its top left corner at `(x, y)` using `font`, with
+    /// every pixel of the font scaled up to a `size` by `size` square
+    ///
+    /// Each line starts at `x` and is placed one line height of the font below
+    /// the one before it. Characters the font doesn't have are drawn as its `?`
+    /// glyph, or skipped if it doesn't have that either. The glyphs are drawn
+    /// like [`Canvas::rect`], so they go through the current transform.
+    pub fn text_with_font<C: Color>(
+        &mut self,
+        font: &BitmapFont,
+        x: i32,
+        y: i32,
+        text: &str,
+        size: u32,
+        color: C,
+    ) {
+        let color = color.pack();
+        let size = size as i32;
+
+        if size <= 0 {
+            return;
+        }
+
+        for (line_index, line) in text.lines().enumerate() {
+            let top = y + line_index as i32 * font.get_line_height() * size;
+            let mut pen = x;
+
+            for character in line.chars() {
+                let Some(glyph) = font.glyph(character) else {
+                    continue;
+                };
+
+                let left = pen + glyph.left * size;
+
+                for (row, pixels) in glyph.pixels.chunks(glyph.width.max(1)).enumerate() {
+                    let row_y = top + (glyph.top + row as i32) * size;
+
+                    // Each run of set pixels along a row is drawn as a single rect
+                    let mut column = 0;
+                    while column < pixels.len() {
+                        if !pixels[column] {
+                            column += 1;
+                            continue;
+                        }
+
+                        let start = column;
+                        while column < pixels.len() && pixels[column] {
+                            column += 1;
+                        }
+
+                        let run_x = left + start as i32 * size;
+                        let run_width = (column - start) as i32 * size;
+
+                        self.rect(run_x, row_y, run_width, size, color);
+                    }
+                }
+
+                pen += glyph.advance * size;
+            }
+        }
+    }
+
+    /// Draws every line of a `layout` made by [`BitmapFont::layout`] with the
+    /// same `font`
+    pub fn draw_text_layout<C: Color>(&mut self, font: &BitmapFont, layout: &TextLayout, color: C) {
+        let color = color.pack();
+
+        for line in &layout.lines {
+            self.text_with_font(font, line.x, line.y, &line.text, layout.size, color);
+        }
+    }
+
+    /// Draws `text` with its top left corner at `(x, y)` using `font`, scaled
+    /// and spaced out according to `style` and drawn with its effect
+    ///
+    /// Pixels of the font scaled by a fraction are rounded to whole pixels of
+    /// the canvas. Overlapping glyphs and their effects still only draw each
+    /// pixel once per color, so translucent text doesn't get darker where they
+    /// overlap. Like [`Canvas::text_with_font`], the text goes through the
+    /// current transform.
+    ///
+    /// ```
+    /// use farba::{BitmapFont, Canvas, RGBAColor, TextEffect, TextStyle};
+    ///
+    /// let style = TextStyle {
+    ///     scale: 1.5,
+    ///     effect: TextEffect::Outline { color: RGBAColor::BLACK },
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let mut canvas = Canvas::new(40, 20);
+    /// canvas.text_styled(BitmapFont::builtin(), 1, 1, "Hi", &style, RGBAColor::WHITE);
+    ///
+    /// // The left stroke of the H is 3 pixels wide, with the outline beside it
+    /// assert_eq!(*canvas.get_pixel(0, 1), RGBAColor::BLACK.into());
+    /// assert_eq!(*canvas.get_pixel(1, 1), RGBAColor::WHITE.into());
+    /// assert_eq!(*canvas.get_pixel(3, 1), RGBAColor::WHITE.into());
+    /// assert_eq!(*canvas.get_pixel(4, 1), RGBAColor::BLACK.into());
+    /// ```
+    pub fn text_styled<C: Color>(
+        &mut self,
+        font: &BitmapFont,
+        x: i32,
+        y: i32,
+        text: &str,
+        style: &TextStyle,
+        color: C,
+    ) {
+        let scale = style.scale;
+
+        if scale.is_nan() || scale <= 0.0 {
+            return;
+        }
+
+        // Scaled pixels are drawn between the rounded positions of their edges,
+        // so neighbouring pixels always meet without gaps or overlaps
+        let edge = |origin: f32, offset: i32| (origin + offset as f32 * scale).round() as i32;
+
+        // `(left, top, right, bottom)` of every scaled pixel, exclusive of the
+        // right and bottom edges
+        let mut pixels = Vec::new();
+
+        let line_advance = font.get_line_height() as f32 * scale + style.line_spacing;
+
+        for (line_index, line) in text.lines().enumerate() {
+            let top = line_index as f32 * line_advance;
+            let mut pen = 0.0;
+
+            for character in line.chars() {
+                let Some(glyph) = font.glyph(character) else {
+                    continue;
+                };
+
+                let (glyph_x, glyph_y) = (
+                    pen + glyph.left as f32 * scale,
+                    top + glyph.top as f32 * scale,
+                );
+
+                for (row, bits) in glyph.pixels.chunks(glyph.width.max(1)).enumerate() {
+                    let row = row as i32;
+
+                    for (column, _) in bits.iter().enumerate().filter(|(_, &set)| set) {
+                        let column = column as i32;
+
+                        pixels.push((
+                            edge(glyph_x, column),
+                            edge(glyph_y, row),
+                            edge(glyph_x, column + 1),
+                            edge(glyph_y, row + 1),
+                        ));
+                    }
+                }
+
+                pen += glyph.advance as f32 * scale + style.letter_spacing;
+            }
+        }
+
+        let Some(mut mask) = TextMask::new(&pixels) else {
+            return;
+        };
+
+        let color = color.pack();
+
+        match &style.effect {
+            TextEffect::None => {}
+            TextEffect::Bold => mask = mask.bolded(),
+            TextEffect::Shadow { offset, color } => {
+                mask.draw(self, x + offset.0, y + offset.1, color.pack());
+            }
+            TextEffect::Outline { color } => {
+                mask.outline().draw(self, x, y, color.pack());
+            }
+        }
+
+        mask.draw(self, x, y, color);
+    }
+
+    /// Draws `text` along `path` using `font`, with every pixel of the font
+    /// scaled up to a `size` by `size` square
+    ///
+    /// The text starts `offset` pixels along the path and is drawn as a single
+    /// line, with the bottom of the line resting on the path. Each glyph is
+    /// rotated to follow the direction of the path under its center, and glyphs
+    /// which would be centered past the end of the path are left out. Subpaths
+    /// are followed one after another, including the segment closing each closed
+    /// subpath.
+    ///
+    /// ```
+    /// use farba::{Canvas, Path, RGBAColor, Vec2};
+    ///
+    /// // An arch over the canvas
+    /// let mut path = Path::new();
+    /// path.move_to(Vec2::new(10.0, 90.0))
+    ///     .quad_to(Vec2::new(100.0, -40.0), Vec2::new(190.0, 90.0));
+    ///
+    /// let mut canvas = Canvas::new(200, 100);
+    /// canvas.text_on_path(&path, "Over the rainbow", 0.0, 1, RGBAColor::WHITE);
+    /// ```
+    pub fn text_on_path<C: Color>(
+        &mut self,
+        path: &Path,
+        text: &str,
+        offset: f32,
+        size: u32,
+        color: C,
+    ) {
+        self.text_on_path_with_font(BitmapFont::builtin(), path, text, offset, size, color);
+    }
+
+    /// Draws `text` along `path` like [`Canvas::text_on_path`], using `font`
+    pub fn text_on_path_with_font<C: Color>(
+        &mut self,
+        font: &BitmapFont,
+        path: &Path,
+        text: &str,
+        offset: f32,
+        size: u32,
+        color: C,
+    ) {
+        let color = color.pack();
+
+        // Every segment of the path along with how far along the path it starts
+        let mut segments = Vec::new();
+        let mut length = 0.0;
+
+        for (mut points, closed) in path.flatten() {
+            if closed {
+                points.push(points[0]);
+            }
+
+            for pair in points.windows(2) {
+                let delta = pair[1] - pair[0];
+                let segment_length = delta.magnitude();
+
+                if segment_length > 0.0 {
+                    segments.push((pair[0], delta, length, segment_length));
+                    length += segment_length;
+                }
+            }
+        }
+
+        let line_height = (font.get_line_height() * size as i32) as f32;
+        let mut pen = offset;
+
+        for character in text.chars() {
+            let Some(glyph) = font.glyph(character) else {
+                continue;
+            };
+
+            let advance = (glyph.advance * size as i32) as f32;
+            let center = pen + advance / 2.0;
+            pen += advance;
+
+            if center < 0.0 {
+                continue;
+            }
+
+            let Some(&(start, delta, segment_start, segment_length)) = segments
+                .iter()
+                .find(|(_, _, start, length)| center <= start + length)
+            else {
+                break;
+            };
+
+            let t = (center - segment_start) / segment_length;
+            let point = start + delta * t;
+
+            // The glyph is drawn around the origin, with the center of the bottom
+            // of its line on the path
+            self.push_transform(Mat3::IDENTITY);
+            self.translate(point.x, point.y);
+            self.rotate(delta.y.atan2(delta.x));
+            self.translate(-advance / 2.0, -line_height);
+
+            let mut buffer = [0; 4];
+            self.text_with_font(font, 0, 0, character.encode_utf8(&mut buffer), size, color);
+
+            self.pop_transform();
+        }
+    }
+}
+
+/// Which pixels are covered by a piece of text, in a grid with a one pixel
+/// margin around it for effects to grow into
+struct TextMask {
+    left: i32,
+    top: i32,
+    width: usize,
+    height: usize,
+    covered: Vec<bool>,
+}
+
+impl TextMask {
+    /// Creates a mask covering every one of `rects` given as
+    /// `(left, top, right, bottom)`, exclusive of the right and bottom edges
+    fn new(rects: &[(i32, i32, i32, i32)]) -> Option<Self> {
+        let left = rects.iter().map(|rect| rect.0).min()? - 1;
+        let top = rects.iter().map(|rect| rect.1).min()? - 1;
+        let right = rects.iter().map(|rect| rect.2).max()? + 1;
+        let bottom = rects.iter().map(|rect| rect.3).max()? + 1;
+
+        let width = (right - left) as usize;
+        let height = (bottom - top) as usize;
+
+        let mut mask = Self {
+            left,
+            top,
+            width,
+            height,
+            covered: vec![false; width * height],
+        };
+
+        for &(x1, y1, x2, y2) in rects {
+            for y in y1..y2 {
+                for x in x1..x2 {
+                    let index = mask.index(x - left, y - top);
+                    mask.covered[index] = true;
+                }
+            }
+        }
+
+        Some(mask)
+    }
+
+    fn index(&self, x: i32, y: i32) -> usize {
+        y as usize * self.width + x as usize
+    }
+
+    fn get(&self, x: i32, y: i32) -> bool {
+        let in_bounds = x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height;
+
+        in_bounds && self.covered[self.index(x, y)]
+    }
+
+    /// Builds a new mask of the same size from whether each pixel should be
+    /// covered
+    fn map<F: Fn(i32, i32) -> bool>(&self, covered: F) -> Self {
+        let covered = (0..self.width * self.height)
+            .map(|i| covered((i % self.width) as i32, (i / self.width) as i32))
+            .collect();
+
+        Self { covered, ..*self }
+    }
+
+    /// The mask with every covered pixel also covering the one to its right
+    fn bolded(&self) -> Self {
+        self.map(|x, y| self.get(x, y) || self.get(x - 1, y))
+    }
+
+    /// The uncovered pixels which touch a covered one, including diagonally
+    fn outline(&self) -> Self {
+        self.map(|x, y| {
+            !self.get(x, y) && (-1..=1).any(|dy| (-1..=1).any(|dx| self.get(x + dx, y + dy)))
+        })
+    }
+
+    /// Draws the covered pixels in `color`, placing the origin of the text at
+    /// `(x, y)`
+    fn draw(&self, canvas: &mut Canvas, x: i32, y: i32, color: u32) {
+        for row in 0..self.height as i32 {
+            let row_y = y + self.top + row;
+
+            // Each run of covered pixels along a row is drawn as a single rect
+            let mut column = 0;
+            while column < self.width as i32 {
+                if !self.get(column, row) {
+                    column += 1;
+                    continue;
+                }
+
+                let start = column;
+                while self.get(column, row) {
+                    column += 1;
+                }
+
+                canvas.rect(x + self.left + start, row_y, column - start, 1, color);
+            }
+        }
+    }
+}
@@ -0,0 +1,211 @@
+use crate::{Canvas, Mat3, Vec2, Vec3};
+
+/// The current 2D transform of a canvas, along with the transforms saved by
+/// [`Canvas::push_transform`]
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct TransformStack {
+    current: Mat3,
+    inverse: Mat3,
+    /// Whether `current` is the identity, so that untransformed drawing can
+    /// skip the work of mapping points
+    identity: bool,
+    saved: Vec<Mat3>,
+}
+
+impl Default for TransformStack {
+    fn default() -> Self {
+        Self {
+            current: Mat3::IDENTITY,
+            inverse: Mat3::IDENTITY,
+            identity: true,
+            saved: Vec::new(),
+        }
+    }
+}
+
+/// Inverts the 2D affine `transform`, giving a matrix which collapses every
+/// point onto the origin if it can't be inverted
+pub(crate) fn affine_inverse(transform: &Mat3) -> Mat3 {
+    let Mat3 {
+        a, b, c, d, e, f, ..
+    } = *transform;
+
+    let determinant = a * e - b * d;
+
+    if determinant == 0.0 {
+        return Mat3 {
+            i: 1.0,
+            ..Mat3::new(0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0)
+        };
+    }
+
+    let inverse_determinant = 1.0 / determinant;
+
+    Mat3::new(
+        e * inverse_determinant,
+        -b * inverse_determinant,
+        (b * f - c * e) * inverse_determinant,
+        -d * inverse_determinant,
+        a * inverse_determinant,
+        (c * d - a * f) * inverse_determinant,
+        0.0,
+        0.0,
+        1.0,
+    )
+}
+
+/// Number of segments needed for a polygon to stay within a quarter of a pixel
+/// of a circle with the given `radius`
+pub(crate) fn circle_segments(radius: f32) -> usize {
+    use std::f32::consts::TAU;
+
+    ((TAU * (2.0 * radius.max(0.0)).sqrt()).ceil() as usize).max(8)
+}
+
+/// Points along the ellipse around `center` with radii `radius_x` and
+/// `radius_y`, rotated clockwise by `rotation`, from `start` to `end` (radians,
+/// clockwise on screen from the positive x axis) in `segments` steps
+pub(crate) fn ellipse_points(
+    center: Vec2,
+    radius_x: f32,
+    radius_y: f32,
+    rotation: f32,
+    (start, end): (f32, f32),
+    segments: usize,
+) -> Vec<Vec2> {
+    let (sin, cos) = rotation.sin_cos();
+
+    (0..=segments)
+        .map(|i| {
+            let angle = start + (end - start) * i as f32 / segments as f32;
+            let (x, y) = (radius_x * angle.cos(), radius_y * angle.sin());
+
+            Vec2::new(center.x + x * cos - y * sin, center.y + x * sin + y * cos)
+        })
+        .collect()
+}
+
+impl Canvas {
+    /// Saves the current transform and then applies `transform` on top of it,
+    /// until the matching call to [`Canvas::pop_transform`]
+    ///
+    /// Transforms are 2D affine matrices which map `(x, y, 1)` from the
+    /// coordinates given to draw calls onto the canvas. Every shape, line and
+    /// image is drawn through the current transform, along with the paint used
+    /// to fill it. Operations on whole pixels, such as [`Canvas::fill`],
+    /// [`Canvas::set_pixel`] and [`Canvas::blit`], ignore it.
+    ///
+    /// ```
+    /// use farba::{Canvas, Mat3, RGBAColor};
+    ///
+    /// let mut canvas = Canvas::new(100, 100);
+    ///
+    /// // Double the size of everything drawn
+    /// canvas.push_transform(Mat3::scale_2d(2.0, 2.0));
+    /// canvas.rect(10, 10, 10, 10, RGBAColor::RED);
+    /// canvas.pop_transform();
+    ///
+    /// assert_eq!(*canvas.get_pixel(39, 39), RGBAColor::RED.into());
+    /// assert_eq!(*canvas.get_pixel(40, 40), 0);
+    /// ```
+    pub fn push_transform(&mut self, transform: Mat3) {
+        let current = self.transforms.current;
+
+        self.transforms.saved.push(current);
+        self.set_transform(current * transform);
+    }
+
+    /// Restores the transform that was current before the last call to
+    /// [`Canvas::push_transform`], or does nothing if there isn't one
+    pub fn pop_transform(&mut self) {
+        if let Some(transform) = self.transforms.saved.pop() {
+            self.set_transform(transform);
+        }
+    }
+
+    /// Moves everything drawn after this by `(x, y)`, within the current transform
+    pub fn translate(&mut self, x: f32, y: f32) {
+        self.set_transform(self.transforms.current * Mat3::translate_2d(x, y));
+    }
+
+    /// Rotates everything drawn after this clockwise (on screen) by `angle`
+    /// radians around the origin, within the current transform
+    pub fn rotate(&mut self, angle: f32) {
+        self.set_transform(self.transforms.current * Mat3::rotate_2d(angle));
+    }
+
+    /// Stretches everything drawn after this by `x` horizontally and `y`
+    /// vertically away from the origin, within the current transform
+    pub fn scale(&mut self, x: f32, y: f32) {
+        self.set_transform(self.transforms.current * Mat3::scale_2d(x, y));
+    }
+
+    pub fn get_transform(&self) -> Mat3 {
+        self.transforms.current
+    }
+
+    /// Replaces the current transform, leaving any saved transforms untouched
+    pub fn set_transform(&mut self, transform: Mat3) {
+        self.transforms.current = transform;
+        self.transforms.inverse = affine_inverse(&transform);
+        self.transforms.identity = transform == Mat3::IDENTITY;
+    }
+
+    /// Replaces the current transform with one that leaves every point where it is
+    pub fn reset_transform(&mut self) {
+        self.set_transform(Mat3::IDENTITY);
+    }
+
+    /// Checks whether draw calls need to be transformed at all
+    #[inline]
+    pub(crate) fn has_transform(&self) -> bool {
+        !self.transforms.identity
+    }
+
+    /// Maps a point given to a draw call onto the canvas
+    pub(crate) fn transform_point(&self, point: Vec2) -> Vec2 {
+        self.transforms.current.transform_point(point)
+    }
+
+    /// Maps the x and y of a 3D vertex onto the canvas, leaving its depth alone
+    pub(crate) fn transform_vertex(&self, vertex: Vec3) -> Vec3 {
+        let point = self.transform_point(Vec2::new(vertex.x, vertex.y));
+
+        Vec3::new(point.x, point.y, vertex.z)
+    }
+
+    /// Maps a point on the canvas back into the coordinates of draw calls
+    pub(crate) fn untransform_point(&self, point: Vec2) -> Vec2 {
+        self.transforms.inverse.transform_point(point)
+    }
+
+    pub(crate) fn get_inverse_transform(&self) -> Mat3 {
+        self.transforms.inverse
+    }
+
+    /// The most that a length can be stretched by the current transform
+    pub(crate) fn transform_stretch(&self) -> f32 {
+        let Mat3 { a, b, d, e, .. } = self.transforms.current;
+
+        (a * a + d * d).sqrt().max((b * b + e * e).sqrt())
+    }
+
+    /// Points along an ellipse like `ellipse_points`, using enough segments for
+    /// the outline to still look smooth once it has been transformed
+    pub(crate) fn ellipse_outline_points(
+        &self,
+        center: Vec2,
+        radius_x: f32,
+        radius_y: f32,
+        rotation: f32,
+        (start, end): (f32, f32),
+    ) -> Vec<Vec2> {
+        use std::f32::consts::TAU;
+
+        let radius = radius_x.max(radius_y) * self.transform_stretch();
+        let fraction = ((end - start).abs() / TAU).min(1.0);
+        let segments = ((circle_segments(radius) as f32 * fraction).ceil() as usize).max(1);
+
+        ellipse_points(center, radius_x, radius_y, rotation, (start, end), segments)
+    }
+}
@@ -0,0 +1,75 @@
+use ab_glyph::{Font, Glyph, GlyphId, PxScale, ScaleFont};
+
+use crate::{Canvas, Color, Vec2};
+
+pub use ab_glyph::{FontArc, FontRef, FontVec, InvalidFont};
+
+impl Canvas {
+    /// Draws `text` with a TrueType or OpenType `font`, with the top left corner
+    /// of the first line at `pos`
+    ///
+    /// `size` is the height of the font in pixels, and may be fractional. The
+    /// outlines of the glyphs are rasterized with exact coverage, so their edges
+    /// are smoothly blended into the canvas. Each line is placed below the one
+    /// before it by the line height of the font, and pairs of characters are
+    /// kerned as the font describes.
+    ///
+    /// Glyphs are drawn in the pixels of the canvas, ignoring the current
+    /// transform.
+    pub fn text_ttf<F: Font, C: Color>(
+        &mut self,
+        font: &F,
+        size: f32,
+        pos: Vec2,
+        text: &str,
+        color: C,
+    ) {
+        if size <= 0.0 {
+            return;
+        }
+
+        let color = color.pack();
+        let font = font.as_scaled(PxScale::from(size));
+
+        let line_height = font.height() + font.line_gap();
+
+        for (line_index, line) in text.lines().enumerate() {
+            let baseline = pos.y + line_index as f32 * line_height + font.ascent();
+
+            let mut pen = pos.x;
+            let mut previous: Option<GlyphId> = None;
+
+            for character in line.chars() {
+                let id = font.glyph_id(character);
+
+                if let Some(previous) = previous {
+                    pen += font.kern(previous, id);
+                }
+
+                let glyph = Glyph {
+                    id,
+                    scale: font.scale(),
+                    position: ab_glyph::point(pen, baseline),
+                };
+
+                pen += font.h_advance(id);
+                previous = Some(id);
+
+                let Some(outline) = font.outline_glyph(glyph) else {
+                    continue;
+                };
+
+                let bounds = outline.px_bounds();
+                let (left, top) = (bounds.min.x as i32, bounds.min.y as i32);
+
+                outline.draw(|x, y, coverage| {
+                    let (x, y) = (left + x as i32, top + y as i32);
+
+                    if coverage > 0.0 && self.in_bounds(x, y) {
+                        self.cover_pixel(x, y, color, coverage.min(1.0));
+                    }
+                });
+            }
+        }
+    }
+}